@@ -201,6 +201,51 @@ fn snark_batch_verify(c: &mut Criterion) {
     });
 }
 
+fn snark_aggregate_verify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("snark_aggregate_verify");
+    let rng = &mut TestRng::default();
+
+    let num_constraints = 100;
+    let num_variables = 25;
+    let mul_depth = 1;
+
+    let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(1000, 1000, 100).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let num_proofs = 10;
+
+    let mut vks_and_inputs = Vec::with_capacity(num_proofs);
+    let mut proofs = Vec::with_capacity(num_proofs);
+    for _ in 0..num_proofs {
+        let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+        let (pk, vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        proofs.push(VarunaInst::prove(universal_prover, &fs_parameters, &pk, &circuit, rng).unwrap());
+        vks_and_inputs.push((vk, vec![public_inputs]));
+    }
+    let instances = vks_and_inputs
+        .iter()
+        .zip(&proofs)
+        .map(|((vk, inputs), proof)| (BTreeMap::from([(vk, inputs.as_slice())]), proof))
+        .collect::<Vec<_>>();
+
+    group.bench_function(format!("individual_{num_proofs}"), |b| {
+        b.iter(|| {
+            for (keys_to_inputs, proof) in &instances {
+                assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, keys_to_inputs, proof).unwrap());
+            }
+        })
+    });
+    group.bench_function(format!("aggregate_{num_proofs}"), |b| {
+        b.iter(|| {
+            assert!(VarunaInst::verify_aggregate(universal_verifier, &fs_parameters, &instances, rng).unwrap());
+        })
+    });
+    group.finish();
+}
+
 fn snark_vk_serialize(c: &mut Criterion) {
     use snarkvm_utilities::serialize::Compress;
     let mut group = c.benchmark_group("snark_vk_serialize");
@@ -316,7 +361,7 @@ fn snark_certificate_verify(c: &mut Criterion) {
 criterion_group! {
     name = varuna_snark;
     config = Criterion::default().measurement_time(Duration::from_secs(10));
-    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_batch_prove, snark_batch_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
+    targets = snark_universal_setup, snark_circuit_setup, snark_prove, snark_verify, snark_batch_prove, snark_batch_verify, snark_aggregate_verify, snark_vk_serialize, snark_vk_deserialize, snark_certificate_prove, snark_certificate_verify,
 }
 
 criterion_main!(varuna_snark);
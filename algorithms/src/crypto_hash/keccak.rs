@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AlgebraicSponge;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+use snarkvm_utilities::ToBytes;
+
+use core::marker::PhantomData;
+use smallvec::SmallVec;
+use tiny_keccak::{Hasher, Keccak};
+
+/// The domain separator for folding the absorbed bytes into the digest.
+const ABSORB_DOMAIN: u8 = 0;
+/// The domain separator for expanding the digest into squeezed bytes.
+const SQUEEZE_DOMAIN: u8 = 1;
+
+/// The number of extra bytes sampled per field element, so that reducing them modulo the field is statistically close to uniform.
+const NUM_EXTRA_BYTES: usize = 16;
+/// The number of bytes sampled per short field element (168 bits), as in `PoseidonSponge`.
+const NUM_SHORT_BYTES: usize = 21;
+
+/// A byte-oriented sponge, which hashes its transcript with Keccak-256.
+///
+/// Unlike `PoseidonSponge`, the transcript is a sequence of bytes rather than of field elements,
+/// which makes it cheap to recompute where field arithmetic is expensive, e.g. in an EVM contract.
+/// Each field element is absorbed as its big-endian encoding, padded to the size of its field's limbs,
+/// and each byte string is absorbed with a big-endian `u64` length prefix.
+///
+/// Absorbed bytes are buffered until the next squeeze, which first folds them into the digest as
+/// `digest := keccak256(0 || digest || bytes)`, and then outputs `keccak256(1 || digest || i)` for `i = 0, 1, ...`,
+/// where `i` is a big-endian `u32`.
+#[derive(Clone, Debug)]
+pub struct KeccakSponge<F: PrimeField, const RATE: usize> {
+    /// The digest of the transcript, up to the last squeeze.
+    digest: [u8; 32],
+    /// The bytes absorbed since the last squeeze.
+    pending: Vec<u8>,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for KeccakSponge<F, RATE> {
+    type Parameters = ();
+
+    fn sample_parameters() -> Self::Parameters {}
+
+    fn new_with_parameters(_parameters: &Self::Parameters) -> Self {
+        Self { digest: [0u8; 32], pending: Vec::new(), _field: PhantomData }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        for element in elements.iter().flat_map(|e| e.to_field_elements().unwrap()) {
+            self.absorb_field_element(element);
+        }
+    }
+
+    /// Takes in field elements.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        for element in elements {
+            self.absorb_field_element(element);
+        }
+    }
+
+    /// Takes in bytes.
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        self.pending.extend_from_slice(&(elements.len() as u64).to_be_bytes());
+        self.pending.extend_from_slice(elements);
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        self.squeeze_field_elements(num, Self::num_bytes_per_element::<F>())
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, Self::num_bytes_per_element::<Target>())
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, NUM_SHORT_BYTES)
+    }
+}
+
+impl<F: PrimeField, const RATE: usize> KeccakSponge<F, RATE> {
+    /// Squeezes the given number of bytes out of the sponge.
+    pub fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        // Fold the pending bytes into the digest.
+        self.digest = keccak256(&[&[ABSORB_DOMAIN], &self.digest[..], &self.pending]);
+        self.pending.clear();
+
+        // Expand the digest into the requested number of bytes.
+        let mut output = Vec::with_capacity(num_bytes + 32);
+        let mut counter = 0u32;
+        while output.len() < num_bytes {
+            output.extend_from_slice(&keccak256(&[&[SQUEEZE_DOMAIN], &self.digest[..], &counter.to_be_bytes()]));
+            counter += 1;
+        }
+        output.truncate(num_bytes);
+        output
+    }
+
+    /// Appends the big-endian encoding of the given field element to the pending bytes.
+    fn absorb_field_element<Target: PrimeField>(&mut self, element: Target) {
+        let mut bytes = element.to_bigint().to_bytes_le().unwrap();
+        bytes.reverse();
+        self.pending.extend_from_slice(&bytes);
+    }
+
+    /// Squeezes `num` field elements, each reduced from `num_bytes` big-endian bytes.
+    fn squeeze_field_elements<Target: PrimeField>(&mut self, num: usize, num_bytes: usize) -> SmallVec<[Target; 10]> {
+        if num == 0 {
+            return SmallVec::new();
+        }
+        let bytes = self.squeeze_bytes(num * num_bytes);
+        bytes.chunks_exact(num_bytes).map(Target::from_bytes_be_mod_order).collect()
+    }
+
+    /// Returns the number of bytes to sample for a uniformly-distributed element of `Target`.
+    fn num_bytes_per_element<Target: PrimeField>() -> usize {
+        (Target::size_in_bits() + 7) / 8 + NUM_EXTRA_BYTES
+    }
+}
+
+/// Returns the Keccak-256 hash of the concatenation of the given byte strings.
+fn keccak256(inputs: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    for input in inputs {
+        hasher.update(input);
+    }
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_utilities::{BigInteger, TestRng, Uniform};
+
+    type Sponge = KeccakSponge<Fq, 2>;
+
+    #[test]
+    fn test_keccak_sponge_is_deterministic() {
+        let rng = &mut TestRng::default();
+        let native = (0..5).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+        let nonnative = (0..5).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let transcript = || {
+            let mut sponge = Sponge::new();
+            sponge.absorb_bytes(b"test");
+            sponge.absorb_native_field_elements(&native);
+            sponge.absorb_nonnative_field_elements(nonnative.iter().copied());
+            let first = sponge.squeeze_nonnative_field_elements::<Fr>(3);
+            let second = sponge.squeeze_short_nonnative_field_elements::<Fr>(3);
+            (first, second, sponge.squeeze_native_field_elements(2))
+        };
+        assert_eq!(transcript(), transcript());
+    }
+
+    #[test]
+    fn test_keccak_sponge_binds_transcript() {
+        // Ensure different transcripts squeeze different challenges.
+        let mut a = Sponge::new();
+        a.absorb_bytes(b"ab");
+        a.absorb_bytes(b"c");
+        let mut b = Sponge::new();
+        b.absorb_bytes(b"a");
+        b.absorb_bytes(b"bc");
+        assert_ne!(a.squeeze_bytes(32), b.squeeze_bytes(32));
+
+        // Ensure consecutive squeezes output different challenges.
+        let mut sponge = Sponge::new();
+        let first = sponge.squeeze_nonnative_field_elements::<Fr>(1);
+        let second = sponge.squeeze_nonnative_field_elements::<Fr>(1);
+        assert_ne!(first, second);
+
+        // Ensure short elements fit in 168 bits.
+        for element in sponge.squeeze_short_nonnative_field_elements::<Fr>(10) {
+            assert!(element.to_bigint().to_biguint().bits() <= 168);
+        }
+    }
+}
@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod keccak;
+pub use keccak::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod scalar_poseidon;
+pub use scalar_poseidon::*;
+
 pub mod sha256;
 pub use sha256::*;
 
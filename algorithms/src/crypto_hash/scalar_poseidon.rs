@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge};
+use snarkvm_fields::{PoseidonParameters, PrimeField, ToConstraintField};
+use snarkvm_utilities::{BigInteger, FromBits, ToBits};
+
+use core::marker::PhantomData;
+use smallvec::SmallVec;
+use std::sync::Arc;
+
+/// The number of bits in a short field element, as in `PoseidonSponge`.
+const NUM_SHORT_BITS: usize = 168;
+
+/// A Poseidon sponge over the field `S`, which implements the Fiat-Shamir transcript over the field `F`.
+///
+/// When `S` is the scalar field of the pairing curve, the transcript is native to circuits over `S`,
+/// which lets a circuit recompute the challenges of a proof, e.g. to verify the proof recursively.
+/// An element of a field whose modulus is at most that of `S` is absorbed as one element of `S`,
+/// and any other element is absorbed as the chunks of its little-endian bits, of `S::size_in_data_bits()` bits each.
+/// A byte string is absorbed as its length, followed by chunks of `S::size_in_data_bits() / 8` little-endian bytes.
+///
+/// A squeezed element of `S` is output as is in a field whose modulus is at least that of `S`,
+/// and as its `Target::size_in_data_bits()` lower bits otherwise. A short element is its 168 lower bits.
+#[derive(Clone, Debug)]
+pub struct ScalarPoseidonSponge<F: PrimeField, S: PrimeField, const RATE: usize> {
+    /// The Poseidon sponge over `S`.
+    sponge: PoseidonSponge<S, RATE, 1>,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, S: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for ScalarPoseidonSponge<F, S, RATE> {
+    type Parameters = Arc<PoseidonParameters<S, RATE, 1>>;
+
+    fn sample_parameters() -> Self::Parameters {
+        <PoseidonSponge<S, RATE, 1> as AlgebraicSponge<S, RATE>>::sample_parameters()
+    }
+
+    fn new_with_parameters(parameters: &Self::Parameters) -> Self {
+        Self { sponge: PoseidonSponge::new_with_parameters(parameters), _field: PhantomData }
+    }
+
+    /// Takes in field elements.
+    fn absorb_native_field_elements<T: ToConstraintField<F>>(&mut self, elements: &[T]) {
+        let elements = elements
+            .iter()
+            .flat_map(|e| e.to_field_elements().unwrap())
+            .flat_map(|e| Self::encode_field_element(&e))
+            .collect::<Vec<_>>();
+        self.sponge.absorb_native_field_elements(&elements);
+    }
+
+    /// Takes in field elements.
+    fn absorb_nonnative_field_elements<Target: PrimeField>(&mut self, elements: impl IntoIterator<Item = Target>) {
+        let elements = elements.into_iter().flat_map(|e| Self::encode_field_element(&e)).collect::<Vec<_>>();
+        self.sponge.absorb_native_field_elements(&elements);
+    }
+
+    /// Takes in bytes.
+    fn absorb_bytes(&mut self, elements: &[u8]) {
+        self.sponge.absorb_native_field_elements(&Self::encode_bytes(elements));
+    }
+
+    fn squeeze_native_field_elements(&mut self, num: usize) -> SmallVec<[F; 10]> {
+        self.squeeze_field_elements(num, Self::num_squeezed_bits::<F>())
+    }
+
+    fn squeeze_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, Self::num_squeezed_bits::<Target>())
+    }
+
+    /// Takes out field elements of 168 bits.
+    fn squeeze_short_nonnative_field_elements<Target: PrimeField>(&mut self, num: usize) -> SmallVec<[Target; 10]> {
+        self.squeeze_field_elements(num, NUM_SHORT_BITS)
+    }
+}
+
+impl<F: PrimeField, S: PrimeField, const RATE: usize> ScalarPoseidonSponge<F, S, RATE> {
+    /// Returns the elements of `S` that the given field element is absorbed as.
+    pub fn encode_field_element<T: PrimeField>(element: &T) -> Vec<S> {
+        element.to_bits_le().chunks(Self::num_bits_per_chunk::<T>()).map(Self::from_bits_le::<S>).collect()
+    }
+
+    /// Returns the number of bits of an element of `T` in each element of `S` that it is absorbed as.
+    pub fn num_bits_per_chunk<T: PrimeField>() -> usize {
+        match T::modulus().to_biguint() <= S::modulus().to_biguint() {
+            true => T::size_in_bits(),
+            false => S::size_in_data_bits(),
+        }
+    }
+
+    /// Returns the elements of `S` that the given bytes are absorbed as.
+    pub fn encode_bytes(bytes: &[u8]) -> Vec<S> {
+        let num_bytes_per_chunk = S::size_in_data_bits() / 8;
+        let mut elements = Vec::with_capacity(1 + bytes.len() / num_bytes_per_chunk + 1);
+        elements.push(S::from(bytes.len() as u64));
+        elements.extend(bytes.chunks(num_bytes_per_chunk).map(S::from_bytes_le_mod_order));
+        elements
+    }
+
+    /// Returns the number of lower bits of an element of `S` that are squeezed as an element of `Target`.
+    pub fn num_squeezed_bits<Target: PrimeField>() -> usize {
+        match S::modulus().to_biguint() <= Target::modulus().to_biguint() {
+            true => S::size_in_bits(),
+            false => Target::size_in_data_bits(),
+        }
+    }
+
+    /// Squeezes `num` field elements, each given by the `num_bits` lower bits of an element of `S`.
+    fn squeeze_field_elements<Target: PrimeField>(&mut self, num: usize, num_bits: usize) -> SmallVec<[Target; 10]> {
+        self.sponge
+            .squeeze_native_field_elements(num)
+            .iter()
+            .map(|element| Self::from_bits_le::<Target>(&element.to_bits_le()[..num_bits]))
+            .collect()
+    }
+
+    /// Returns the field element with the given little-endian bits, which must be less than the modulus.
+    fn from_bits_le<T: PrimeField>(bits: &[bool]) -> T {
+        T::from_bigint(T::BigInteger::from_bits_le(bits).unwrap()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Fq, Fr};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type Sponge = ScalarPoseidonSponge<Fq, Fr, 2>;
+
+    #[test]
+    fn test_scalar_poseidon_sponge_encodings() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an element of the scalar field is absorbed as itself.
+        let scalar = Fr::rand(rng);
+        assert_eq!(Sponge::encode_field_element(&scalar), vec![scalar]);
+
+        // Ensure an element of the base field is absorbed as its chunks of bits.
+        let base = Fq::rand(rng);
+        let chunks = Sponge::encode_field_element(&base);
+        assert_eq!(chunks.len(), 2);
+        let bits = chunks[0].to_bits_le()[..Fr::size_in_data_bits()].iter().chain(&chunks[1].to_bits_le()).copied();
+        assert_eq!(bits.take(Fq::size_in_bits()).collect::<Vec<_>>(), base.to_bits_le());
+
+        // Ensure the squeezed elements of the scalar field are the squeezed elements of the underlying sponge.
+        let mut sponge = Sponge::new();
+        let mut poseidon = PoseidonSponge::<Fr, 2, 1>::new();
+        sponge.absorb_nonnative_field_elements([scalar]);
+        poseidon.absorb_native_field_elements(&[scalar]);
+        assert_eq!(sponge.squeeze_nonnative_field_elements::<Fr>(3), poseidon.squeeze_native_field_elements(3));
+
+        // Ensure the short elements fit in 168 bits.
+        for element in sponge.squeeze_short_nonnative_field_elements::<Fr>(10) {
+            assert!(element.to_bigint().to_biguint().bits() <= 168);
+        }
+    }
+
+    #[test]
+    fn test_scalar_poseidon_sponge_binds_transcript() {
+        // Ensure different transcripts squeeze different challenges.
+        let mut a = Sponge::new();
+        a.absorb_bytes(b"ab");
+        a.absorb_bytes(b"c");
+        let mut b = Sponge::new();
+        b.absorb_bytes(b"a");
+        b.absorb_bytes(b"bc");
+        assert_ne!(a.squeeze_native_field_elements(1), b.squeeze_native_field_elements(1));
+
+        // Ensure squeezing no elements leaves the transcript unchanged.
+        let mut a = Sponge::new();
+        let mut b = Sponge::new();
+        a.squeeze_nonnative_field_elements::<Fr>(0);
+        assert_eq!(a.squeeze_nonnative_field_elements::<Fr>(1), b.squeeze_nonnative_field_elements::<Fr>(1));
+    }
+}
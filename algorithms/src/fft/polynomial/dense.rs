@@ -703,6 +703,30 @@ mod tests {
         // Note PolyMultiplier doesn't support a evaluations with no polynomials
     }
 
+    #[test]
+    fn mul_polynomials_in_chunks() {
+        let rng = &mut TestRng::default();
+
+        for num_polys in 1..4 {
+            let polys = (0..num_polys)
+                .map(|_| DensePolynomial::<Fr>::rand((rng.next_u32() as usize) % 200, rng))
+                .collect::<Vec<_>>();
+            let multiplier = || {
+                let mut multiplier = PolyMultiplier::new();
+                polys.iter().for_each(|p| multiplier.add_polynomial_ref(p, "p"));
+                multiplier
+            };
+            let expected = multiplier().multiply().unwrap();
+
+            // Ensure the product is the same for any number of chunks, including more chunks than domain elements.
+            for num_chunks in [1, 2, 4, 8, 1 << 12] {
+                assert_eq!(multiplier().multiply_in_chunks(num_chunks).unwrap(), expected);
+            }
+            // Ensure the number of chunks must be a power of two.
+            assert!(multiplier().multiply_in_chunks(3).is_none());
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut TestRng::default();
@@ -132,6 +132,69 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
         }
     }
 
+    /// Multiplies all polynomials stored in `self`, over `num_chunks` cosets of a subgroup of the domain in turn.
+    ///
+    /// Unlike `multiply`, this only holds the product and one chunk of evaluations of each polynomial in memory,
+    /// instead of the evaluations of every polynomial over the whole domain, at the cost of more field operations.
+    ///
+    /// Returns `None` if any evaluations are stored, if `num_chunks` is not a power of two,
+    /// or if `F` does not contain a sufficiently large subgroup for interpolation.
+    pub fn multiply_in_chunks(self, num_chunks: usize) -> Option<DensePolynomial<F>> {
+        if !self.evaluations.is_empty() || !num_chunks.is_power_of_two() {
+            return None;
+        }
+        if self.polynomials.is_empty() {
+            return Some(DensePolynomial::zero());
+        }
+        let degree = self.polynomials.iter().map(|(_, p)| p.degree() + 1).sum::<usize>();
+        let domain = EvaluationDomain::new(degree)?;
+
+        // Let D = domain, with generator ω, and let S be its subgroup of size m = |D| / num_chunks.
+        // Then D is the union of the cosets ω^j·S, for 0 <= j < num_chunks.
+        let num_chunks = num_chunks.min(domain.size());
+        let chunk_domain = EvaluationDomain::<F>::new(domain.size() / num_chunks)?;
+        let chunk_size = chunk_domain.size();
+        // Compute 1 / num_chunks.
+        let num_chunks_inv = chunk_domain.size_as_field_element * domain.size_inv;
+
+        let mut result = vec![F::zero(); domain.size()];
+        let mut offset = F::one();
+        for _ in 0..num_chunks {
+            // Evaluate each polynomial p over ω^j·S, by reducing p(ω^j·X) modulo X^m - 1, and computing an FFT over S.
+            let evaluate_on_coset = |p: &DensePolynomial<F>| {
+                let mut chunk = vec![F::zero(); chunk_size];
+                let mut power = F::one();
+                for (i, coeff) in p.coeffs.iter().enumerate() {
+                    chunk[i % chunk_size] += *coeff * power;
+                    power *= offset;
+                }
+                chunk_domain.fft_in_place(&mut chunk);
+                chunk
+            };
+            let mut chunk = self
+                .polynomials
+                .iter()
+                .map(|(_, p)| evaluate_on_coset(p))
+                .reduce(|mut a, b| {
+                    cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                    a
+                })
+                .unwrap();
+
+            // Interpolate the product r over ω^j·S, which yields r(ω^j·X) modulo X^m - 1.
+            // Then, r_k = (1 / num_chunks) * \sum_j ω^{-jk} * (r(ω^j·X) mod X^m - 1)_{k mod m}.
+            chunk_domain.ifft_in_place(&mut chunk);
+            let offset_inv = offset.inverse()?;
+            let mut power = num_chunks_inv;
+            for (k, coeff) in result.iter_mut().enumerate() {
+                *coeff += chunk[k % chunk_size] * power;
+                power *= offset_inv;
+            }
+            offset *= domain.group_gen;
+        }
+        Some(DensePolynomial::from_coefficients_vec(result))
+    }
+
     pub fn element_wise_arithmetic_4_over_domain<T: Borrow<str>>(
         mut self,
         domain: EvaluationDomain<F>,
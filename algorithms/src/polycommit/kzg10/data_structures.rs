@@ -106,6 +106,7 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(UniversalVerifier {
             vk: VerifierKey::<E> { g, gamma_g, h, beta_h, prepared_h, prepared_beta_h },
             prepared_negative_powers_of_beta_h: self.powers.read().prepared_negative_powers_of_beta_h(),
+            negative_powers_of_beta_h: self.powers.read().negative_powers_of_beta_h(),
         })
     }
 }
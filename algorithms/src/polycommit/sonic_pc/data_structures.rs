@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -393,6 +393,11 @@ impl<E: PairingEngine> BatchProof<E> {
     pub fn is_hiding(&self) -> bool {
         self.0.iter().any(|c| c.is_hiding())
     }
+
+    /// Returns the opening proofs, one for each evaluation point, ordered by the name of the point.
+    pub fn proofs(&self) -> &[kzg10::KZGProof<E>] {
+        &self.0
+    }
 }
 
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
@@ -679,3 +684,47 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// An accumulator of KZG opening checks, which are decided together by a single product of pairings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KZGAccumulator<E: PairingEngine> {
+    /// The combined commitments, indexed by their degree bound.
+    pub(super) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined opening proofs.
+    pub(super) combined_witness: E::G1Projective,
+    /// The combined opening proofs, adjusted by their evaluation points and values.
+    pub(super) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for KZGAccumulator<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> KZGAccumulator<E> {
+    /// Initializes an accumulator from its combined commitments (indexed by their degree bound) and opening proofs,
+    /// e.g. as computed by a verifier of the polynomial commitments outside of `SonicKZG10`.
+    pub fn new(
+        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+        combined_witness: E::G1Projective,
+        combined_adjusted_witness: E::G1Projective,
+    ) -> Self {
+        Self { combined_comms, combined_witness, combined_adjusted_witness }
+    }
+
+    /// Adds `other` into `self`, scaled by `randomizer`.
+    /// The randomizer must be unpredictable to whoever produced `other`,
+    /// so that the combined check implies each of the accumulated checks.
+    pub fn accumulate(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += *comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
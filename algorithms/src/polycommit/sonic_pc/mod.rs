@@ -243,7 +243,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     .collect::<Result<Vec<_>, _>>()?
                     .into_iter()
                     .fold((E::G1Projective::zero(), Randomness::empty()), |mut a, b| {
-                        a.0.add_assign_mixed(&b.0.0);
+                        a.0.add_assign_mixed(&b.0 .0);
                         a.1 += (E::Fr::one(), &b.1);
                         a
                     });
@@ -350,12 +350,28 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let accumulator = Self::batch_accumulate(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_accumulator(vk, accumulator)
+    }
+
+    /// Accumulates the opening checks of `batch_check`, deferring the final product of pairings.
+    pub fn batch_accumulate<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<KZGAccumulator<E>, PCError>
     where
         Commitment<E>: 'a,
     {
         let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
         let batch_check_time = start_timer!(|| format!(
-            "Checking {} commitments at query set of size {}",
+            "Accumulating {} commitments at query set of size {}",
             commitments.len(),
             query_set.len(),
         ));
@@ -370,9 +386,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut accumulator = KZGAccumulator::default();
 
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
             let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
@@ -390,9 +404,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut accumulator.combined_comms,
+                &mut accumulator.combined_witness,
+                &mut accumulator.combined_adjusted_witness,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -405,9 +419,14 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result
+        Ok(accumulator)
+    }
+
+    /// Checks the accumulated opening checks with a single product of pairings.
+    pub fn check_accumulator(vk: &UniversalVerifier<E>, accumulator: KZGAccumulator<E>) -> Result<bool, PCError> {
+        let KZGAccumulator { combined_comms, combined_witness, combined_adjusted_witness } = accumulator;
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
     }
 
     pub fn open_combinations<'a>(
@@ -425,45 +444,20 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     {
         let label_map =
             polynomials.into_iter().zip_eq(rands).map(|(p, r)| (p.to_label(), (p, r))).collect::<BTreeMap<_, _>>();
+        let mut polynomial = |label: &str| {
+            label_map
+                .get(label)
+                .map(|(p, r)| (p, *r))
+                .ok_or_else(|| PCError::MissingPolynomial { label: label.to_string() })
+        };
 
         let mut lc_polynomials = Vec::new();
         let mut lc_randomness = Vec::new();
-        let mut lc_info = Vec::new();
 
         for lc in linear_combinations {
-            let lc_label = lc.label().to_string();
-            let mut poly = DensePolynomial::zero();
-            let mut randomness = Randomness::empty();
-            let mut degree_bound = None;
-            let mut hiding_bound = None;
-
-            let num_polys = lc.len();
-            // We filter out l.is_one() entries because those constants are not committed to and used directly by the verifier.
-            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
-                let label: &String = label.try_into().expect("cannot be one!");
-                let (cur_poly, cur_rand) =
-                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
-                if let Some(cur_degree_bound) = cur_poly.degree_bound() {
-                    if num_polys != 1 {
-                        return Err(PCError::EquationHasDegreeBounds(lc_label));
-                    }
-                    assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
-                    if let Some(old_degree_bound) = degree_bound {
-                        assert_eq!(old_degree_bound, cur_degree_bound)
-                    } else {
-                        degree_bound = cur_poly.degree_bound();
-                    }
-                }
-                // Some(_) > None, always.
-                hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
-                poly += (*coeff, cur_poly.polynomial());
-                randomness += (*coeff, *cur_rand);
-            }
-
-            let lc_poly = LabeledPolynomial::new(lc_label.clone(), poly, degree_bound, hiding_bound);
+            let (lc_poly, randomness) = Self::linear_combination_polynomial(lc, &mut polynomial)?;
             lc_polynomials.push(lc_poly);
             lc_randomness.push(randomness);
-            lc_info.push((lc_label, degree_bound));
         }
 
         let proof =
@@ -472,6 +466,69 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok(BatchLCProof { proof })
     }
 
+    /// Like `open_combinations`, but only holds the polynomials of a single linear combination in memory at once.
+    ///
+    /// The polynomials are fetched by label from `polynomial` whenever they are needed, so they may be kept on disk.
+    /// For the same inputs, this outputs the same proof as `open_combinations`.
+    pub fn open_combinations_streaming<'a, P: Borrow<LabeledPolynomial<E::Fr>>>(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterUnionKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        mut polynomial: impl FnMut(&str) -> Result<(P, &'a Randomness<E>), PCError>,
+        query_set: &QuerySet<E::Fr>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<E>, PCError>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let linear_combinations: BTreeMap<_, _> = linear_combinations.into_iter().map(|lc| (lc.label(), lc)).collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} linear combinations at query set of size {}",
+            linear_combinations.len(),
+            query_set.len(),
+        ));
+
+        // Note: The points and labels are traversed in the same order as in `batch_open`,
+        // so that the same challenges are squeezed from `fs_rng`.
+        let mut query_to_labels_map = BTreeMap::new();
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&query, labels)) in query_to_labels_map.into_iter() {
+            let mut combined_poly = DensePolynomial::zero();
+            let mut combined_rand = Randomness::empty();
+
+            for label in labels {
+                let lc = linear_combinations
+                    .get(label.as_str())
+                    .ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let (lc_poly, lc_rand) = Self::linear_combination_polynomial(lc, &mut polynomial)?;
+                kzg10::KZG10::<E>::check_degrees_and_bounds(
+                    universal_prover.max_degree,
+                    ck.enforced_degree_bounds.as_deref(),
+                    &lc_poly,
+                )?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+                combined_poly += (challenge, lc_poly.polynomial());
+                combined_rand += (challenge, &lc_rand);
+            }
+            let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
+
+            let proof_time = start_timer!(|| "Creating proof");
+            proofs.push(kzg10::KZG10::open(&ck.powers(), &combined_poly, query, &combined_rand)?);
+            end_timer!(proof_time);
+        }
+        end_timer!(open_time);
+
+        Ok(BatchLCProof { proof: BatchProof(proofs) })
+    }
+
     /// Checks that `values` are the true evaluations at `query_set` of the polynomials
     /// committed in `labeled_commitments`.
     pub fn check_combinations<'a>(
@@ -483,6 +540,24 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let accumulator =
+            Self::accumulate_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?;
+        Self::check_accumulator(vk, accumulator)
+    }
+
+    /// Accumulates the opening checks of `check_combinations`, deferring the final product of pairings.
+    pub fn accumulate_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<KZGAccumulator<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -539,11 +614,52 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_accumulate(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Returns the polynomial and randomness of the linear combination `lc`,
+    /// fetching the polynomial and randomness of each of its terms from `polynomial`.
+    fn linear_combination_polynomial<'a, P: Borrow<LabeledPolynomial<E::Fr>>>(
+        lc: &LinearCombination<E::Fr>,
+        polynomial: &mut impl FnMut(&str) -> Result<(P, &'a Randomness<E>), PCError>,
+    ) -> Result<(LabeledPolynomial<E::Fr>, Randomness<E>), PCError>
+    where
+        Randomness<E>: 'a,
+    {
+        let lc_label = lc.label().to_string();
+        let mut poly = DensePolynomial::zero();
+        let mut randomness = Randomness::empty();
+        let mut degree_bound = None;
+        let mut hiding_bound = None;
+
+        let num_polys = lc.len();
+        // We filter out l.is_one() entries because those constants are not committed to and used directly by the verifier.
+        for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
+            let label: &String = label.try_into().expect("cannot be one!");
+            let (cur_poly, cur_rand) = polynomial(label.as_str())?;
+            let cur_poly: &LabeledPolynomial<E::Fr> = cur_poly.borrow();
+            if let Some(cur_degree_bound) = cur_poly.degree_bound() {
+                if num_polys != 1 {
+                    return Err(PCError::EquationHasDegreeBounds(lc_label));
+                }
+                assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                if let Some(old_degree_bound) = degree_bound {
+                    assert_eq!(old_degree_bound, cur_degree_bound)
+                } else {
+                    degree_bound = cur_poly.degree_bound();
+                }
+            }
+            // Some(_) > None, always.
+            hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
+            poly += (*coeff, cur_poly.polynomial());
+            randomness += (*coeff, cur_rand);
+        }
+
+        Ok((LabeledPolynomial::new(lc_label, poly, degree_bound, hiding_bound), randomness))
+    }
+
     fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
     ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
@@ -41,7 +41,7 @@ use rand::{
     distributions::{self, Distribution},
     Rng,
 };
-use std::marker::PhantomData;
+use std::{collections::BTreeMap, marker::PhantomData};
 
 #[derive(Default)]
 struct TestInfo {
@@ -122,8 +122,8 @@ pub fn bad_degree_bound_test<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>() -
     Ok(())
 }
 
-pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>()
--> Result<Vec<TestComponents<E, S>>, PCError> {
+pub fn lagrange_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
+) -> Result<Vec<TestComponents<E, S>>, PCError> {
     let num_iters = 10usize;
     let max_degree = 256usize;
     let supported_degree = 127usize;
@@ -481,6 +481,25 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
         println!("Generated query set");
         println!("Linear combinations: {linear_combinations:?}");
 
+        // Ensure the streaming opening outputs the same proof as the in-memory one.
+        let mut sponge_for_open = S::new();
+        let labels_to_polynomials: BTreeMap<_, _> =
+            polynomials.iter().zip_eq(&rands).map(|(p, r)| (p.label(), (p, r))).collect();
+        let streaming_proof = SonicKZG10::open_combinations_streaming(
+            universal_prover,
+            &ck,
+            &linear_combinations,
+            |label| {
+                labels_to_polynomials
+                    .get(label)
+                    .copied()
+                    .ok_or_else(|| PCError::MissingPolynomial { label: label.to_string() })
+            },
+            &query_set,
+            &mut sponge_for_open,
+        )?;
+        drop(labels_to_polynomials);
+
         let mut sponge_for_open = S::new();
         let proof = SonicKZG10::open_combinations(
             universal_prover,
@@ -491,6 +510,7 @@ fn equation_test_template<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>>(
             &query_set,
             &mut sponge_for_open,
         )?;
+        assert_eq!(proof, streaming_proof);
         println!("Generated proof");
         let mut sponge_for_check = S::new();
         let result = SonicKZG10::check_combinations(
@@ -169,7 +169,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// (1) simple comitments: $\{\cm{g_A}, \cm{g_B}, \cm{g_C}\}$ and $\{\cm{\hat{z}_{B,i,j}}\}_{i \in {[\mathcal{D}]}}$, $\cm{g_1}$
     /// (2) virtual commitments for the lincheck_sumcheck and matrix_sumcheck. These are linear combinations of the simple commitments
     #[allow(non_snake_case)]
-    pub fn construct_linear_combinations<E: EvaluationsProvider<F>>(
+    pub fn construct_linear_combinations<E: EvaluationsProvider<F> + ?Sized>(
         public_inputs: &BTreeMap<CircuitId, Vec<Vec<F>>>,
         evals: &E,
         prover_third_message: &prover::ThirdMessage<F>,
@@ -404,7 +404,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         lhs
     }
 
-    fn construct_matrix_linear_combinations<E: EvaluationsProvider<F>>(
+    fn construct_matrix_linear_combinations<E: EvaluationsProvider<F> + ?Sized>(
         evals: &E,
         id: CircuitId,
         matrix: &str,
@@ -486,6 +486,27 @@ where
     }
 }
 
+/// The `EvaluationsProvider` used by the prover, which reads back any polynomials that were spilled
+impl<F: PrimeField> EvaluationsProvider<F> for [prover::PolynomialBuffer<F>] {
+    fn get_lc_eval(&self, lc: &LinearCombination<F>, point: F) -> Result<F> {
+        let mut eval = F::zero();
+        for (coeff, term) in lc.iter() {
+            let value = if let LCTerm::PolyLabel(label) = term {
+                self.iter()
+                    .find(|p| p.label() == label)
+                    .ok_or_else(|| AHPError::MissingEval(format!("Missing {} for {}", label, lc.label)))?
+                    .read()?
+                    .evaluate(point)
+            } else {
+                ensure!(term.is_one());
+                F::one()
+            };
+            eval += &(*coeff * value)
+        }
+        Ok(eval)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spilled prover buffers hold witness data: the private variables of each instance,
+//! and the polynomials that are derived from them. To keep the witness confidential,
+//! each spill file is encrypted under a key that is sampled once per process and is never written to disk,
+//! is only readable by the current user, and is removed as soon as it is no longer needed.
+//! Spill files are also checked against a digest held in memory when they are read back.
+//! Still, a crash or a power loss can leave spill files behind, so the spill directory
+//! should be on local storage that is not shared with other users.
+
+use crate::polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo};
+use snarkvm_fields::Field;
+use snarkvm_utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{rngs::OsRng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+use std::{
+    borrow::Cow,
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        OnceLock,
+    },
+};
+
+/// A counter used to give each spilled buffer a unique file name and keystream.
+static SPILL_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// The key that spilled buffers are encrypted under, which is sampled once per process.
+static SPILL_FILE_KEY: OnceLock<[u8; 32]> = OnceLock::new();
+
+/// An intermediate prover value, which is either held in memory or spilled to a file on disk.
+pub(crate) enum Buffer<T> {
+    InMemory(T),
+    OnDisk(SpillFile),
+}
+
+impl<T: CanonicalSerialize + CanonicalDeserialize> Buffer<T> {
+    /// Returns a buffer for the given value, spilling it to a new file in `spill_dir` if one is given.
+    pub(crate) fn new(value: T, spill_dir: Option<&Path>) -> Result<Self> {
+        match spill_dir {
+            Some(spill_dir) => Ok(Self::OnDisk(SpillFile::write(&value, spill_dir)?)),
+            None => Ok(Self::InMemory(value)),
+        }
+    }
+
+    /// Returns the value without consuming the buffer, reading it back from disk if it was spilled.
+    pub(crate) fn read(&self) -> Result<Cow<'_, T>>
+    where
+        T: Clone,
+    {
+        match self {
+            Self::InMemory(value) => Ok(Cow::Borrowed(value)),
+            Self::OnDisk(file) => file.read().map(Cow::Owned),
+        }
+    }
+
+    /// Returns the value, reading it back from disk if it was spilled.
+    pub(crate) fn into_inner(self) -> Result<T> {
+        match self {
+            Self::InMemory(value) => Ok(value),
+            Self::OnDisk(file) => file.read(),
+        }
+    }
+}
+
+/// A labeled polynomial, which is either held in memory or spilled to a file on disk.
+/// The label and bounds of the polynomial are always held in memory.
+pub(crate) struct PolynomialBuffer<F: Field> {
+    info: PolynomialInfo,
+    buffer: Buffer<LabeledPolynomial<F>>,
+}
+
+impl<F: Field> PolynomialBuffer<F> {
+    /// Returns a buffer for the given polynomial, spilling it to a new file in `spill_dir` if one is given.
+    pub(crate) fn new(polynomial: LabeledPolynomial<F>, spill_dir: Option<&Path>) -> Result<Self> {
+        let info = polynomial.info().clone();
+        Ok(Self { info, buffer: Buffer::new(polynomial, spill_dir)? })
+    }
+
+    /// Returns buffers for the given polynomials, spilling each one to a new file in `spill_dir` if one is given.
+    pub(crate) fn new_all(
+        polynomials: impl IntoIterator<Item = LabeledPolynomial<F>>,
+        spill_dir: Option<&Path>,
+    ) -> Result<Vec<Self>> {
+        polynomials.into_iter().map(|polynomial| Self::new(polynomial, spill_dir)).collect()
+    }
+
+    /// Returns the label and bounds of the polynomial.
+    pub(crate) fn info(&self) -> &PolynomialInfo {
+        &self.info
+    }
+
+    /// Returns the label of the polynomial.
+    pub(crate) fn label(&self) -> &str {
+        self.info.label()
+    }
+
+    /// Returns the polynomial without consuming the buffer, reading it back from disk if it was spilled.
+    pub(crate) fn read(&self) -> Result<Cow<'_, LabeledPolynomial<F>>> {
+        self.buffer.read()
+    }
+
+    /// Returns the polynomial, reading it back from disk if it was spilled.
+    pub(crate) fn into_inner(self) -> Result<LabeledPolynomial<F>> {
+        self.buffer.into_inner()
+    }
+}
+
+impl<F: Field> fmt::Debug for PolynomialBuffer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Note: The coefficients are omitted, as they may be derived from the witness.
+        let is_spilled = matches!(self.buffer, Buffer::OnDisk(_));
+        f.debug_struct("PolynomialBuffer").field("info", &self.info).field("is_spilled", &is_spilled).finish()
+    }
+}
+
+/// An encrypted file holding a spilled value, which is removed once it is dropped.
+pub(crate) struct SpillFile {
+    path: PathBuf,
+    /// The index of the file, which selects its keystream.
+    id: u64,
+    /// The digest of the serialized value, which is checked when the value is read back.
+    digest: [u8; 32],
+}
+
+impl SpillFile {
+    /// Writes the given value to a new file in `spill_dir`.
+    fn write<T: CanonicalSerialize>(value: &T, spill_dir: &Path) -> Result<Self> {
+        let id = SPILL_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = spill_dir.join(format!("varuna-{}-{id}.buffer", std::process::id()));
+        let writer = BufWriter::new(create_private_file(&path)?);
+        // Note: The file is removed on drop, including when the write below fails.
+        let mut file = Self { path, id, digest: [0u8; 32] };
+
+        let mut writer = SpillStream::new(writer, id);
+        value
+            .serialize_uncompressed(&mut writer)
+            .map_err(|error| anyhow!("Failed to spill a prover buffer to '{}': {error}", file.path.display()))?;
+        writer.flush()?;
+        file.digest = writer.digest();
+        Ok(file)
+    }
+
+    /// Reads the value back from the file.
+    fn read<T: CanonicalDeserialize>(&self) -> Result<T> {
+        let mut reader = SpillStream::new(BufReader::new(File::open(&self.path)?), self.id);
+        // Note: The value is checked against the digest below, so it does not need to be validated again.
+        let value = T::deserialize_uncompressed_unchecked(&mut reader)
+            .map_err(|error| anyhow!("Failed to read a prover buffer from '{}': {error}", self.path.display()))?;
+        ensure!(reader.digest() == self.digest, "The prover buffer in '{}' was modified", self.path.display());
+        Ok(value)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Creates a new file at `path`, which only the current user may read and write.
+fn create_private_file(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    // Note: This fails if anything exists at `path`, so a planted file or symlink is never written through.
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+}
+
+/// A reader or writer that encrypts the bytes of a spill file with its keystream, and hashes them.
+struct SpillStream<S> {
+    inner: S,
+    keystream: ChaCha20Rng,
+    block: [u8; 64],
+    position: usize,
+    hasher: Sha256,
+}
+
+impl<S> SpillStream<S> {
+    /// Returns a stream over `inner`, for the spill file with the given index.
+    fn new(inner: S, id: u64) -> Self {
+        let key = SPILL_FILE_KEY.get_or_init(|| {
+            let mut key = [0u8; 32];
+            OsRng.fill_bytes(&mut key);
+            key
+        });
+        let mut keystream = ChaCha20Rng::from_seed(*key);
+        keystream.set_stream(id);
+        Self { inner, keystream, block: [0u8; 64], position: 64, hasher: Sha256::new() }
+    }
+
+    /// XORs the given bytes with the next bytes of the keystream.
+    fn apply_keystream(&mut self, bytes: &mut [u8]) {
+        for byte in bytes {
+            if self.position == self.block.len() {
+                self.keystream.fill_bytes(&mut self.block);
+                self.position = 0;
+            }
+            *byte ^= self.block[self.position];
+            self.position += 1;
+        }
+    }
+
+    /// Returns the digest of the plaintext bytes seen so far.
+    fn digest(&self) -> [u8; 32] {
+        self.hasher.clone().finalize().into()
+    }
+}
+
+impl<W: Write> Write for SpillStream<W> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.hasher.update(bytes);
+        let mut ciphertext = bytes.to_vec();
+        self.apply_keystream(&mut ciphertext);
+        self.inner.write_all(&ciphertext)?;
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<R: Read> Read for SpillStream<R> {
+    fn read(&mut self, bytes: &mut [u8]) -> io::Result<usize> {
+        let num_bytes = self.inner.read(bytes)?;
+        self.apply_keystream(&mut bytes[..num_bytes]);
+        self.hasher.update(&bytes[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_spill_and_read() {
+        let rng = &mut TestRng::default();
+        let spill_dir = std::env::temp_dir();
+
+        let values: Vec<Vec<Fr>> = (0..4).map(|i| (0..i * 100).map(|_| Fr::rand(rng)).collect()).collect();
+        let new_all = |spill_dir: Option<&Path>| {
+            values.iter().map(|value| Buffer::new(value.clone(), spill_dir)).collect::<Result<Vec<_>>>().unwrap()
+        };
+        let into_inner_all =
+            |buffers: Vec<Buffer<_>>| buffers.into_iter().map(Buffer::into_inner).collect::<Result<Vec<_>>>().unwrap();
+
+        // Ensure in-memory buffers are returned as is.
+        let buffers = new_all(None);
+        assert!(buffers.iter().all(|buffer| matches!(buffer, Buffer::InMemory(_))));
+        assert_eq!(values, into_inner_all(buffers));
+
+        // Ensure spilled buffers are read back, and their files are removed.
+        let buffers = new_all(Some(&spill_dir));
+        let paths: Vec<_> = buffers
+            .iter()
+            .map(|buffer| match buffer {
+                Buffer::OnDisk(file) => file.path.clone(),
+                Buffer::InMemory(_) => panic!("Expected the buffer to be spilled"),
+            })
+            .collect();
+        assert!(paths.iter().all(|path| path.exists()));
+        assert_eq!(values, into_inner_all(buffers));
+        assert!(paths.iter().all(|path| !path.exists()));
+    }
+
+    #[test]
+    fn test_spill_file_is_encrypted() {
+        let rng = &mut TestRng::default();
+        let spill_dir = std::env::temp_dir();
+
+        let value: Vec<Fr> = (0..100).map(|_| Fr::rand(rng)).collect();
+        let mut plaintext = Vec::new();
+        value.serialize_uncompressed(&mut plaintext).unwrap();
+
+        let buffer = Buffer::new(value.clone(), Some(&spill_dir)).unwrap();
+        let Buffer::OnDisk(file) = &buffer else { panic!("Expected the buffer to be spilled") };
+
+        // Ensure the file does not hold the serialized value, and is only accessible to the current user.
+        let ciphertext = std::fs::read(&file.path).unwrap();
+        assert_eq!(plaintext.len(), ciphertext.len());
+        assert_ne!(plaintext, ciphertext);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&file.path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        // Ensure the value can be read back repeatedly.
+        assert_eq!(value, *buffer.read().unwrap());
+        assert_eq!(value, *buffer.read().unwrap());
+
+        // Ensure a modified file is rejected.
+        let mut modified = ciphertext;
+        *modified.last_mut().unwrap() ^= 1;
+        std::fs::write(&file.path, modified).unwrap();
+        assert!(buffer.read().is_err());
+    }
+}
@@ -14,6 +14,9 @@
 
 #![allow(non_snake_case)]
 
+mod buffer;
+pub(crate) use buffer::*;
+
 mod constraint_system;
 pub(crate) use constraint_system::*;
 
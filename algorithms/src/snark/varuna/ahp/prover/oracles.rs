@@ -12,17 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::BTreeMap, path::Path};
 
 use snarkvm_fields::PrimeField;
 
+use super::PolynomialBuffer;
 use crate::{
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::varuna::CircuitId,
 };
 
+use anyhow::Result;
+
 /// The first set of prover oracles.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct FirstOracles<F: PrimeField> {
     pub(in crate::snark::varuna) batches: BTreeMap<CircuitId, Vec<WitnessPoly<F>>>,
     /// The sum-check hiding polynomial.
@@ -30,14 +33,24 @@ pub struct FirstOracles<F: PrimeField> {
 }
 
 impl<F: PrimeField> FirstOracles<F> {
-    /// Iterate over the polynomials output by the prover in the first round.
-    pub fn iter(&self) -> impl Iterator<Item = &'_ LabeledPolynomial<F>> {
-        self.batches.values().flat_map(|b| b.iter()).flat_map(|b| b.iter()).chain(self.mask_poly.as_ref())
-    }
-
-    /// Iterate over the polynomials output by the prover in the first round.
-    pub fn into_iter(self) -> impl Iterator<Item = LabeledPolynomial<F>> {
-        self.batches.into_values().flat_map(|b| b.into_iter()).map(|b| b.0).chain(self.mask_poly.into_iter())
+    /// Iterate over the polynomials output by the prover in the first round,
+    /// reading back any that were spilled.
+    pub fn iter(&self) -> impl Iterator<Item = Result<Cow<'_, LabeledPolynomial<F>>>> {
+        self.batches
+            .values()
+            .flat_map(|b| b.iter())
+            .map(|b| b.0.read())
+            .chain(self.mask_poly.as_ref().map(|p| Ok(Cow::Borrowed(p))))
+    }
+
+    /// Returns the buffers of the polynomials output by the prover in the first round,
+    /// spilling the mask polynomial to a new file in `spill_dir` if one is given.
+    pub(in crate::snark::varuna) fn into_buffers(self, spill_dir: Option<&Path>) -> Result<Vec<PolynomialBuffer<F>>> {
+        let mut buffers = self.batches.into_values().flat_map(|b| b.into_iter()).map(|b| b.0).collect::<Vec<_>>();
+        if let Some(mask_poly) = self.mask_poly {
+            buffers.push(PolynomialBuffer::new(mask_poly, spill_dir)?);
+        }
+        Ok(buffers)
     }
 
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
@@ -46,16 +59,11 @@ impl<F: PrimeField> FirstOracles<F> {
     }
 }
 
-/// The LDE of `w`.
-#[derive(Debug, Clone)]
-pub(in crate::snark::varuna) struct WitnessPoly<F: PrimeField>(pub(in crate::snark::varuna) LabeledPolynomial<F>);
+/// The LDE of `w`, which is spilled to disk until it is needed if the prover is memory-bounded.
+#[derive(Debug)]
+pub(in crate::snark::varuna) struct WitnessPoly<F: PrimeField>(pub(in crate::snark::varuna) PolynomialBuffer<F>);
 
 impl<F: PrimeField> WitnessPoly<F> {
-    /// Iterate over the polynomials output by the prover in the first round.
-    pub fn iter(&self) -> impl Iterator<Item = &LabeledPolynomial<F>> {
-        [(&self.0)].into_iter()
-    }
-
     pub fn matches_info(&self, info: &BTreeMap<PolynomialLabel, PolynomialInfo>) -> bool {
         Some(self.0.info()) == info.get(self.0.label())
     }
@@ -63,6 +63,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     ) -> Result<prover::State<'a, F, SM>, AHPError> {
         let round_time = start_timer!(|| "AHP::Prover::FirstRound");
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(state.total_instances);
+        let spill_dir = state.config.spill_dir();
         for (circuit, circuit_state) in state.circuit_specific_states.iter_mut() {
             let batch_size = circuit_state.batch_size;
 
@@ -76,11 +77,17 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
             for (j, (private_vars, x_poly)) in itertools::izip!(private_variables, x_polys).enumerate() {
                 let w_label = witness_label(circuit.id, "w", j);
-                job_pool.add_job(move || Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit));
+                job_pool.add_job(move || {
+                    let private_vars = private_vars.into_inner()?;
+                    let w_poly = Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit);
+                    // Spill the witness polynomial until it is needed, if the prover is memory-bounded.
+                    Ok::<_, anyhow::Error>(prover::WitnessPoly(prover::PolynomialBuffer::new(w_poly, spill_dir)?))
+                });
             }
         }
-        let mut batches =
-            job_pool.execute_all().into_iter().map(|w_poly| prover::WitnessPoly(w_poly)).collect::<Vec<_>>();
+        let mut batches = super::execute_jobs(job_pool, state.config.max_concurrent_instances())
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
         assert_eq!(batches.len(), state.total_instances);
 
         let mut circuit_specific_batches = BTreeMap::new();
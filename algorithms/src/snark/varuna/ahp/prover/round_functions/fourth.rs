@@ -90,6 +90,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
 
         let max_non_zero_domain_size = state.max_non_zero_domain;
         let matrix_labels = ["a", "b", "c"];
+        let num_fft_chunks = state.config.num_fft_chunks();
         for (&circuit, state_i) in &state.circuit_specific_states {
             let v_R_i_at_alpha = state_i.constraint_domain.evaluate_vanishing_polynomial(*alpha);
             let v_C_i_at_beta = state_i.variable_domain.evaluate_vanishing_polynomial(*beta);
@@ -113,16 +114,19 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                         max_non_zero_domain_size,
                         &circuit.fft_precomputation,
                         &circuit.ifft_precomputation,
+                        num_fft_chunks,
                     );
                     (circuit, result)
                 });
             }
         }
 
+        // Bound the number of matrix sumchecks (and their FFTs) in flight at once by the prover configuration.
+        let max_concurrent_jobs = state.config.max_concurrent_instances().map(|n| 3 * n);
         let mut sums = Vec::with_capacity(state.circuit_specific_states.len());
         let mut gs = BTreeMap::new();
         for ((circuit_a, results_a), (circuit_b, results_b), (circuit_c, results_c)) in
-            pool.execute_all().into_iter().tuples()
+            super::execute_jobs(pool, max_concurrent_jobs).into_iter().tuples()
         {
             assert_eq!(circuit_a, circuit_b);
             assert_eq!(circuit_a, circuit_c);
@@ -164,6 +168,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         max_non_zero_domain: EvaluationDomain<F>,
         fft_precomputation: &FFTPrecomputation<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
+        num_fft_chunks: Option<usize>,
     ) -> Result<(Sum<F>, Lhs<F>, Gpoly<F>, Apoly<F>, Bpoly<F>)> {
         let (row_on_K, col_on_K, row_col_val) =
             (&arithmetization.row, &arithmetization.col, &arithmetization.row_col_val);
@@ -225,7 +230,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                 multiplier.add_polynomial_ref(&b_poly, "b");
                 multiplier.add_polynomial_ref(&f, "f");
                 multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
-                multiplier.multiply().unwrap()
+                super::multiply(multiplier, num_fft_chunks).unwrap()
             };
 
         let combiner = F::one(); // We are applying combiners in the fifth round when summing the witnesses
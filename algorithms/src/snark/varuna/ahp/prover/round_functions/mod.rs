@@ -13,10 +13,12 @@
 // limitations under the License.
 
 use crate::{
+    fft::{DensePolynomial, PolyMultiplier},
     r1cs::ConstraintSynthesizer,
     snark::varuna::{
         ahp::{indexer::Circuit, AHPError, AHPForR1CS},
         prover,
+        ProverConfig,
         SNARKMode,
     },
 };
@@ -28,9 +30,9 @@ use rand::Rng;
 use rand_core::CryptoRng;
 use std::collections::BTreeMap;
 
-use snarkvm_utilities::cfg_iter;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
+use snarkvm_utilities::{cfg_iter, ExecutionPool};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -45,9 +47,11 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     /// Initialize the AHP prover.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
         circuits_to_constraints: &BTreeMap<&'a Circuit<F, SM>, &[C]>,
+        config: &ProverConfig,
         rng: &mut R,
     ) -> Result<prover::State<'a, F, SM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
+        let spill_dir = config.spill_dir();
 
         let mut randomizing_assignments = Vec::with_capacity(circuits_to_constraints.len());
         for constraints in circuits_to_constraints.values() {
@@ -152,20 +156,44 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                             .collect();
                         end_timer!(eval_z_c_time);
 
-                        Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
+                        // Spill the witness vectors until they are needed, if the prover is memory-bounded.
+                        let spill = |values: Vec<F>| prover::Buffer::new(values, spill_dir);
+                        Ok(prover::Assignments::<F>(
+                            padded_public_variables,
+                            spill(private_variables)?,
+                            spill(z_a)?,
+                            spill(z_b)?,
+                            spill(z_c)?,
+                        ))
                     })
                     .collect::<Result<Vec<prover::Assignments<F>>, AHPError>>()?;
                 Ok((*circuit, assignments))
             })
             .collect::<Result<BTreeMap<&'a Circuit<F, SM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
 
-        let state = prover::State::initialize(indices_and_assignments)?;
+        let state = prover::State::initialize(indices_and_assignments, config)?;
         end_timer!(init_time);
 
         Ok(state)
     }
 }
 
+/// Executes the jobs in the pool, computing at most `max_concurrent_instances` of them at once if given.
+fn execute_jobs<T: Send + Sync>(job_pool: ExecutionPool<T>, max_concurrent_instances: Option<usize>) -> Vec<T> {
+    match max_concurrent_instances {
+        Some(chunk_size) => job_pool.execute_in_chunks(chunk_size),
+        None => job_pool.execute_all(),
+    }
+}
+
+/// Multiplies the polynomials in the multiplier, over `num_fft_chunks` cosets of the domain in turn if given.
+fn multiply<F: PrimeField>(multiplier: PolyMultiplier<F>, num_fft_chunks: Option<usize>) -> Option<DensePolynomial<F>> {
+    match num_fft_chunks {
+        Some(num_chunks) => multiplier.multiply_in_chunks(num_chunks),
+        None => multiplier.multiply(),
+    }
+}
+
 fn inner_product<F: PrimeField>(
     public_variables: &[F],
     private_variables: &[F],
@@ -78,6 +78,8 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
     ) -> Result<DensePolynomial<F>> {
         let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
         let max_constraint_domain = state.max_constraint_domain;
+        let max_concurrent_instances = state.config.max_concurrent_instances();
+        let num_fft_chunks = state.config.num_fft_chunks();
 
         for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
             let z_a = circuit_specific_state.z_a.take().unwrap();
@@ -96,6 +98,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                 itertools::izip!(instance_combiners, z_a, z_b, z_c).enumerate()
             {
                 job_pool.add_job(move || {
+                    let (z_a, z_b, z_c) = (z_a.into_inner()?, z_b.into_inner()?, z_c.into_inner()?);
                     let mut instance_lhs = DensePolynomial::zero();
                     let za_label = witness_label(circuit.id, "z_a", j);
                     let zb_label = witness_label(circuit.id, "z_b", j);
@@ -107,7 +110,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                     multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
                     multiplier_2.add_polynomial(z_a, "z_a");
                     multiplier_2.add_polynomial(z_b, "z_b");
-                    let mut rowcheck = multiplier_2.multiply().unwrap();
+                    let mut rowcheck = super::multiply(multiplier_2, num_fft_chunks).unwrap();
                     cfg_iter_mut!(rowcheck.coeffs).zip(&z_c.coeffs).for_each(|(ab, c)| *ab -= c);
 
                     instance_lhs += &(&rowcheck * instance_combiner);
@@ -126,15 +129,15 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         }
 
         let h_sum_time = start_timer!(|| "AHP::Prover::SecondRound h_sum");
-        let h_sum: DensePolynomial<F> =
-            cfg_reduce!(cfg_into_iter!(job_pool.execute_all()), || Ok(DensePolynomial::zero()), |a, b| {
-                a.and_then(|a| {
-                    b.map(|mut b| {
-                        b += &a;
-                        b
-                    })
+        let h_sums = super::execute_jobs(job_pool, max_concurrent_instances);
+        let h_sum: DensePolynomial<F> = cfg_reduce!(cfg_into_iter!(h_sums), || Ok(DensePolynomial::zero()), |a, b| {
+            a.and_then(|a| {
+                b.map(|mut b| {
+                    b += &a;
+                    b
                 })
-            })?;
+            })
+        })?;
         end_timer!(h_sum_time);
 
         Ok(h_sum)
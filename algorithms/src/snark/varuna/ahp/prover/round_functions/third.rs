@@ -137,28 +137,48 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         let matrix_labels = ["a", "b", "c"];
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
-        // Compute lineval sumcheck witnesses
-        let mut job_pool = ExecutionPool::with_capacity(total_instances * 3);
+        // Flatten the instances, so that the witnesses of at most `max_concurrent_instances` of them
+        // (and the FFTs computing them) are held in memory at once before being folded into the sums.
+        let mut instances = Vec::with_capacity(total_instances);
         for ((((circuit, circuit_specific_state), batch_combiner), assignments_i), matrix_transposes_i) in state
             .circuit_specific_states
-            .iter_mut()
+            .iter()
             .zip_eq(batch_combiners.values())
             .zip_eq(assignments.values())
             .zip_eq(matrix_transposes.values())
         {
             let circuit_combiner = batch_combiner.circuit_combiner;
-            let instance_combiners = &batch_combiner.instance_combiners;
-            let constraint_domain = &circuit_specific_state.constraint_domain;
-            let variable_domain = &circuit_specific_state.variable_domain;
-            let fft_precomputation = &circuit.fft_precomputation;
-            let ifft_precomputation = &circuit.ifft_precomputation;
+            for (&instance_combiner, assignment) in batch_combiner.instance_combiners.iter().zip_eq(assignments_i) {
+                instances.push((
+                    circuit,
+                    circuit_specific_state,
+                    circuit_combiner * instance_combiner,
+                    assignment,
+                    matrix_transposes_i,
+                ));
+            }
+        }
+        let chunk_size = state.config.max_concurrent_instances().unwrap_or(total_instances).max(1);
+        let num_fft_chunks = state.config.num_fft_chunks();
+
+        let mut sums = num_instances.iter().map(|n| Vec::with_capacity(*n)).collect_vec();
+        let mut h_1_sum = DensePolynomial::zero();
+        let mut xg_1_sum = DensePolynomial::zero();
+        let mut circuit_index = 0;
+        let mut instances_seen = 0;
+        let mut i = 0;
+        for chunk in instances.chunks(chunk_size) {
+            // Compute lineval sumcheck witnesses
+            let mut job_pool = ExecutionPool::with_capacity(chunk.len() * 3);
+            for &(circuit, circuit_specific_state, instance_combiner, assignment, matrix_transposes_i) in chunk {
+                let constraint_domain = &circuit_specific_state.constraint_domain;
+                let variable_domain = &circuit_specific_state.variable_domain;
+                let fft_precomputation = &circuit.fft_precomputation;
+                let ifft_precomputation = &circuit.ifft_precomputation;
 
-            for (_j, (&instance_combiner, assignment)) in
-                itertools::izip!(instance_combiners, assignments_i).enumerate()
-            {
                 for (label, matrix_combiner) in itertools::izip!(matrix_labels, matrix_combiners) {
                     let matrix_transpose = &matrix_transposes_i[label];
-                    let combiner = circuit_combiner * instance_combiner * matrix_combiner;
+                    let combiner = instance_combiner * matrix_combiner;
                     job_pool.add_job(move || {
                         Self::calculate_lineval_sumcheck_instance_witness(
                             label,
@@ -171,37 +191,34 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
                             matrix_transpose,
                             *alpha,
                             combiner,
+                            num_fft_chunks,
                         )
                     });
                 }
             }
-        }
 
-        let mut sums = num_instances.iter().map(|n| Vec::with_capacity(*n)).collect_vec();
-        let mut h_1_sum = DensePolynomial::zero();
-        let mut xg_1_sum = DensePolynomial::zero();
-        let mut circuit_index = 0;
-        let mut instances_seen = 0;
-        for (i, linevals) in job_pool.execute_all().chunks_exact_mut(3).enumerate() {
-            if linevals[0].is_ok() && linevals[1].is_ok() && linevals[2].is_ok() {
-                let lineval_a = linevals[0].as_ref().unwrap();
-                let lineval_b = linevals[1].as_ref().unwrap();
-                let lineval_c = linevals[2].as_ref().unwrap();
-                h_1_sum += &lineval_a.h_1_i;
-                h_1_sum += &lineval_b.h_1_i;
-                h_1_sum += &lineval_c.h_1_i;
-                xg_1_sum += &lineval_a.xg_1_i;
-                xg_1_sum += &lineval_b.xg_1_i;
-                xg_1_sum += &lineval_c.xg_1_i;
-                sums[circuit_index].push(MatrixSums {
-                    sum_a: lineval_a.sum,
-                    sum_b: lineval_b.sum,
-                    sum_c: lineval_c.sum,
-                });
-                if 1 + i - instances_seen == num_instances[circuit_index] {
-                    instances_seen += num_instances[circuit_index];
-                    circuit_index += 1;
+            for linevals in job_pool.execute_all().chunks_exact_mut(3) {
+                if linevals[0].is_ok() && linevals[1].is_ok() && linevals[2].is_ok() {
+                    let lineval_a = linevals[0].as_ref().unwrap();
+                    let lineval_b = linevals[1].as_ref().unwrap();
+                    let lineval_c = linevals[2].as_ref().unwrap();
+                    h_1_sum += &lineval_a.h_1_i;
+                    h_1_sum += &lineval_b.h_1_i;
+                    h_1_sum += &lineval_c.h_1_i;
+                    xg_1_sum += &lineval_a.xg_1_i;
+                    xg_1_sum += &lineval_b.xg_1_i;
+                    xg_1_sum += &lineval_c.xg_1_i;
+                    sums[circuit_index].push(MatrixSums {
+                        sum_a: lineval_a.sum,
+                        sum_b: lineval_b.sum,
+                        sum_c: lineval_c.sum,
+                    });
+                    if 1 + i - instances_seen == num_instances[circuit_index] {
+                        instances_seen += num_instances[circuit_index];
+                        circuit_index += 1;
+                    }
                 }
+                i += 1;
             }
         }
 
@@ -229,22 +246,23 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             .map(|((circuit, circuit_specific_state), w_polys)| {
                 let x_polys = &circuit_specific_state.x_polys;
                 let input_domain = &circuit_specific_state.input_domain;
-                let assignments_i: Vec<_> = cfg_iter!(w_polys)
+                let assignments_i = cfg_iter!(w_polys)
                     .zip_eq(x_polys)
                     .enumerate()
                     .map(|(_j, (w_poly, x_poly))| {
                         let z_time = start_timer!(move || format!("Compute z poly for circuit {} {}", circuit.id, _j));
+                        let w_poly = w_poly.0.read()?;
                         let mut assignment =
-                            w_poly.0.polynomial().as_dense().unwrap().mul_by_vanishing_poly(*input_domain);
+                            w_poly.polynomial().as_dense().unwrap().mul_by_vanishing_poly(*input_domain);
                         // Zip safety: `x_poly` is smaller than `z_poly`.
                         assignment.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
                         end_timer!(z_time);
-                        assignment
+                        Ok::<_, anyhow::Error>(assignment)
                     })
-                    .collect();
-                (circuit.id, assignments_i)
+                    .collect::<Result<Vec<_>>>()?;
+                Ok::<_, anyhow::Error>((circuit.id, assignments_i))
             })
-            .collect();
+            .collect::<Result<_>>()?;
         end_timer!(assignments_time);
         Ok(assignments)
     }
@@ -289,6 +307,7 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         matrix_transpose: &Matrix<F>,
         alpha: F,
         combiner: F,
+        num_fft_chunks: Option<usize>,
     ) -> Result<LinevalInstance<F>> {
         let sumcheck_time = start_timer!(|| format!("Compute LHS of sumcheck for {_label}"));
 
@@ -314,8 +333,11 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
         multiplier.add_polynomial(m_at_alpha, "m_at_alpha");
         multiplier.add_polynomial_ref(assignment, "assignment");
-        let mut z_m_at_alpha = multiplier.multiply().unwrap();
-        let sum = z_m_at_alpha.evaluate_over_domain_by_ref(*variable_domain).evaluations.into_iter().sum::<F>();
+        let mut z_m_at_alpha = super::multiply(multiplier, num_fft_chunks).unwrap();
+        // The sum of a polynomial p over C is |C| times the sum of the coefficients p_i with i = 0 mod |C|,
+        // which avoids evaluating z_m_at_alpha over the whole of C.
+        let sum = variable_domain.size_as_field_element
+            * z_m_at_alpha.coeffs.iter().step_by(variable_domain.size()).sum::<F>();
         end_timer!(z_m_at_alpha_time);
 
         let (h_1_i, xg_1_i) =
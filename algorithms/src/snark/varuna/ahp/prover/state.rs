@@ -14,11 +14,12 @@
 
 use std::collections::BTreeMap;
 
+use super::Buffer;
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::LabeledPolynomial,
     r1cs::{SynthesisError, SynthesisResult},
-    snark::varuna::{AHPError, AHPForR1CS, Circuit, SNARKMode},
+    snark::varuna::{AHPError, AHPForR1CS, Circuit, ProverConfig, SNARKMode},
 };
 use anyhow::anyhow;
 use snarkvm_fields::PrimeField;
@@ -41,19 +42,19 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// The list of private variables for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) private_variables: Vec<Vec<F>>,
+    pub(super) private_variables: Vec<Buffer<Vec<F>>>,
 
     /// The list of Az vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_a: Option<Vec<Vec<F>>>,
+    pub(super) z_a: Option<Vec<Buffer<Vec<F>>>>,
 
     /// The list of Bz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_b: Option<Vec<Vec<F>>>,
+    pub(super) z_b: Option<Vec<Buffer<Vec<F>>>>,
 
     /// The list of Cz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_c: Option<Vec<Vec<F>>>,
+    pub(super) z_c: Option<Vec<Buffer<Vec<F>>>>,
 
     /// A list of polynomials corresponding to the interpolation of the public input.
     /// The length of this list must be equal to the batch size.
@@ -84,18 +85,20 @@ pub struct State<'a, F: PrimeField, SM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// The configuration of the prover.
+    pub(in crate::snark) config: ProverConfig,
 }
 
 /// The public inputs for a single instance.
 type PaddedPubInputs<F> = Vec<F>;
 /// The private inputs for a single instance.
-type PrivateInputs<F> = Vec<F>;
+type PrivateInputs<F> = Buffer<Vec<F>>;
 /// The z_i_j*A_i vector for a single instance.
-type Za<F> = Vec<F>;
+type Za<F> = Buffer<Vec<F>>;
 /// The z_i_j*B_i vector for a single instance.
-type Zb<F> = Vec<F>;
+type Zb<F> = Buffer<Vec<F>>;
 /// The z_i_j*C_i vector for a single instance.
-type Zc<F> = Vec<F>;
+type Zc<F> = Buffer<Vec<F>>;
 /// Assignments for a single instance.
 pub(super) struct Assignments<F>(
     pub(super) PaddedPubInputs<F>,
@@ -108,6 +111,7 @@ pub(super) struct Assignments<F>(
 impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
     pub(super) fn initialize(
         indices_and_assignments: BTreeMap<&'a Circuit<F, SM>, Vec<Assignments<F>>>,
+        config: &ProverConfig,
     ) -> Result<Self, AHPError> {
         let mut max_non_zero_domain: Option<EvaluationDomain<F>> = None;
         let mut max_num_constraints = 0;
@@ -186,6 +190,7 @@ impl<'a, F: PrimeField, SM: SNARKMode> State<'a, F, SM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            config: config.clone(),
         })
     }
 
@@ -28,6 +28,10 @@ pub use circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// The Varuna prover configuration.
+pub(super) mod prover_config;
+pub use prover_config::*;
+
 /// A test circuit.
 #[cfg(any(test, feature = "test"))]
 pub(super) mod test_circuit;
@@ -291,6 +291,29 @@ impl<E: PairingEngine> Proof<E> {
         }
         Ok(())
     }
+
+    /// Returns the compressed size in bytes of a hiding proof over circuits with the given batch sizes.
+    pub fn expected_size_in_bytes(batch_sizes: &[usize]) -> usize {
+        let num_circuits = batch_sizes.len();
+        let num_instances = batch_sizes.iter().sum::<usize>();
+        // Note: `Option` and `Vec` lengths are serialized as a flag byte and a `u64` respectively.
+        let (flag, length) = (1usize, 8usize);
+        let commitment = E::G1Affine::default().compressed_size();
+        let field = E::Fr::default().compressed_size();
+
+        // The batch sizes.
+        let mut size = length + num_circuits * 8;
+        // The witness commitments, the mask polynomial, `h_0`, `g_1`, `h_1`, `g_{a,b,c}`, and `h_2`.
+        size += num_instances * commitment + (flag + commitment) + 3 * commitment + 3 * num_circuits * commitment;
+        size += commitment;
+        // The evaluations of `g_1` and `g_{a,b,c}`.
+        size += field + 3 * num_circuits * field;
+        // The third and fourth message sums.
+        size += num_instances * 3 * field + num_circuits * 3 * field;
+        // The openings at `alpha`, `beta`, and `gamma`, of which only the one at `beta` is hiding.
+        size += length + 3 * (commitment + flag) + field;
+        size
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{ensure, Result};
+use std::path::{Path, PathBuf};
+
+/// The configuration of the Varuna prover.
+///
+/// By default, the prover keeps every intermediate buffer in memory, and processes all instances in a batch at once.
+/// A memory-bounded prover instead spills intermediate buffers to disk while they are not in use,
+/// and computes the polynomials (and their FFTs) for only a few instances at a time.
+/// Within a single instance, it also multiplies polynomials over a few cosets of the domain at a time,
+/// so that the evaluations of a large circuit are never held over its whole domain.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProverConfig {
+    /// The directory to spill intermediate buffers to, if any.
+    spill_dir: Option<PathBuf>,
+    /// The maximum number of instances to compute polynomials for at once, if any.
+    max_concurrent_instances: Option<usize>,
+    /// The number of cosets to split the domain of a polynomial product into, if any.
+    num_fft_chunks: Option<usize>,
+}
+
+impl ProverConfig {
+    /// The default number of cosets a memory-bounded prover splits the domain of a polynomial product into.
+    pub const DEFAULT_NUM_FFT_CHUNKS: usize = 4;
+
+    /// Returns a configuration that keeps every intermediate buffer in memory.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Returns a configuration that spills intermediate buffers to files in `spill_dir`,
+    /// and computes polynomials for at most `max_concurrent_instances` instances at once,
+    /// multiplying them over `DEFAULT_NUM_FFT_CHUNKS` cosets of their domain in turn.
+    ///
+    /// **Warning:** the spilled buffers hold the prover's witness polynomials, i.e. private data.
+    /// Each file is encrypted under a process-local key, readable only by the current user,
+    /// and removed once it is no longer needed, but `spill_dir` should still be a local directory
+    /// that is not shared with other users or synchronized to remote storage.
+    pub fn memory_bounded(spill_dir: impl Into<PathBuf>, max_concurrent_instances: usize) -> Result<Self> {
+        ensure!(max_concurrent_instances > 0, "The prover must process at least one instance at a time");
+        Ok(Self {
+            spill_dir: Some(spill_dir.into()),
+            max_concurrent_instances: Some(max_concurrent_instances),
+            num_fft_chunks: Some(Self::DEFAULT_NUM_FFT_CHUNKS),
+        })
+    }
+
+    /// Returns the configuration with polynomial products computed over `num_fft_chunks` cosets of their domain in turn.
+    /// A larger number of chunks lowers the peak memory of the prover, at the cost of more field operations.
+    pub fn with_num_fft_chunks(mut self, num_fft_chunks: usize) -> Result<Self> {
+        ensure!(num_fft_chunks.is_power_of_two(), "The number of FFT chunks must be a power of two");
+        self.num_fft_chunks = Some(num_fft_chunks);
+        Ok(self)
+    }
+
+    /// Returns the directory to spill intermediate buffers to, if any.
+    pub fn spill_dir(&self) -> Option<&Path> {
+        self.spill_dir.as_deref()
+    }
+
+    /// Returns the maximum number of instances to compute polynomials for at once, if any.
+    pub const fn max_concurrent_instances(&self) -> Option<usize> {
+        self.max_concurrent_instances
+    }
+
+    /// Returns the number of cosets to split the domain of a polynomial product into, if any.
+    pub const fn num_fft_chunks(&self) -> Option<usize> {
+        self.num_fft_chunks
+    }
+
+    /// Returns `true` if the prover spills intermediate buffers to disk.
+    pub const fn is_memory_bounded(&self) -> bool {
+        self.spill_dir.is_some()
+    }
+}
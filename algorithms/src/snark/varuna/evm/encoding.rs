@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bytes of a word of the EVM, which encodes an element of `Fr`.
+pub const WORD_SIZE: usize = 32;
+/// The number of bytes of an element of `Fq`, as encoded by EIP-2539.
+pub const FQ_SIZE: usize = 64;
+/// The number of bytes of a point of G1, as encoded by EIP-2539.
+pub const G1_SIZE: usize = 2 * FQ_SIZE;
+/// The number of bytes of a point of G2, as encoded by EIP-2539.
+pub const G2_SIZE: usize = 4 * FQ_SIZE;
+
+/// Returns the encoding of the given element of `Fr`, as a big-endian word.
+pub fn encode_fr(element: &Fr) -> Vec<u8> {
+    encode_field(element, WORD_SIZE)
+}
+
+/// Returns the element of `Fr` encoded by the given big-endian word, or `None` if it is not canonical.
+pub fn decode_fr(bytes: &[u8]) -> Option<Fr> {
+    match bytes.len() == WORD_SIZE {
+        true => decode_field(bytes),
+        false => None,
+    }
+}
+
+/// Returns the encoding of the given point of G1, as in EIP-2539.
+/// The point at infinity is encoded as zeros.
+pub fn encode_g1(point: &G1Affine) -> Vec<u8> {
+    match point.is_zero() {
+        true => vec![0u8; G1_SIZE],
+        false => [encode_field(&point.x, FQ_SIZE), encode_field(&point.y, FQ_SIZE)].concat(),
+    }
+}
+
+/// Returns the point of G1 encoded by the given bytes, as in EIP-2539,
+/// or `None` if the encoding is not canonical, or if the point is not in the prime-order subgroup.
+pub fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != G1_SIZE {
+        return None;
+    }
+    let x = decode_field::<Fq>(&bytes[..FQ_SIZE])?;
+    let y = decode_field::<Fq>(&bytes[FQ_SIZE..])?;
+    match x.is_zero() && y.is_zero() {
+        true => Some(G1Affine::zero()),
+        false => G1Affine::from_coordinates((x, y, false)),
+    }
+}
+
+/// Returns the encoding of the given point of G2, as in EIP-2539.
+/// The point at infinity is encoded as zeros.
+pub fn encode_g2(point: &G2Affine) -> Vec<u8> {
+    match point.is_zero() {
+        true => vec![0u8; G2_SIZE],
+        false => [point.x.c0, point.x.c1, point.y.c0, point.y.c1]
+            .iter()
+            .flat_map(|element| encode_field(element, FQ_SIZE))
+            .collect(),
+    }
+}
+
+/// Returns the point of G2 encoded by the given bytes, as in EIP-2539,
+/// or `None` if the encoding is not canonical, or if the point is not in the prime-order subgroup.
+pub fn decode_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != G2_SIZE {
+        return None;
+    }
+    let elements = bytes.chunks(FQ_SIZE).map(decode_field::<Fq>).collect::<Option<Vec<_>>>()?;
+    let (x, y) = (Fq2::new(elements[0], elements[1]), Fq2::new(elements[2], elements[3]));
+    match x.is_zero() && y.is_zero() {
+        true => Some(G2Affine::zero()),
+        false => G2Affine::from_coordinates((x, y, false)),
+    }
+}
+
+/// Returns the big-endian encoding of the given field element, left-padded with zeros to `size` bytes.
+fn encode_field<F: PrimeField>(element: &F, size: usize) -> Vec<u8> {
+    let mut bytes = element.to_bigint().to_bytes_le().unwrap();
+    bytes.resize(size, 0);
+    bytes.reverse();
+    bytes
+}
+
+/// Returns the field element encoded by the given big-endian bytes, which are left-padded with zeros,
+/// or `None` if the encoding is not canonical.
+fn decode_field<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    let num_padding_bytes = bytes.len().checked_sub(<F::BigInteger as BigInteger>::NUM_LIMBS * 8)?;
+    let (padding, bytes) = bytes.split_at(num_padding_bytes);
+    if padding.iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    let bytes = bytes.iter().rev().copied().collect::<Vec<_>>();
+    F::from_bigint(F::BigInteger::read_le(&bytes[..]).ok()?)
+}
+
+/// The byte offsets of the elements of a proof, as encoded by `encode_proof`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct ProofLayout {
+    /// The offset of the commitment to `w`.
+    pub(super) w: usize,
+    /// The offset of the commitment to the masking polynomial, in hiding mode.
+    pub(super) mask_poly: Option<usize>,
+    /// The offset of the commitment to `h_0`.
+    pub(super) h_0: usize,
+    /// The offset of the commitment to `g_1`.
+    pub(super) g_1: usize,
+    /// The offset of the commitment to `h_1`.
+    pub(super) h_1: usize,
+    /// The offsets of the commitments to `g_a`, `g_b`, and `g_c`.
+    pub(super) g_m: [usize; 3],
+    /// The offset of the commitment to `h_2`.
+    pub(super) h_2: usize,
+    /// The offset of the evaluation of `g_1` at `beta`, which is followed by `g_m_evals`.
+    pub(super) g_1_eval: usize,
+    /// The offsets of the evaluations of `g_a`, `g_b`, and `g_c` at `gamma`.
+    pub(super) g_m_evals: [usize; 3],
+    /// The offsets of the sums of the matrices in the third message of the prover.
+    pub(super) third_sums: [usize; 3],
+    /// The offsets of the sums of the matrices in the fourth message of the prover.
+    pub(super) fourth_sums: [usize; 3],
+    /// The offsets of the KZG opening proofs at `alpha`, `beta`, and `gamma`, and of their random evaluations in hiding mode.
+    pub(super) pc_proofs: [(usize, Option<usize>); 3],
+    /// The size of the encoding.
+    pub(super) size: usize,
+}
+
+impl ProofLayout {
+    /// Returns the layout of a proof in the given hiding mode.
+    pub(super) fn new(is_hiding: bool) -> Self {
+        let mut size = 0;
+        let mut next = |num_bytes: usize| {
+            size += num_bytes;
+            size - num_bytes
+        };
+        let w = next(G1_SIZE);
+        let mask_poly = is_hiding.then(|| next(G1_SIZE));
+        let h_0 = next(G1_SIZE);
+        let g_1 = next(G1_SIZE);
+        let h_1 = next(G1_SIZE);
+        let g_m = [next(G1_SIZE), next(G1_SIZE), next(G1_SIZE)];
+        let h_2 = next(G1_SIZE);
+        let g_1_eval = next(WORD_SIZE);
+        let g_m_evals = [next(WORD_SIZE), next(WORD_SIZE), next(WORD_SIZE)];
+        let third_sums = [next(WORD_SIZE), next(WORD_SIZE), next(WORD_SIZE)];
+        let fourth_sums = [next(WORD_SIZE), next(WORD_SIZE), next(WORD_SIZE)];
+        let pc_proofs = [(); 3].map(|_| (next(G1_SIZE), is_hiding.then(|| next(WORD_SIZE))));
+        Self { w, mask_poly, h_0, g_1, h_1, g_m, h_2, g_1_eval, g_m_evals, third_sums, fourth_sums, pc_proofs, size }
+    }
+}
+
+/// Returns the encoding of the given Varuna proof, as expected by `EvmVerifier::verify` and its contract.
+/// The points are encoded as in EIP-2539, and the scalars as big-endian words, at the offsets of `ProofLayout`.
+///
+/// This method fails if the proof is not for a single instance of a single circuit.
+pub fn encode_proof(proof: &Proof<Bls12_377>) -> Result<Vec<u8>> {
+    proof.check_batch_sizes()?;
+    ensure!(proof.batch_sizes() == [1], "The proof is not for a single instance of a single circuit");
+    let pc_proofs = proof.pc_proof.proof.proofs();
+    ensure!(pc_proofs.len() == 3, "The proof has {} KZG opening proofs, instead of 3", pc_proofs.len());
+    let is_hiding = proof.commitments.mask_poly.is_some();
+    ensure!(
+        pc_proofs.iter().all(|pc_proof| pc_proof.random_v.is_some() == is_hiding),
+        "The proof has an inconsistent hiding mode"
+    );
+
+    let layout = ProofLayout::new(is_hiding);
+    let mut bytes = vec![0u8; layout.size];
+    let mut write = |offset: usize, encoding: Vec<u8>| bytes[offset..][..encoding.len()].copy_from_slice(&encoding);
+
+    let commitments = &proof.commitments;
+    write(layout.w, encode_g1(&commitments.witness_commitments[0].w.0));
+    if let (Some(offset), Some(mask_poly)) = (layout.mask_poly, &commitments.mask_poly) {
+        write(offset, encode_g1(&mask_poly.0));
+    }
+    write(layout.h_0, encode_g1(&commitments.h_0.0));
+    write(layout.g_1, encode_g1(&commitments.g_1.0));
+    write(layout.h_1, encode_g1(&commitments.h_1.0));
+    let g_m = [&commitments.g_a_commitments[0], &commitments.g_b_commitments[0], &commitments.g_c_commitments[0]];
+    for (offset, commitment) in layout.g_m.into_iter().zip(g_m) {
+        write(offset, encode_g1(&commitment.0));
+    }
+    write(layout.h_2, encode_g1(&commitments.h_2.0));
+
+    let evaluations = &proof.evaluations;
+    write(layout.g_1_eval, encode_fr(&evaluations.g_1_eval));
+    let g_m_evals = [evaluations.g_a_evals[0], evaluations.g_b_evals[0], evaluations.g_c_evals[0]];
+    for (offset, evaluation) in layout.g_m_evals.into_iter().zip(g_m_evals) {
+        write(offset, encode_fr(&evaluation));
+    }
+    let third_sums = &proof.third_msg.sums[0][0];
+    let fourth_sums = &proof.fourth_msg.sums[0];
+    for (offsets, sums) in [(layout.third_sums, third_sums), (layout.fourth_sums, fourth_sums)] {
+        for (offset, sum) in offsets.into_iter().zip([sums.sum_a, sums.sum_b, sums.sum_c]) {
+            write(offset, encode_fr(&sum));
+        }
+    }
+
+    for ((offset, random_v_offset), pc_proof) in layout.pc_proofs.into_iter().zip(pc_proofs) {
+        write(offset, encode_g1(&pc_proof.w));
+        if let (Some(offset), Some(random_v)) = (random_v_offset, pc_proof.random_v) {
+            write(offset, encode_fr(&random_v));
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_encoding_round_trips() {
+        let rng = &mut TestRng::default();
+        for _ in 0..ITERATIONS {
+            let scalar = Fr::rand(rng);
+            assert_eq!(Some(scalar), decode_fr(&encode_fr(&scalar)));
+
+            let g1 = G1Affine::rand(rng);
+            assert_eq!(Some(g1), decode_g1(&encode_g1(&g1)));
+
+            let g2 = G2Affine::rand(rng);
+            assert_eq!(Some(g2), decode_g2(&encode_g2(&g2)));
+        }
+        assert_eq!(Some(G1Affine::zero()), decode_g1(&encode_g1(&G1Affine::zero())));
+        assert_eq!(Some(G2Affine::zero()), decode_g2(&encode_g2(&G2Affine::zero())));
+    }
+
+    #[test]
+    fn test_decoding_rejects_non_canonical_encodings() {
+        // Ensure the modulus is rejected.
+        let mut modulus = Fr::modulus().to_bytes_le().unwrap();
+        modulus.reverse();
+        assert_eq!(None, decode_fr(&modulus));
+        assert_eq!(None, decode_fr(&[0u8; WORD_SIZE + 1]));
+
+        // Ensure the padding of the coordinates must be zero.
+        let rng = &mut TestRng::default();
+        let mut encoding = encode_g1(&G1Affine::rand(rng));
+        encoding[0] = 1;
+        assert_eq!(None, decode_g1(&encoding));
+
+        // Ensure points outside of the curve are rejected.
+        let mut encoding = encode_g1(&G1Affine::rand(rng));
+        encoding[G1_SIZE - 1] ^= 1;
+        assert_eq!(None, decode_g1(&encoding));
+    }
+
+    #[test]
+    fn test_proof_layout() {
+        for is_hiding in [true, false] {
+            let layout = ProofLayout::new(is_hiding);
+            let num_points = 8 + usize::from(is_hiding) + 3;
+            let num_words = 10 + 3 * usize::from(is_hiding);
+            assert_eq!(num_points * G1_SIZE + num_words * WORD_SIZE, layout.size);
+            assert_eq!(layout.g_1_eval + WORD_SIZE, layout.g_m_evals[0]);
+            assert_eq!(layout.g_m_evals[2] + WORD_SIZE, layout.third_sums[0]);
+            assert_eq!(layout.third_sums[2] + WORD_SIZE, layout.fourth_sums[0]);
+        }
+    }
+}
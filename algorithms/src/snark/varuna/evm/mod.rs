@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of Varuna proofs on the EVM.
+//!
+//! `EvmVerifier` is a reference implementation, in Rust, of the Solidity contract that it generates.
+//! Both verify a proof of a single instance of a fixed circuit over BLS12-377, created with the
+//! Keccak transcript `EvmFiatShamir`, and follow the same steps on the same encodings of the inputs.
+//! The group operations are delegated to the EIP-2539 precompiles for BLS12-377, whose behavior
+//! is given in Rust by `g1_multiexp` and `pairing`.
+//!
+//! Note: Ethereum L1 does not provide the EIP-2539 precompiles, so the contract can only be deployed
+//! on a chain that does, at the addresses given to `EvmVerifier::to_solidity`.
+
+mod encoding;
+pub use encoding::*;
+
+mod precompiles;
+pub use precompiles::*;
+
+mod solidity;
+
+mod verifier;
+pub use verifier::*;
+
+use crate::{
+    crypto_hash::KeccakSponge,
+    fft::EvaluationDomain,
+    snark::varuna::{AHPForR1CS, CircuitId, CircuitVerifyingKey, Proof, SNARKMode, VarunaSNARK},
+    srs::UniversalVerifier,
+    AlgebraicSponge,
+};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fq, Fq2, Fr, G1Affine, G2Affine},
+    AffineCurve,
+    PairingCurve,
+    PairingEngine,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{Field, One, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{BigInteger, FromBytes, ToBytes};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+/// The Fiat-Shamir transcript of the Varuna proofs that can be verified on the EVM.
+pub type EvmFiatShamir = KeccakSponge<Fq, 2>;
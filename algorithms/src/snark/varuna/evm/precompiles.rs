@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bytes of a point and a scalar, in the input of `BLS12_377_G1MULTIEXP`.
+const MULTIEXP_PAIR_SIZE: usize = G1_SIZE + WORD_SIZE;
+/// The number of bytes of a pair of points, in the input of `BLS12_377_PAIRING`.
+const PAIRING_PAIR_SIZE: usize = G1_SIZE + G2_SIZE;
+
+/// The addresses of the EIP-2539 precompiles for BLS12-377 that are called by the contract,
+/// on the chain that it is deployed to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Eip2539Precompiles {
+    /// The address of `BLS12_377_G1MULTIEXP`.
+    pub g1_multiexp: u64,
+    /// The address of `BLS12_377_PAIRING`.
+    pub pairing: u64,
+}
+
+/// Returns the output of the `BLS12_377_G1MULTIEXP` precompile of EIP-2539 on the given input,
+/// or `None` if the call fails.
+///
+/// The input is a non-empty sequence of points of G1 and of words, which are multiplied as scalars,
+/// and the output is the encoding of the sum of the products.
+pub fn g1_multiexp(input: &[u8]) -> Option<Vec<u8>> {
+    if input.is_empty() || input.len() % MULTIEXP_PAIR_SIZE != 0 {
+        return None;
+    }
+    let mut output = <Bls12_377 as PairingEngine>::G1Projective::zero();
+    for pair in input.chunks(MULTIEXP_PAIR_SIZE) {
+        let point = decode_g1(&pair[..G1_SIZE])?;
+        // Note: The word is reduced modulo the order of the subgroup, which contains the point.
+        output += point * Fr::from_bytes_be_mod_order(&pair[G1_SIZE..]);
+    }
+    Some(encode_g1(&output.to_affine()))
+}
+
+/// Returns the output of the `BLS12_377_PAIRING` precompile of EIP-2539 on the given input,
+/// or `None` if the call fails.
+///
+/// The input is a non-empty sequence of points of G1 and G2, and the output is the word `1`
+/// if the product of their pairings is one, and the word `0` otherwise.
+pub fn pairing(input: &[u8]) -> Option<Vec<u8>> {
+    if input.is_empty() || input.len() % PAIRING_PAIR_SIZE != 0 {
+        return None;
+    }
+    let pairs = input
+        .chunks(PAIRING_PAIR_SIZE)
+        .map(|pair| Some((decode_g1(&pair[..G1_SIZE])?.prepare(), decode_g2(&pair[G1_SIZE..])?.prepare())))
+        .collect::<Option<Vec<_>>>()?;
+    let is_one = Bls12_377::product_of_pairings(pairs.iter().map(|(g1, g2)| (g1, g2))).is_one();
+
+    let mut output = vec![0u8; WORD_SIZE];
+    output[WORD_SIZE - 1] = u8::from(is_one);
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_g1_multiexp() {
+        let rng = &mut TestRng::default();
+        let (a, b) = (G1Affine::rand(rng), G1Affine::rand(rng));
+        let (x, y) = (Fr::rand(rng), Fr::rand(rng));
+
+        let input = [encode_g1(&a), encode_fr(&x), encode_g1(&b), encode_fr(&y)].concat();
+        let expected = (a * x + b * y).to_affine();
+        assert_eq!(Some(encode_g1(&expected)), g1_multiexp(&input));
+
+        // Ensure the call fails on an empty or a truncated input.
+        assert_eq!(None, g1_multiexp(&[]));
+        assert_eq!(None, g1_multiexp(&input[1..]));
+    }
+
+    #[test]
+    fn test_pairing() {
+        let rng = &mut TestRng::default();
+        let (g, h) = (G1Affine::prime_subgroup_generator(), G2Affine::prime_subgroup_generator());
+        let x = Fr::rand(rng);
+
+        // Ensure e(x * g, h) * e(-g, x * h) is one.
+        let (xg, xh) = ((g * x).to_affine(), (h * x).to_affine());
+        let input = [encode_g1(&xg), encode_g2(&h), encode_g1(&-g), encode_g2(&xh)].concat();
+        assert_eq!(Some(encode_fr(&Fr::one())), pairing(&input));
+
+        // Ensure e(x * g, h) * e(g, x * h) is not one.
+        let input = [encode_g1(&xg), encode_g2(&h), encode_g1(&g), encode_g2(&xh)].concat();
+        assert_eq!(Some(encode_fr(&Fr::zero())), pairing(&input));
+        assert_eq!(None, pairing(&[]));
+    }
+}
@@ -0,0 +1,555 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.19;
+
+/// @title {{CONTRACT_NAME}}
+/// @notice Verifies Varuna proofs of a single instance of a fixed circuit over BLS12-377.
+/// @dev This contract is generated by `EvmVerifier::to_solidity` in snarkVM, whose `EvmVerifier::verify`
+/// follows the same steps. The group operations call the EIP-2539 precompiles for BLS12-377.
+contract {{CONTRACT_NAME}} {
+    /// The order of the scalar field of BLS12-377.
+    uint256 internal constant R = {{R}};
+    /// 2^256 modulo R.
+    uint256 internal constant TWO_256 = {{TWO_256}};
+
+    /// The addresses of the precompiles.
+    address internal constant MODEXP = address(uint160(0x05));
+    address internal constant G1_MULTIEXP = address(uint160({{G1_MULTIEXP_ADDRESS}}));
+    address internal constant PAIRING = address(uint160({{PAIRING_ADDRESS}}));
+
+    /// The sizes of the encodings of a point of G1, and of an element sampled from the transcript.
+    uint256 internal constant G1_SIZE = 128;
+    uint256 internal constant SAMPLE_SIZE = 48;
+    /// The number of bytes of a short challenge, which is shifted out of a word.
+    uint256 internal constant SHORT_SAMPLE_SIZE = 21;
+    uint256 internal constant SHORT_SAMPLE_SHIFT = 88;
+
+    /// Whether the proofs are hiding.
+    bool internal constant ZK = {{ZK}};
+
+    /// The domains of the circuit.
+    uint256 internal constant CONSTRAINT_LOG_SIZE = {{CONSTRAINT_LOG_SIZE}};
+    uint256 internal constant VARIABLE_LOG_SIZE = {{VARIABLE_LOG_SIZE}};
+    uint256 internal constant VARIABLE_SIZE_INV = {{VARIABLE_SIZE_INV}};
+    uint256 internal constant INPUT_LOG_SIZE = {{INPUT_LOG_SIZE}};
+    uint256 internal constant INPUT_SIZE = {{INPUT_SIZE}};
+    uint256 internal constant INPUT_SIZE_INV = {{INPUT_SIZE_INV}};
+    uint256 internal constant INPUT_GENERATOR = {{INPUT_GENERATOR}};
+    uint256 internal constant NON_ZERO_LOG_SIZE_A = {{NON_ZERO_LOG_SIZE_A}};
+    uint256 internal constant NON_ZERO_LOG_SIZE_B = {{NON_ZERO_LOG_SIZE_B}};
+    uint256 internal constant NON_ZERO_LOG_SIZE_C = {{NON_ZERO_LOG_SIZE_C}};
+    uint256 internal constant NON_ZERO_SIZE_A = {{NON_ZERO_SIZE_A}};
+    uint256 internal constant NON_ZERO_SIZE_B = {{NON_ZERO_SIZE_B}};
+    uint256 internal constant NON_ZERO_SIZE_C = {{NON_ZERO_SIZE_C}};
+    uint256 internal constant MAX_NON_ZERO_LOG_SIZE = {{MAX_NON_ZERO_LOG_SIZE}};
+    uint256 internal constant MAX_NON_ZERO_SIZE_INV = {{MAX_NON_ZERO_SIZE_INV}};
+    /// The product of the sizes of the constraint and variable domains.
+    uint256 internal constant RC = {{RC}};
+
+    /// The length of a proof, and the offsets of its elements.
+    uint256 internal constant PROOF_LENGTH = {{PROOF_LENGTH}};
+    uint256 internal constant W = {{W}};
+    uint256 internal constant MASK_POLY = {{MASK_POLY}};
+    uint256 internal constant H_0 = {{H_0}};
+    uint256 internal constant G_1 = {{G_1}};
+    uint256 internal constant H_1 = {{H_1}};
+    uint256 internal constant G_A = {{G_A}};
+    uint256 internal constant G_B = {{G_B}};
+    uint256 internal constant G_C = {{G_C}};
+    uint256 internal constant H_2 = {{H_2}};
+    uint256 internal constant G_1_EVAL = {{G_1_EVAL}};
+    uint256 internal constant G_A_EVAL = {{G_A_EVAL}};
+    uint256 internal constant G_B_EVAL = {{G_B_EVAL}};
+    uint256 internal constant G_C_EVAL = {{G_C_EVAL}};
+    uint256 internal constant SUM_A = {{SUM_A}};
+    uint256 internal constant SUM_B = {{SUM_B}};
+    uint256 internal constant SUM_C = {{SUM_C}};
+    uint256 internal constant SUM_A_4 = {{SUM_A_4}};
+    uint256 internal constant SUM_B_4 = {{SUM_B_4}};
+    uint256 internal constant SUM_C_4 = {{SUM_C_4}};
+    uint256 internal constant PC_PROOF_ALPHA = {{PC_PROOF_ALPHA}};
+    uint256 internal constant PC_PROOF_BETA = {{PC_PROOF_BETA}};
+    uint256 internal constant PC_PROOF_GAMMA = {{PC_PROOF_GAMMA}};
+    uint256 internal constant RANDOM_V_ALPHA = {{RANDOM_V_ALPHA}};
+    uint256 internal constant RANDOM_V_BETA = {{RANDOM_V_BETA}};
+    uint256 internal constant RANDOM_V_GAMMA = {{RANDOM_V_GAMMA}};
+
+    /// The absorbed protocol name and batch size, the absorbed commitments of the circuit,
+    /// and the absorbed point at infinity.
+    bytes internal constant TRANSCRIPT_PREFIX = hex"{{TRANSCRIPT_PREFIX}}";
+    bytes internal constant CIRCUIT_COMMITMENTS_TRANSCRIPT = hex"{{CIRCUIT_COMMITMENTS_TRANSCRIPT}}";
+    bytes internal constant INFINITY_TRANSCRIPT = hex"{{INFINITY_TRANSCRIPT}}";
+
+    /// The commitments to the index polynomials of the circuit.
+    bytes internal constant ROW_A = hex"{{ROW_A}}";
+    bytes internal constant COL_A = hex"{{COL_A}}";
+    bytes internal constant ROW_COL_A = hex"{{ROW_COL_A}}";
+    bytes internal constant ROW_COL_VAL_A = hex"{{ROW_COL_VAL_A}}";
+    bytes internal constant ROW_B = hex"{{ROW_B}}";
+    bytes internal constant COL_B = hex"{{COL_B}}";
+    bytes internal constant ROW_COL_B = hex"{{ROW_COL_B}}";
+    bytes internal constant ROW_COL_VAL_B = hex"{{ROW_COL_VAL_B}}";
+    bytes internal constant ROW_C = hex"{{ROW_C}}";
+    bytes internal constant COL_C = hex"{{COL_C}}";
+    bytes internal constant ROW_COL_C = hex"{{ROW_COL_C}}";
+    bytes internal constant ROW_COL_VAL_C = hex"{{ROW_COL_VAL_C}}";
+
+    /// The KZG verifying key, and the points of G2 that enforce the degree bounds.
+    bytes internal constant G = hex"{{G}}";
+    bytes internal constant GAMMA_G = hex"{{GAMMA_G}}";
+    bytes internal constant H = hex"{{H}}";
+    bytes internal constant BETA_H = hex"{{BETA_H}}";
+    bytes internal constant G_1_SHIFT = hex"{{G_1_SHIFT}}";
+    bytes internal constant G_A_SHIFT = hex"{{G_A_SHIFT}}";
+    bytes internal constant G_B_SHIFT = hex"{{G_B_SHIFT}}";
+    bytes internal constant G_C_SHIFT = hex"{{G_C_SHIFT}}";
+
+    /// The transcript and the intermediate values of a verification.
+    struct State {
+        bytes32 digest;
+        bytes pending;
+        uint256 alpha;
+        uint256 etaB;
+        uint256 etaC;
+        uint256 beta;
+        uint256 deltaB;
+        uint256 deltaC;
+        uint256 gamma;
+        uint256 vRAtAlpha;
+        uint256 vCAtBeta;
+        uint256 vKAtGamma;
+        uint256 vXAtBeta;
+        uint256 lineval;
+        uint256 rowcheckValue;
+        uint256 linevalValue;
+        uint256 matrixValue;
+        uint256[3] matrixCoefficients;
+        uint256[3] matrixB;
+        uint256 challengeRowcheck;
+        uint256 randomizerBeta;
+        uint256 challengeG1;
+        uint256 challengeLineval;
+        uint256 randomizerGamma;
+        uint256[3] challengesGM;
+        uint256 challengeMatrix;
+    }
+
+    /// @notice Returns `true` if the given proof is valid for the given public inputs.
+    /// @param inputs The public inputs, without the leading one of the instance.
+    /// @param proof The proof, as encoded by `encode_proof` in snarkVM.
+    function verify(uint256[] calldata inputs, bytes calldata proof) external view returns (bool) {
+        if (inputs.length >= INPUT_SIZE || proof.length != PROOF_LENGTH) {
+            return false;
+        }
+        for (uint256 i = 0; i < inputs.length; i++) {
+            if (inputs[i] >= R) {
+                return false;
+            }
+        }
+        if (!checkScalars(proof)) {
+            return false;
+        }
+        State memory s;
+        if (!computeChallenges(s, inputs, proof)) {
+            return false;
+        }
+        if (!computeLinearCombinations(s, inputs, proof)) {
+            return false;
+        }
+        squeezeOpeningChallenges(s);
+        return checkPairing(s, proof);
+    }
+
+    /// Returns `true` if the scalars of the proof are canonical.
+    function checkScalars(bytes calldata proof) internal pure returns (bool) {
+        // The evaluations and the sums are contiguous.
+        for (uint256 offset = G_1_EVAL; offset <= SUM_C_4; offset += 32) {
+            if (word(proof, offset) >= R) {
+                return false;
+            }
+        }
+        if (ZK) {
+            return word(proof, RANDOM_V_ALPHA) < R && word(proof, RANDOM_V_BETA) < R && word(proof, RANDOM_V_GAMMA) < R;
+        }
+        return true;
+    }
+
+    /// Computes the challenges of the verifier, and returns `false` if a challenge is in the domain that it is checked against.
+    function computeChallenges(State memory s, uint256[] calldata inputs, bytes calldata proof)
+        internal
+        pure
+        returns (bool)
+    {
+        // Absorb the protocol name, the batch size, the padded public inputs, and the circuit commitments.
+        s.pending = TRANSCRIPT_PREFIX;
+        absorb(s, abi.encodePacked(uint256(1), inputs, new bytes(32 * (INPUT_SIZE - 1 - inputs.length))));
+        absorb(s, CIRCUIT_COMMITMENTS_TRANSCRIPT);
+
+        // Absorb the first round.
+        absorbPoint(s, proof, W);
+        if (ZK) {
+            absorbPoint(s, proof, MASK_POLY);
+        }
+
+        // Absorb the second round, and squeeze `alpha`, `eta_b`, and `eta_c`.
+        absorbPoint(s, proof, H_0);
+        uint256[] memory elements = squeezeElements(s, 3);
+        (s.alpha, s.etaB, s.etaC) = (elements[0], elements[1], elements[2]);
+        s.vRAtAlpha = vanishing(s.alpha, CONSTRAINT_LOG_SIZE);
+        if (s.vRAtAlpha == 0) {
+            return false;
+        }
+
+        // Absorb the third round, and squeeze `beta`.
+        absorbPoint(s, proof, G_1);
+        absorbPoint(s, proof, H_1);
+        absorb(s, proof[SUM_A:SUM_C + 32]);
+        s.beta = squeezeElements(s, 1)[0];
+        s.vCAtBeta = vanishing(s.beta, VARIABLE_LOG_SIZE);
+        if (s.vCAtBeta == 0) {
+            return false;
+        }
+
+        // Absorb the fourth round, and squeeze `delta_b` and `delta_c`.
+        absorbPoint(s, proof, G_A);
+        absorbPoint(s, proof, G_B);
+        absorbPoint(s, proof, G_C);
+        absorb(s, proof[SUM_A_4:SUM_C_4 + 32]);
+        elements = squeezeElements(s, 2);
+        (s.deltaB, s.deltaC) = (elements[0], elements[1]);
+
+        // Absorb the fifth round, and squeeze `gamma`.
+        absorbPoint(s, proof, H_2);
+        s.gamma = squeezeElements(s, 1)[0];
+        s.vKAtGamma = vanishing(s.gamma, MAX_NON_ZERO_LOG_SIZE);
+        if (s.vKAtGamma == 0) {
+            return false;
+        }
+
+        // Absorb the evaluations.
+        absorb(s, proof[G_1_EVAL:G_C_EVAL + 32]);
+        return true;
+    }
+
+    /// Computes the linear combinations of the rowcheck, lineval sumcheck, and matrix sumcheck,
+    /// and returns `false` if an inverse does not exist.
+    function computeLinearCombinations(State memory s, uint256[] calldata inputs, bytes calldata proof)
+        internal
+        view
+        returns (bool)
+    {
+        (bool ok, uint256 xAtBeta) = evaluateInputPolynomial(s, inputs);
+        if (!ok) {
+            return false;
+        }
+
+        // Compute the constant terms of the rowcheck and lineval sumcheck linear combinations.
+        uint256 sumA = word(proof, SUM_A);
+        uint256 sumB = word(proof, SUM_B);
+        uint256 sumC = word(proof, SUM_C);
+        s.rowcheckValue = neg(addmod(mulmod(sumA, sumB, R), R - sumC, R));
+        s.lineval = addmod(
+            mulmod(word(proof, SUM_A_4), NON_ZERO_SIZE_A, R),
+            addmod(
+                mulmod(mulmod(s.etaB, word(proof, SUM_B_4), R), NON_ZERO_SIZE_B, R),
+                mulmod(mulmod(s.etaC, word(proof, SUM_C_4), R), NON_ZERO_SIZE_C, R),
+                R
+            ),
+            R
+        );
+        uint256 linevalSum = mulmod(
+            addmod(sumA, addmod(mulmod(s.etaB, sumB, R), mulmod(s.etaC, sumC, R), R), R), VARIABLE_SIZE_INV, R
+        );
+        s.linevalValue = neg(
+            addmod(mulmod(s.lineval, xAtBeta, R), R - addmod(mulmod(s.beta, word(proof, G_1_EVAL), R), linevalSum, R), R)
+        );
+
+        // Compute the coefficients and the constant term of the matrix sumcheck linear combination.
+        return computeMatrixCoefficients(s, proof, 0, 1, NON_ZERO_LOG_SIZE_A, NON_ZERO_SIZE_A, G_A_EVAL, SUM_A_4)
+            && computeMatrixCoefficients(s, proof, 1, s.deltaB, NON_ZERO_LOG_SIZE_B, NON_ZERO_SIZE_B, G_B_EVAL, SUM_B_4)
+            && computeMatrixCoefficients(s, proof, 2, s.deltaC, NON_ZERO_LOG_SIZE_C, NON_ZERO_SIZE_C, G_C_EVAL, SUM_C_4);
+    }
+
+    /// Evaluates the public input polynomial at `beta`, in the Lagrange basis of the input domain,
+    /// and returns `false` if an inverse does not exist.
+    function evaluateInputPolynomial(State memory s, uint256[] calldata inputs) internal view returns (bool, uint256) {
+        s.vXAtBeta = vanishing(s.beta, INPUT_LOG_SIZE);
+        uint256 xAtBeta = 0;
+        uint256 element = 1;
+        for (uint256 i = 0; i <= inputs.length; i++) {
+            (bool ok, uint256 denominator) = inverse(addmod(s.beta, R - element, R));
+            if (!ok) {
+                return (false, 0);
+            }
+            uint256 input = i == 0 ? 1 : inputs[i - 1];
+            xAtBeta = addmod(xAtBeta, mulmod(mulmod(input, element, R), denominator, R), R);
+            element = mulmod(element, INPUT_GENERATOR, R);
+        }
+        return (true, mulmod(xAtBeta, mulmod(s.vXAtBeta, INPUT_SIZE_INV, R), R));
+    }
+
+    /// Computes the coefficients of the given matrix in the matrix sumcheck linear combination,
+    /// and adds its constant term, and returns `false` if an inverse does not exist.
+    function computeMatrixCoefficients(
+        State memory s,
+        bytes calldata proof,
+        uint256 i,
+        uint256 delta,
+        uint256 logSize,
+        uint256 size,
+        uint256 evalOffset,
+        uint256 sumOffset
+    ) internal view returns (bool) {
+        uint256 selector = 1;
+        if (logSize != MAX_NON_ZERO_LOG_SIZE) {
+            (bool ok, uint256 denominator) = inverse(vanishing(s.gamma, logSize));
+            if (!ok) {
+                return false;
+            }
+            selector = mulmod(mulmod(s.vKAtGamma, mulmod(size, MAX_NON_ZERO_SIZE_INV, R), R), denominator, R);
+        }
+        uint256 coefficient = mulmod(delta, selector, R);
+        uint256 b = mulmod(
+            mulmod(coefficient, addmod(mulmod(s.gamma, word(proof, evalOffset), R), word(proof, sumOffset), R), R), RC, R
+        );
+        s.matrixValue = addmod(s.matrixValue, mulmod(mulmod(b, s.alpha, R), s.beta, R), R);
+        s.matrixCoefficients[i] = coefficient;
+        s.matrixB[i] = b;
+        return true;
+    }
+
+    /// Squeezes the opening challenges of the linear combinations at `alpha`, `beta`, and `gamma`,
+    /// and the randomizers of the points after the first.
+    function squeezeOpeningChallenges(State memory s) internal pure {
+        s.challengeRowcheck = squeezeShort(s);
+        s.randomizerBeta = squeezeShort(s);
+        s.challengeG1 = squeezeShort(s);
+        s.challengeLineval = squeezeShort(s);
+        s.randomizerGamma = squeezeShort(s);
+        s.challengesGM[0] = squeezeShort(s);
+        s.challengesGM[1] = squeezeShort(s);
+        s.challengesGM[2] = squeezeShort(s);
+        s.challengeMatrix = squeezeShort(s);
+    }
+
+    /// Returns `true` if the KZG openings of the linear combinations are valid.
+    function checkPairing(State memory s, bytes calldata proof) internal view returns (bool) {
+        (bool success, bytes memory output) = multiexp(combinedCommitmentInput(s, proof));
+        bytes memory input = bytes.concat(output, H);
+
+        // Pair each commitment with a degree bound with its shift.
+        bool ok;
+        (ok, output) = boundedTerm(proof, G_1, mulmod(s.randomizerBeta, s.challengeG1, R));
+        input = bytes.concat(input, output, G_1_SHIFT);
+        success = success && ok;
+        (ok, output) = boundedTerm(proof, G_A, mulmod(s.randomizerGamma, s.challengesGM[0], R));
+        input = bytes.concat(input, output, G_A_SHIFT);
+        success = success && ok;
+        (ok, output) = boundedTerm(proof, G_B, mulmod(s.randomizerGamma, s.challengesGM[1], R));
+        input = bytes.concat(input, output, G_B_SHIFT);
+        success = success && ok;
+        (ok, output) = boundedTerm(proof, G_C, mulmod(s.randomizerGamma, s.challengesGM[2], R));
+        input = bytes.concat(input, output, G_C_SHIFT);
+        success = success && ok;
+
+        // Pair the opening proofs with `beta_h`.
+        (ok, output) = multiexp(
+            bytes.concat(
+                proof[PC_PROOF_ALPHA:PC_PROOF_ALPHA + G1_SIZE],
+                bytes32(R - 1),
+                proof[PC_PROOF_BETA:PC_PROOF_BETA + G1_SIZE],
+                bytes32(neg(s.randomizerBeta)),
+                proof[PC_PROOF_GAMMA:PC_PROOF_GAMMA + G1_SIZE],
+                bytes32(neg(s.randomizerGamma))
+            )
+        );
+        input = bytes.concat(input, output, BETA_H);
+        if (!success || !ok) {
+            return false;
+        }
+
+        (ok, output) = PAIRING.staticcall(input);
+        return ok && output.length == 32 && abi.decode(output, (uint256)) == 1;
+    }
+
+    /// Returns the product of the commitment with a degree bound at the given offset of the proof and the given scalar.
+    function boundedTerm(bytes calldata proof, uint256 offset, uint256 scalar)
+        internal
+        view
+        returns (bool, bytes memory)
+    {
+        return multiexp(bytes.concat(proof[offset:offset + G1_SIZE], bytes32(scalar)));
+    }
+
+    /// Returns the input of the multi-scalar multiplication of the commitments without a degree bound,
+    /// less the evaluations and the opening proofs.
+    function combinedCommitmentInput(State memory s, bytes calldata proof) internal pure returns (bytes memory input) {
+        uint256 linevalScalar = mulmod(s.randomizerBeta, s.challengeLineval, R);
+        uint256 matrixScalar = mulmod(s.randomizerGamma, s.challengeMatrix, R);
+        input = matrixTerms(s, input, 0, matrixScalar, ROW_COL_VAL_A, COL_A, ROW_A, ROW_COL_A);
+        input = matrixTerms(s, input, 1, matrixScalar, ROW_COL_VAL_B, COL_B, ROW_B, ROW_COL_B);
+        input = matrixTerms(s, input, 2, matrixScalar, ROW_COL_VAL_C, COL_C, ROW_C, ROW_COL_C);
+        input = bytes.concat(
+            input, proof[H_0:H_0 + G1_SIZE], bytes32(neg(mulmod(s.challengeRowcheck, s.vRAtAlpha, R)))
+        );
+        if (ZK) {
+            input = bytes.concat(input, proof[MASK_POLY:MASK_POLY + G1_SIZE], bytes32(linevalScalar));
+        }
+        input = bytes.concat(
+            input,
+            proof[W:W + G1_SIZE],
+            bytes32(mulmod(mulmod(linevalScalar, s.lineval, R), s.vXAtBeta, R)),
+            proof[H_1:H_1 + G1_SIZE],
+            bytes32(neg(mulmod(linevalScalar, s.vCAtBeta, R))),
+            proof[H_2:H_2 + G1_SIZE],
+            bytes32(neg(mulmod(matrixScalar, s.vKAtGamma, R)))
+        );
+        input = bytes.concat(input, G, bytes32(neg(combinedEvaluation(s, proof))));
+        if (ZK) {
+            uint256 combinedRandomV = addmod(
+                word(proof, RANDOM_V_ALPHA),
+                addmod(
+                    mulmod(s.randomizerBeta, word(proof, RANDOM_V_BETA), R),
+                    mulmod(s.randomizerGamma, word(proof, RANDOM_V_GAMMA), R),
+                    R
+                ),
+                R
+            );
+            input = bytes.concat(input, GAMMA_G, bytes32(neg(combinedRandomV)));
+        }
+        input = bytes.concat(
+            input,
+            proof[PC_PROOF_ALPHA:PC_PROOF_ALPHA + G1_SIZE],
+            bytes32(s.alpha),
+            proof[PC_PROOF_BETA:PC_PROOF_BETA + G1_SIZE],
+            bytes32(mulmod(s.randomizerBeta, s.beta, R)),
+            proof[PC_PROOF_GAMMA:PC_PROOF_GAMMA + G1_SIZE],
+            bytes32(mulmod(s.randomizerGamma, s.gamma, R))
+        );
+    }
+
+    /// Appends the terms of the given matrix to the input of a multi-scalar multiplication.
+    function matrixTerms(
+        State memory s,
+        bytes memory input,
+        uint256 i,
+        uint256 matrixScalar,
+        bytes memory rowColVal,
+        bytes memory col,
+        bytes memory row,
+        bytes memory rowCol
+    ) internal pure returns (bytes memory) {
+        uint256 b = mulmod(matrixScalar, s.matrixB[i], R);
+        uint256 vRC = mulmod(s.vRAtAlpha, s.vCAtBeta, R);
+        return bytes.concat(
+            input,
+            rowColVal,
+            bytes32(mulmod(mulmod(matrixScalar, s.matrixCoefficients[i], R), vRC, R)),
+            col,
+            bytes32(mulmod(b, s.alpha, R)),
+            row,
+            bytes32(mulmod(b, s.beta, R)),
+            rowCol,
+            bytes32(neg(b))
+        );
+    }
+
+    /// Returns the combination of the evaluations at `alpha`, `beta`, and `gamma`.
+    function combinedEvaluation(State memory s, bytes calldata proof) internal pure returns (uint256) {
+        uint256 atBeta = addmod(
+            mulmod(s.challengeG1, word(proof, G_1_EVAL), R), mulmod(s.challengeLineval, s.linevalValue, R), R
+        );
+        uint256 atGamma = mulmod(s.challengeMatrix, s.matrixValue, R);
+        atGamma = addmod(atGamma, mulmod(s.challengesGM[0], word(proof, G_A_EVAL), R), R);
+        atGamma = addmod(atGamma, mulmod(s.challengesGM[1], word(proof, G_B_EVAL), R), R);
+        atGamma = addmod(atGamma, mulmod(s.challengesGM[2], word(proof, G_C_EVAL), R), R);
+        return addmod(
+            mulmod(s.challengeRowcheck, s.rowcheckValue, R),
+            addmod(mulmod(s.randomizerBeta, atBeta, R), mulmod(s.randomizerGamma, atGamma, R), R),
+            R
+        );
+    }
+
+    /// Appends the given bytes to the transcript.
+    function absorb(State memory s, bytes memory data) internal pure {
+        s.pending = bytes.concat(s.pending, data);
+    }
+
+    /// Appends the point of G1 at the given offset of the proof to the transcript, as its affine coordinates
+    /// and its infinity flag, each as 48 big-endian bytes.
+    function absorbPoint(State memory s, bytes calldata proof, uint256 offset) internal pure {
+        bytes calldata point = proof[offset:offset + G1_SIZE];
+        if (bytes32(point[0:32]) | bytes32(point[32:64]) | bytes32(point[64:96]) | bytes32(point[96:128]) == bytes32(0)) {
+            absorb(s, INFINITY_TRANSCRIPT);
+        } else {
+            absorb(s, bytes.concat(point[16:64], point[80:128], new bytes(48)));
+        }
+    }
+
+    /// Folds the absorbed bytes into the digest, and returns at least `numBytes` squeezed bytes.
+    function squeeze(State memory s, uint256 numBytes) internal pure returns (bytes memory output) {
+        s.digest = keccak256(abi.encodePacked(uint8(0), s.digest, s.pending));
+        s.pending = "";
+        for (uint32 counter = 0; output.length < numBytes; counter++) {
+            output = bytes.concat(output, keccak256(abi.encodePacked(uint8(1), s.digest, counter)));
+        }
+    }
+
+    /// Squeezes `num` challenges, each reduced modulo R from 48 big-endian bytes.
+    function squeezeElements(State memory s, uint256 num) internal pure returns (uint256[] memory elements) {
+        bytes memory output = squeeze(s, num * SAMPLE_SIZE);
+        elements = new uint256[](num);
+        for (uint256 i = 0; i < num; i++) {
+            uint256 high = load(output, i * SAMPLE_SIZE) >> 128;
+            uint256 low = load(output, i * SAMPLE_SIZE + 16);
+            elements[i] = addmod(mulmod(high, TWO_256, R), low, R);
+        }
+    }
+
+    /// Squeezes a short challenge of 21 bytes.
+    function squeezeShort(State memory s) internal pure returns (uint256) {
+        return load(squeeze(s, SHORT_SAMPLE_SIZE), 0) >> SHORT_SAMPLE_SHIFT;
+    }
+
+    /// Returns the evaluation of the vanishing polynomial of the domain of size `2^logSize` at `x`.
+    function vanishing(uint256 x, uint256 logSize) internal pure returns (uint256) {
+        for (uint256 i = 0; i < logSize; i++) {
+            x = mulmod(x, x, R);
+        }
+        return addmod(x, R - 1, R);
+    }
+
+    /// Returns the inverse of `x` modulo R, or `false` if `x` is zero.
+    function inverse(uint256 x) internal view returns (bool, uint256) {
+        if (x == 0) {
+            return (false, 0);
+        }
+        (bool ok, bytes memory output) = MODEXP.staticcall(abi.encode(uint256(32), uint256(32), uint256(32), x, R - 2, R));
+        if (!ok || output.length != 32) {
+            return (false, 0);
+        }
+        return (true, abi.decode(output, (uint256)));
+    }
+
+    /// Returns the output of the G1 multi-scalar multiplication precompile on the given input.
+    function multiexp(bytes memory input) internal view returns (bool, bytes memory) {
+        (bool ok, bytes memory output) = G1_MULTIEXP.staticcall(input);
+        return (ok && output.length == G1_SIZE, output);
+    }
+
+    /// Returns `-x` modulo R.
+    function neg(uint256 x) internal pure returns (uint256) {
+        return x == 0 ? 0 : R - x;
+    }
+
+    /// Returns the word at the given offset of the proof.
+    function word(bytes calldata proof, uint256 offset) internal pure returns (uint256) {
+        return uint256(bytes32(proof[offset:offset + 32]));
+    }
+
+    /// Returns the word at the given offset of the given bytes.
+    function load(bytes memory data, uint256 offset) internal pure returns (uint256 value) {
+        assembly {
+            value := mload(add(add(data, 32), offset))
+        }
+    }
+}
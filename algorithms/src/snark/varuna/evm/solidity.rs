@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The template of the contract, whose `{{KEY}}` placeholders are replaced by `EvmVerifier::to_solidity`.
+const TEMPLATE: &str = include_str!("resources/VarunaVerifier.sol");
+
+impl<SM: SNARKMode> EvmVerifier<SM> {
+    /// Returns the Solidity source of a contract with the given name, which verifies the same proofs
+    /// as `EvmVerifier::verify`, by calling the EIP-2539 precompiles at the given addresses.
+    pub fn to_solidity(&self, contract_name: &str, precompiles: &Eip2539Precompiles) -> Result<String> {
+        if !is_identifier(contract_name) {
+            bail!("'{contract_name}' is not a valid name for a contract");
+        }
+
+        let layout = &self.layout;
+        let [non_zero_a, non_zero_b, non_zero_c] = self.non_zero_domains;
+        let [g_a_shift, g_b_shift, g_c_shift] = &self.g_m_shifts;
+        let [(pc_proof_alpha, random_v_alpha), (pc_proof_beta, random_v_beta), (pc_proof_gamma, random_v_gamma)] =
+            layout.pc_proofs;
+        let rc = self.constraint_domain.size_as_field_element * self.variable_domain.size_as_field_element;
+
+        let constants = [
+            ("CONTRACT_NAME", contract_name.to_string()),
+            ("R", format!("0x{}", hex::encode(encode_field_modulus()))),
+            ("TWO_256", scalar(&Fr::from_bytes_be_mod_order(&[&[1u8][..], &[0u8; 32]].concat()))),
+            ("G1_MULTIEXP_ADDRESS", format!("{:#x}", precompiles.g1_multiexp)),
+            ("PAIRING_ADDRESS", format!("{:#x}", precompiles.pairing)),
+            ("ZK", SM::ZK.to_string()),
+            ("CONSTRAINT_LOG_SIZE", self.constraint_domain.log_size_of_group.to_string()),
+            ("VARIABLE_LOG_SIZE", self.variable_domain.log_size_of_group.to_string()),
+            ("VARIABLE_SIZE_INV", scalar(&self.variable_domain.size_inv)),
+            ("INPUT_LOG_SIZE", self.input_domain.log_size_of_group.to_string()),
+            ("INPUT_SIZE", self.input_domain.size().to_string()),
+            ("INPUT_SIZE_INV", scalar(&self.input_domain.size_inv)),
+            ("INPUT_GENERATOR", scalar(&self.input_domain.group_gen)),
+            ("NON_ZERO_LOG_SIZE_A", non_zero_a.log_size_of_group.to_string()),
+            ("NON_ZERO_LOG_SIZE_B", non_zero_b.log_size_of_group.to_string()),
+            ("NON_ZERO_LOG_SIZE_C", non_zero_c.log_size_of_group.to_string()),
+            ("NON_ZERO_SIZE_A", non_zero_a.size().to_string()),
+            ("NON_ZERO_SIZE_B", non_zero_b.size().to_string()),
+            ("NON_ZERO_SIZE_C", non_zero_c.size().to_string()),
+            ("MAX_NON_ZERO_LOG_SIZE", self.max_non_zero_domain.log_size_of_group.to_string()),
+            ("MAX_NON_ZERO_SIZE_INV", scalar(&self.max_non_zero_domain.size_inv)),
+            ("RC", scalar(&rc)),
+            ("PROOF_LENGTH", layout.size.to_string()),
+            ("W", layout.w.to_string()),
+            ("MASK_POLY", layout.mask_poly.unwrap_or(0).to_string()),
+            ("H_0", layout.h_0.to_string()),
+            ("G_1", layout.g_1.to_string()),
+            ("H_1", layout.h_1.to_string()),
+            ("G_A", layout.g_m[0].to_string()),
+            ("G_B", layout.g_m[1].to_string()),
+            ("G_C", layout.g_m[2].to_string()),
+            ("H_2", layout.h_2.to_string()),
+            ("G_1_EVAL", layout.g_1_eval.to_string()),
+            ("G_A_EVAL", layout.g_m_evals[0].to_string()),
+            ("G_B_EVAL", layout.g_m_evals[1].to_string()),
+            ("G_C_EVAL", layout.g_m_evals[2].to_string()),
+            ("SUM_A", layout.third_sums[0].to_string()),
+            ("SUM_B", layout.third_sums[1].to_string()),
+            ("SUM_C", layout.third_sums[2].to_string()),
+            ("SUM_A_4", layout.fourth_sums[0].to_string()),
+            ("SUM_B_4", layout.fourth_sums[1].to_string()),
+            ("SUM_C_4", layout.fourth_sums[2].to_string()),
+            ("PC_PROOF_ALPHA", pc_proof_alpha.to_string()),
+            ("PC_PROOF_BETA", pc_proof_beta.to_string()),
+            ("PC_PROOF_GAMMA", pc_proof_gamma.to_string()),
+            ("RANDOM_V_ALPHA", random_v_alpha.unwrap_or(0).to_string()),
+            ("RANDOM_V_BETA", random_v_beta.unwrap_or(0).to_string()),
+            ("RANDOM_V_GAMMA", random_v_gamma.unwrap_or(0).to_string()),
+            ("TRANSCRIPT_PREFIX", hex::encode(transcript_prefix::<SM>())),
+            (
+                "CIRCUIT_COMMITMENTS_TRANSCRIPT",
+                hex::encode(self.circuit_commitments.values().flat_map(point_transcript).collect::<Vec<_>>()),
+            ),
+            ("INFINITY_TRANSCRIPT", hex::encode(point_transcript(&G1Affine::zero()))),
+            ("G", hex::encode(encode_g1(&self.g))),
+            ("GAMMA_G", hex::encode(encode_g1(&self.gamma_g))),
+            ("H", hex::encode(encode_g2(&self.h))),
+            ("BETA_H", hex::encode(encode_g2(&self.beta_h))),
+            ("G_1_SHIFT", hex::encode(encode_g2(&self.g_1_shift))),
+            ("G_A_SHIFT", hex::encode(encode_g2(g_a_shift))),
+            ("G_B_SHIFT", hex::encode(encode_g2(g_b_shift))),
+            ("G_C_SHIFT", hex::encode(encode_g2(g_c_shift))),
+        ];
+        let mut constants = constants.into_iter().map(|(key, value)| (key.to_string(), value)).collect::<Vec<_>>();
+        for matrix in ["a", "b", "c"] {
+            for polynomial in ["row", "col", "row_col", "row_col_val"] {
+                let commitment = encode_g1(&self.circuit_commitment(polynomial, matrix));
+                constants.push((format!("{polynomial}_{matrix}").to_uppercase(), hex::encode(commitment)));
+            }
+        }
+
+        let mut contract = TEMPLATE.to_string();
+        for (key, value) in constants {
+            contract = contract.replace(&format!("{{{{{key}}}}}"), &value);
+        }
+        ensure!(!contract.contains("{{"), "The contract template has a placeholder without a value");
+        Ok(contract)
+    }
+}
+
+/// Returns `true` if the given name is a valid Solidity identifier.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' || first == '$' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    }
+}
+
+/// Returns the big-endian encoding of the modulus of `Fr`, as a word.
+fn encode_field_modulus() -> Vec<u8> {
+    let mut bytes = Fr::modulus().to_bytes_le().unwrap();
+    bytes.resize(WORD_SIZE, 0);
+    bytes.reverse();
+    bytes
+}
+
+/// Returns the given element of `Fr` as a hexadecimal Solidity literal.
+fn scalar(element: &Fr) -> String {
+    format!("0x{}", hex::encode(encode_fr(element)))
+}
+
+/// Returns the bytes that `KeccakSponge::absorb_bytes` appends for the protocol name and the batch size,
+/// with which the transcript of `VarunaSNARK::verify` starts.
+fn transcript_prefix<SM: SNARKMode>() -> Vec<u8> {
+    let mut prefix = Vec::new();
+    for bytes in [VarunaSNARK::<Bls12_377, EvmFiatShamir, SM>::PROTOCOL_NAME, &1u64.to_le_bytes()[..]] {
+        prefix.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+        prefix.extend_from_slice(bytes);
+    }
+    prefix
+}
+
+/// Returns the bytes that `KeccakSponge` appends when it absorbs the given point,
+/// i.e. its coordinates and its infinity flag as elements of `Fq`.
+fn point_transcript(point: &G1Affine) -> Vec<u8> {
+    let elements: Vec<Fq> = point.to_field_elements().unwrap();
+    elements
+        .iter()
+        .flat_map(|element| {
+            let mut bytes = element.to_bigint().to_bytes_le().unwrap();
+            bytes.reverse();
+            bytes
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        snark::varuna::{test_circuit::TestCircuit, VarunaHidingMode, VarunaNonHidingMode},
+        traits::SNARK,
+    };
+    use snarkvm_utilities::TestRng;
+
+    type Varuna<SM> = VarunaSNARK<Bls12_377, EvmFiatShamir, SM>;
+
+    const PRECOMPILES: Eip2539Precompiles = Eip2539Precompiles { g1_multiexp: 0x0f, pairing: 0x10 };
+
+    fn sample_verifier<SM: SNARKMode>() -> EvmVerifier<SM> {
+        let rng = &mut TestRng::default();
+        let max_degree = AHPForR1CS::<Fr, SM>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = Varuna::<SM>::universal_setup(max_degree).unwrap();
+        let universal_verifier = universal_srs.to_universal_verifier().unwrap();
+        let (circuit, _) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (_, vk) = Varuna::<SM>::circuit_setup(&universal_srs, &circuit).unwrap();
+        EvmVerifier::new(&vk, &universal_verifier).unwrap()
+    }
+
+    fn check_to_solidity<SM: SNARKMode>() {
+        let verifier = sample_verifier::<SM>();
+        let contract = verifier.to_solidity("VarunaVerifier", &PRECOMPILES).unwrap();
+        assert_eq!(contract, verifier.to_solidity("VarunaVerifier", &PRECOMPILES).unwrap());
+
+        // Ensure the constants are spliced into the template.
+        assert!(contract.contains("contract VarunaVerifier {"));
+        assert!(contract.contains(&format!("uint256 internal constant PROOF_LENGTH = {};", verifier.layout.size)));
+        assert!(contract.contains(&format!("bool internal constant ZK = {};", SM::ZK)));
+        assert!(contract.contains("address(uint160(0xf))"));
+        assert!(contract.contains(&hex::encode(encode_g2(&verifier.beta_h))));
+        for commitment in verifier.circuit_commitments.values() {
+            assert!(contract.contains(&hex::encode(encode_g1(commitment))));
+        }
+        assert!(!contract.contains("{{"));
+    }
+
+    #[test]
+    fn test_to_solidity_hiding() {
+        check_to_solidity::<VarunaHidingMode>();
+    }
+
+    #[test]
+    fn test_to_solidity_non_hiding() {
+        check_to_solidity::<VarunaNonHidingMode>();
+    }
+
+    #[test]
+    fn test_to_solidity_rejects_invalid_names() {
+        let verifier = sample_verifier::<VarunaNonHidingMode>();
+        for name in ["", "1Verifier", "Varuna-Verifier", "Varuna Verifier"] {
+            assert!(verifier.to_solidity(name, &PRECOMPILES).is_err());
+        }
+    }
+
+    #[test]
+    fn test_transcript_prefix() {
+        // Ensure the prefix is the encoding of the protocol name and of the batch size.
+        let prefix = transcript_prefix::<VarunaNonHidingMode>();
+        assert_eq!(&prefix[..8], &11u64.to_be_bytes());
+        assert_eq!(&prefix[8..19], b"VARUNA-2023");
+        assert_eq!(&prefix[19..27], &8u64.to_be_bytes());
+        assert_eq!(&prefix[27..], &1u64.to_le_bytes());
+    }
+}
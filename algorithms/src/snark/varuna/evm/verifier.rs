@@ -0,0 +1,461 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A verifier of Varuna proofs on the EVM, for proofs of a single instance of a fixed circuit,
+/// which are created with the transcript `EvmFiatShamir` and encoded by `encode_proof`.
+///
+/// `EvmVerifier::verify` follows the steps of the contract generated by `EvmVerifier::to_solidity`,
+/// and calls the reference implementations of the precompiles on the same inputs as the contract.
+#[derive(Clone, Debug)]
+pub struct EvmVerifier<SM: SNARKMode> {
+    /// The ID of the circuit.
+    pub(super) id: CircuitId,
+    /// The commitments to the index polynomials of the circuit, by label.
+    pub(super) circuit_commitments: BTreeMap<String, G1Affine>,
+    /// The domain of the constraints.
+    pub(super) constraint_domain: EvaluationDomain<Fr>,
+    /// The domain of the variables.
+    pub(super) variable_domain: EvaluationDomain<Fr>,
+    /// The domain of the public inputs.
+    pub(super) input_domain: EvaluationDomain<Fr>,
+    /// The domains of the non-zero entries of the matrices `A`, `B`, and `C`.
+    pub(super) non_zero_domains: [EvaluationDomain<Fr>; 3],
+    /// The largest of `non_zero_domains`.
+    pub(super) max_non_zero_domain: EvaluationDomain<Fr>,
+    /// The generator of G1 of the KZG verifying key.
+    pub(super) g: G1Affine,
+    /// The generator of G1 of the KZG verifying key, which is used for making a commitment hiding.
+    pub(super) gamma_g: G1Affine,
+    /// The generator of G2 of the KZG verifying key.
+    pub(super) h: G2Affine,
+    /// `beta` times the generator of G2.
+    pub(super) beta_h: G2Affine,
+    /// The point of G2 that enforces the degree bound of `g_1`.
+    pub(super) g_1_shift: G2Affine,
+    /// The points of G2 that enforce the degree bounds of `g_a`, `g_b`, and `g_c`.
+    pub(super) g_m_shifts: [G2Affine; 3],
+    /// The layout of the encoded proofs.
+    pub(super) layout: ProofLayout,
+    _mode: PhantomData<SM>,
+}
+
+/// The challenges of the verifier, and the vanishing polynomials at those challenges.
+#[derive(Clone, Copy, Debug)]
+struct Challenges {
+    alpha: Fr,
+    eta_b: Fr,
+    eta_c: Fr,
+    beta: Fr,
+    delta_b: Fr,
+    delta_c: Fr,
+    gamma: Fr,
+    v_r_at_alpha: Fr,
+    v_c_at_beta: Fr,
+    v_k_at_gamma: Fr,
+}
+
+/// The elements of a proof, as decoded from the encoding of `encode_proof`.
+#[derive(Clone, Debug)]
+struct DecodedProof {
+    w: G1Affine,
+    mask_poly: Option<G1Affine>,
+    h_0: G1Affine,
+    g_1: G1Affine,
+    h_1: G1Affine,
+    g_m: [G1Affine; 3],
+    h_2: G1Affine,
+    g_1_eval: Fr,
+    g_m_evals: [Fr; 3],
+    third_sums: [Fr; 3],
+    fourth_sums: [Fr; 3],
+    /// The KZG opening proofs at `alpha`, `beta`, and `gamma`, with their random evaluations in hiding mode.
+    pc_proofs: [(G1Affine, Option<Fr>); 3],
+}
+
+impl<SM: SNARKMode> EvmVerifier<SM> {
+    /// Initializes a new verifier for the circuit of the given verifying key.
+    pub fn new(
+        verifying_key: &CircuitVerifyingKey<Bls12_377>,
+        universal_verifier: &UniversalVerifier<Bls12_377>,
+    ) -> Result<Self> {
+        let id = verifying_key.id;
+        let info = &verifying_key.circuit_info;
+
+        // Label the commitments to the index polynomials, which are ordered by label in the verifying key.
+        let labels = AHPForR1CS::<Fr, SM>::index_polynomial_info(core::iter::once(&id)).into_keys().collect::<Vec<_>>();
+        ensure!(
+            labels.len() == verifying_key.circuit_commitments.len(),
+            "Expected {} circuit commitments, found {}",
+            labels.len(),
+            verifying_key.circuit_commitments.len()
+        );
+        let circuit_commitments =
+            labels.into_iter().zip(&verifying_key.circuit_commitments).map(|(label, c)| (label, c.0)).collect();
+
+        let domain = |size: usize| {
+            EvaluationDomain::new(size).ok_or_else(|| anyhow!("Unsupported domain size {size} in the verifying key"))
+        };
+        let non_zero_domains =
+            [domain(info.num_non_zero_a)?, domain(info.num_non_zero_b)?, domain(info.num_non_zero_c)?];
+        let max_non_zero_domain = non_zero_domains.into_iter().max_by_key(|domain| domain.size()).unwrap();
+        let variable_domain = domain(info.num_variables)?;
+
+        // Retrieve the points of G2 that enforce the degree bounds, as in `SonicKZG10::check_elems`.
+        let vk = &universal_verifier.vk;
+        let shift = |degree_bound: Option<usize>| match degree_bound {
+            Some(degree_bound) => universal_verifier
+                .negative_powers_of_beta_h
+                .get(&degree_bound)
+                .copied()
+                .ok_or_else(|| anyhow!("The universal verifier does not support the degree bound {degree_bound}")),
+            None => Ok(vk.h),
+        };
+        let g_1_info = &AHPForR1CS::<Fr, SM>::third_round_polynomial_info(variable_domain.size())["g_1"];
+        let g_1_shift = shift(g_1_info.degree_bound())?;
+        let g_m_shifts = AHPForR1CS::<Fr, SM>::fourth_round_polynomial_info(core::iter::once((id, info)))
+            .into_values()
+            .map(|info| shift(info.degree_bound()))
+            .collect::<Result<Vec<_>>>()?;
+        let g_m_shifts = g_m_shifts.try_into().map_err(|_| anyhow!("Expected the degree bounds of 3 matrices"))?;
+
+        Ok(Self {
+            id,
+            circuit_commitments,
+            constraint_domain: domain(info.num_constraints)?,
+            variable_domain,
+            input_domain: domain(info.num_public_inputs)?,
+            non_zero_domains,
+            max_non_zero_domain,
+            g: vk.g,
+            gamma_g: vk.gamma_g,
+            h: vk.h,
+            beta_h: vk.beta_h,
+            g_1_shift,
+            g_m_shifts,
+            layout: ProofLayout::new(SM::ZK),
+            _mode: PhantomData,
+        })
+    }
+
+    /// Returns `true` if the given proof, as encoded by `encode_proof`, is valid for the given public inputs.
+    /// The public inputs do not include the leading one of the instance.
+    pub fn verify(&self, inputs: &[Fr], proof: &[u8]) -> bool {
+        // Note: `None` corresponds to the contract returning `false` before the final pairing check.
+        self.verify_proof(inputs, proof).unwrap_or(false)
+    }
+
+    /// Returns the result of the final pairing check, or `None` if the proof is rejected before it.
+    fn verify_proof(&self, inputs: &[Fr], proof: &[u8]) -> Option<bool> {
+        // Check the number of public inputs, which are padded with a leading one.
+        if inputs.len() >= self.input_domain.size() {
+            return None;
+        }
+        let proof = self.decode_proof(proof)?;
+        let (challenges, sponge) = self.compute_challenges(inputs, &proof)?;
+        let pairing_input = self.accumulate(inputs, &proof, &challenges, sponge)?;
+        Some(pairing(&pairing_input)? == encode_fr(&Fr::one()))
+    }
+
+    /// Returns the elements of the given encoding of a proof, or `None` if the encoding is invalid.
+    ///
+    /// The contract reads the points of the proof directly from its encoding, in which case
+    /// an invalid point is rejected by the precompiles instead.
+    fn decode_proof(&self, proof: &[u8]) -> Option<DecodedProof> {
+        if proof.len() != self.layout.size {
+            return None;
+        }
+        let layout = &self.layout;
+        let point = |offset: usize| decode_g1(&proof[offset..][..G1_SIZE]);
+        let scalar = |offset: usize| decode_fr(&proof[offset..][..WORD_SIZE]);
+        let scalars = |offsets: [usize; 3]| Some([scalar(offsets[0])?, scalar(offsets[1])?, scalar(offsets[2])?]);
+        let pc_proof = |(offset, random_v): (usize, Option<usize>)| {
+            Some((point(offset)?, match random_v {
+                Some(offset) => Some(scalar(offset)?),
+                None => None,
+            }))
+        };
+
+        Some(DecodedProof {
+            w: point(layout.w)?,
+            mask_poly: match layout.mask_poly {
+                Some(offset) => Some(point(offset)?),
+                None => None,
+            },
+            h_0: point(layout.h_0)?,
+            g_1: point(layout.g_1)?,
+            h_1: point(layout.h_1)?,
+            g_m: [point(layout.g_m[0])?, point(layout.g_m[1])?, point(layout.g_m[2])?],
+            h_2: point(layout.h_2)?,
+            g_1_eval: scalar(layout.g_1_eval)?,
+            g_m_evals: scalars(layout.g_m_evals)?,
+            third_sums: scalars(layout.third_sums)?,
+            fourth_sums: scalars(layout.fourth_sums)?,
+            pc_proofs: [pc_proof(layout.pc_proofs[0])?, pc_proof(layout.pc_proofs[1])?, pc_proof(layout.pc_proofs[2])?],
+        })
+    }
+
+    /// Returns the challenges of the verifier, and the transcript from which the opening challenges are squeezed,
+    /// or `None` if a challenge is in the domain that it is checked against.
+    fn compute_challenges(&self, inputs: &[Fr], proof: &DecodedProof) -> Option<(Challenges, EvmFiatShamir)> {
+        let mut sponge = EvmFiatShamir::new_with_parameters(&());
+
+        // Absorb the protocol name, the batch size, the padded public inputs, and the circuit commitments.
+        sponge.absorb_bytes(VarunaSNARK::<Bls12_377, EvmFiatShamir, SM>::PROTOCOL_NAME);
+        sponge.absorb_bytes(&1u64.to_le_bytes());
+        let mut padded_inputs = Vec::with_capacity(self.input_domain.size());
+        padded_inputs.push(Fr::one());
+        padded_inputs.extend_from_slice(inputs);
+        padded_inputs.resize(self.input_domain.size(), Fr::zero());
+        sponge.absorb_nonnative_field_elements(padded_inputs);
+        sponge.absorb_native_field_elements(&self.circuit_commitments.values().copied().collect::<Vec<_>>());
+
+        // Absorb the first round. As there is a single instance of a single circuit, nothing is squeezed.
+        let first_round = [Some(proof.w), proof.mask_poly].into_iter().flatten().collect::<Vec<_>>();
+        sponge.absorb_native_field_elements(&first_round);
+
+        // Absorb the second round, and squeeze `alpha`, `eta_b`, and `eta_c`.
+        sponge.absorb_native_field_elements(&[proof.h_0]);
+        let [alpha, eta_b, eta_c] = <[Fr; 3]>::try_from(&sponge.squeeze_nonnative_field_elements::<Fr>(3)[..]).ok()?;
+        let v_r_at_alpha = vanishing_polynomial_at(&self.constraint_domain, alpha);
+        if v_r_at_alpha.is_zero() {
+            return None;
+        }
+
+        // Absorb the third round, and squeeze `beta`.
+        sponge.absorb_native_field_elements(&[proof.g_1, proof.h_1]);
+        sponge.absorb_nonnative_field_elements(proof.third_sums);
+        let beta = sponge.squeeze_nonnative_field_elements::<Fr>(1)[0];
+        let v_c_at_beta = vanishing_polynomial_at(&self.variable_domain, beta);
+        if v_c_at_beta.is_zero() {
+            return None;
+        }
+
+        // Absorb the fourth round, and squeeze `delta_b` and `delta_c`.
+        sponge.absorb_native_field_elements(&proof.g_m);
+        sponge.absorb_nonnative_field_elements(proof.fourth_sums);
+        let [delta_b, delta_c] = <[Fr; 2]>::try_from(&sponge.squeeze_nonnative_field_elements::<Fr>(2)[..]).ok()?;
+
+        // Absorb the fifth round, and squeeze `gamma`.
+        sponge.absorb_native_field_elements(&[proof.h_2]);
+        let gamma = sponge.squeeze_nonnative_field_elements::<Fr>(1)[0];
+        let v_k_at_gamma = vanishing_polynomial_at(&self.max_non_zero_domain, gamma);
+        if v_k_at_gamma.is_zero() {
+            return None;
+        }
+
+        // Absorb the evaluations.
+        sponge.absorb_nonnative_field_elements([proof.g_1_eval].into_iter().chain(proof.g_m_evals));
+
+        let challenges =
+            Challenges { alpha, eta_b, eta_c, beta, delta_b, delta_c, gamma, v_r_at_alpha, v_c_at_beta, v_k_at_gamma };
+        Some((challenges, sponge))
+    }
+
+    /// Returns the input of the final pairing check, as in `SonicKZG10::batch_check`,
+    /// or `None` if a call to a precompile fails.
+    ///
+    /// The commitments without a degree bound are combined into a single multi-scalar multiplication,
+    /// together with the evaluations and the opening proofs, which is paired with `h`.
+    /// Each commitment with a degree bound is paired with its shift, and the opening proofs with `beta_h`.
+    fn accumulate(
+        &self,
+        inputs: &[Fr],
+        proof: &DecodedProof,
+        challenges: &Challenges,
+        mut sponge: EvmFiatShamir,
+    ) -> Option<Vec<u8>> {
+        let Challenges { alpha, eta_b, eta_c, beta, delta_b, delta_c, gamma, v_r_at_alpha, v_c_at_beta, v_k_at_gamma } =
+            *challenges;
+
+        // Evaluate the public input polynomial at `beta`, in the Lagrange basis of the input domain.
+        let v_x_at_beta = vanishing_polynomial_at(&self.input_domain, beta);
+        let mut x_at_beta = Fr::zero();
+        let mut element = Fr::one();
+        for input in core::iter::once(&Fr::one()).chain(inputs) {
+            x_at_beta += *input * element * (beta - element).inverse()?;
+            element *= self.input_domain.group_gen;
+        }
+        x_at_beta *= v_x_at_beta * self.input_domain.size_inv;
+
+        // Compute the constant terms of the rowcheck and lineval sumcheck linear combinations.
+        let [sum_a, sum_b, sum_c] = proof.third_sums;
+        let [sum_a_4, sum_b_4, sum_c_4] = proof.fourth_sums;
+        let [size_a, size_b, size_c] = self.non_zero_domains.map(|domain| domain.size_as_field_element);
+        let rowcheck_value = -(sum_a * sum_b - sum_c);
+        let lineval = sum_a_4 * size_a + eta_b * sum_b_4 * size_b + eta_c * sum_c_4 * size_c;
+        let lineval_sum = (sum_a + eta_b * sum_b + eta_c * sum_c) * self.variable_domain.size_inv;
+        let lineval_value = -(lineval * x_at_beta - beta * proof.g_1_eval - lineval_sum);
+
+        // Compute the coefficients and the constant term of the matrix sumcheck linear combination.
+        let v_rc = v_r_at_alpha * v_c_at_beta;
+        let rc = self.constraint_domain.size_as_field_element * self.variable_domain.size_as_field_element;
+        let deltas = [Fr::one(), delta_b, delta_c];
+        let mut matrix_coefficients = [(Fr::zero(), Fr::zero()); 3];
+        let mut matrix_value = Fr::zero();
+        for (i, domain) in self.non_zero_domains.iter().enumerate() {
+            let selector = match domain.size() == self.max_non_zero_domain.size() {
+                true => Fr::one(),
+                false => {
+                    let scale = domain.size_as_field_element * self.max_non_zero_domain.size_inv;
+                    v_k_at_gamma * scale * vanishing_polynomial_at(domain, gamma).inverse()?
+                }
+            };
+            let coefficient = deltas[i] * selector;
+            let b = coefficient * (gamma * proof.g_m_evals[i] + proof.fourth_sums[i]) * rc;
+            matrix_value += b * alpha * beta;
+            matrix_coefficients[i] = (coefficient, b);
+        }
+
+        // Squeeze the opening challenges of the linear combinations at `alpha`, `beta`, and `gamma`,
+        // and the randomizers of the points after the first, as in `SonicKZG10::batch_check`.
+        let mut squeeze = || sponge.squeeze_short_nonnative_field_element::<Fr>();
+        let challenge_rowcheck = squeeze();
+        let randomizer_beta = squeeze();
+        let challenge_g_1 = squeeze();
+        let challenge_lineval = squeeze();
+        let randomizer_gamma = squeeze();
+        let challenges_g_m = [squeeze(), squeeze(), squeeze()];
+        let challenge_matrix = squeeze();
+
+        // Combine the evaluations and the random evaluations at each point.
+        let combined_eval = challenge_rowcheck * rowcheck_value
+            + randomizer_beta * (challenge_g_1 * proof.g_1_eval + challenge_lineval * lineval_value)
+            + randomizer_gamma
+                * (challenges_g_m.iter().zip(proof.g_m_evals).map(|(c, eval)| *c * eval).sum::<Fr>()
+                    + challenge_matrix * matrix_value);
+        let randomizers = [Fr::one(), randomizer_beta, randomizer_gamma];
+        let points = [alpha, beta, gamma];
+
+        // Combine the commitments without a degree bound, less the evaluations and the opening proofs.
+        let lineval_scalar = randomizer_beta * challenge_lineval;
+        let matrix_scalar = randomizer_gamma * challenge_matrix;
+        let mut terms = Vec::new();
+        for (matrix, (coefficient, b)) in ["a", "b", "c"].into_iter().zip(matrix_coefficients) {
+            terms.push((self.circuit_commitment("row_col_val", matrix), matrix_scalar * coefficient * v_rc));
+            terms.push((self.circuit_commitment("col", matrix), matrix_scalar * b * alpha));
+            terms.push((self.circuit_commitment("row", matrix), matrix_scalar * b * beta));
+            terms.push((self.circuit_commitment("row_col", matrix), -(matrix_scalar * b)));
+        }
+        terms.push((proof.h_0, -(challenge_rowcheck * v_r_at_alpha)));
+        if let Some(mask_poly) = proof.mask_poly {
+            terms.push((mask_poly, lineval_scalar));
+        }
+        terms.push((proof.w, lineval_scalar * lineval * v_x_at_beta));
+        terms.push((proof.h_1, -(lineval_scalar * v_c_at_beta)));
+        terms.push((proof.h_2, -(matrix_scalar * v_k_at_gamma)));
+        terms.push((self.g, -combined_eval));
+        if SM::ZK {
+            let random_vs = proof.pc_proofs.iter().map(|(_, random_v)| *random_v).collect::<Option<Vec<_>>>()?;
+            let combined_random_v = randomizers.iter().zip(random_vs).map(|(r, random_v)| *r * random_v).sum::<Fr>();
+            terms.push((self.gamma_g, -combined_random_v));
+        }
+        for (((w, _), randomizer), point) in proof.pc_proofs.iter().zip(randomizers).zip(points) {
+            terms.push((*w, randomizer * point));
+        }
+
+        let mut pairing_input = [multiexp(&terms)?, encode_g2(&self.h)].concat();
+        pairing_input.extend(multiexp(&[(proof.g_1, randomizer_beta * challenge_g_1)])?);
+        pairing_input.extend(encode_g2(&self.g_1_shift));
+        for ((g_m, challenge), shift) in proof.g_m.iter().zip(challenges_g_m).zip(&self.g_m_shifts) {
+            pairing_input.extend(multiexp(&[(*g_m, randomizer_gamma * challenge)])?);
+            pairing_input.extend(encode_g2(shift));
+        }
+        let witness_terms = proof.pc_proofs.iter().zip(randomizers).map(|((w, _), r)| (*w, -r)).collect::<Vec<_>>();
+        pairing_input.extend(multiexp(&witness_terms)?);
+        pairing_input.extend(encode_g2(&self.beta_h));
+        Some(pairing_input)
+    }
+
+    /// Returns the commitment to the given index polynomial of the given matrix.
+    fn circuit_commitment(&self, polynomial: &str, matrix: &str) -> G1Affine {
+        // Note: The labels of all index polynomials are checked in `EvmVerifier::new`.
+        self.circuit_commitments[&format!("circuit_{}_{polynomial}_{matrix}", self.id)]
+    }
+}
+
+/// Returns the evaluation of the vanishing polynomial of the given domain at `x`, by repeated squaring.
+pub(super) fn vanishing_polynomial_at(domain: &EvaluationDomain<Fr>, x: Fr) -> Fr {
+    (0..domain.log_size_of_group).fold(x, |power, _| power.square()) - Fr::one()
+}
+
+/// Returns the output of `BLS12_377_G1MULTIEXP` on the given points and scalars.
+fn multiexp(terms: &[(G1Affine, Fr)]) -> Option<Vec<u8>> {
+    g1_multiexp(
+        &terms.iter().flat_map(|(point, scalar)| [encode_g1(point), encode_fr(scalar)].concat()).collect::<Vec<_>>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        snark::varuna::{test_circuit::TestCircuit, VarunaHidingMode, VarunaNonHidingMode},
+        traits::SNARK,
+    };
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    type Varuna<SM> = VarunaSNARK<Bls12_377, EvmFiatShamir, SM>;
+
+    fn check_verify<SM: SNARKMode>() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, SM>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = Varuna::<SM>::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+
+        let (circuit, inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+        let (pk, vk) = Varuna::<SM>::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = Varuna::<SM>::prove(universal_prover, &(), &pk, &circuit, rng).unwrap();
+        let verify = |inputs: &[Fr]| Varuna::<SM>::verify(universal_verifier, &(), &vk, inputs, &proof).unwrap();
+
+        let verifier = EvmVerifier::<SM>::new(&vk, universal_verifier).unwrap();
+        let encoded_proof = encode_proof(&proof).unwrap();
+        assert_eq!(verifier.layout.size, encoded_proof.len());
+
+        // Ensure a valid proof is accepted by both verifiers.
+        assert!(verify(&inputs));
+        assert!(verifier.verify(&inputs, &encoded_proof));
+
+        // Ensure a proof for other public inputs is rejected by both verifiers.
+        let other_inputs = inputs.iter().map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        assert!(!verify(&other_inputs));
+        assert!(!verifier.verify(&other_inputs, &encoded_proof));
+
+        // Ensure a tampered proof is rejected.
+        let mut tampered_proof = encoded_proof.clone();
+        tampered_proof[verifier.layout.g_1_eval..][..WORD_SIZE].copy_from_slice(&encode_fr(&Fr::rand(rng)));
+        assert!(!verifier.verify(&inputs, &tampered_proof));
+        let mut tampered_proof = encoded_proof.clone();
+        tampered_proof[verifier.layout.h_2..][..G1_SIZE].copy_from_slice(&encode_g1(&G1Affine::rand(rng)));
+        assert!(!verifier.verify(&inputs, &tampered_proof));
+
+        // Ensure a truncated proof, and too many public inputs, are rejected.
+        assert!(!verifier.verify(&inputs, &encoded_proof[1..]));
+        let too_many_inputs = vec![Fr::one(); verifier.input_domain.size()];
+        assert!(!verifier.verify(&too_many_inputs, &encoded_proof));
+    }
+
+    #[test]
+    fn test_verify_hiding() {
+        check_verify::<VarunaHidingMode>();
+    }
+
+    #[test]
+    fn test_verify_non_hiding() {
+        check_verify::<VarunaNonHidingMode>();
+    }
+}
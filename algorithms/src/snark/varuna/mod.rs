@@ -27,5 +27,8 @@ pub use varuna::*;
 mod mode;
 pub use mode::*;
 
+/// Implements a verifier of Varuna proofs on the EVM, and a generator of its Solidity contract.
+pub mod evm;
+
 #[cfg(test)]
 pub mod tests;
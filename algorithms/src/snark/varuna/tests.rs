@@ -298,11 +298,13 @@ mod varuna {
 #[cfg(any(test, feature = "test"))]
 mod varuna_hiding {
     use crate::{
-        crypto_hash::PoseidonSponge,
+        crypto_hash::{KeccakSponge, PoseidonSponge},
         snark::varuna::{
             ahp::AHPForR1CS,
             test_circuit::TestCircuit,
             CircuitVerifyingKey,
+            Proof,
+            ProverConfig,
             VarunaHidingMode,
             VarunaSNARK,
         },
@@ -315,7 +317,8 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use rand::RngCore;
+    use std::{collections::BTreeMap, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -492,6 +495,158 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn prove_and_verify_memory_bounded() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove a batch of instances for two circuits.
+        let mul_depth = 2;
+        let mut pks = Vec::new();
+        let mut vks = Vec::new();
+        let mut all_circuits = Vec::new();
+        let mut all_inputs = Vec::new();
+        for (num_constraints, num_variables) in [(100, 25), (25, 100)] {
+            let (circuits, inputs): (Vec<_>, Vec<_>) =
+                (0..3).map(|_| TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng)).unzip();
+            let (pk, vk) = VarunaInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+            pks.push(pk);
+            vks.push(vk);
+            all_circuits.push(circuits);
+            all_inputs.push(inputs);
+        }
+        let keys_to_constraints: BTreeMap<_, _> =
+            pks.iter().zip(&all_circuits).map(|(pk, circuits)| (pk, circuits.as_slice())).collect();
+        let keys_to_inputs: BTreeMap<_, _> =
+            vks.iter().zip(&all_inputs).map(|(vk, inputs)| (vk, inputs.as_slice())).collect();
+
+        let spill_dir = std::env::temp_dir();
+        let config = ProverConfig::memory_bounded(&spill_dir, 1).unwrap();
+        let seed = rng.next_u64();
+        let expected =
+            VarunaInst::prove_batch(universal_prover, &fs_parameters, &keys_to_constraints, &mut TestRng::fixed(seed))
+                .unwrap();
+        let candidate = VarunaInst::prove_batch_with_config(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &config,
+            &mut TestRng::fixed(seed),
+        )
+        .unwrap();
+
+        // Ensure the memory-bounded prover produces the same proof as the in-memory prover.
+        assert_eq!(expected, candidate);
+        assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &candidate).unwrap());
+
+        // Ensure a memory-bounded prover must process at least one instance at a time.
+        assert!(ProverConfig::memory_bounded(&spill_dir, 0).is_err());
+    }
+
+    #[test]
+    fn prove_and_verify_with_keccak_transcript() {
+        type KeccakVarunaInst = VarunaSNARK<Bls12_377, KeccakSponge<Fq, 2>, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let poseidon_parameters = FS::sample_parameters();
+
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let mul_depth = 2;
+            let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+            // Ensure proofs round-trip under the Poseidon transcript.
+            let poseidon_proof =
+                VarunaInst::prove(universal_prover, &poseidon_parameters, &index_pk, &circuit, rng).unwrap();
+            let candidate = Proof::read_le(&poseidon_proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(poseidon_proof, candidate);
+            assert!(VarunaInst::verify(
+                universal_verifier,
+                &poseidon_parameters,
+                &index_vk,
+                public_inputs.as_slice(),
+                &candidate
+            )
+            .unwrap());
+
+            // Ensure proofs round-trip under the Keccak transcript.
+            let keccak_proof = KeccakVarunaInst::prove(universal_prover, &(), &index_pk, &circuit, rng).unwrap();
+            let candidate = Proof::read_le(&keccak_proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert_eq!(keccak_proof, candidate);
+            assert!(KeccakVarunaInst::verify(universal_verifier, &(), &index_vk, public_inputs.as_slice(), &candidate)
+                .unwrap());
+
+            // Ensure a proof does not verify under the other transcript.
+            assert!(!KeccakVarunaInst::verify(
+                universal_verifier,
+                &(),
+                &index_vk,
+                public_inputs.as_slice(),
+                &poseidon_proof
+            )
+            .unwrap());
+            assert!(!VarunaInst::verify(
+                universal_verifier,
+                &poseidon_parameters,
+                &index_vk,
+                public_inputs.as_slice(),
+                &keccak_proof
+            )
+            .unwrap());
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_aggregate() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove a few circuits of different sizes, each in its own proof.
+        let mut vks_and_inputs = Vec::new();
+        let mut proofs = Vec::new();
+        for (num_constraints, num_variables) in [(25, 25), (100, 25), (25, 100)] {
+            let mul_depth = 2;
+            let (circuit, public_inputs) = TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            vks_and_inputs.push((index_vk, vec![public_inputs]));
+            proofs.push(proof);
+        }
+
+        // Ensure the proofs verify as an aggregate.
+        let instances = vks_and_inputs
+            .iter()
+            .zip(&proofs)
+            .map(|((vk, inputs), proof)| (BTreeMap::from([(vk, inputs.as_slice())]), proof))
+            .collect::<Vec<_>>();
+        assert!(VarunaInst::verify_aggregate(universal_verifier, &fs_parameters, &instances, rng).unwrap());
+
+        // Ensure the aggregate fails if any one of the proofs is invalid.
+        let invalid_inputs = vec![vec![Fr::rand(rng), Fr::rand(rng)]];
+        let mut instances = instances;
+        instances[1].0 = BTreeMap::from([(&vks_and_inputs[1].0, invalid_inputs.as_slice())]);
+        assert!(!VarunaInst::verify_aggregate(universal_verifier, &fs_parameters, &instances, rng).unwrap());
+
+        // Ensure an empty aggregate is rejected.
+        let instances: Vec<(BTreeMap<&CircuitVerifyingKey<Bls12_377>, &[Vec<Fr>]>, _)> = vec![];
+        assert!(VarunaInst::verify_aggregate(universal_verifier, &fs_parameters, &instances, rng).is_err());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
@@ -535,7 +690,7 @@ mod varuna_hiding {
 mod varuna_test_vectors {
     use crate::{
         fft::EvaluationDomain,
-        snark::varuna::{ahp::verifier, AHPForR1CS, TestCircuit, VarunaNonHidingMode, VarunaSNARK},
+        snark::varuna::{ahp::verifier, AHPForR1CS, ProverConfig, TestCircuit, VarunaNonHidingMode, VarunaSNARK},
         traits::snark::SNARK,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
@@ -669,13 +824,14 @@ mod varuna_test_vectors {
         keys_to_constraints.insert(index_pk.circuit.deref(), std::slice::from_ref(&circ));
 
         // Begin the Varuna protocol execution.
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&keys_to_constraints, rng).unwrap();
+        let prover_state =
+            AHPForR1CS::<_, MM>::init_prover(&keys_to_constraints, &ProverConfig::default(), rng).unwrap();
         let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, rng).unwrap();
         let first_round_oracles = Arc::new(prover_state.first_round_oracles.as_ref().unwrap());
 
         // Get private witness polynomial coefficients.
         let (_, w_poly) = first_round_oracles.batches.iter().next().unwrap();
-        let w_lde = format!("{:?}", w_poly[0].0.coeffs().map(|(_, coeff)| coeff).collect::<Vec<_>>());
+        let w_lde = format!("{:?}", w_poly[0].0.read().unwrap().coeffs().map(|(_, coeff)| coeff).collect::<Vec<_>>());
         if create_test_vectors {
             create_test_vector("polynomials", "w_lde", &w_lde, circuit);
         }
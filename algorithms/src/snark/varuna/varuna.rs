@@ -15,14 +15,19 @@
 use super::Certificate;
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{
-        Commitment,
-        CommitterUnionKey,
-        Evaluations,
-        LabeledCommitment,
-        QuerySet,
-        Randomness,
-        SonicKZG10,
+    polycommit::{
+        sonic_pc::{
+            Commitment,
+            CommitterUnionKey,
+            Evaluations,
+            KZGAccumulator,
+            LabeledCommitment,
+            LabeledPolynomial,
+            QuerySet,
+            Randomness,
+            SonicKZG10,
+        },
+        PCError,
     },
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
@@ -33,6 +38,7 @@ use crate::{
         CircuitProvingKey,
         CircuitVerifyingKey,
         Proof,
+        ProverConfig,
         SNARKMode,
         UniversalSRS,
     },
@@ -44,19 +50,27 @@ use crate::{
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, ToBytes, Uniform};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BTreeMap,
+    ops::Deref,
+    sync::Arc,
+};
 
 use crate::srs::UniversalProver;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
 /// The Varuna proof system.
+///
+/// The Fiat-Shamir transcript is given by `FS`, which may be any `AlgebraicSponge` over the base field of `E`,
+/// and is initialized from the `fs_parameters` passed to the prover and verifier.
 #[derive(Clone, Debug)]
 pub struct VarunaSNARK<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode>(
     #[doc(hidden)] PhantomData<(E, FS, SM)>,
@@ -164,6 +178,34 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
         Ok(sponge)
     }
 
+    /// Commits to the given oracles. If the prover is memory-bounded, each oracle is read and committed to in turn,
+    /// which yields the same commitments and randomness as committing to all of them at once.
+    fn commit_oracles<'a>(
+        universal_prover: &UniversalProver<E>,
+        committer_key: &CommitterUnionKey<E>,
+        config: &ProverConfig,
+        oracles: impl Iterator<Item = Result<Cow<'a, LabeledPolynomial<E::Fr>>>>,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>)> {
+        if !config.is_memory_bounded() {
+            let oracles = oracles.collect::<Result<Vec<_>>>()?;
+            let oracles = oracles.iter().map(|oracle| (&**oracle).into());
+            return Ok(SonicKZG10::<E, FS>::commit(universal_prover, committer_key, oracles, rng)?);
+        }
+
+        let mut commitments = Vec::new();
+        let mut randomnesses = Vec::new();
+        for oracle in oracles {
+            let oracle = oracle?;
+            let rng = rng.as_mut().map(|rng| &mut **rng as &mut dyn RngCore);
+            let (commitment, randomness) =
+                SonicKZG10::<E, FS>::commit(universal_prover, committer_key, [(&*oracle).into()], rng)?;
+            commitments.extend(commitment);
+            randomnesses.extend(randomness);
+        }
+        Ok((commitments, randomnesses))
+    }
+
     fn absorb_labeled_with_sums(
         comms: &[LabeledCommitment<Commitment<E>>],
         sums: &[prover::MatrixSums<E::Fr>],
@@ -345,6 +387,59 @@ where
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
         zk_rng: &mut R,
     ) -> Result<Self::Proof> {
+        Self::prove_batch_with_config(
+            universal_prover,
+            fs_parameters,
+            keys_to_constraints,
+            &ProverConfig::default(),
+            zk_rng,
+        )
+    }
+
+    /// This is the main entrypoint for verifying proofs.
+    /// You can find a specification of the verifier algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs
+    fn verify_batch<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        // Run the verifier, deferring the final pairing check.
+        let accumulator = match Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)? {
+            Some(accumulator) => accumulator,
+            None => return Ok(false),
+        };
+
+        let pc_time = start_timer!(|| "Checking the accumulated pairings");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_accumulator(universal_verifier, accumulator)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
+        }
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Creates a batch proof, as in `SNARK::prove_batch`, with the given prover configuration.
+    /// A memory-bounded configuration lets the prover spill intermediate buffers and oracles to disk,
+    /// and compute the polynomials of only a few instances at once, at the cost of proving time.
+    pub fn prove_batch_with_config<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, SM>, &[C]>,
+        config: &ProverConfig,
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>> {
         let prover_time = start_timer!(|| "Varuna::Prover");
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
@@ -354,7 +449,7 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let prover_state = AHPForR1CS::<_, SM>::init_prover(&circuits_to_constraints, config, zk_rng)?;
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
@@ -395,10 +490,11 @@ where
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
         let (first_commitments, first_commitment_randomnesses) = {
             let first_round_oracles = prover_state.first_round_oracles.as_ref().unwrap();
-            SonicKZG10::<E, FS>::commit(
+            Self::commit_oracles(
                 universal_prover,
                 &committer_key,
-                first_round_oracles.iter().map(Into::into),
+                config,
+                first_round_oracles.iter(),
                 SM::ZK.then_some(zk_rng),
             )?
         };
@@ -430,6 +526,8 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(second_round_comm_time);
+        // The oracles are only needed again for the final openings, so spill them if the prover is memory-bounded.
+        let second_oracles = prover::PolynomialBuffer::new_all(second_oracles.into_iter(), config.spill_dir())?;
 
         Self::absorb_labeled(&second_commitments, &mut sponge);
 
@@ -455,6 +553,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(third_round_comm_time);
+        let third_oracles = prover::PolynomialBuffer::new_all(third_oracles.into_iter(), config.spill_dir())?;
 
         Self::absorb_labeled_with_sums(
             &third_commitments,
@@ -480,6 +579,7 @@ where
             SM::ZK.then_some(zk_rng),
         )?;
         end_timer!(fourth_round_comm_time);
+        let fourth_oracles = prover::PolynomialBuffer::new_all(fourth_oracles.into_iter(), config.spill_dir())?;
 
         Self::absorb_labeled_with_sums(&fourth_commitments, &prover_fourth_message.sums, &mut sponge);
 
@@ -489,10 +589,15 @@ where
 
         // We take out values from state before they are consumed.
         let first_round_oracles = prover_state.first_round_oracles.take().unwrap();
-        let index_a_polys =
-            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.a_polys.take().unwrap()).collect_vec();
-        let index_b_polys =
-            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.b_polys.take().unwrap()).collect_vec();
+        let index_a_polys = prover::PolynomialBuffer::new_all(
+            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.a_polys.take().unwrap()),
+            config.spill_dir(),
+        )?;
+        let index_b_polys = prover::PolynomialBuffer::new_all(
+            prover_state.circuit_specific_states.values_mut().flat_map(|s| s.b_polys.take().unwrap()),
+            config.spill_dir(),
+        )?;
+        let first_round_oracles = first_round_oracles.into_buffers(config.spill_dir())?;
 
         // --------------------------------------------------------------------
         // Fifth round
@@ -512,16 +617,14 @@ where
         let verifier_state = AHPForR1CS::<_, SM>::verifier_fifth_round(verifier_state, &mut sponge)?;
         // --------------------------------------------------------------------
 
-        // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = index_a_polys
-            .into_iter()
-            .chain(index_b_polys)
-            .chain(first_round_oracles.into_iter())
-            .chain(second_oracles.into_iter())
-            .chain(third_oracles.into_iter())
-            .chain(fourth_oracles.into_iter())
-            .chain(fifth_oracles.into_iter())
-            .collect();
+        // Gather prover polynomials in one vector. Any spilled polynomials are only read back when they are needed.
+        let mut polynomials = Vec::new();
+        for oracles in
+            [index_a_polys, index_b_polys, first_round_oracles, second_oracles, third_oracles, fourth_oracles]
+        {
+            polynomials.extend(oracles);
+        }
+        polynomials.extend(prover::PolynomialBuffer::new_all(fifth_oracles.into_iter(), None)?);
         ensure!(
             polynomials.len()
                 == num_unique_circuits * 6 + // numerator and denominator for each matrix sumcheck
@@ -579,7 +682,7 @@ where
         let (query_set, verifier_state) = AHPForR1CS::<_, SM>::verifier_query_set(verifier_state);
         let lc_s = AHPForR1CS::<_, SM>::construct_linear_combinations(
             &public_inputs,
-            &polynomials,
+            polynomials.as_slice(),
             &prover_third_message,
             &prover_fourth_message,
             &verifier_state,
@@ -590,7 +693,7 @@ where
         for (label, (_, point)) in query_set.to_set() {
             if !AHPForR1CS::<E::Fr, SM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
                 let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
+                let evaluation = polynomials.as_slice().get_lc_eval(lc, point)?;
                 evaluations.insert(label, evaluation);
             }
         }
@@ -600,15 +703,36 @@ where
 
         sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            universal_prover,
-            &committer_key,
-            lc_s.values(),
-            polynomials,
-            &commitment_randomnesses,
-            &query_set.to_set(),
-            &mut sponge,
-        )?;
+        let pc_proof = if config.is_memory_bounded() {
+            // Open the linear combinations one at a time, reading back each of their polynomials when it is needed.
+            let labels_to_indices: BTreeMap<_, _> =
+                polynomials.iter().enumerate().map(|(i, p)| (p.label(), i)).collect();
+            SonicKZG10::<E, FS>::open_combinations_streaming(
+                universal_prover,
+                &committer_key,
+                lc_s.values(),
+                |label| {
+                    let index = *labels_to_indices
+                        .get(label)
+                        .ok_or_else(|| PCError::MissingPolynomial { label: label.to_string() })?;
+                    Ok((polynomials[index].read()?, &commitment_randomnesses[index]))
+                },
+                &query_set.to_set(),
+                &mut sponge,
+            )?
+        } else {
+            let polynomials =
+                polynomials.into_iter().map(prover::PolynomialBuffer::into_inner).collect::<Result<Vec<_>>>()?;
+            SonicKZG10::<E, FS>::open_combinations(
+                universal_prover,
+                &committer_key,
+                lc_s.values(),
+                polynomials,
+                &commitment_randomnesses,
+                &query_set.to_set(),
+                &mut sponge,
+            )?
+        };
 
         let proof = Proof::<E>::new(
             batch_sizes,
@@ -625,15 +749,15 @@ where
         Ok(proof)
     }
 
-    /// This is the main entrypoint for verifying proofs.
-    /// You can find a specification of the verifier algorithm in:
-    /// https://github.com/AleoHQ/protocol-docs
-    fn verify_batch<B: Borrow<Self::VerifierInput>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
-        proof: &Self::Proof,
-    ) -> Result<bool> {
+    /// Runs the verifier for the given batch proof, up to the final pairing check of the polynomial commitments.
+    /// Returns the accumulated KZG opening checks, or `None` if the proof fails a check that is not deferred.
+    /// The proof is valid iff `SonicKZG10::check_accumulator` succeeds on the returned accumulator.
+    pub fn verify_batch_deferred<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<KZGAccumulator<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -721,7 +845,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -895,8 +1019,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let accumulator = SonicKZG10::<E, FS>::accumulate_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -907,15 +1031,45 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+        end_timer!(verifier_time);
+        Ok(Some(accumulator))
+    }
+
+    /// Verifies many batch proofs with a single product of pairings.
+    /// Each proof is verified up to its final pairing check, and the deferred checks are combined
+    /// with a random linear combination sampled from `rng`, so that the combined check implies each individual check.
+    pub fn verify_aggregate<B: Borrow<[E::Fr]>, R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        instances: &[(BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        if instances.is_empty() {
+            bail!(SNARKError::EmptyBatch);
         }
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        let aggregate_time = start_timer!(|| format!("Varuna::VerifyAggregate with {} proofs", instances.len()));
+        let mut accumulator = KZGAccumulator::default();
+        for (i, (keys_to_inputs, proof)) in instances.iter().enumerate() {
+            // Run the verifier for the proof, deferring its final pairing check.
+            let proof_accumulator =
+                match Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)? {
+                    Some(proof_accumulator) => proof_accumulator,
+                    None => return Ok(false),
+                };
+            // Note: As in `SonicKZG10::batch_check`, the first randomizer is fixed to one.
+            let randomizer = match i {
+                0 => E::Fr::one(),
+                _ => E::Fr::rand(rng),
+            };
+            accumulator.accumulate(&proof_accumulator, randomizer);
+        }
+
+        let pc_time = start_timer!(|| "Checking the accumulated pairings");
+        let is_valid = SonicKZG10::<E, FS>::check_accumulator(universal_verifier, accumulator)?;
+        end_timer!(pc_time);
+
+        end_timer!(aggregate_time);
+        Ok(is_valid)
     }
 }
@@ -26,4 +26,6 @@ pub struct UniversalVerifier<E: PairingEngine> {
     /// Each pair is in the form `(degree_bound, \beta^{max_degree - i} H),` where `H` is the generator of G2,
     /// and `i` is of the form `2^k - 1` for `k` in `1` to `log_2(max_degree)`.
     pub prepared_negative_powers_of_beta_h: Arc<BTreeMap<usize, <E::G2Affine as PairingCurve>::Prepared>>,
+    /// The pairs of `prepared_negative_powers_of_beta_h`, before preparation, e.g. for a verifier outside of this library.
+    pub negative_powers_of_beta_h: Arc<BTreeMap<usize, E::G2Affine>>,
 }
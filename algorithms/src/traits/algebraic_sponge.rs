@@ -21,6 +21,11 @@ use core::fmt::Debug;
 /// The interface for a cryptographic sponge.
 /// A sponge can `absorb` or take in inputs and later `squeeze` or output bytes or field elements.
 /// The outputs are dependent on previous `absorb` and `squeeze` calls.
+///
+/// This is the Fiat-Shamir transcript of `VarunaSNARK` and `SonicKZG10`, over the base field of the pairing curve.
+/// An implementation must be deterministic, and each output must be bound to every prior input, in order.
+/// A proof only verifies under the same implementation and parameters that it was created with.
+/// See `PoseidonSponge` for an algebraic transcript, and `KeccakSponge` for a byte-oriented transcript.
 pub trait AlgebraicSponge<F: PrimeField, const RATE: usize>: Clone + Debug {
     /// Parameters used by the sponge.
     type Parameters;
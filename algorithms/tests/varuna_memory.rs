@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Measures the peak heap usage of the Varuna prover, with and without a memory-bounded configuration.
+
+use snarkvm_algorithms::{
+    crypto_hash::PoseidonSponge,
+    snark::varuna::{ahp::AHPForR1CS, ProverConfig, TestCircuit, VarunaHidingMode, VarunaSNARK},
+    AlgebraicSponge,
+    SNARK,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+use snarkvm_utilities::TestRng;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+type FS = PoseidonSponge<Fq, 2, 1>;
+
+/// A global allocator that tracks the current and peak number of allocated bytes.
+struct CountingAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Serializes the measurements, as the tests in this file otherwise run concurrently and share the counters.
+static MEASURE_LOCK: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(current, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, and returns its output along with the peak number of bytes it allocated on top of what was already live.
+fn measure_peak<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let baseline = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+    let output = f();
+    (output, PEAK.load(Ordering::SeqCst) - baseline)
+}
+
+/// Proves `num_instances` instances of a test circuit with and without a memory-bounded configuration,
+/// and ensures the memory-bounded prover produces the same, valid proof with a lower peak memory.
+fn check_memory_bounded_prover(num_constraints: usize, num_variables: usize, num_instances: usize) {
+    let _guard = MEASURE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let rng = &mut TestRng::default();
+
+    let max_degree =
+        AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(num_constraints, num_variables, 3 * num_constraints).unwrap();
+    let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+    let universal_prover = &universal_srs.to_universal_prover().unwrap();
+    let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+    let fs_parameters = FS::sample_parameters();
+
+    let mul_depth = 2;
+    let (circuits, inputs): (Vec<_>, Vec<_>) =
+        (0..num_instances).map(|_| TestCircuit::gen_rand(mul_depth, num_constraints, num_variables, rng)).unzip();
+    let (pk, vk) = VarunaInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+    let keys_to_constraints = BTreeMap::from([(&pk, circuits.as_slice())]);
+    let keys_to_inputs = BTreeMap::from([(&vk, inputs.as_slice())]);
+
+    let spill_dir = std::env::temp_dir();
+    let config = ProverConfig::memory_bounded(&spill_dir, 1).unwrap();
+
+    let (expected, in_memory_peak) = measure_peak(|| {
+        VarunaInst::prove_batch(universal_prover, &fs_parameters, &keys_to_constraints, &mut TestRng::fixed(1)).unwrap()
+    });
+    let (candidate, memory_bounded_peak) = measure_peak(|| {
+        VarunaInst::prove_batch_with_config(
+            universal_prover,
+            &fs_parameters,
+            &keys_to_constraints,
+            &config,
+            &mut TestRng::fixed(1),
+        )
+        .unwrap()
+    });
+
+    assert_eq!(expected, candidate);
+    assert!(VarunaInst::verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &candidate).unwrap());
+    assert!(
+        memory_bounded_peak < in_memory_peak,
+        "For {num_instances} instance(s), the memory-bounded prover peaked at {memory_bounded_peak} bytes, the in-memory prover at {in_memory_peak} bytes"
+    );
+}
+
+#[test]
+fn memory_bounded_prover_lowers_peak_memory() {
+    check_memory_bounded_prover(1000, 1000, 16);
+}
+
+#[test]
+fn memory_bounded_prover_lowers_peak_memory_for_a_single_large_instance() {
+    // With a single instance, the reduction cannot come from proving fewer instances at once.
+    check_memory_bounded_prover(1 << 13, 1 << 13, 1);
+}
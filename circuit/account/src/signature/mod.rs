@@ -14,6 +14,8 @@
 
 mod equal;
 mod helpers;
+#[cfg(console)]
+mod sign;
 mod ternary;
 mod verify;
 
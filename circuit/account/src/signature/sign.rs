@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Note: These domains must match the domains of `console::Signature::sign_deterministic`.
+static SIGNATURE_R_SIG_DOMAIN: &str = "AleoSignatureRandomizer0";
+static SIGNATURE_NONCE_DOMAIN: &str = "AleoSignatureNonce0";
+
+impl<A: Aleo> Signature<A> {
+    /// Returns a deterministic signature `(challenge, response, compute_key)` for a given message, where:
+    ///     nonce := HashToScalar(sk_sig, message)
+    ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
+    ///     response := nonce - challenge * sk_sig
+    /// The compute key is derived from `sk_sig`, as in `console::Signature::deterministic_compute_key`.
+    pub fn sign_deterministic(sk_sig: &Scalar<A>, message: &[Field<A>]) -> Self {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > A::MAX_DATA_SIZE_IN_FIELDS as usize {
+            A::halt("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Ensure the signature secret key is nonzero.
+        A::assert(sk_sig.is_not_equal(&Scalar::zero()));
+
+        // Compute r_sig := HashToScalar(sk_sig).
+        let r_sig_domain = Field::constant(console::Field::new_domain_separator(SIGNATURE_R_SIG_DOMAIN));
+        let r_sig = A::hash_to_scalar_psd2(&[r_sig_domain, sk_sig.to_field()]);
+        // Derive the compute key from (pk_sig, pr_sig).
+        let compute_key = ComputeKey::from((A::g_scalar_multiply(sk_sig), A::g_scalar_multiply(&r_sig)));
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the address from the compute key.
+        let address = compute_key.to_address();
+
+        // Derive the nonce from the signature secret key and the message.
+        let mut preimage = Vec::with_capacity(2 + message.len());
+        preimage.push(Field::constant(console::Field::new_domain_separator(SIGNATURE_NONCE_DOMAIN)));
+        preimage.push(sk_sig.to_field());
+        preimage.extend_from_slice(message);
+        let nonce = A::hash_to_scalar_psd8(&preimage);
+        // Compute `g_nonce` as `nonce * G`.
+        let g_nonce = A::g_scalar_multiply(&nonce);
+
+        // Construct the hash input as (nonce * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([&g_nonce, pk_sig, pr_sig].map(|point| point.to_x_coordinate()));
+        preimage.push(address.to_field());
+        preimage.extend_from_slice(message);
+
+        // Compute the verifier challenge.
+        let challenge = A::hash_to_scalar_psd8(&preimage);
+
+        // Witness the prover response, as the scalar field is not native to the circuit.
+        let mode = match nonce.is_constant() & challenge.is_constant() & sk_sig.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let response: Scalar<A> =
+            A::new_witness(mode, || nonce.eject_value() - (challenge.eject_value() * sk_sig.eject_value()));
+        // Ensure `response` := nonce - challenge * sk_sig, by checking (response * G) + (challenge * pk_sig) == nonce * G.
+        A::assert((A::g_scalar_multiply(&response) + (pk_sig * &challenge)).is_equal(&g_nonce));
+
+        // Output the signature.
+        Self { challenge, response, compute_key }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_sign_deterministic(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a signature secret key and a message.
+            let sk_sig = console::Scalar::<CurrentNetwork>::rand(rng);
+            let message: Vec<console::Field<CurrentNetwork>> = (0..i).map(|_| Uniform::rand(rng)).collect();
+
+            // Compute the expected signature.
+            let expected = console::Signature::sign_deterministic(&sk_sig, &message)?;
+            let address = console::Address::try_from(console::Signature::deterministic_compute_key(&sk_sig)?)?;
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                let sk_sig = Scalar::<Circuit>::new(mode, sk_sig);
+                let message: Vec<_> = message.iter().map(|field| Field::new(mode, *field)).collect();
+
+                let candidate = Signature::sign_deterministic(&sk_sig, &message);
+                assert_eq!(expected, candidate.eject_value());
+                // Ensure the signature verifies.
+                assert!(candidate.verify(&Address::new(mode, address), &message).eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_deterministic_constant() -> Result<()> {
+        check_sign_deterministic(Mode::Constant)
+    }
+
+    #[test]
+    fn test_sign_deterministic_public() -> Result<()> {
+        check_sign_deterministic(Mode::Public)
+    }
+
+    #[test]
+    fn test_sign_deterministic_private() -> Result<()> {
+        check_sign_deterministic(Mode::Private)
+    }
+}
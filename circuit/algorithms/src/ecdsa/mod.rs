@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod nonnative;
+pub use nonnative::*;
+
+mod point;
+pub use point::*;
+
+mod verify;
+
+#[cfg(all(test, console))]
+use snarkvm_utilities::{TestRng, Uniform};
+
+#[cfg(console)]
+use snarkvm_circuit_types::Boolean;
+use snarkvm_circuit_types::{environment::prelude::*, Field};
+
+use core::marker::PhantomData;
+#[cfg(console)]
+use num_bigint::{BigInt, BigUint};
+
+/// An element of the scalar field of secp256k1.
+pub type Secp256k1ScalarField<E> = NonNativeField<E, Secp256k1Scalar>;
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used in Bitcoin and Ethereum.
+///
+/// The inputs are encoded as in `console::EcdsaSecp256k1`: a signature is `r || s`, a public key is
+/// `x || y`, and a digest is a 32-byte integer, where each component is a big-endian integer,
+/// given as the **little-endian** bits of its byte encoding.
+///
+/// The secp256k1 fields are emulated with non-native arithmetic over the base field,
+/// and the scalar multiplications use a joint double-and-add over complete projective formulas.
+#[derive(Clone)]
+pub struct EcdsaSecp256k1<E: Environment> {
+    /// The generator of secp256k1.
+    generator: Secp256k1Point<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> EcdsaSecp256k1<E> {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub fn new() -> Self {
+        // Returns the given little-endian 64-bit limbs as an integer.
+        let to_biguint =
+            |limbs: [u64; 4]| limbs.iter().rev().fold(BigUint::from(0u32), |value, limb| (value << 64) + *limb);
+        let generator = Secp256k1Point::from_affine(
+            Secp256k1BaseField::constant(&to_biguint(console::EcdsaSecp256k1::GENERATOR_X)),
+            Secp256k1BaseField::constant(&to_biguint(console::EcdsaSecp256k1::GENERATOR_Y)),
+        );
+        Self { generator }
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment> Default for EcdsaSecp256k1<E> {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits in a limb.
+const LIMB_SIZE_IN_BITS: usize = 64;
+/// The number of limbs in an element.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in a carry, which includes an offset of `2^(NUM_CARRY_BITS - 1)` for negative carries.
+const NUM_CARRY_BITS: usize = 72;
+
+/// A prime modulus of (up to) 256 bits, which is greater than `2^255`.
+pub trait Modulus: Copy + Clone + Debug + Send + Sync + 'static {
+    /// The modulus, as little-endian 64-bit limbs.
+    const LIMBS: [u64; NUM_LIMBS];
+}
+
+/// The modulus of the base field of secp256k1.
+#[derive(Copy, Clone, Debug)]
+pub struct Secp256k1Base;
+
+#[cfg(console)]
+impl Modulus for Secp256k1Base {
+    const LIMBS: [u64; NUM_LIMBS] = console::EcdsaSecp256k1::BASE_MODULUS;
+}
+
+/// The modulus of the scalar field of secp256k1.
+#[derive(Copy, Clone, Debug)]
+pub struct Secp256k1Scalar;
+
+#[cfg(console)]
+impl Modulus for Secp256k1Scalar {
+    const LIMBS: [u64; NUM_LIMBS] = console::EcdsaSecp256k1::SCALAR_MODULUS;
+}
+
+/// An element of a prime field with a (up to) 256-bit modulus, emulated over the base field as four 64-bit limbs.
+///
+/// Each operation witnesses a quotient and a remainder, and enforces the relation over the integers
+/// by comparing limbs with range-checked carries. The limbs of an element are range-checked,
+/// however an element is not necessarily reduced (i.e. it may be greater than the modulus).
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, M: Modulus> {
+    /// The little-endian 64-bit limbs.
+    limbs: Vec<Field<E>>,
+    /// PhantomData.
+    _modulus: PhantomData<M>,
+}
+
+#[cfg(console)]
+impl<E: Environment, M: Modulus> NonNativeField<E, M> {
+    /// Initializes a constant element from the given integer, which must be less than `2^256`.
+    pub fn constant(value: &BigUint) -> Self {
+        Self { limbs: Self::new_limbs(Mode::Constant, value, NUM_LIMBS), _modulus: PhantomData }
+    }
+
+    /// Initializes an element from the given 256 little-endian bits.
+    pub fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is correct.
+        if bits_le.len() != NUM_LIMBS * LIMB_SIZE_IN_BITS {
+            E::halt(format!("Expected {} bits for a non-native field element", NUM_LIMBS * LIMB_SIZE_IN_BITS))
+        }
+        let limbs = bits_le.chunks(LIMB_SIZE_IN_BITS).map(Field::from_bits_le).collect();
+        Self { limbs, _modulus: PhantomData }
+    }
+
+    /// Returns the 256 little-endian bits of the element.
+    pub fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_SIZE_IN_BITS)).collect()
+    }
+
+    /// Returns the element as an element of a field with a different modulus, with the same limbs.
+    pub fn cast<M2: Modulus>(&self) -> NonNativeField<E, M2> {
+        NonNativeField { limbs: self.limbs.clone(), _modulus: PhantomData }
+    }
+
+    /// Returns `true` if the element is less than the modulus.
+    pub fn is_less_than_modulus(&self) -> Boolean<E> {
+        let mut is_less = Boolean::constant(false);
+        let mut is_equal = Boolean::constant(true);
+        // Compare the bits, from the most significant bit.
+        for (i, bit) in self.to_bits_le().iter().enumerate().rev() {
+            match (M::LIMBS[i / LIMB_SIZE_IN_BITS] >> (i % LIMB_SIZE_IN_BITS)) & 1 == 1 {
+                true => {
+                    is_less = &is_less | &(&is_equal & !bit);
+                    is_equal = &is_equal & bit;
+                }
+                false => is_equal = &is_equal & !bit,
+            }
+        }
+        is_less
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        let limbs = first.limbs.iter().zip_eq(&second.limbs).map(|(a, b)| Field::ternary(condition, a, b)).collect();
+        Self { limbs, _modulus: PhantomData }
+    }
+
+    /// Returns `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        let columns = self.limbs.iter().zip_eq(&other.limbs).map(|(a, b)| a + b).collect();
+        // The sum is less than `2^257`, so the quotient fits in one limb.
+        Self::reduce(columns, 1)
+    }
+
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        // Compute `self - other + 2 * modulus`, which is nonnegative as `other < 2^256 < 2 * modulus`.
+        let mut columns = Self::modulus_multiple_limbs(2);
+        for (column, (a, b)) in columns.iter_mut().zip(self.limbs.iter().zip_eq(&other.limbs)) {
+            *column += a - b;
+        }
+        // The difference is less than `2^258`, so the quotient fits in one limb.
+        Self::reduce(columns, 1)
+    }
+
+    /// Returns `self * other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut columns = vec![Field::zero(); 2 * NUM_LIMBS - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in other.limbs.iter().enumerate() {
+                columns[i + j] += a * b;
+            }
+        }
+        // The product is less than `2^512`, so the quotient fits in five limbs (as `modulus > 2^255`).
+        Self::reduce(columns, NUM_LIMBS + 1)
+    }
+
+    /// Returns the inverse of the element, and `true` if the element is invertible.
+    /// If the element is not invertible (i.e. it is zero), the returned inverse is unconstrained.
+    pub fn inverse(&self) -> (Self, Boolean<E>) {
+        let modulus = Self::modulus();
+        // Compute the inverse as `self^(modulus - 2)`, which is zero if `self` is zero.
+        let inverse = Self::value(&self.limbs).modpow(&(&modulus - 2u32), &modulus);
+        let inverse =
+            Self { limbs: Self::new_limbs(Self::mode(&self.limbs), &inverse, NUM_LIMBS), _modulus: PhantomData };
+        // Check `self * inverse == 1`.
+        let is_invertible = self.mul(&inverse).is_equal(&Self::constant(&BigUint::from(1u32)));
+        (inverse, is_invertible)
+    }
+
+    /// Returns `true` if `self` and `other` are equal modulo the modulus.
+    pub fn is_equal(&self, other: &Self) -> Boolean<E> {
+        // Reduce the difference, and check that each limb is zero.
+        let difference = self.sub(other).to_canonical();
+        difference.limbs.iter().fold(Boolean::constant(true), |is_zero, limb| is_zero & limb.is_equal(&Field::zero()))
+    }
+
+    /// Returns the unique element less than the modulus that is equal to `self` modulo the modulus.
+    pub fn to_canonical(&self) -> Self {
+        let modulus = Self::modulus();
+        let value = Self::value(&self.limbs);
+        let mode = Self::mode(&self.limbs);
+
+        // As `self < 2^256 < 2 * modulus`, it holds that `self == canonical + is_reduced * modulus`.
+        let is_reduced = Boolean::new(mode, value >= modulus);
+        let canonical = Self { limbs: Self::new_limbs(mode, &(value % &modulus), NUM_LIMBS), _modulus: PhantomData };
+
+        // Ensure `self == canonical + is_reduced * modulus`.
+        let columns = self
+            .limbs
+            .iter()
+            .zip_eq(&canonical.limbs)
+            .zip_eq(M::LIMBS)
+            .map(|((a, b), m)| a - b - Field::from_boolean(&is_reduced) * Field::constant(console::Field::from_u64(m)))
+            .collect::<Vec<_>>();
+        Self::assert_zero(&columns);
+        // Ensure `canonical < modulus`.
+        E::assert(canonical.is_less_than_modulus());
+
+        canonical
+    }
+}
+
+#[cfg(console)]
+impl<E: Environment, M: Modulus> NonNativeField<E, M> {
+    /// Returns the modulus as an integer.
+    fn modulus() -> BigUint {
+        M::LIMBS.iter().rev().fold(BigUint::from(0u32), |value, limb| (value << LIMB_SIZE_IN_BITS) + *limb)
+    }
+
+    /// Returns `multiple * modulus` as constant limbs, with one more limb than an element.
+    fn modulus_multiple_limbs(multiple: u32) -> Vec<Field<E>> {
+        Self::new_limbs(Mode::Constant, &(Self::modulus() * multiple), NUM_LIMBS + 1)
+    }
+
+    /// Returns the given integer, which must be less than `2^128`, as a console field element.
+    fn field_from_u128(value: &BigUint) -> console::Field<E::Network> {
+        let mut digits = value.iter_u64_digits();
+        let (low, high) = (digits.next().unwrap_or(0), digits.next().unwrap_or(0));
+        console::Field::from_u128((u128::from(high) << LIMB_SIZE_IN_BITS) | u128::from(low))
+    }
+
+    /// Returns `Mode::Constant` if all of the given circuits are constant, and `Mode::Private` otherwise.
+    fn mode(circuits: &[Field<E>]) -> Mode {
+        match circuits.iter().all(|circuit| circuit.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    /// Returns the given value as the given number of range-checked 64-bit limbs.
+    fn new_limbs(mode: Mode, value: &BigUint, num_limbs: usize) -> Vec<Field<E>> {
+        let mut digits = value.iter_u64_digits();
+        (0..num_limbs)
+            .map(|_| {
+                let limb = Field::new(mode, console::Field::from_u64(digits.next().unwrap_or(0)));
+                // Ensure the limb is less than `2^64`.
+                limb.to_lower_bits_le(LIMB_SIZE_IN_BITS);
+                limb
+            })
+            .collect()
+    }
+
+    /// Returns the value of the given field element as a signed integer, where
+    /// elements in the upper half of the base field represent negative integers.
+    fn signed_value(field: &Field<E>) -> BigInt {
+        // Returns the given little-endian bits as an integer.
+        let to_biguint = |bits: Vec<bool>| {
+            bits.into_iter().rev().fold(BigUint::from(0u32), |value, bit| (value << 1) + u32::from(bit))
+        };
+        let value = field.eject_value();
+        let (positive, negative) = (to_biguint(value.to_bits_le()), to_biguint((-value).to_bits_le()));
+        match positive.bits() <= negative.bits() {
+            true => BigInt::from(positive),
+            false => -BigInt::from(negative),
+        }
+    }
+
+    /// Returns the (nonnegative) value of the integer `Σ_i columns[i] * 2^(64 * i)`.
+    fn value(columns: &[Field<E>]) -> BigUint {
+        let value =
+            columns.iter().rev().fold(BigInt::from(0), |value, column| (value << 64) + Self::signed_value(column));
+        value.to_biguint().unwrap_or_else(|| E::halt("Expected a nonnegative non-native field element"))
+    }
+
+    /// Returns the remainder of the integer `Σ_i columns[i] * 2^(64 * i)` modulo the modulus,
+    /// where the integer is nonnegative and the quotient fits in the given number of limbs.
+    fn reduce(columns: Vec<Field<E>>, num_quotient_limbs: usize) -> Self {
+        let modulus = Self::modulus();
+        let value = Self::value(&columns);
+        let mode = Self::mode(&columns);
+
+        // Witness the quotient and the remainder.
+        let quotient = Self::new_limbs(mode, &(&value / &modulus), num_quotient_limbs);
+        let remainder = Self::new_limbs(mode, &(&value % &modulus), NUM_LIMBS);
+
+        // Compute `columns - quotient * modulus - remainder`.
+        let mut columns = columns;
+        columns.resize(columns.len().max(num_quotient_limbs + NUM_LIMBS - 1), Field::zero());
+        for (i, q) in quotient.iter().enumerate() {
+            for (j, m) in M::LIMBS.iter().enumerate() {
+                columns[i + j] -= q * Field::constant(console::Field::from_u64(*m));
+            }
+        }
+        for (column, r) in columns.iter_mut().zip(&remainder) {
+            *column -= r;
+        }
+        // Ensure `columns == quotient * modulus + remainder`.
+        Self::assert_zero(&columns);
+
+        Self { limbs: remainder, _modulus: PhantomData }
+    }
+
+    /// Ensures the integer `Σ_i columns[i] * 2^(64 * i)` is zero,
+    /// where each column is less than `2^134` in absolute value.
+    fn assert_zero(columns: &[Field<E>]) {
+        let base = Field::constant(console::Field::from_u128(1u128 << LIMB_SIZE_IN_BITS));
+        let offset = 1u128 << (NUM_CARRY_BITS - 1);
+
+        // Propagate the carry from each column to the next column, and ensure the final sum is zero.
+        let mut carry = Field::zero();
+        for (i, column) in columns.iter().enumerate() {
+            let sum = column + &carry;
+            if i == columns.len() - 1 {
+                E::assert_eq(sum, Field::<E>::zero());
+                break;
+            }
+            // Witness the next carry, which is `sum / 2^64` (offset to be nonnegative).
+            let value = (Self::signed_value(&sum) >> LIMB_SIZE_IN_BITS) + offset;
+            let value = value.to_biguint().unwrap_or_else(|| E::halt("Invalid carry in a non-native field element"));
+            let offset_carry = Field::new(Self::mode(core::slice::from_ref(&sum)), Self::field_from_u128(&value));
+            // Ensure the carry is in `[-2^71, 2^71)`.
+            offset_carry.to_lower_bits_le(NUM_CARRY_BITS);
+            carry = offset_carry - Field::constant(console::Field::from_u128(offset));
+            // Ensure `sum == carry * 2^64`.
+            E::assert_eq(sum, &carry * &base);
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 10;
+
+    type Element = NonNativeField<Circuit, Secp256k1Base>;
+
+    /// Samples a random element, less than `2^256`.
+    fn sample_element(mode: Mode, rng: &mut TestRng) -> (Element, BigUint) {
+        let bits = (0..256).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+        let value = bits.iter().rev().fold(BigUint::from(0u32), |value, bit| (value << 1) + u32::from(*bit));
+        (Element::from_bits_le(&bits.into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>()), value)
+    }
+
+    fn check_operations(mode: Mode) {
+        let rng = &mut TestRng::default();
+        let modulus = Element::modulus();
+
+        for i in 0..ITERATIONS {
+            let (a, a_value) = sample_element(mode, rng);
+            let (b, b_value) = sample_element(mode, rng);
+
+            Circuit::scope(format!("NonNativeField {mode} {i}"), || {
+                let check = |element: &Element, expected: BigUint| {
+                    assert_eq!(Element::value(&element.limbs) % &modulus, expected % &modulus);
+                };
+                check(&a.add(&b), &a_value + &b_value);
+                check(&a.sub(&b), &a_value + 2u32 * &modulus - &b_value);
+                check(&a.mul(&b), &a_value * &b_value);
+
+                // Ensure the inverse is correct.
+                let (inverse, is_invertible) = a.inverse();
+                assert!(is_invertible.eject_value());
+                check(&a.mul(&inverse), BigUint::from(1u32));
+
+                // Ensure the canonical element is reduced.
+                let canonical = a.to_canonical();
+                assert_eq!(Element::value(&canonical.limbs), &a_value % &modulus);
+                assert!(canonical.is_less_than_modulus().eject_value());
+
+                // Ensure equality is modulo the modulus.
+                assert!(a.is_equal(&a.add(&Element::constant(&modulus))).eject_value());
+                assert_eq!(a.is_equal(&b).eject_value(), &a_value % &modulus == &b_value % &modulus);
+
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_operations_constant() {
+        check_operations(Mode::Constant);
+    }
+
+    #[test]
+    fn test_operations_private() {
+        check_operations(Mode::Private);
+    }
+
+    #[test]
+    fn test_zero_is_not_invertible() {
+        let zero = Element::from_bits_le(&vec![Boolean::new(Mode::Private, false); 256]);
+        let (_, is_invertible) = zero.inverse();
+        assert!(!is_invertible.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An element of the base field of secp256k1.
+pub type Secp256k1BaseField<E> = NonNativeField<E, Secp256k1Base>;
+
+/// A point on the secp256k1 curve `y^2 = x^3 + 7`, in projective coordinates `(X : Y : Z)`,
+/// which represents the affine point `(X / Z, Y / Z)`. The point at infinity is `(0 : 1 : 0)`.
+///
+/// The addition and doubling use the complete formulas of Renes, Costello, and Batina
+/// (https://eprint.iacr.org/2015/1060, Algorithms 7 and 9), so there are no exceptional cases.
+#[derive(Clone)]
+pub struct Secp256k1Point<E: Environment> {
+    x: Secp256k1BaseField<E>,
+    y: Secp256k1BaseField<E>,
+    z: Secp256k1BaseField<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Secp256k1Point<E> {
+    /// Returns the point at infinity.
+    pub fn zero() -> Self {
+        let (zero, one) = (BigUint::from(0u32), BigUint::from(1u32));
+        Self {
+            x: Secp256k1BaseField::constant(&zero),
+            y: Secp256k1BaseField::constant(&one),
+            z: Secp256k1BaseField::constant(&zero),
+        }
+    }
+
+    /// Initializes a point from the given affine coordinates.
+    /// Note: This method does **not** check that the point is on the curve.
+    pub fn from_affine(x: Secp256k1BaseField<E>, y: Secp256k1BaseField<E>) -> Self {
+        Self { x, y, z: Secp256k1BaseField::constant(&BigUint::from(1u32)) }
+    }
+
+    /// Returns the affine x-coordinate of the point, reduced modulo the base field modulus,
+    /// and `true` if the point is not the point at infinity.
+    /// If the point is the point at infinity, the returned x-coordinate is unconstrained.
+    pub fn to_affine_x(&self) -> (Secp256k1BaseField<E>, Boolean<E>) {
+        let (z_inverse, is_finite) = self.z.inverse();
+        (self.x.mul(&z_inverse).to_canonical(), is_finite)
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: Secp256k1BaseField::ternary(condition, &first.x, &second.x),
+            y: Secp256k1BaseField::ternary(condition, &first.y, &second.y),
+            z: Secp256k1BaseField::ternary(condition, &first.z, &second.z),
+        }
+    }
+
+    /// Returns `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        let b3 = Self::b3();
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&other.x, &other.y, &other.z);
+
+        let t0 = x1.mul(x2);
+        let t1 = y1.mul(y2);
+        let t2 = z1.mul(z2);
+        let t3 = x1.add(y1).mul(&x2.add(y2)).sub(&t0.add(&t1));
+        let t4 = y1.add(z1).mul(&y2.add(z2)).sub(&t1.add(&t2));
+        let y3 = x1.add(z1).mul(&x2.add(z2)).sub(&t0.add(&t2));
+        let t0 = t0.add(&t0).add(&t0);
+        let t2 = b3.mul(&t2);
+        let z3 = t1.add(&t2);
+        let t1 = t1.sub(&t2);
+        let y3 = b3.mul(&y3);
+        let x3 = t3.mul(&t1).sub(&t4.mul(&y3));
+        let y3 = t1.mul(&z3).add(&y3.mul(&t0));
+        let z3 = z3.mul(&t4).add(&t0.mul(&t3));
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns `2 * self`.
+    pub fn double(&self) -> Self {
+        let b3 = Self::b3();
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+
+        let t0 = y.mul(y);
+        let z3 = t0.add(&t0);
+        let z3 = z3.add(&z3);
+        let z3 = z3.add(&z3);
+        let t1 = y.mul(z);
+        let t2 = b3.mul(&z.mul(z));
+        let x3 = t2.mul(&z3);
+        let y3 = t0.add(&t2);
+        let z3 = t1.mul(&z3);
+        let t2 = t2.add(&t2).add(&t2);
+        let t0 = t0.sub(&t2);
+        let y3 = x3.add(&t0.mul(&y3));
+        let x3 = t0.mul(&x.mul(y));
+        let x3 = x3.add(&x3);
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Returns the constant `3 * b`, where `b = 7` is the curve coefficient.
+    fn b3() -> Secp256k1BaseField<E> {
+        Secp256k1BaseField::constant(&BigUint::from(21u32))
+    }
+}
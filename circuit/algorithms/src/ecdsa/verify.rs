@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<E: Environment> EcdsaSecp256k1<E> {
+    /// Returns `true` if the given signature is valid for the given public key and digest.
+    /// Returns `false` if the signature or the public key is malformed (e.g. if `s` is zero,
+    /// or if the public key is not on the curve), and halts if the inputs are of the wrong size.
+    pub fn verify(&self, signature: &[Boolean<E>], public_key: &[Boolean<E>], digest: &[Boolean<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the correct size.
+        if signature.len() != console::EcdsaSecp256k1::SIGNATURE_SIZE_IN_BITS {
+            E::halt("Invalid ECDSA signature size")
+        }
+        if public_key.len() != console::EcdsaSecp256k1::PUBLIC_KEY_SIZE_IN_BITS {
+            E::halt("Invalid ECDSA public key size")
+        }
+        if digest.len() != console::EcdsaSecp256k1::DIGEST_SIZE_IN_BITS {
+            E::halt("Invalid ECDSA digest size")
+        }
+
+        // Decode the inputs.
+        let r = Secp256k1ScalarField::from_bits_le(&Self::to_integer_bits_le(&signature[..256]));
+        let s = Secp256k1ScalarField::from_bits_le(&Self::to_integer_bits_le(&signature[256..]));
+        let x = Secp256k1BaseField::from_bits_le(&Self::to_integer_bits_le(&public_key[..256]));
+        let y = Secp256k1BaseField::from_bits_le(&Self::to_integer_bits_le(&public_key[256..]));
+        let z = Secp256k1ScalarField::from_bits_le(&Self::to_integer_bits_le(digest));
+
+        // Ensure `r` is in `[1, n - 1]`.
+        let is_r_valid = r.is_less_than_modulus() & !r.is_equal(&Secp256k1ScalarField::constant(&BigUint::from(0u32)));
+        // Compute `w := s^{-1} mod n`, and ensure `s` is in `[1, n - 1]`.
+        let (w, is_s_invertible) = s.inverse();
+        let is_s_valid = s.is_less_than_modulus() & is_s_invertible;
+        // Ensure the public key is on the curve.
+        let is_on_curve = x.is_less_than_modulus()
+            & y.is_less_than_modulus()
+            & y.mul(&y).is_equal(&x.mul(&x).mul(&x).add(&Secp256k1BaseField::constant(&BigUint::from(7u32))));
+
+        // Compute `u1 := z * w mod n` and `u2 := r * w mod n`.
+        let u1 = z.mul(&w);
+        let u2 = r.mul(&w);
+
+        // Compute `R := u1 * G + u2 * Q`, with a joint double-and-add over the table `[0, G, Q, G + Q]`.
+        let public_key = Secp256k1Point::from_affine(x, y);
+        let generator_plus_public_key = self.generator.add(&public_key);
+        let mut output = Secp256k1Point::zero();
+        for (bit_1, bit_2) in u1.to_bits_le().iter().zip_eq(&u2.to_bits_le()).rev() {
+            output = output.double();
+            let addend = Secp256k1Point::ternary(
+                bit_2,
+                &Secp256k1Point::ternary(bit_1, &generator_plus_public_key, &public_key),
+                &Secp256k1Point::ternary(bit_1, &self.generator, &Secp256k1Point::zero()),
+            );
+            output = output.add(&addend);
+        }
+
+        // Ensure `R` is not the point at infinity, and `x(R) mod n == r`.
+        let (x_r, is_finite) = output.to_affine_x();
+        let is_x_r_valid = is_finite & x_r.cast::<Secp256k1Scalar>().is_equal(&r);
+
+        is_r_valid & is_s_valid & is_on_curve & is_x_r_valid
+    }
+
+    /// Returns the little-endian bits of the integer that is encoded
+    /// as the little-endian bits of its big-endian bytes.
+    fn to_integer_bits_le(bits: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        bits.chunks(8).rev().flatten().cloned().collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    fn check_verify(mode: Mode, rng: &mut TestRng) {
+        let native = console::EcdsaSecp256k1;
+        let ecdsa = EcdsaSecp256k1::<Circuit>::new();
+
+        // Sample a secret key and a digest.
+        let secret_key = (0..256).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+        let digest = (0..256).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+        let public_key = native.public_key(&secret_key).unwrap();
+        let signature = native.sign(&secret_key, &digest, rng).unwrap();
+
+        // Flip a bit of the digest, for an invalid signature.
+        let mut invalid_digest = digest.clone();
+        invalid_digest[0] = !invalid_digest[0];
+
+        for (digest, expected) in [(digest, true), (invalid_digest, false)] {
+            // Ensure the console verification is as expected.
+            assert_eq!(expected, native.verify(&signature, &public_key, &digest).unwrap());
+
+            // Inject the inputs.
+            let inject = |bits: &[bool]| bits.iter().map(|bit| Boolean::new(mode, *bit)).collect::<Vec<_>>();
+            let (signature, public_key, digest) = (inject(&signature), inject(&public_key), inject(&digest));
+
+            Circuit::scope(format!("ECDSA {mode} {expected}"), || {
+                let candidate = ecdsa.verify(&signature, &public_key, &digest);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope(), "(is_satisfied_in_scope)");
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_constant() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Constant, &mut rng);
+    }
+
+    #[test]
+    fn test_verify_private() {
+        let mut rng = TestRng::default();
+        check_verify(Mode::Private, &mut rng);
+    }
+}
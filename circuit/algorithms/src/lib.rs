@@ -18,6 +18,9 @@
 pub mod bhp;
 pub use bhp::*;
 
+pub mod ecdsa;
+pub use ecdsa::EcdsaSecp256k1;
+
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -30,5 +33,13 @@ pub use pedersen::*;
 pub mod poseidon;
 pub use poseidon::*;
 
+pub mod sha256;
+pub use sha256::*;
+
 pub mod traits;
 pub use traits::*;
+
+#[cfg(console)]
+pub mod varuna;
+#[cfg(console)]
+pub use varuna::*;
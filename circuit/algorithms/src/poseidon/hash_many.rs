@@ -41,7 +41,7 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Absorbs the input elements into state.
     #[inline]
-    fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
+    pub(crate) fn absorb(&self, state: &mut [Field<E>], mode: &mut DuplexSpongeMode, input: &[Field<E>]) {
         if !input.is_empty() {
             // Determine the absorb index.
             let (mut absorb_index, should_permute) = match *mode {
@@ -178,7 +178,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
     /// Apply the permutation for all rounds in-place.
     #[inline]
-    fn permute(&self, state: &mut [Field<E>]) {
+    pub(crate) fn permute(&self, state: &mut [Field<E>]) {
         // Determine the partial rounds range bound.
         let full_rounds_over_2 = self.full_rounds / 2;
         let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + self.partial_rounds);
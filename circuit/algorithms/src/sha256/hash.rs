@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Hash for Sha256<E> {
+    type Input = Boolean<E>;
+    type Output = Vec<Boolean<E>>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Self::Output {
+        // The intermediate hash value `H(i)`, initialized as `H(0)`.
+        let mut state = self.initial_state.clone();
+
+        // Process each padded block `M(i)`.
+        for block in Self::pad(input).chunks(BLOCK_SIZE_IN_BITS) {
+            state = self.compress(&state, block);
+        }
+
+        // Return the digest `H(N)`, as the big-endian bytes of each word.
+        state.iter().flat_map(Self::to_bytes_be).collect()
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// In SHA-256, the message `M` is padded as `M || 0x80 || 0x00…0x00 || ℓ`, where `ℓ` is the length
+    /// of `M` in bits as a 64-bit big-endian integer, such that the padded message is a multiple of 512 bits.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Resize the input to a multiple of 8.
+        let mut padded_input = input.to_vec();
+        padded_input.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Retrieve the message length `ℓ`.
+        let length = padded_input.len() as u64;
+
+        // Step 1: Append the "0x80" byte to the message.
+        padded_input.extend((0..8).map(|i| Boolean::constant(i == 7)));
+
+        // Step 2: Append "0" bits until the length of the message is congruent to 448 mod 512.
+        while (padded_input.len() % BLOCK_SIZE_IN_BITS) != (BLOCK_SIZE_IN_BITS - 64) {
+            padded_input.push(Boolean::constant(false));
+        }
+
+        // Step 3: Append the message length `ℓ` as a 64-bit big-endian integer.
+        for byte in length.to_be_bytes() {
+            padded_input.extend((0..8).map(|i| Boolean::constant((byte >> i) & 1 == 1)));
+        }
+        padded_input
+    }
+
+    /// The compression function updates the intermediate hash value `H(i-1)` with the message block `M(i)`.
+    fn compress(&self, state: &[U32<E>], block: &[Boolean<E>]) -> Vec<U32<E>> {
+        debug_assert_eq!(state.len(), 8, "The state must have 8 words");
+        debug_assert_eq!(block.len(), BLOCK_SIZE_IN_BITS, "The block must have {BLOCK_SIZE_IN_BITS} bits");
+
+        /* Prepare the message schedule `W`:
+         *
+         * W[t] = M[t]                                               for 0 ≤ t ≤ 15
+         * W[t] = σ1(W[t-2]) + W[t-7] + σ0(W[t-15]) + W[t-16]        for 16 ≤ t ≤ 63
+         */
+        let mut w = Vec::with_capacity(NUM_ROUNDS);
+        w.extend(block.chunks(32).map(Self::from_bytes_be));
+        for t in 16..NUM_ROUNDS {
+            let s0 = Self::xor(&Self::rotr(&w[t - 15], 7), &Self::rotr(&w[t - 15], 18), &Self::shr(&w[t - 15], 3));
+            let s1 = Self::xor(&Self::rotr(&w[t - 2], 17), &Self::rotr(&w[t - 2], 19), &Self::shr(&w[t - 2], 10));
+            w.push(Self::add_many(&[&s1, &w[t - 7], &s0, &w[t - 16]]));
+        }
+
+        // Initialize the working variables.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+
+        /* Apply the rounds of the compression function:
+         *
+         * T1 = h + Σ1(e) + Ch(e, f, g) + K[t] + W[t]
+         * T2 = Σ0(a) + Maj(a, b, c)
+         * (h, g, f, e, d, c, b, a) = (g, f, e, d + T1, c, b, a, T1 + T2)
+         */
+        for (k, w) in self.round_constants.iter().zip_eq(&w) {
+            let sigma_1 = Self::xor(&Self::rotr(&e, 6), &Self::rotr(&e, 11), &Self::rotr(&e, 25));
+            // Ch(e, f, g) = (e ∧ f) ⊕ (¬e ∧ g), which is equivalent to g ⊕ (e ∧ (f ⊕ g)).
+            let ch = &g ^ &(&e & &(&f ^ &g));
+            let sigma_0 = Self::xor(&Self::rotr(&a, 2), &Self::rotr(&a, 13), &Self::rotr(&a, 22));
+            // Maj(a, b, c) = (a ∧ b) ⊕ (a ∧ c) ⊕ (b ∧ c), which is equivalent to (a ∧ b) ⊕ (c ∧ (a ⊕ b)).
+            let maj = &(&a & &b) ^ &(&c & &(&a ^ &b));
+
+            // Note: The sums for `T1 + T2` and `d + T1` are each computed with a single range check.
+            let new_a = Self::add_many(&[&h, &sigma_1, &ch, k, w, &sigma_0, &maj]);
+            let new_e = Self::add_many(&[&d, &h, &sigma_1, &ch, k, w]);
+
+            h = g;
+            g = f;
+            f = e;
+            e = new_e;
+            d = c;
+            c = b;
+            b = a;
+            a = new_a;
+        }
+
+        // Compute the intermediate hash value `H(i)`.
+        [a, b, c, d, e, f, g, h].iter().zip_eq(state).map(|(word, state)| Self::add_many(&[state, word])).collect()
+    }
+
+    /// Returns the sum of the given words, modulo `2^32`.
+    fn add_many(words: &[&U32<E>]) -> U32<E> {
+        debug_assert!(words.len() > 1, "There must be at least two words to sum");
+
+        // Instead of adding the words pairwise, the words are converted into field elements,
+        // and summed, before extracting the lower 32 bits of the sum (with enough bits for the carry).
+        // Note: This is safe as the field is larger than the sum of (up to) 7 words.
+        let sum = words.iter().fold(Field::zero(), |sum, word| sum + word.to_field());
+        let num_carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+        let bits_le = sum.to_lower_bits_le(32 + num_carry_bits);
+        // Drop the carry bits, as the operation is wrapped addition.
+        U32::from_bits_le(&bits_le[..32])
+    }
+
+    /// Returns `a ⊕ b ⊕ c`.
+    fn xor(a: &U32<E>, b: &U32<E>, c: &U32<E>) -> U32<E> {
+        &(a ^ b) ^ c
+    }
+
+    /// Performs a rotate right operation on the given `u32` value.
+    fn rotr(value: &U32<E>, n: usize) -> U32<E> {
+        let mut bits_le = value.to_bits_le();
+        bits_le.rotate_left(n);
+        U32::from_bits_le(&bits_le)
+    }
+
+    /// Performs a shift right operation on the given `u32` value.
+    fn shr(value: &U32<E>, n: usize) -> U32<E> {
+        // Note: `U32::from_bits_le` pads the upper bits with zeros.
+        U32::from_bits_le(&value.to_bits_le()[n..])
+    }
+
+    /// Returns the `u32` value of the given 4 bytes, in big-endian byte order (and little-endian bit order).
+    fn from_bytes_be(bits: &[Boolean<E>]) -> U32<E> {
+        debug_assert_eq!(bits.len(), 32, "A word must have 32 bits");
+        U32::from_bits_le(&bits.chunks(8).rev().flatten().cloned().collect::<Vec<_>>())
+    }
+
+    /// Returns the 4 bytes of the given `u32` value, in big-endian byte order (and little-endian bit order).
+    fn to_bytes_be(value: &U32<E>) -> Vec<Boolean<E>> {
+        value.to_bits_le().chunks(8).rev().flatten().cloned().collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        use console::Hash as H;
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("SHA-256 {mode} {i}"), || {
+                let candidate = sha256.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_sha256_hash_constant() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Constant, 1, 6376, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 7, 6376, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 8, 6376, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 64, 6376, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 256, 6376, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 447, 12752, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 448, 12752, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 512, 12752, 0, 0, 0, &mut rng);
+        check_hash(Mode::Constant, 1024, 19128, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_sha256_hash_public() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Public, 1, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Public, 7, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Public, 8, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Public, 64, 0, 0, 32784, 32968, &mut rng);
+        check_hash(Mode::Public, 256, 0, 0, 33150, 33334, &mut rng);
+        check_hash(Mode::Public, 447, 1632, 0, 60785, 61105, &mut rng);
+        check_hash(Mode::Public, 448, 1632, 0, 60788, 61108, &mut rng);
+        check_hash(Mode::Public, 512, 1632, 0, 61018, 61338, &mut rng);
+        check_hash(Mode::Public, 1024, 1632, 0, 95442, 95946, &mut rng);
+    }
+
+    #[test]
+    fn test_sha256_hash_private() {
+        let mut rng = TestRng::default();
+
+        check_hash(Mode::Private, 1, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Private, 7, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Private, 8, 102, 0, 32276, 32457, &mut rng);
+        check_hash(Mode::Private, 64, 0, 0, 32784, 32968, &mut rng);
+        check_hash(Mode::Private, 256, 0, 0, 33150, 33334, &mut rng);
+        check_hash(Mode::Private, 447, 1632, 0, 60785, 61105, &mut rng);
+        check_hash(Mode::Private, 448, 1632, 0, 60788, 61108, &mut rng);
+        check_hash(Mode::Private, 512, 1632, 0, 61018, 61338, &mut rng);
+        check_hash(Mode::Private, 1024, 1632, 0, 95442, 95946, &mut rng);
+    }
+
+    #[test]
+    fn test_sha256_equivalence() {
+        use console::Hash as H;
+
+        let rng = &mut TestRng::default();
+
+        let native = console::Sha256;
+        let sha256 = Sha256::<Circuit>::new();
+
+        let mut input_sizes = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16, 32, 64, 128, 256, 440, 448, 512, 1024];
+        input_sizes.extend((0..5).map(|_| rng.gen_range(1..2048)));
+
+        for num_inputs in input_sizes {
+            println!("Checking equivalence for {num_inputs} inputs");
+
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>();
+
+            // Compute the console hash.
+            let expected = native.hash(&native_input).expect("Failed to hash console input");
+
+            // Compute the circuit hash.
+            let candidate = sha256.hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+}
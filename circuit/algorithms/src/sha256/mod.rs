@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+#[cfg(all(test, console))]
+use snarkvm_circuit_types::environment::assert_scope;
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::Hash;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U32};
+
+/// The number of rounds in the compression function.
+const NUM_ROUNDS: usize = 64;
+/// The number of bits in a message block.
+const BLOCK_SIZE_IN_BITS: usize = 512;
+
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// The input bits are packed into bytes in little-endian order (i.e. bit `i` is bit `i % 8` of byte `i / 8`),
+/// and zero-padded up to a multiple of 8 bits. The digest is returned in the same bit order,
+/// so that the bytes of the digest match the standard (big-endian) encoding of SHA-256.
+#[derive(Clone, Debug, Default)]
+pub struct Sha256<E: Environment> {
+    /// The round constants `K[t]`, which are the first 32 bits of the fractional parts
+    /// of the cube roots of the first 64 prime numbers.
+    round_constants: Vec<U32<E>>,
+    /// The initial hash value `H(0)`, which are the first 32 bits of the fractional parts
+    /// of the square roots of the first 8 prime numbers.
+    initial_state: Vec<U32<E>>,
+}
+
+impl<E: Environment> Sha256<E> {
+    /// Initializes a new SHA-256 hash function.
+    pub fn new() -> Self {
+        Self {
+            round_constants: Self::ROUND_CONSTANTS.into_iter().map(|e| U32::constant(console::U32::new(e))).collect(),
+            initial_state: Self::INITIAL_STATE.into_iter().map(|e| U32::constant(console::U32::new(e))).collect(),
+        }
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// The initial hash value `H(0)`.
+    const INITIAL_STATE: [u32; 8] =
+        [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+    /// The round constants `K[t]`, for `t ∈ {0, 1, …, 63}`.
+    const ROUND_CONSTANTS: [u32; NUM_ROUNDS] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+        0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+        0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+        0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+        0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+        0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+        0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A linear combination of points, with scalars in the base field of the circuit.
+pub type G1Combination<E> = Vec<(Field<E>, G1Point<E>)>;
+
+/// The opening checks of a Varuna proof, as accumulated by `VarunaVerifier::verify`.
+///
+/// Each group element of a `sonic_pc::KZGAccumulator` is given as a linear combination of points,
+/// whose multi-scalar multiplication is deferred to outside of the circuit, along with the final product of pairings.
+#[derive(Clone)]
+pub struct KZGAccumulator<E: Environment> {
+    /// The combined commitments, indexed by their degree bound.
+    pub(super) combined_comms: BTreeMap<Option<usize>, G1Combination<E>>,
+    /// The combined opening proofs.
+    pub(super) combined_witness: G1Combination<E>,
+    /// The combined opening proofs, adjusted by their evaluation points and values.
+    pub(super) combined_adjusted_witness: G1Combination<E>,
+}
+
+impl<E: Environment> KZGAccumulator<E> {
+    /// Returns an iterator over the scalars and points of the accumulator.
+    pub fn terms(&self) -> impl Iterator<Item = &(Field<E>, G1Point<E>)> {
+        self.combined_comms.values().flatten().chain(&self.combined_witness).chain(&self.combined_adjusted_witness)
+    }
+}
+
+impl<E: Environment> Default for KZGAccumulator<E> {
+    /// Initializes an empty accumulator.
+    fn default() -> Self {
+        Self { combined_comms: BTreeMap::new(), combined_witness: Vec::new(), combined_adjusted_witness: Vec::new() }
+    }
+}
+
+impl<E: Environment> Eject for KZGAccumulator<E> {
+    type Primitive = sonic_pc::KZGAccumulator<PairingCurve<E>>;
+
+    /// Ejects the mode of the accumulator.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(
+            Mode::Constant,
+            self.terms().flat_map(|(scalar, point)| [scalar.eject_mode(), point.eject_mode()]),
+        )
+    }
+
+    /// Ejects the accumulator, by computing the multi-scalar multiplication of each linear combination.
+    fn eject_value(&self) -> Self::Primitive {
+        // Returns the sum of the given terms.
+        let combine = |terms: &G1Combination<E>| {
+            terms
+                .iter()
+                .map(|(scalar, point)| point.eject_value() * *scalar.eject_value())
+                .sum::<<PairingCurve<E> as PairingEngine>::G1Projective>()
+        };
+        sonic_pc::KZGAccumulator::new(
+            self.combined_comms.iter().map(|(degree_bound, terms)| (*degree_bound, combine(terms))).collect(),
+            combine(&self.combined_witness),
+            combine(&self.combined_adjusted_witness),
+        )
+    }
+}
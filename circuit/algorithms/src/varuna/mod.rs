@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod accumulator;
+pub use accumulator::*;
+
+mod point;
+pub use point::*;
+
+mod proof;
+pub use proof::*;
+
+mod sponge;
+pub use sponge::*;
+
+mod verify;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::{DuplexSpongeMode, Poseidon};
+use snarkvm_algorithms::{
+    crypto_hash,
+    fft::EvaluationDomain,
+    polycommit::sonic_pc,
+    snark::varuna::{AHPForR1CS, CircuitId, CircuitVerifyingKey, Proof, SNARKMode},
+    srs::UniversalVerifier,
+};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+use snarkvm_curves::{AffineCurve, PairingEngine};
+
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+/// The pairing curve of the network, over which Varuna proofs are created.
+pub type PairingCurve<E> = <<E as Environment>::Network as console::Environment>::PairingCurve;
+/// The base field of the pairing curve, in which the coordinates of its points lie.
+pub type PairingBaseField<E> = <PairingCurve<E> as PairingEngine>::Fq;
+/// An affine point of the first group of the pairing curve.
+pub type PairingG1Affine<E> = <PairingCurve<E> as PairingEngine>::G1Affine;
+
+/// The Fiat-Shamir transcript of the Varuna proofs that can be verified in-circuit.
+/// The sponge is over the scalar field of the pairing curve, which is the base field of the circuit.
+pub type VarunaFiatShamir<E> = crypto_hash::ScalarPoseidonSponge<PairingBaseField<E>, <E as Environment>::BaseField, 2>;
+
+/// A verifier of Varuna proofs for a fixed circuit, whose proofs are created with `VarunaFiatShamir`
+/// and its default parameters, for a single instance of the circuit.
+///
+/// The verifier recomputes the Fiat-Shamir challenges with the circuit Poseidon sponge, and checks the
+/// evaluations of the AHP, which are over the base field of the circuit. The opening checks of the
+/// polynomial commitments are over the pairing curve, whose base field is non-native to the circuit.
+/// Instead of emulating the group operations and the pairings, the verifier combines the opening checks
+/// as in `SonicKZG10::accumulate_combinations`, and outputs the scalars and points of the combination
+/// as a `KZGAccumulator`. The proof is valid iff the ejected accumulator passes `SonicKZG10::check_accumulator`,
+/// i.e. the final multi-scalar multiplications and product of pairings are deferred to the verifier of this circuit.
+#[derive(Clone)]
+pub struct VarunaVerifier<E: Environment, SM: SNARKMode> {
+    /// The ID of the circuit.
+    id: CircuitId,
+    /// The commitments to the index polynomials, by label.
+    circuit_commitments: BTreeMap<String, G1Point<E>>,
+    /// The domain of the constraints.
+    constraint_domain: EvaluationDomain<E::BaseField>,
+    /// The domain of the variables.
+    variable_domain: EvaluationDomain<E::BaseField>,
+    /// The domain of the public inputs.
+    input_domain: EvaluationDomain<E::BaseField>,
+    /// The domains of the non-zero entries of the matrices `A`, `B`, and `C`.
+    non_zero_domains: [EvaluationDomain<E::BaseField>; 3],
+    /// The largest of the domains of the non-zero entries.
+    max_non_zero_domain: EvaluationDomain<E::BaseField>,
+    /// The degree bound of `g_1`.
+    g_1_degree_bound: Option<usize>,
+    /// The degree bounds of `g_a`, `g_b`, and `g_c`.
+    g_m_degree_bounds: [Option<usize>; 3],
+    /// The generator `g` of the KZG verifying key.
+    g: G1Point<E>,
+    /// The generator `gamma_g` of the KZG verifying key.
+    gamma_g: G1Point<E>,
+    _mode: PhantomData<SM>,
+}
+
+impl<E: Environment, SM: SNARKMode> VarunaVerifier<E, SM> {
+    /// Initializes a verifier for the given verifying key and universal verifier.
+    pub fn new(
+        verifying_key: &CircuitVerifyingKey<PairingCurve<E>>,
+        universal_verifier: &UniversalVerifier<PairingCurve<E>>,
+    ) -> Self {
+        let id = verifying_key.id;
+        let info = &verifying_key.circuit_info;
+
+        // Order the commitments to the index polynomials by label, as in the verifying key.
+        let labels = AHPForR1CS::<E::BaseField, SM>::index_polynomial_info(core::iter::once(&id)).into_keys();
+        if labels.len() != verifying_key.circuit_commitments.len() {
+            E::halt("Invalid number of circuit commitments in the Varuna verifying key")
+        }
+        let circuit_commitments = labels
+            .zip(&verifying_key.circuit_commitments)
+            .map(|(label, commitment)| (label, G1Point::constant(commitment.0)))
+            .collect();
+
+        // Initialize the domains.
+        let domain = |size: usize| match EvaluationDomain::new(size) {
+            Some(domain) => domain,
+            None => E::halt("Invalid domain size in the Varuna verifying key"),
+        };
+        let non_zero_domains = [domain(info.num_non_zero_a), domain(info.num_non_zero_b), domain(info.num_non_zero_c)];
+        let max_non_zero_domain = non_zero_domains.into_iter().max_by_key(|domain| domain.size()).unwrap();
+        let variable_domain = domain(info.num_variables);
+
+        // Retrieve the degree bounds of the oracles, as in `VarunaSNARK::verify_batch_deferred`.
+        let g_1_degree_bound =
+            AHPForR1CS::<E::BaseField, SM>::third_round_polynomial_info(variable_domain.size())["g_1"].degree_bound();
+        let g_m_degree_bounds =
+            AHPForR1CS::<E::BaseField, SM>::fourth_round_polynomial_info(core::iter::once((id, info)))
+                .into_values()
+                .map(|info| info.degree_bound())
+                .collect::<Vec<_>>();
+        let g_m_degree_bounds = match g_m_degree_bounds.try_into() {
+            Ok(degree_bounds) => degree_bounds,
+            Err(_) => E::halt("Invalid number of matrices in the Varuna verifying key"),
+        };
+
+        Self {
+            id,
+            circuit_commitments,
+            constraint_domain: domain(info.num_constraints),
+            variable_domain,
+            input_domain: domain(info.num_public_inputs),
+            non_zero_domains,
+            max_non_zero_domain,
+            g_1_degree_bound,
+            g_m_degree_bounds,
+            g: G1Point::constant(universal_verifier.vk.g),
+            gamma_g: G1Point::constant(universal_verifier.vk.gamma_g),
+            _mode: PhantomData,
+        }
+    }
+}
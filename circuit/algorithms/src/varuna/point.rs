@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A point of the first group of the pairing curve, e.g. a KZG commitment or opening proof.
+///
+/// The point is given by the **little-endian** bits of its affine coordinates, which are elements of
+/// the base field of the pairing curve, and are range-checked to be less than its modulus.
+/// As the group operations on the point are deferred, the point is checked to be on the curve,
+/// and in the prime-order subgroup, when it is ejected.
+#[derive(Clone)]
+pub struct G1Point<E: Environment> {
+    /// The bits of the x-coordinate.
+    x: Vec<Boolean<E>>,
+    /// The bits of the y-coordinate.
+    y: Vec<Boolean<E>>,
+    /// Whether the point is the point at infinity.
+    infinity: Boolean<E>,
+}
+
+impl<E: Environment> G1Point<E> {
+    /// Returns the elements of the base field that the point is absorbed as in `VarunaFiatShamir`,
+    /// i.e. the chunks of the bits of `x`, `y`, and `infinity`, where `infinity` is an element of
+    /// the base field of the pairing curve.
+    pub fn to_sponge_fields(&self) -> Vec<Field<E>> {
+        let num_bits_per_chunk = VarunaFiatShamir::<E>::num_bits_per_chunk::<PairingBaseField<E>>();
+        let mut infinity = vec![Boolean::constant(false); self.x.len()];
+        infinity[0] = self.infinity.clone();
+        [&self.x, &self.y, &infinity]
+            .into_iter()
+            .flat_map(|bits| bits.chunks(num_bits_per_chunk).map(Field::from_bits_le))
+            .collect()
+    }
+}
+
+impl<E: Environment> Inject for G1Point<E> {
+    type Primitive = PairingG1Affine<E>;
+
+    /// Initializes a point from the given affine point.
+    fn new(mode: Mode, point: Self::Primitive) -> Self {
+        // Inject the bits of the coordinates.
+        let inject = |coordinate: PairingBaseField<E>| {
+            coordinate.to_bits_le().into_iter().map(|bit| Boolean::new(mode, bit)).collect::<Vec<_>>()
+        };
+        let x = inject(point.to_x_coordinate());
+        let y = inject(point.to_y_coordinate());
+
+        // Ensure the coordinates are less than the modulus.
+        let modulus_minus_one = (-PairingBaseField::<E>::one()).to_bits_le();
+        Boolean::assert_less_than_or_equal_constant(&x, &modulus_minus_one);
+        Boolean::assert_less_than_or_equal_constant(&y, &modulus_minus_one);
+
+        Self { x, y, infinity: Boolean::new(mode, point.is_zero()) }
+    }
+}
+
+impl<E: Environment> Eject for G1Point<E> {
+    type Primitive = PairingG1Affine<E>;
+
+    /// Ejects the mode of the point.
+    fn eject_mode(&self) -> Mode {
+        Mode::combine(self.infinity.eject_mode(), self.x.iter().chain(&self.y).map(Eject::eject_mode))
+    }
+
+    /// Ejects the point, and halts if it is not in the prime-order subgroup of the pairing curve.
+    fn eject_value(&self) -> Self::Primitive {
+        // Returns the coordinate with the given bits.
+        let to_coordinate = |bits: &[Boolean<E>]| {
+            let bits = bits.iter().map(|bit| bit.eject_value()).collect::<Vec<_>>();
+            <PairingBaseField<E> as PrimeField>::BigInteger::from_bits_le(&bits)
+                .ok()
+                .and_then(PairingBaseField::<E>::from_bigint)
+                .unwrap_or_else(|| E::halt("Invalid coordinate of a G1 point"))
+        };
+
+        let point = match self.infinity.eject_value() {
+            true => PairingG1Affine::<E>::zero(),
+            false => {
+                let (x, y) = (to_coordinate(&self.x), to_coordinate(&self.y));
+                // Recover the point from its x-coordinate, and ensure it has the given y-coordinate.
+                [true, false]
+                    .into_iter()
+                    .filter_map(|greatest| PairingG1Affine::<E>::from_x_coordinate(x, greatest))
+                    .find(|point| point.to_y_coordinate() == y)
+                    .unwrap_or_else(|| E::halt("G1 point is not on the curve"))
+            }
+        };
+        match point.is_in_correct_subgroup_assuming_on_curve() {
+            true => point,
+            false => E::halt("G1 point is not in the prime-order subgroup"),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Varuna proof for a single instance of a single circuit.
+#[derive(Clone)]
+pub struct VarunaProof<E: Environment> {
+    /// The commitment to the witness polynomial `w`.
+    pub(super) w: G1Point<E>,
+    /// The commitment to the masking polynomial, in hiding mode.
+    pub(super) mask_poly: Option<G1Point<E>>,
+    /// The commitment to `h_0`.
+    pub(super) h_0: G1Point<E>,
+    /// The commitment to `g_1`.
+    pub(super) g_1: G1Point<E>,
+    /// The commitment to `h_1`.
+    pub(super) h_1: G1Point<E>,
+    /// The commitments to `g_a`, `g_b`, and `g_c`.
+    pub(super) g_m: [G1Point<E>; 3],
+    /// The commitment to `h_2`.
+    pub(super) h_2: G1Point<E>,
+    /// The evaluation of `g_1` at `beta`.
+    pub(super) g_1_eval: Field<E>,
+    /// The evaluations of `g_a`, `g_b`, and `g_c` at `gamma`.
+    pub(super) g_m_evals: [Field<E>; 3],
+    /// The sums of the matrices `A`, `B`, and `C` in the third message of the prover.
+    pub(super) third_sums: [Field<E>; 3],
+    /// The sums of the matrices `A`, `B`, and `C` in the fourth message of the prover.
+    pub(super) fourth_sums: [Field<E>; 3],
+    /// The KZG opening proofs at `alpha`, `beta`, and `gamma`, with their random evaluations in hiding mode.
+    pub(super) pc_proofs: [(G1Point<E>, Option<Field<E>>); 3],
+}
+
+impl<E: Environment> Inject for VarunaProof<E> {
+    type Primitive = Proof<PairingCurve<E>>;
+
+    /// Initializes a proof from the given Varuna proof, and halts if it is not for a single instance of a single circuit.
+    fn new(mode: Mode, proof: Self::Primitive) -> Self {
+        // Ensure the proof is for a single instance of a single circuit.
+        if proof.check_batch_sizes().is_err() || proof.batch_sizes() != [1] {
+            E::halt("Varuna proof is not for a single instance of a single circuit")
+        }
+
+        let point = |commitment: &sonic_pc::Commitment<PairingCurve<E>>| G1Point::new(mode, commitment.0);
+        let field = |field: E::BaseField| Field::new(mode, console::Field::new(field));
+
+        let commitments = &proof.commitments;
+        let evaluations = &proof.evaluations;
+        let third_sums = &proof.third_msg.sums[0][0];
+        let fourth_sums = &proof.fourth_msg.sums[0];
+        let pc_proofs = proof
+            .pc_proof
+            .proof
+            .proofs()
+            .iter()
+            .map(|proof| (G1Point::new(mode, proof.w), proof.random_v.map(field)))
+            .collect::<Vec<_>>();
+
+        Self {
+            w: point(&commitments.witness_commitments[0].w),
+            mask_poly: commitments.mask_poly.as_ref().map(point),
+            h_0: point(&commitments.h_0),
+            g_1: point(&commitments.g_1),
+            h_1: point(&commitments.h_1),
+            g_m: [
+                point(&commitments.g_a_commitments[0]),
+                point(&commitments.g_b_commitments[0]),
+                point(&commitments.g_c_commitments[0]),
+            ],
+            h_2: point(&commitments.h_2),
+            g_1_eval: field(evaluations.g_1_eval),
+            g_m_evals: [
+                field(evaluations.g_a_evals[0]),
+                field(evaluations.g_b_evals[0]),
+                field(evaluations.g_c_evals[0]),
+            ],
+            third_sums: [field(third_sums.sum_a), field(third_sums.sum_b), field(third_sums.sum_c)],
+            fourth_sums: [field(fourth_sums.sum_a), field(fourth_sums.sum_b), field(fourth_sums.sum_c)],
+            pc_proofs: match pc_proofs.try_into() {
+                Ok(pc_proofs) => pc_proofs,
+                Err(_) => E::halt("Varuna proof has an invalid number of KZG opening proofs"),
+            },
+        }
+    }
+}
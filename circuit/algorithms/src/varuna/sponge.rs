@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The capacity of the sponge.
+const CAPACITY: usize = 1;
+/// The number of bits in a short element, as in `PoseidonSponge`.
+const NUM_SHORT_BITS: usize = 168;
+
+/// The Poseidon sponge of `crypto_hash::ScalarPoseidonSponge`, over the base field of the circuit,
+/// which is the scalar field of the pairing curve.
+///
+/// The sponge absorbs and squeezes elements of the base field exactly as `PoseidonSponge`,
+/// so that it recomputes the Fiat-Shamir challenges of the transcript in-circuit.
+#[derive(Clone)]
+pub struct ScalarPoseidonSponge<E: Environment, const RATE: usize> {
+    /// The Poseidon permutation, with the default parameters of the base field.
+    poseidon: Poseidon<E, RATE>,
+    /// The state of the sponge, with its capacity first.
+    state: Vec<Field<E>>,
+    /// The mode of the sponge.
+    mode: DuplexSpongeMode,
+}
+
+impl<E: Environment, const RATE: usize> ScalarPoseidonSponge<E, RATE> {
+    /// Initializes a new sponge.
+    pub fn new() -> Self {
+        // Note: The domain separator is unused, as the sponge does not hash.
+        let poseidon = match console::Poseidon::<E::Network, RATE>::setup("ScalarPoseidonSponge") {
+            Ok(poseidon) => Poseidon::constant(poseidon),
+            Err(error) => E::halt(format!("Failed to initialize the Poseidon sponge: {error}")),
+        };
+        Self {
+            poseidon,
+            state: vec![Field::zero(); RATE + CAPACITY],
+            mode: DuplexSpongeMode::Absorbing { next_absorb_index: 0 },
+        }
+    }
+
+    /// Absorbs the given elements of the base field.
+    pub fn absorb(&mut self, elements: &[Field<E>]) {
+        self.poseidon.absorb(&mut self.state, &mut self.mode, elements);
+    }
+
+    /// Absorbs the given bytes, as in `crypto_hash::ScalarPoseidonSponge::absorb_bytes`.
+    pub fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let elements = VarunaFiatShamir::<E>::encode_bytes(bytes)
+            .into_iter()
+            .map(|element| Field::constant(console::Field::new(element)))
+            .collect::<Vec<_>>();
+        self.absorb(&elements);
+    }
+
+    /// Squeezes the given number of elements of the base field.
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<Field<E>> {
+        let mut output = Vec::with_capacity(num_elements);
+        if num_elements != 0 {
+            // Determine the squeeze index, and permute the state if necessary.
+            let mut squeeze_index = match self.mode {
+                DuplexSpongeMode::Squeezing { next_squeeze_index } if next_squeeze_index < RATE => next_squeeze_index,
+                _ => {
+                    self.poseidon.permute(&mut self.state);
+                    0
+                }
+            };
+            while output.len() < num_elements {
+                // Permute the state once all of its elements are squeezed.
+                if squeeze_index == RATE {
+                    self.poseidon.permute(&mut self.state);
+                    squeeze_index = 0;
+                }
+                output.push(self.state[CAPACITY + squeeze_index].clone());
+                squeeze_index += 1;
+            }
+            self.mode = DuplexSpongeMode::Squeezing { next_squeeze_index: squeeze_index };
+        }
+        output
+    }
+
+    /// Squeezes an element of the base field of 168 bits, as in `squeeze_short_nonnative_field_elements`.
+    pub fn squeeze_short(&mut self) -> Field<E> {
+        let element = &self.squeeze(1)[0];
+        Field::from_bits_le(&element.to_bits_le()[..NUM_SHORT_BITS])
+    }
+}
+
+impl<E: Environment, const RATE: usize> Default for ScalarPoseidonSponge<E, RATE> {
+    /// Initializes a new sponge.
+    fn default() -> Self {
+        Self::new()
+    }
+}
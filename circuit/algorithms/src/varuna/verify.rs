@@ -0,0 +1,372 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::snark::varuna::VarunaSNARK;
+
+/// A linear combination of committed polynomials, and its claimed evaluation at a query point,
+/// as in `SonicKZG10::accumulate_combinations`.
+struct Opening<E: Environment> {
+    /// The coefficients and commitments of the linear combination.
+    terms: G1Combination<E>,
+    /// The degree bound of the linear combination.
+    degree_bound: Option<usize>,
+    /// The claimed evaluation, less the constant terms of the linear combination.
+    value: Field<E>,
+}
+
+impl<E: Environment, SM: SNARKMode> VarunaVerifier<E, SM> {
+    /// Returns the accumulated opening checks of the given proof for the given public inputs,
+    /// after checking the AHP as in `VarunaSNARK::verify_batch_deferred`.
+    /// The proof is valid iff the ejected accumulator passes `SonicKZG10::check_accumulator`.
+    ///
+    /// This method halts if the proof is not of the hiding mode of `SM`, or if there are too many inputs.
+    pub fn verify(&self, inputs: &[Field<E>], proof: &VarunaProof<E>) -> KZGAccumulator<E> {
+        // Ensure the proof is of the correct hiding mode.
+        let is_hiding = proof.pc_proofs.iter().any(|(_, random_v)| random_v.is_some());
+        if is_hiding != SM::ZK || proof.mask_poly.is_some() != SM::ZK {
+            E::halt("Varuna proof has an incorrect hiding mode")
+        }
+
+        // Format the public inputs, and pad them to the size of the input domain.
+        let input_domain_size = self.input_domain.size();
+        if inputs.len() >= input_domain_size {
+            E::halt("Too many public inputs for the Varuna verifying key")
+        }
+        let mut padded_inputs = Vec::with_capacity(input_domain_size);
+        padded_inputs.push(Field::one());
+        padded_inputs.extend_from_slice(inputs);
+        let num_formatted_inputs = padded_inputs.len();
+        padded_inputs.resize(input_domain_size, Field::zero());
+
+        // Initialize the sponge, with the public inputs and the circuit commitments.
+        let mut sponge = ScalarPoseidonSponge::<E, 2>::new();
+        sponge.absorb_bytes(VarunaSNARK::<PairingCurve<E>, VarunaFiatShamir<E>, SM>::PROTOCOL_NAME);
+        sponge.absorb_bytes(&1u64.to_le_bytes());
+        sponge.absorb(&padded_inputs);
+        sponge.absorb(&Self::to_sponge_fields(self.circuit_commitments.values()));
+
+        // Note: There are no batch combiners to squeeze in the first round, as there is a single instance of a single circuit.
+        sponge.absorb(&Self::to_sponge_fields(core::iter::once(&proof.w).chain(&proof.mask_poly)));
+
+        // Squeeze the challenges of the second round.
+        sponge.absorb(&proof.h_0.to_sponge_fields());
+        let challenges = sponge.squeeze(3);
+        let (alpha, eta_b, eta_c) = (&challenges[0], &challenges[1], &challenges[2]);
+        let v_r_at_alpha = Self::evaluate_vanishing_polynomial(&self.constraint_domain, alpha);
+        E::assert(v_r_at_alpha.is_not_equal(&Field::zero()));
+
+        // Squeeze the challenge of the third round.
+        let mut message = Self::to_sponge_fields([&proof.g_1, &proof.h_1]);
+        message.extend_from_slice(&proof.third_sums);
+        sponge.absorb(&message);
+        let beta = sponge.squeeze(1).remove(0);
+        let v_c_at_beta = Self::evaluate_vanishing_polynomial(&self.variable_domain, &beta);
+        E::assert(v_c_at_beta.is_not_equal(&Field::zero()));
+
+        // Squeeze the challenges of the fourth round.
+        let mut message = Self::to_sponge_fields(&proof.g_m);
+        message.extend_from_slice(&proof.fourth_sums);
+        sponge.absorb(&message);
+        let challenges = sponge.squeeze(2);
+        let deltas = [Field::one(), challenges[0].clone(), challenges[1].clone()];
+
+        // Squeeze the challenge of the fifth round.
+        sponge.absorb(&proof.h_2.to_sponge_fields());
+        let gamma = sponge.squeeze(1).remove(0);
+        let v_k_at_gamma = Self::evaluate_vanishing_polynomial(&self.max_non_zero_domain, &gamma);
+        E::assert(v_k_at_gamma.is_not_equal(&Field::zero()));
+
+        // Absorb the evaluations.
+        let mut evaluations = vec![proof.g_1_eval.clone()];
+        evaluations.extend_from_slice(&proof.g_m_evals);
+        sponge.absorb(&evaluations);
+
+        // Construct the linear combinations, as in `AHPForR1CS::construct_linear_combinations`.
+        // Note: The selectors at `alpha` and `beta` are one, as there is a single circuit.
+        let constant = |value: E::BaseField| Field::constant(console::Field::new(value));
+
+        // Construct the rowcheck.
+        let [sum_a, sum_b, sum_c] = &proof.third_sums;
+        let rowcheck_zerocheck = Opening {
+            terms: vec![(-&v_r_at_alpha, proof.h_0.clone())],
+            degree_bound: None,
+            value: -(sum_a * sum_b - sum_c),
+        };
+
+        // Construct the lineval sumcheck.
+        let v_x_at_beta = Self::evaluate_vanishing_polynomial(&self.input_domain, &beta);
+        let x_at_beta = padded_inputs[..num_formatted_inputs]
+            .iter()
+            .zip(self.input_domain.elements())
+            .map(|(input, element)| input * constant(element) * (&beta - constant(element)).inverse())
+            .fold(Field::zero(), |sum, term| sum + term)
+            * &v_x_at_beta
+            * constant(self.input_domain.size_inv);
+        let [size_a, size_b, size_c] = self.non_zero_domains.map(|domain| constant(domain.size_as_field_element));
+        let [sum_a_fourth, sum_b_fourth, sum_c_fourth] = &proof.fourth_sums;
+        let lineval = sum_a_fourth * size_a + eta_b * sum_b_fourth * size_b + eta_c * sum_c_fourth * size_c;
+        let lineval_sum = (sum_a + eta_b * sum_b + eta_c * sum_c) * constant(self.variable_domain.size_inv);
+        let mut lineval_terms =
+            proof.mask_poly.iter().map(|mask_poly| (Field::one(), mask_poly.clone())).collect::<Vec<_>>();
+        lineval_terms.push((&lineval * &v_x_at_beta, proof.w.clone()));
+        lineval_terms.push((-&v_c_at_beta, proof.h_1.clone()));
+        let lineval_sumcheck = Opening {
+            terms: lineval_terms,
+            degree_bound: None,
+            value: -(lineval * x_at_beta - &beta * &proof.g_1_eval - lineval_sum),
+        };
+        let g_1 = Opening {
+            terms: vec![(Field::one(), proof.g_1.clone())],
+            degree_bound: self.g_1_degree_bound,
+            value: proof.g_1_eval.clone(),
+        };
+
+        // Construct the matrix sumcheck.
+        let v_rc = &v_r_at_alpha * &v_c_at_beta;
+        let rc = constant(self.constraint_domain.size_as_field_element * self.variable_domain.size_as_field_element);
+        let mut matrix_terms = Vec::new();
+        let mut matrix_constant = Field::zero();
+        let mut g_m = Vec::with_capacity(3);
+        for (i, matrix) in ["a", "b", "c"].into_iter().enumerate() {
+            let non_zero_domain = &self.non_zero_domains[i];
+            // Compute the selector of the non-zero domain at `gamma`.
+            let selector = match non_zero_domain.size == self.max_non_zero_domain.size {
+                true => Field::one(),
+                false => {
+                    let numerator = &v_k_at_gamma * constant(non_zero_domain.size_as_field_element);
+                    let v_k_m_at_gamma = Self::evaluate_vanishing_polynomial(non_zero_domain, &gamma);
+                    numerator * (v_k_m_at_gamma * constant(self.max_non_zero_domain.size_as_field_element)).inverse()
+                }
+            };
+            let coefficient = &deltas[i] * selector;
+            let b_term = &gamma * &proof.g_m_evals[i] + &proof.fourth_sums[i];
+            let b_coefficient = &coefficient * b_term * &rc;
+
+            matrix_terms.push((&coefficient * &v_rc, self.circuit_commitment("row_col_val", matrix)));
+            matrix_terms.push((&b_coefficient * alpha, self.circuit_commitment("col", matrix)));
+            matrix_terms.push((&b_coefficient * &beta, self.circuit_commitment("row", matrix)));
+            matrix_terms.push((-&b_coefficient, self.circuit_commitment("row_col", matrix)));
+            matrix_constant -= b_coefficient * alpha * &beta;
+
+            g_m.push(Opening {
+                terms: vec![(Field::one(), proof.g_m[i].clone())],
+                degree_bound: self.g_m_degree_bounds[i],
+                value: proof.g_m_evals[i].clone(),
+            });
+        }
+        matrix_terms.push((-&v_k_at_gamma, proof.h_2.clone()));
+        let matrix_sumcheck = Opening { terms: matrix_terms, degree_bound: None, value: -matrix_constant };
+
+        // Accumulate the openings at each query point, ordered by the name of the point, and then by label.
+        let mut gamma_openings = g_m;
+        gamma_openings.push(matrix_sumcheck);
+        let queries =
+            [(alpha.clone(), vec![rowcheck_zerocheck]), (beta, vec![g_1, lineval_sumcheck]), (gamma, gamma_openings)];
+
+        // Accumulate the opening checks, as in `SonicKZG10::batch_accumulate`.
+        let mut accumulator = KZGAccumulator::default();
+        let mut randomizer = Field::one();
+        for ((point, openings), (witness, random_v)) in queries.into_iter().zip(&proof.pc_proofs) {
+            let mut combined_value = Field::zero();
+            for opening in openings {
+                let challenge = sponge.squeeze_short();
+                combined_value += &opening.value * &challenge;
+
+                let coefficient = &randomizer * challenge;
+                let combined_comm = accumulator.combined_comms.entry(opening.degree_bound).or_default();
+                combined_comm.extend(opening.terms.into_iter().map(|(scalar, point)| (scalar * &coefficient, point)));
+            }
+
+            accumulator.combined_witness.push((randomizer.clone(), witness.clone()));
+            accumulator.combined_adjusted_witness.push((&randomizer * combined_value, self.g.clone()));
+            accumulator.combined_adjusted_witness.push((-(&randomizer * point), witness.clone()));
+            if let Some(random_v) = random_v {
+                accumulator.combined_adjusted_witness.push((&randomizer * random_v, self.gamma_g.clone()));
+            }
+
+            randomizer = sponge.squeeze_short();
+        }
+        accumulator
+    }
+
+    /// Returns the commitment to the given index polynomial of the given matrix.
+    fn circuit_commitment(&self, polynomial: &str, matrix: &str) -> G1Point<E> {
+        match self.circuit_commitments.get(&format!("circuit_{}_{polynomial}_{matrix}", self.id)) {
+            Some(commitment) => commitment.clone(),
+            None => E::halt(format!("Missing the commitment to '{polynomial}_{matrix}' in the Varuna verifying key")),
+        }
+    }
+
+    /// Returns the elements of the base field that the given points are absorbed as.
+    fn to_sponge_fields<'a>(points: impl IntoIterator<Item = &'a G1Point<E>>) -> Vec<Field<E>> {
+        points.into_iter().flat_map(G1Point::to_sponge_fields).collect()
+    }
+
+    /// Returns the evaluation of the vanishing polynomial of the given domain at the given point.
+    fn evaluate_vanishing_polynomial(domain: &EvaluationDomain<E::BaseField>, point: &Field<E>) -> Field<E> {
+        (0..domain.log_size_of_group).fold(point.clone(), |power, _| power.square()) - Field::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{
+        crypto_hash::PoseidonSponge,
+        polycommit::sonic_pc::SonicKZG10,
+        snark::varuna::{VarunaHidingMode, VarunaNonHidingMode},
+        AlgebraicSponge,
+        SNARK,
+    };
+    use snarkvm_circuit_types::environment::{Assignment, Circuit};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+
+    type FS = VarunaFiatShamir<Circuit>;
+    type ConsoleField = console::Field<<Circuit as Environment>::Network>;
+
+    /// Synthesizes a circuit that checks `a^5 + b == c`, for public `a` and `c`, and returns its assignment.
+    fn sample_assignment(rng: &mut TestRng) -> Assignment<Fr> {
+        let (a, b) = (ConsoleField::rand(rng), ConsoleField::rand(rng));
+        let c = a * a * a * a * a + b;
+
+        let (a, b, c) =
+            (Field::<Circuit>::new(Mode::Public, a), Field::new(Mode::Private, b), Field::new(Mode::Public, c));
+        Circuit::assert_eq(&a * &a * &a * &a * &a + b, c);
+        assert!(Circuit::is_satisfied());
+        Circuit::eject_assignment_and_reset()
+    }
+
+    /// Proves the given assignment, and returns the universal verifier, the verifying key, the public inputs, and the proof.
+    #[allow(clippy::type_complexity)]
+    fn prove<SM: SNARKMode, S: AlgebraicSponge<Fq, 2>>(
+        assignment: &Assignment<Fr>,
+        rng: &mut TestRng,
+    ) -> (UniversalVerifier<Bls12_377>, CircuitVerifyingKey<Bls12_377>, Vec<Fr>, Proof<Bls12_377>) {
+        let (num_non_zero_a, num_non_zero_b, num_non_zero_c) = assignment.num_nonzeros();
+        let max_degree = AHPForR1CS::<Fr, SM>::max_degree(
+            assignment.num_constraints() as usize,
+            (assignment.num_public() + assignment.num_private()) as usize,
+            num_non_zero_a.max(num_non_zero_b).max(num_non_zero_c) as usize,
+        )
+        .unwrap();
+        let universal_srs = VarunaSNARK::<Bls12_377, S, SM>::universal_setup(max_degree).unwrap();
+        let universal_prover = universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = S::sample_parameters();
+
+        let (proving_key, verifying_key) =
+            VarunaSNARK::<Bls12_377, S, SM>::circuit_setup(&universal_srs, assignment).unwrap();
+        let proof =
+            VarunaSNARK::<Bls12_377, S, SM>::prove(&universal_prover, &fs_parameters, &proving_key, assignment, rng)
+                .unwrap();
+
+        // Note: The public inputs include the constant one of the circuit.
+        let inputs = assignment.public_inputs().iter().map(|(_, input)| *input).collect::<Vec<_>>();
+        assert!(VarunaSNARK::<Bls12_377, S, SM>::verify(
+            &universal_verifier,
+            &fs_parameters,
+            &verifying_key,
+            inputs.as_slice(),
+            &proof
+        )
+        .unwrap());
+        (universal_verifier, verifying_key, inputs, proof)
+    }
+
+    /// Verifies the given proof in-circuit, and returns the accumulator.
+    fn verify<SM: SNARKMode>(
+        universal_verifier: &UniversalVerifier<Bls12_377>,
+        verifying_key: &CircuitVerifyingKey<Bls12_377>,
+        inputs: &[Fr],
+        proof: &Proof<Bls12_377>,
+    ) -> KZGAccumulator<Circuit> {
+        let verifier = VarunaVerifier::<Circuit, SM>::new(verifying_key, universal_verifier);
+        let inputs =
+            inputs.iter().map(|input| Field::new(Mode::Public, console::Field::new(*input))).collect::<Vec<_>>();
+        let proof = VarunaProof::new(Mode::Private, proof.clone());
+        verifier.verify(&inputs, &proof)
+    }
+
+    fn check_verify<SM: SNARKMode>(rng: &mut TestRng) {
+        let assignment = sample_assignment(rng);
+        let (universal_verifier, verifying_key, inputs, proof) = prove::<SM, FS>(&assignment, rng);
+
+        // Change the last public input, for an invalid proof.
+        let mut invalid_inputs = inputs.clone();
+        *invalid_inputs.last_mut().unwrap() += Fr::one();
+
+        for (inputs, expected) in [(inputs, true), (invalid_inputs, false)] {
+            Circuit::scope(format!("VarunaVerifier {expected}"), || {
+                let candidate = verify::<SM>(&universal_verifier, &verifying_key, &inputs, &proof);
+                assert!(Circuit::is_satisfied_in_scope());
+
+                // Ensure the accumulator matches the one of the native verifier.
+                let batch = [inputs.clone()];
+                let keys_to_inputs = BTreeMap::from([(&verifying_key, &batch[..])]);
+                let accumulator = VarunaSNARK::<Bls12_377, FS, SM>::verify_batch_deferred(
+                    &universal_verifier,
+                    &FS::sample_parameters(),
+                    &keys_to_inputs,
+                    &proof,
+                )
+                .unwrap()
+                .unwrap();
+                assert_eq!(accumulator, candidate.eject_value());
+
+                // Ensure the deferred check of the accumulator is as expected.
+                let is_valid =
+                    SonicKZG10::<Bls12_377, FS>::check_accumulator(&universal_verifier, candidate.eject_value())
+                        .unwrap();
+                assert_eq!(expected, is_valid);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_verify_hiding() {
+        check_verify::<VarunaHidingMode>(&mut TestRng::default());
+    }
+
+    #[test]
+    fn test_verify_non_hiding() {
+        check_verify::<VarunaNonHidingMode>(&mut TestRng::default());
+    }
+
+    #[test]
+    fn test_verify_recursively() {
+        let rng = &mut TestRng::default();
+
+        // Prove the inner circuit, and verify its proof in the outer circuit.
+        let assignment = sample_assignment(rng);
+        let (universal_verifier, verifying_key, inputs, proof) = prove::<VarunaHidingMode, FS>(&assignment, rng);
+        let accumulator = verify::<VarunaHidingMode>(&universal_verifier, &verifying_key, &inputs, &proof);
+
+        // Expose the accumulator as public inputs of the outer circuit, for its deferred check.
+        for (scalar, point) in accumulator.terms() {
+            for field in core::iter::once(scalar.clone()).chain(point.to_sponge_fields()) {
+                Circuit::assert_eq(Field::new(Mode::Public, field.eject_value()), field);
+            }
+        }
+        assert!(Circuit::is_satisfied());
+        let accumulator = accumulator.eject_value();
+        let outer_assignment = Circuit::eject_assignment_and_reset();
+
+        // Prove and verify the outer circuit, and check the accumulator of the inner proof.
+        prove::<VarunaHidingMode, PoseidonSponge<Fq, 2, 1>>(&outer_assignment, rng);
+        assert!(SonicKZG10::<Bls12_377, FS>::check_accumulator(&universal_verifier, accumulator).unwrap());
+    }
+}
@@ -109,6 +109,9 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Field<Self>;
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
@@ -178,4 +181,11 @@ pub trait Aleo: Environment {
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
+
+    /// Returns `true` if the given ECDSA signature over secp256k1 is valid for the given public key and digest.
+    fn verify_ecdsa_secp256k1(
+        signature: &[Boolean<Self>],
+        public_key: &[Boolean<Self>],
+        digest: &[Boolean<Self>],
+    ) -> Boolean<Self>;
 }
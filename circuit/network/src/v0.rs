@@ -16,6 +16,7 @@ use crate::Aleo;
 use snarkvm_circuit_algorithms::{
     Commit,
     CommitUncompressed,
+    EcdsaSecp256k1,
     Hash,
     HashMany,
     HashToGroup,
@@ -29,6 +30,7 @@ use snarkvm_circuit_algorithms::{
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -70,6 +72,9 @@ thread_local! {
     /// The BHP hash function, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<AleoV0> = BHP1024::<AleoV0>::constant(console::BHP_1024.clone());
 
+    /// The ECDSA signature scheme over secp256k1.
+    static ECDSA_SECP256K1: EcdsaSecp256k1<AleoV0> = EcdsaSecp256k1::<AleoV0>::new();
+
     /// The Keccak hash function, which outputs 256 bits.
     static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::new();
     /// The Keccak hash function, which outputs 384 bits.
@@ -89,6 +94,8 @@ thread_local! {
     /// The Poseidon hash function, using a rate of 8.
     static POSEIDON_8: Poseidon8<AleoV0> = Poseidon8::<AleoV0>::constant(console::POSEIDON_8.clone());
 
+    /// The SHA-256 hash function.
+    static SHA_256: Sha256<AleoV0> = Sha256::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 256 bits.
     static SHA3_256: Sha3_256<AleoV0> = Sha3_256::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 384 bits.
@@ -247,6 +254,11 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash(input))
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
+        SHA_256.with(|sha256| sha256.hash(input))
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>> {
         SHA3_256.with(|sha3| sha3.hash(input))
@@ -354,6 +366,15 @@ impl Aleo for AleoV0 {
     ) -> Boolean<Self> {
         POSEIDON_4.with(|psd4| POSEIDON_2.with(|psd2| path.verify(psd4, psd2, root, leaf)))
     }
+
+    /// Returns `true` if the given ECDSA signature over secp256k1 is valid for the given public key and digest.
+    fn verify_ecdsa_secp256k1(
+        signature: &[Boolean<Self>],
+        public_key: &[Boolean<Self>],
+        digest: &[Boolean<Self>],
+    ) -> Boolean<Self> {
+        ECDSA_SECP256K1.with(|ecdsa| ecdsa.verify(signature, public_key, digest))
+    }
 }
 
 impl Environment for AleoV0 {
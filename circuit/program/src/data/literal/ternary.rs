@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Literal<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// This method halts if `first` and `second` are of different types, or are strings.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Address(a), Self::Address(b)) => Self::Address(Address::ternary(condition, a, b)),
+            (Self::Boolean(a), Self::Boolean(b)) => Self::Boolean(Boolean::ternary(condition, a, b)),
+            (Self::Field(a), Self::Field(b)) => Self::Field(Field::ternary(condition, a, b)),
+            (Self::Group(a), Self::Group(b)) => Self::Group(Group::ternary(condition, a, b)),
+            (Self::I8(a), Self::I8(b)) => Self::I8(I8::ternary(condition, a, b)),
+            (Self::I16(a), Self::I16(b)) => Self::I16(I16::ternary(condition, a, b)),
+            (Self::I32(a), Self::I32(b)) => Self::I32(I32::ternary(condition, a, b)),
+            (Self::I64(a), Self::I64(b)) => Self::I64(I64::ternary(condition, a, b)),
+            (Self::I128(a), Self::I128(b)) => Self::I128(I128::ternary(condition, a, b)),
+            (Self::U8(a), Self::U8(b)) => Self::U8(U8::ternary(condition, a, b)),
+            (Self::U16(a), Self::U16(b)) => Self::U16(U16::ternary(condition, a, b)),
+            (Self::U32(a), Self::U32(b)) => Self::U32(U32::ternary(condition, a, b)),
+            (Self::U64(a), Self::U64(b)) => Self::U64(U64::ternary(condition, a, b)),
+            (Self::U128(a), Self::U128(b)) => Self::U128(U128::ternary(condition, a, b)),
+            (Self::Scalar(a), Self::Scalar(b)) => Self::Scalar(Scalar::ternary(condition, a, b)),
+            (Self::Signature(a), Self::Signature(b)) => Self::Signature(Box::<Signature<A>>::ternary(condition, a, b)),
+            (Self::String(..), Self::String(..)) => A::halt("Cannot select between two strings"),
+            _ => A::halt("Cannot select between literals of different types"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_ternary() {
+        let first = Literal::<Circuit>::from_str("1u8.private").unwrap();
+        let second = Literal::<Circuit>::from_str("2u8.private").unwrap();
+
+        let candidate = Literal::ternary(&Boolean::new(Mode::Private, true), &first, &second);
+        assert_eq!(candidate.eject_value(), first.eject_value());
+        let candidate = Literal::ternary(&Boolean::new(Mode::Private, false), &first, &second);
+        assert_eq!(candidate.eject_value(), second.eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+}
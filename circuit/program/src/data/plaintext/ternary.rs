@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Ternary for Plaintext<A> {
+    type Boolean = Boolean<A>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// This method halts if `first` and `second` do not have the same structure.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        match (first, second) {
+            (Self::Literal(a, _), Self::Literal(b, _)) => {
+                Self::Literal(Literal::ternary(condition, a, b), Default::default())
+            }
+            (Self::Struct(a, _), Self::Struct(b, _)) => {
+                // Ensure the structs have the same number of members.
+                if a.len() != b.len() {
+                    A::halt("Cannot select between structs with a different number of members")
+                }
+                // Recursively select each member.
+                let members = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|((name_a, plaintext_a), (name_b, plaintext_b))| {
+                        // Ensure the member names match.
+                        if !name_a.is_equal(name_b).eject_value() {
+                            A::halt("Cannot select between structs with different members")
+                        }
+                        (name_a.clone(), Self::ternary(condition, plaintext_a, plaintext_b))
+                    })
+                    .collect();
+                Self::Struct(members, Default::default())
+            }
+            (Self::Array(a, _), Self::Array(b, _)) => {
+                // Ensure the arrays have the same length.
+                if a.len() != b.len() {
+                    A::halt("Cannot select between arrays of different lengths")
+                }
+                // Recursively select each element.
+                let elements = a
+                    .iter()
+                    .zip_eq(b.iter())
+                    .map(|(plaintext_a, plaintext_b)| Self::ternary(condition, plaintext_a, plaintext_b))
+                    .collect();
+                Self::Array(elements, Default::default())
+            }
+            _ => A::halt("Cannot select between plaintexts of different types"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn check_ternary(first: &str, second: &str) {
+        let first = Plaintext::<Circuit>::new(Mode::Private, console::Plaintext::from_str(first).unwrap());
+        let second = Plaintext::<Circuit>::new(Mode::Private, console::Plaintext::from_str(second).unwrap());
+
+        let candidate = Plaintext::ternary(&Boolean::new(Mode::Private, true), &first, &second);
+        assert_eq!(candidate.eject_value(), first.eject_value());
+        let candidate = Plaintext::ternary(&Boolean::new(Mode::Private, false), &first, &second);
+        assert_eq!(candidate.eject_value(), second.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_ternary() {
+        check_ternary("1field", "2field");
+        check_ternary("{ a: 1u8, b: [true, false] }", "{ a: 2u8, b: [false, true] }");
+        check_ternary("[{ x: 1i64 }, { x: 2i64 }]", "[{ x: 3i64 }, { x: 4i64 }]");
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> Plaintext<A> {
+    /// Initializes a vector from its length and its elements, padded to the capacity of the vector.
+    /// Note: The caller is responsible for enforcing that the length does not exceed the capacity.
+    pub fn new_vector(length: U32<A>, elements: Vec<Plaintext<A>>) -> Result<Self> {
+        Ok(Self::Struct(
+            IndexMap::from_iter([
+                (
+                    Identifier::from_str(console::Plaintext::<A::Network>::VECTOR_LENGTH)?,
+                    Self::from(Literal::U32(length)),
+                ),
+                (
+                    Identifier::from_str(console::Plaintext::<A::Network>::VECTOR_ELEMENTS)?,
+                    Self::Array(elements, Default::default()),
+                ),
+            ]),
+            Default::default(),
+        ))
+    }
+
+    /// Returns the length and the padded elements of the vector.
+    /// This method enforces that the length does not exceed the capacity,
+    /// and that the unused slots hold the default value, so that equal vectors have equal representations.
+    pub fn to_vector(&self) -> Result<(&U32<A>, &[Plaintext<A>])> {
+        let (length, elements) = match self {
+            Self::Struct(members, _) if members.len() == 2 => {
+                // Retrieve the length and the elements.
+                let length = members.get(&Identifier::from_str(console::Plaintext::<A::Network>::VECTOR_LENGTH)?);
+                let elements = members.get(&Identifier::from_str(console::Plaintext::<A::Network>::VECTOR_ELEMENTS)?);
+                match (length, elements) {
+                    (Some(Self::Literal(Literal::U32(length), _)), Some(Self::Array(elements, _))) => {
+                        (length, elements)
+                    }
+                    _ => bail!("The plaintext is not a vector"),
+                }
+            }
+            _ => bail!("The plaintext is not a vector"),
+        };
+
+        // Ensure the length does not exceed the capacity.
+        let capacity = U32::constant(console::U32::new(elements.len() as u32));
+        A::assert(length.is_less_than_or_equal(&capacity));
+        // Ensure each slot at or past the length holds the default value.
+        for (index, element) in elements.iter().enumerate() {
+            let is_unused = length.is_less_than_or_equal(&U32::constant(console::U32::new(index as u32)));
+            A::assert(!is_unused | element.is_equal(&element.to_default()?));
+        }
+        Ok((length, elements))
+    }
+
+    /// Returns the default value with the same layout as this plaintext, as a constant.
+    fn to_default(&self) -> Result<Self> {
+        match self {
+            Self::Literal(literal, _) => {
+                Ok(Self::constant(console::Plaintext::from(console::Literal::zero(literal.to_type())?)))
+            }
+            Self::Struct(members, _) => Ok(Self::Struct(
+                members
+                    .iter()
+                    .map(|(name, member)| Ok((name.clone(), member.to_default()?)))
+                    .collect::<Result<IndexMap<_, _>>>()?,
+                Default::default(),
+            )),
+            Self::Array(elements, _) => Ok(Self::Array(
+                elements.iter().map(|element| element.to_default()).collect::<Result<Vec<_>>>()?,
+                Default::default(),
+            )),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_vector() -> Result<()> {
+        let expected = console::Plaintext::from_str("{ len: 1u32, elements: [7u8, 0u8] }")?;
+        let vector = Plaintext::<Circuit>::new(Mode::Private, expected.clone());
+
+        // Ensure the vector matches its console counterpart.
+        let (length, elements) = vector.to_vector()?;
+        let candidate = Plaintext::new_vector(length.clone(), elements.to_vec())?;
+        assert_eq!(candidate.eject_value(), expected);
+        assert_eq!(candidate.to_bits_le().eject_value(), expected.to_bits_le());
+        // Ensure the well-formed vector satisfies its constraints.
+        assert!(Circuit::is_satisfied());
+
+        // Ensure other plaintexts are not vectors.
+        let array = Plaintext::<Circuit>::new(Mode::Private, console::Plaintext::from_str("[7u8, 0u8]")?);
+        assert!(array.to_vector().is_err());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_with_nonzero_padding() -> Result<()> {
+        let vector = console::Plaintext::from_str("{ len: 1u32, elements: [7u8, 3u8] }")?;
+        let vector = Plaintext::<Circuit>::new(Mode::Private, vector);
+
+        // Ensure the unused slot is constrained to the default value.
+        vector.to_vector()?;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+
+        let vector =
+            console::Plaintext::from_str("{ len: 1u32, elements: [{ a: 7u8, b: true }, { a: 0u8, b: true }] }")?;
+        let vector = Plaintext::<Circuit>::new(Mode::Private, vector);
+
+        // Ensure every member of the unused slot is constrained to the default value.
+        vector.to_vector()?;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_exceeding_capacity() -> Result<()> {
+        let vector = console::Plaintext::from_str("{ len: 3u32, elements: [7u8, 8u8] }")?;
+        let vector = Plaintext::<Circuit>::new(Mode::Private, vector);
+
+        // Ensure the length is constrained to the capacity.
+        vector.to_vector()?;
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+}
@@ -17,6 +17,8 @@
 
 mod equal;
 mod helpers;
+#[cfg(console)]
+mod operations;
 
 #[cfg(test)]
 use console::TestRng;
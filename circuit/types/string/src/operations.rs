@@ -0,0 +1,285 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_circuit_types_integers::U32;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.bytes.len().saturating_add(other.bytes.len());
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Cannot concatenate strings: the result exceeds {} bytes", E::MAX_STRING_BYTES))
+        }
+        // Concatenate the bytes of the two strings.
+        let bytes = self.bytes.iter().chain(&other.bytes).cloned().collect::<Vec<_>>();
+        Self::from_bits_le(&bytes.to_bits_le())
+    }
+
+    /// Returns the size of the string in bytes.
+    /// Note: The size of a string is part of the circuit shape, so it is returned as a constant.
+    pub fn size_in_bytes(&self) -> U32<E> {
+        U32::constant(console::Integer::new(self.bytes.len() as u32))
+    }
+
+    /// Returns the byte of the string at the given index.
+    pub fn byte_at(&self, index: &U32<E>) -> U8<E> {
+        // Ensure the string is not empty.
+        let (last, rest) = match self.bytes.split_last() {
+            Some((last, rest)) => (last, rest),
+            None => E::halt("Cannot index into an empty string"),
+        };
+        // Ensure the index is in bounds.
+        E::assert(index.is_less_than(&self.size_in_bytes()));
+        // Select the byte at the index.
+        rest.iter().enumerate().rev().fold(last.clone(), |selected, (i, byte)| {
+            let is_index = index.is_equal(&U32::constant(console::Integer::new(i as u32)));
+            U8::ternary(&is_index, byte, &selected)
+        })
+    }
+
+    /// Returns the bytes of the string, padded with trailing zeros to the given length.
+    pub fn to_padded_bytes(&self, length: u32) -> Vec<U8<E>> {
+        // Ensure the string fits within the given length.
+        if self.bytes.len() > length as usize {
+            E::halt(format!("Cannot fit a string of {} bytes into {length} bytes", self.bytes.len()))
+        }
+        // Return the padded bytes.
+        let mut bytes = self.bytes.clone();
+        bytes.resize(length as usize, U8::constant(console::Integer::new(0)));
+        bytes
+    }
+
+    /// Initializes a new string from UTF-8 bytes, ignoring any trailing zeros.
+    /// The constraints depend only on the number of given bytes: the length of the string is a witness,
+    /// and the bytes are constrained to be valid UTF-8, followed by zeros, with a nonzero last byte.
+    /// Note: As with `Inject`, the size of the output string is part of the circuit shape of its uses.
+    pub fn from_padded_bytes(bytes: &[U8<E>]) -> Self {
+        // Ensure the bytes are within the allowed capacity.
+        if bytes.len() > E::MAX_STRING_BYTES as usize {
+            E::halt(format!(
+                "Cannot initialize a string from {} bytes, as it exceeds {} bytes",
+                bytes.len(),
+                E::MAX_STRING_BYTES
+            ))
+        }
+
+        // Determine the mode.
+        let mode = bytes.eject_mode();
+        // Inject the number of bytes, without the trailing zeros, as a witness.
+        let num_bytes = bytes.iter().rposition(|byte| *byte.eject_value() != 0).map_or(0, |index| index + 1);
+        let length = match mode.is_constant() {
+            true => U32::constant(console::Integer::new(num_bytes as u32)),
+            false => U32::new(Mode::Private, console::Integer::new(num_bytes as u32)),
+        };
+
+        let zero = U8::constant(console::Integer::new(0));
+        for (i, byte) in bytes.iter().enumerate() {
+            // Ensure the bytes past the length are zero.
+            let is_padding = length.is_less_than_or_equal(&U32::constant(console::Integer::new(i as u32)));
+            E::assert(!is_padding | byte.is_equal(&zero));
+            // Ensure the last byte of the string is nonzero, so that the length is unique.
+            let is_last = length.is_equal(&U32::constant(console::Integer::new(i as u32 + 1)));
+            E::assert(!is_last | byte.is_not_equal(&zero));
+        }
+        // Ensure the bytes are valid UTF-8.
+        // Note: The trailing zeros are valid UTF-8, so it suffices to check all of the bytes.
+        Self::enforce_utf8(bytes);
+
+        Self { mode, bytes: bytes[..num_bytes].to_vec(), size_in_bytes: Field::from_bits_le(&length.to_bits_le()) }
+    }
+
+    /// Enforces that the given bytes are valid UTF-8, as specified in RFC 3629.
+    fn enforce_utf8(bytes: &[U8<E>]) {
+        // Returns `true` if `byte` is within the inclusive range `[lower, upper]`.
+        let is_within = |byte: &U8<E>, lower: u8, upper: u8| {
+            byte.is_greater_than_or_equal(&U8::constant(console::Integer::new(lower)))
+                & byte.is_less_than_or_equal(&U8::constant(console::Integer::new(upper)))
+        };
+        // Returns `true` if `byte` is equal to `value`.
+        let is_byte = |byte: &U8<E>, value: u8| byte.is_equal(&U8::constant(console::Integer::new(value)));
+
+        // Track the number of continuation bytes that are still expected, as one-hot flags.
+        let (mut expects_one, mut expects_two, mut expects_three) =
+            (Boolean::constant(false), Boolean::constant(false), Boolean::constant(false));
+        // Track the leading bytes whose first continuation byte has a narrower range.
+        let (mut after_e0, mut after_ed, mut after_f0, mut after_f4) =
+            (Boolean::constant(false), Boolean::constant(false), Boolean::constant(false), Boolean::constant(false));
+
+        for byte in bytes {
+            let is_continuation = &expects_one | &expects_two | &expects_three;
+
+            // Ensure an expected continuation byte is in `[0x80, 0xBF]`, or in the narrower range of its leading byte.
+            E::assert(!&is_continuation | is_within(byte, 0x80, 0xBF));
+            E::assert(!&after_e0 | is_within(byte, 0xA0, 0xBF));
+            E::assert(!&after_ed | is_within(byte, 0x80, 0x9F));
+            E::assert(!&after_f0 | is_within(byte, 0x90, 0xBF));
+            E::assert(!&after_f4 | is_within(byte, 0x80, 0x8F));
+
+            // Otherwise, ensure the byte is a valid leading byte.
+            let is_two = is_within(byte, 0xC2, 0xDF);
+            let is_three = is_within(byte, 0xE0, 0xEF);
+            let is_four = is_within(byte, 0xF0, 0xF4);
+            let is_ascii = byte.is_less_than(&U8::constant(console::Integer::new(0x80)));
+            E::assert(&is_continuation | &is_ascii | &is_two | &is_three | &is_four);
+
+            // Update the number of expected continuation bytes.
+            let is_leading = !&is_continuation;
+            expects_one = (&is_continuation & &expects_two) | (&is_leading & is_two);
+            expects_two = (&is_continuation & &expects_three) | (&is_leading & is_three);
+            expects_three = &is_leading & is_four;
+            after_e0 = &is_leading & is_byte(byte, 0xE0);
+            after_ed = &is_leading & is_byte(byte, 0xED);
+            after_f0 = &is_leading & is_byte(byte, 0xF0);
+            after_f4 = &is_leading & is_byte(byte, 0xF4);
+        }
+        // Ensure the last character is complete.
+        E::assert(!(expects_one | expects_two | expects_three));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn sample_string(mode: Mode, rng: &mut TestRng) -> StringType<Circuit> {
+        // Sample a random string. Take 1/4th to ensure we fit for all code points.
+        let given = rng.next_string(Circuit::MAX_STRING_BYTES / 4, true);
+        StringType::<Circuit>::new(mode, console::StringType::new(&given))
+    }
+
+    fn check_operations(mode: Mode) -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample two strings.
+        let string_a = sample_string(mode, &mut rng);
+        let string_b = sample_string(mode, &mut rng);
+        let (expected_a, expected_b) = (string_a.eject_value(), string_b.eject_value());
+
+        Circuit::scope(format!("{mode} concat"), || {
+            let candidate = string_a.concat(&string_b);
+            assert_eq!(expected_a.concat(&expected_b).unwrap(), candidate.eject_value());
+            assert_eq!(
+                expected_a.size_in_bytes() + expected_b.size_in_bytes(),
+                candidate.size_in_bytes().eject_value()
+            );
+        });
+
+        Circuit::scope(format!("{mode} byte_at"), || {
+            for i in 0..expected_a.len() as u32 {
+                let index = U32::new(mode, console::Integer::new(i));
+                let candidate = string_a.byte_at(&index);
+                assert_eq!(expected_a.byte_at(&console::Integer::new(i)).unwrap(), candidate.eject_value());
+            }
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+
+        Circuit::scope(format!("{mode} padded_bytes"), || {
+            let length = expected_a.len() as u32 + 4;
+            let bytes = string_a.to_padded_bytes(length);
+            assert_eq!(expected_a.to_padded_bytes(length).unwrap(), bytes.eject_value());
+            let candidate = StringType::from_padded_bytes(&bytes);
+            assert_eq!(expected_a, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_operations_constant() -> Result<()> {
+        check_operations(Mode::Constant)
+    }
+
+    #[test]
+    fn test_operations_public() -> Result<()> {
+        check_operations(Mode::Public)
+    }
+
+    #[test]
+    fn test_operations_private() -> Result<()> {
+        check_operations(Mode::Private)
+    }
+
+    /// Returns the number of constraints to recover a string from the given padded bytes, and whether they are satisfied.
+    fn check_from_padded_bytes(bytes: &[u8]) -> (u64, bool) {
+        let bytes = bytes.iter().map(|byte| U8::new(Mode::Private, console::Integer::new(*byte))).collect::<Vec<_>>();
+        let num_constraints = Circuit::num_constraints();
+        let candidate = StringType::from_padded_bytes(&bytes);
+        let output = (Circuit::num_constraints() - num_constraints, Circuit::is_satisfied());
+        // Ensure the string matches, if the bytes are valid.
+        let expected = console::StringType::from_padded_bytes(&bytes.eject_value());
+        if let Ok(expected) = expected {
+            assert_eq!(expected, candidate.eject_value());
+            assert_eq!(console::Field::from_u32(expected.len() as u32), candidate.size_in_bytes.eject_value());
+        }
+        Circuit::reset();
+        output
+    }
+
+    #[test]
+    fn test_from_padded_bytes_is_sized_by_capacity() {
+        // Ensure the number of constraints depends only on the number of padded bytes.
+        let strings = ["", "a", "aleo", "\u{e9}t\u{e9}", "\u{20ac}1", "\u{1d11e}!", "12345678"];
+        let mut expected_num_constraints = None;
+        for string in strings {
+            let mut bytes = string.as_bytes().to_vec();
+            bytes.resize(8, 0);
+            let (num_constraints, is_satisfied) = check_from_padded_bytes(&bytes);
+            assert!(is_satisfied, "Failed to recover '{string}' from its padded bytes");
+            assert_eq!(*expected_num_constraints.get_or_insert(num_constraints), num_constraints);
+        }
+    }
+
+    #[test]
+    fn test_from_padded_bytes_fails() {
+        for bytes in [
+            // A continuation byte without a leading byte.
+            [0x80, 0, 0, 0],
+            // An overlong encoding.
+            [0xC0, 0x80, 0, 0],
+            // A truncated character.
+            [0xE2, 0x82, 0, 0],
+            // An overlong three-byte encoding.
+            [0xE0, 0x80, 0x80, 0],
+            // A surrogate.
+            [0xED, 0xA0, 0x80, 0],
+            // An overlong four-byte encoding.
+            [0xF0, 0x80, 0x80, 0x80],
+            // A code point above U+10FFFF.
+            [0xF4, 0x90, 0x80, 0x80],
+            // An invalid leading byte.
+            [0xF8, 0x80, 0x80, 0x80],
+        ] {
+            assert!(console::StringType::<<Circuit as Environment>::Network>::from_padded_bytes(
+                &bytes.map(console::Integer::new)
+            )
+            .is_err());
+            let (_, is_satisfied) = check_from_padded_bytes(&bytes);
+            assert!(!is_satisfied, "Recovered a string from the invalid bytes {bytes:x?}");
+        }
+    }
+
+    #[test]
+    fn test_byte_at_out_of_bounds() {
+        let string = StringType::<Circuit>::new(Mode::Private, console::StringType::new("aleo"));
+        let index = U32::new(Mode::Private, console::Integer::new(4));
+        let _ = string.byte_at(&index);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset();
+    }
+}
@@ -14,6 +14,9 @@
 
 use super::*;
 
+static SIGNATURE_R_SIG_DOMAIN: &str = "AleoSignatureRandomizer0";
+static SIGNATURE_NONCE_DOMAIN: &str = "AleoSignatureNonce0";
+
 impl<N: Network> Signature<N> {
     /// Returns a signature `(challenge, response, compute_key)` for a given message and RNG, where:
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
@@ -75,4 +78,101 @@ impl<N: Network> Signature<N> {
         // Sign the message.
         Self::sign(private_key, &fields, rng)
     }
+
+    /// Returns a deterministic signature `(challenge, response, compute_key)` for a given message, where:
+    ///     nonce := HashToScalar(sk_sig, message)
+    ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
+    ///     response := nonce - challenge * sk_sig
+    /// The compute key is derived from `sk_sig`, as in `Self::deterministic_compute_key`.
+    pub fn sign_deterministic(sk_sig: &Scalar<N>, message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+
+        // Derive the compute key from the signature secret key.
+        let compute_key = Self::deterministic_compute_key(sk_sig)?;
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Derive the nonce from the signature secret key and the message.
+        let mut preimage = Vec::with_capacity(2 + message.len());
+        preimage.push(Field::new_domain_separator(SIGNATURE_NONCE_DOMAIN));
+        preimage.push(sk_sig.to_field()?);
+        preimage.extend(message);
+        let nonce = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute `g_r` as `nonce * G`.
+        let g_r = N::g_scalar_multiply(&nonce);
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute the prover response.
+        let response = nonce - (challenge * sk_sig);
+
+        // Output the signature.
+        Ok(Self { challenge, response, compute_key })
+    }
+
+    /// Returns the compute key `(pk_sig, pr_sig)` of deterministic signatures for the given `sk_sig`, where:
+    ///     pk_sig := G^sk_sig
+    ///     pr_sig := G^r_sig, with r_sig := HashToScalar(sk_sig)
+    /// Note: This compute key differs from the compute key of an account with the same `sk_sig`
+    /// (whose `r_sig` is derived from its seed), so the two have different addresses.
+    pub fn deterministic_compute_key(sk_sig: &Scalar<N>) -> Result<ComputeKey<N>> {
+        // Ensure the signature secret key is nonzero.
+        ensure!(!sk_sig.is_zero(), "The signature secret key must be nonzero");
+        // Compute r_sig := HashToScalar(sk_sig).
+        let r_sig = N::hash_to_scalar_psd2(&[Field::new_domain_separator(SIGNATURE_R_SIG_DOMAIN), sk_sig.to_field()?])?;
+        // Derive the compute key from (pk_sig, pr_sig).
+        ComputeKey::try_from((N::g_scalar_multiply(sk_sig), N::g_scalar_multiply(&r_sig)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_deterministic() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a signature secret key, and derive its address.
+            let sk_sig = Scalar::<CurrentNetwork>::rand(rng);
+            let address = Address::try_from(Signature::deterministic_compute_key(&sk_sig)?)?;
+
+            // Check that the signature is valid for the message.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign_deterministic(&sk_sig, &message)?;
+            assert!(signature.verify(&address, &message));
+
+            // Check that the signature is deterministic.
+            assert_eq!(signature, Signature::sign_deterministic(&sk_sig, &message)?);
+
+            // Check that the signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            if message != failure_message {
+                assert!(!signature.verify(&address, &failure_message));
+            }
+        }
+
+        // Ensure a zero signature secret key is rejected.
+        assert!(Signature::<CurrentNetwork>::sign_deterministic(&Scalar::zero(), &[]).is_err());
+        Ok(())
+    }
 }
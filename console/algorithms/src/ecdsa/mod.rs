@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_types::environment::prelude::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+use num_bigint::BigUint;
+
+/// An affine point on the secp256k1 curve, where `None` is the point at infinity.
+type Point = Option<(BigUint, BigUint)>;
+
+/// The ECDSA signature scheme over the secp256k1 curve `y^2 = x^3 + 7`, as used in Bitcoin and Ethereum.
+///
+/// A signature is encoded as `r || s`, and a public key is encoded as `x || y` (i.e. uncompressed,
+/// without the `0x04` prefix), where each component is a 32-byte big-endian integer.
+/// A digest is a 32-byte big-endian integer, such as the Keccak-256 hash of the message.
+/// All inputs and outputs are the **little-endian** bits of these byte encodings.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct EcdsaSecp256k1;
+
+impl EcdsaSecp256k1 {
+    /// The modulus `p` of the base field, as little-endian 64-bit limbs.
+    pub const BASE_MODULUS: [u64; 4] = [0xFFFFFFFEFFFFFC2F, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF];
+    /// The number of bits in a digest.
+    pub const DIGEST_SIZE_IN_BITS: usize = 256;
+    /// The x-coordinate of the generator, as little-endian 64-bit limbs.
+    pub const GENERATOR_X: [u64; 4] = [0x59F2815B16F81798, 0x029BFCDB2DCE28D9, 0x55A06295CE870B07, 0x79BE667EF9DCBBAC];
+    /// The y-coordinate of the generator, as little-endian 64-bit limbs.
+    pub const GENERATOR_Y: [u64; 4] = [0x9C47D08FFB10D4B8, 0xFD17B448A6855419, 0x5DA4FBFC0E1108A8, 0x483ADA7726A3C465];
+    /// The number of bits in a public key.
+    pub const PUBLIC_KEY_SIZE_IN_BITS: usize = 512;
+    /// The order `n` of the generator (i.e. the modulus of the scalar field), as little-endian 64-bit limbs.
+    pub const SCALAR_MODULUS: [u64; 4] =
+        [0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF];
+    /// The number of bits in a signature.
+    pub const SIGNATURE_SIZE_IN_BITS: usize = 512;
+
+    /// Returns `true` if the given signature is valid for the given public key and digest.
+    /// Returns `false` if the signature or the public key is malformed (e.g. if `s` is zero,
+    /// or if the public key is not on the curve), and an error if the inputs are of the wrong size.
+    pub fn verify(&self, signature: &[bool], public_key: &[bool], digest: &[bool]) -> Result<bool> {
+        // Ensure the inputs are of the correct size.
+        ensure!(signature.len() == Self::SIGNATURE_SIZE_IN_BITS, "Invalid ECDSA signature size");
+        ensure!(public_key.len() == Self::PUBLIC_KEY_SIZE_IN_BITS, "Invalid ECDSA public key size");
+        ensure!(digest.len() == Self::DIGEST_SIZE_IN_BITS, "Invalid ECDSA digest size");
+
+        // Decode the inputs.
+        let (r, s) = Self::decode_pair(signature);
+        let (x, y) = Self::decode_pair(public_key);
+        let z = BigUint::from_bytes_be(&bytes_from_bits_le(digest));
+
+        let (p, n) = (Self::to_biguint(&Self::BASE_MODULUS), Self::to_biguint(&Self::SCALAR_MODULUS));
+        let zero = BigUint::from(0u32);
+
+        // Ensure `r` and `s` are in `[1, n - 1]`.
+        if r == zero || r >= n || s == zero || s >= n {
+            return Ok(false);
+        }
+        // Ensure the public key is on the curve.
+        if x >= p || y >= p || (&y * &y) % &p != (&x * &x * &x + 7u32) % &p {
+            return Ok(false);
+        }
+
+        // Compute `w := s^{-1} mod n`, `u1 := z * w mod n`, and `u2 := r * w mod n`.
+        let w = Self::inverse(&s, &n);
+        let u1 = &z * &w % &n;
+        let u2 = &r * &w % &n;
+
+        // Compute `R := u1 * G + u2 * Q`, and ensure `R` is not the point at infinity and `x(R) mod n == r`.
+        match Self::add(&Self::mul(&u1, &Self::generator()), &Self::mul(&u2, &Some((x, y)))) {
+            Some((x, _)) => Ok(x % &n == r),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the public key for the given 256-bit secret key.
+    pub fn public_key(&self, secret_key: &[bool]) -> Result<Vec<bool>> {
+        // Decode the secret key, and ensure it is in `[1, n - 1]`.
+        let secret_key = Self::decode_secret_key(secret_key)?;
+        // Compute the public key.
+        match Self::mul(&secret_key, &Self::generator()) {
+            Some((x, y)) => Ok(Self::encode_pair(&x, &y)),
+            None => bail!("Invalid ECDSA secret key"),
+        }
+    }
+
+    /// Returns a signature on the given digest, for the given 256-bit secret key.
+    pub fn sign<R: Rng + CryptoRng>(&self, secret_key: &[bool], digest: &[bool], rng: &mut R) -> Result<Vec<bool>> {
+        // Ensure the digest is of the correct size.
+        ensure!(digest.len() == Self::DIGEST_SIZE_IN_BITS, "Invalid ECDSA digest size");
+
+        // Decode the secret key, and ensure it is in `[1, n - 1]`.
+        let secret_key = Self::decode_secret_key(secret_key)?;
+        let z = BigUint::from_bytes_be(&bytes_from_bits_le(digest));
+        let n = Self::to_biguint(&Self::SCALAR_MODULUS);
+        let zero = BigUint::from(0u32);
+
+        loop {
+            // Sample a random nonce `k`.
+            let k = BigUint::from_bytes_be(&rng.gen::<[u8; 32]>()) % &n;
+            if k == zero {
+                continue;
+            }
+            // Compute `r := x(k * G) mod n`.
+            let r = match Self::mul(&k, &Self::generator()) {
+                Some((x, _)) => x % &n,
+                None => continue,
+            };
+            // Compute `s := k^{-1} * (z + r * secret_key) mod n`.
+            let s = Self::inverse(&k, &n) * (&z + &r * &secret_key) % &n;
+            if r != zero && s != zero {
+                return Ok(Self::encode_pair(&r, &s));
+            }
+        }
+    }
+}
+
+impl EcdsaSecp256k1 {
+    /// Returns the given little-endian 64-bit limbs as an integer.
+    fn to_biguint(limbs: &[u64; 4]) -> BigUint {
+        BigUint::from_bytes_le(&limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>())
+    }
+
+    /// Returns the generator of the curve.
+    fn generator() -> Point {
+        Some((Self::to_biguint(&Self::GENERATOR_X), Self::to_biguint(&Self::GENERATOR_Y)))
+    }
+
+    /// Decodes the given 512 bits as a pair of 32-byte big-endian integers.
+    fn decode_pair(bits: &[bool]) -> (BigUint, BigUint) {
+        let bytes = bytes_from_bits_le(bits);
+        (BigUint::from_bytes_be(&bytes[..32]), BigUint::from_bytes_be(&bytes[32..]))
+    }
+
+    /// Encodes the given pair of integers as the bits of two 32-byte big-endian integers.
+    fn encode_pair(first: &BigUint, second: &BigUint) -> Vec<bool> {
+        let mut bytes = [0u8; 64];
+        let (first, second) = (first.to_bytes_be(), second.to_bytes_be());
+        bytes[32 - first.len()..32].copy_from_slice(&first);
+        bytes[64 - second.len()..].copy_from_slice(&second);
+        bits_from_bytes_le(&bytes).collect()
+    }
+
+    /// Decodes the given 256 bits as a secret key, ensuring it is in `[1, n - 1]`.
+    fn decode_secret_key(bits: &[bool]) -> Result<BigUint> {
+        ensure!(bits.len() == 256, "Invalid ECDSA secret key size");
+        let secret_key = BigUint::from_bytes_be(&bytes_from_bits_le(bits));
+        ensure!(
+            secret_key != BigUint::from(0u32) && secret_key < Self::to_biguint(&Self::SCALAR_MODULUS),
+            "Invalid ECDSA secret key"
+        );
+        Ok(secret_key)
+    }
+
+    /// Returns the inverse of the given element modulo the given prime.
+    fn inverse(element: &BigUint, modulus: &BigUint) -> BigUint {
+        element.modpow(&(modulus - 2u32), modulus)
+    }
+
+    /// Returns the sum of the given points.
+    fn add(a: &Point, b: &Point) -> Point {
+        let p = Self::to_biguint(&Self::BASE_MODULUS);
+        let ((x1, y1), (x2, y2)) = match (a, b) {
+            (None, _) => return b.clone(),
+            (_, None) => return a.clone(),
+            (Some(a), Some(b)) => (a, b),
+        };
+        // Compute the slope of the line through the points (or the tangent, if the points are equal).
+        let lambda = if x1 == x2 {
+            // If the points are inverses, return the point at infinity.
+            if (y1 + y2) % &p == BigUint::from(0u32) {
+                return None;
+            }
+            BigUint::from(3u32) * x1 * x1 % &p * Self::inverse(&(BigUint::from(2u32) * y1 % &p), &p) % &p
+        } else {
+            (y2 + &p - y1) % &p * Self::inverse(&((x2 + &p - x1) % &p), &p) % &p
+        };
+        // Compute the sum.
+        let x3 = (&lambda * &lambda + BigUint::from(2u32) * &p - x1 - x2) % &p;
+        let y3 = (lambda * ((x1 + &p - &x3) % &p) + &p - y1) % &p;
+        Some((x3, y3))
+    }
+
+    /// Returns the given point multiplied by the given scalar.
+    fn mul(scalar: &BigUint, point: &Point) -> Point {
+        let mut output = None;
+        for i in (0..scalar.bits()).rev() {
+            output = Self::add(&output, &output);
+            if scalar.bit(i) {
+                output = Self::add(&output, point);
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the little-endian bits of the given hex string.
+    fn bits_from_hex(hex: &str) -> Vec<bool> {
+        bits_from_bytes_le(&hex::decode(hex).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_verify_vector() {
+        let ecdsa = EcdsaSecp256k1;
+
+        let secret_key = bits_from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let public_key = bits_from_hex(
+            "2c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae64564b95e4fdb6948c0386e189b006a29f686769b011704275e4459822dc3328085",
+        );
+        let signature = bits_from_hex(
+            "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+        );
+        let digest = bits_from_hex("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+
+        // Ensure the public key is derived correctly.
+        assert_eq!(ecdsa.public_key(&secret_key).unwrap(), public_key);
+        // Ensure the signature is valid.
+        assert!(ecdsa.verify(&signature, &public_key, &digest).unwrap());
+
+        // Ensure a modified digest is rejected.
+        let mut modified = digest.clone();
+        modified[0] = !modified[0];
+        assert!(!ecdsa.verify(&signature, &public_key, &modified).unwrap());
+        // Ensure a zero signature is rejected.
+        assert!(!ecdsa.verify(&[false; 512], &public_key, &digest).unwrap());
+        // Ensure a public key that is not on the curve is rejected.
+        let mut modified = public_key.clone();
+        modified[0] = !modified[0];
+        assert!(!ecdsa.verify(&signature, &modified, &digest).unwrap());
+        // Ensure inputs of the wrong size are rejected.
+        assert!(ecdsa.verify(&signature[1..], &public_key, &digest).is_err());
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+        let ecdsa = EcdsaSecp256k1;
+
+        for _ in 0..ITERATIONS {
+            // Sample a secret key and a digest.
+            let secret_key = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let digest = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+            let public_key = ecdsa.public_key(&secret_key).unwrap();
+            let signature = ecdsa.sign(&secret_key, &digest, rng).unwrap();
+            assert!(ecdsa.verify(&signature, &public_key, &digest).unwrap());
+
+            // Ensure the signature is rejected for a different public key.
+            let other_key = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let other_public_key = ecdsa.public_key(&other_key).unwrap();
+            assert!(!ecdsa.verify(&signature, &other_public_key, &digest).unwrap());
+        }
+    }
+}
@@ -25,6 +25,9 @@ pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::EcdsaSecp256k1;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
@@ -36,3 +39,6 @@ pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+mod sha256;
+pub use sha256::Sha256;
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_utilities::{bits_from_bytes_le, bytes_from_bits_le};
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        Ok(bits_from_bytes_le(&sha256_native(&bytes_from_bits_le(input))).collect())
+    }
+}
+
+/// Computes the SHA-256 hash of the given preimage as bytes.
+fn sha256_native(preimage: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&NativeSha256::digest(preimage));
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the SHA-256 hash of the given bytes, as a hex string.
+    fn hash_hex(preimage: &[u8]) -> String {
+        let input = bits_from_bytes_le(preimage).collect::<Vec<_>>();
+        hex::encode(bytes_from_bits_le(&Sha256.hash(&input).unwrap()))
+    }
+
+    #[test]
+    fn test_sha256_vectors() {
+        // The test vectors are from FIPS 180-4 (Appendix B).
+        assert_eq!(hash_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_eq!(hash_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(
+            hash_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_sha256_partial_byte() {
+        // Ensure an input that is not a multiple of 8 bits is zero-padded to the next byte.
+        let input = [true, false, true];
+        let expected = Sha256.hash(&[true, false, true, false, false, false, false, false]).unwrap();
+        assert_eq!(Sha256.hash(&input).unwrap(), expected);
+        assert_eq!(expected.len(), 256);
+    }
+}
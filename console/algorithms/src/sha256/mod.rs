@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod hash;
+
+use crate::Hash;
+use snarkvm_console_types::environment::prelude::*;
+
+use sha2::{Digest, Sha256 as NativeSha256};
+
+/// The SHA-256 hash function, as specified in FIPS 180-4.
+///
+/// The input bits are packed into bytes in little-endian order (i.e. bit `i` is bit `i % 8` of byte `i / 8`),
+/// and zero-padded up to a multiple of 8 bits. The digest is returned in the same bit order,
+/// so that the bytes of the digest match the standard (big-endian) encoding of SHA-256.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sha256;
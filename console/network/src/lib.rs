@@ -150,6 +150,8 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
+    /// The maximum number of iterations in a `repeat` block.
+    const MAX_REPEAT_ITERATIONS: usize = Self::MAX_ARRAY_ELEMENTS;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
@@ -278,6 +280,9 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8.
     fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>>;
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
 
@@ -353,4 +358,7 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given ECDSA signature over secp256k1 is valid for the given public key and digest.
+    fn verify_ecdsa_secp256k1(signature: &[bool], public_key: &[bool], digest: &[bool]) -> Result<bool>;
 }
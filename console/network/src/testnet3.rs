@@ -15,6 +15,7 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    EcdsaSecp256k1,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -23,6 +24,7 @@ use snarkvm_console_algorithms::{
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
     Sha3_256,
     Sha3_384,
     Sha3_512,
@@ -362,6 +364,11 @@ impl Network for Testnet3 {
         POSEIDON_8.hash(input)
     }
 
+    /// Returns the SHA-256 hash.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256.hash(input)
+    }
+
     /// Returns the SHA-3 hash with a 256-bit output.
     fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
         Sha3_256::default().hash(input)
@@ -479,6 +486,11 @@ impl Network for Testnet3 {
     ) -> bool {
         path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
     }
+
+    /// Returns `true` if the given ECDSA signature over secp256k1 is valid for the given public key and digest.
+    fn verify_ecdsa_secp256k1(signature: &[bool], public_key: &[bool], digest: &[bool]) -> Result<bool> {
+        EcdsaSecp256k1.verify(signature, public_key, digest)
+    }
 }
 
 #[cfg(test)]
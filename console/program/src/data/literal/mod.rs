@@ -27,6 +27,7 @@ mod size_in_bits;
 mod to_bits;
 mod to_type;
 mod variant;
+mod zero;
 
 use crate::LiteralType;
 use snarkvm_console_account::{ComputeKey, PrivateKey, Signature};
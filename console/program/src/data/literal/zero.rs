@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Returns the zero value of the given literal type.
+    /// This is `0` for numeric types, `false`, the zero address, the empty string,
+    /// and the signature whose scalars and compute key are all zero.
+    pub fn zero(literal_type: LiteralType) -> Result<Self> {
+        Ok(match literal_type {
+            LiteralType::Address => Literal::Address(Address::zero()),
+            LiteralType::Boolean => Literal::Boolean(Boolean::new(false)),
+            LiteralType::Field => Literal::Field(Field::zero()),
+            LiteralType::Group => Literal::Group(Group::zero()),
+            LiteralType::I8 => Literal::I8(I8::zero()),
+            LiteralType::I16 => Literal::I16(I16::zero()),
+            LiteralType::I32 => Literal::I32(I32::zero()),
+            LiteralType::I64 => Literal::I64(I64::zero()),
+            LiteralType::I128 => Literal::I128(I128::zero()),
+            LiteralType::U8 => Literal::U8(U8::zero()),
+            LiteralType::U16 => Literal::U16(U16::zero()),
+            LiteralType::U32 => Literal::U32(U32::zero()),
+            LiteralType::U64 => Literal::U64(U64::zero()),
+            LiteralType::U128 => Literal::U128(U128::zero()),
+            LiteralType::Scalar => Literal::Scalar(Scalar::zero()),
+            LiteralType::Signature => Literal::Signature(Box::new(Signature::from((
+                Scalar::zero(),
+                Scalar::zero(),
+                ComputeKey::try_from((Group::zero(), Group::zero()))?,
+            )))),
+            LiteralType::String => Literal::String(StringType::new("")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_zero() -> Result<()> {
+        for variant in 0..=16u8 {
+            let literal_type = LiteralType::from_bytes_le(&[variant])?;
+            let zero = Literal::<CurrentNetwork>::zero(literal_type)?;
+            assert_eq!(zero.to_type(), literal_type);
+            // Ensure the zero value is deterministic.
+            assert_eq!(zero, Literal::<CurrentNetwork>::zero(literal_type)?);
+        }
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::U8)?.to_string(), "0u8");
+        assert_eq!(Literal::<CurrentNetwork>::zero(LiteralType::String)?.to_string(), "\"\"");
+        Ok(())
+    }
+}
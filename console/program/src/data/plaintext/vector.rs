@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// The member holding the elements of a vector, padded to its capacity.
+    pub const VECTOR_ELEMENTS: &'static str = "elements";
+    /// The member holding the number of elements in a vector.
+    pub const VECTOR_LENGTH: &'static str = "len";
+
+    /// Initializes a vector from its length and its elements, padded to the capacity of the vector.
+    /// A vector is the struct `{ len: u32, elements: [T; capacity] }`, so its size depends only on its type.
+    pub fn new_vector(length: U32<N>, elements: Vec<Plaintext<N>>) -> Result<Self> {
+        // Ensure the length does not exceed the capacity.
+        ensure!(*length as usize <= elements.len(), "Vector length {length} exceeds its capacity {}", elements.len());
+        // Construct the vector.
+        Ok(Self::Struct(
+            IndexMap::from_iter([
+                (Identifier::from_str(Self::VECTOR_LENGTH)?, Self::from(Literal::U32(length))),
+                (Identifier::from_str(Self::VECTOR_ELEMENTS)?, Self::Array(elements, Default::default())),
+            ]),
+            Default::default(),
+        ))
+    }
+
+    /// Returns the length and the padded elements of the vector.
+    pub fn to_vector(&self) -> Result<(U32<N>, &[Plaintext<N>])> {
+        match self {
+            Self::Struct(members, _) if members.len() == 2 => {
+                // Retrieve the length and the elements.
+                let length = members.get(&Identifier::from_str(Self::VECTOR_LENGTH)?);
+                let elements = members.get(&Identifier::from_str(Self::VECTOR_ELEMENTS)?);
+                match (length, elements) {
+                    (Some(Self::Literal(Literal::U32(length), _)), Some(Self::Array(elements, _))) => {
+                        // Ensure the length does not exceed the capacity.
+                        ensure!(
+                            **length as usize <= elements.len(),
+                            "Vector length {length} exceeds its capacity {}",
+                            elements.len()
+                        );
+                        Ok((*length, elements))
+                    }
+                    _ => bail!("'{self}' is not a vector"),
+                }
+            }
+            _ => bail!("'{self}' is not a vector"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_vector() -> Result<()> {
+        let elements = vec![Plaintext::from_str("1u8")?, Plaintext::from_str("2u8")?, Plaintext::from_str("0u8")?];
+        let vector = Plaintext::<CurrentNetwork>::new_vector(U32::new(2), elements.clone())?;
+        assert_eq!(vector, Plaintext::from_str("{ len: 2u32, elements: [1u8, 2u8, 0u8] }")?);
+
+        let (length, candidate) = vector.to_vector()?;
+        assert_eq!(length, U32::new(2));
+        assert_eq!(candidate, &elements[..]);
+
+        // Ensure the length cannot exceed the capacity.
+        assert!(Plaintext::<CurrentNetwork>::new_vector(U32::new(4), elements).is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ len: 2u32, elements: [1u8] }")?.to_vector().is_err());
+        // Ensure other plaintexts are not vectors.
+        assert!(Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8]")?.to_vector().is_err());
+        assert!(Plaintext::<CurrentNetwork>::from_str("{ len: 2u32, items: [1u8, 2u8] }")?.to_vector().is_err());
+        Ok(())
+    }
+}
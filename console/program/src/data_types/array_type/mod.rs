@@ -34,6 +34,8 @@ impl<N: Network> ArrayType<N> {
     /// Initializes a new multi-dimensional array type.
     /// Note that the dimensions must be specified from the outermost to the innermost.
     pub fn new(plaintext_type: PlaintextType<N>, mut dimensions: Vec<U32<N>>) -> Result<Self> {
+        // Check that the element type is not a vector.
+        ensure!(!matches!(plaintext_type, PlaintextType::Vector(..)), "An array cannot contain vectors");
         // Check that the number of dimensions are valid.
         ensure!(!dimensions.is_empty(), "An array must have at least one dimension");
         ensure!(dimensions.len() <= N::MAX_DATA_DEPTH, "An array can have at most {} dimensions", N::MAX_DATA_DEPTH);
@@ -173,5 +175,8 @@ mod tests {
 
         let type_ = ArrayType::<CurrentNetwork>::from_str("[foo; 1u8]");
         assert!(type_.is_err());
+
+        let type_ = ArrayType::<CurrentNetwork>::from_str("[[u8; <=2u32]; 1u32]");
+        assert!(type_.is_err());
     }
 }
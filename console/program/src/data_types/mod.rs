@@ -35,3 +35,6 @@ pub use struct_type::StructType;
 
 mod value_type;
 pub use value_type::ValueType;
+
+mod vector_type;
+pub use vector_type::VectorType;
@@ -21,6 +21,7 @@ impl<N: Network> Parser for PlaintextType<N> {
         // Parse to determine the plaintext type (order matters).
         alt((
             map(ArrayType::parse, |type_| Self::Array(type_)),
+            map(VectorType::parse, |type_| Self::Vector(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -61,6 +62,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Struct(struct_) => Display::fmt(struct_, f),
             // Prints the array type, i.e. [field; 2u32]
             Self::Array(array) => Display::fmt(array, f),
+            // Prints the vector type, i.e. [field; <=2u32]
+            Self::Vector(vector) => Display::fmt(vector, f),
         }
     }
 }
@@ -90,6 +93,10 @@ mod tests {
             PlaintextType::parse("[field; 1u32]"),
             Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 1u32]")?)))
         );
+        assert_eq!(
+            PlaintextType::parse("[field; <=1u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Vector(VectorType::from_str("[field; <=1u32]")?)))
+        );
         Ok(())
     }
 
@@ -180,6 +187,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 8u32]")?).to_string(),
             "[field; 8u32]"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Vector(VectorType::from_str("[field; <=8u32]")?).to_string(),
+            "[field; <=8u32]"
+        );
         Ok(())
     }
 }
@@ -67,6 +67,9 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Vector
+        "[u8; <=4u32]",
+        "[foo; <=32u32]",
     ];
 
     fn check_serde_json<
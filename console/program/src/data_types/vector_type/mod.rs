@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{PlaintextType, U32};
+use snarkvm_console_network::prelude::*;
+
+use core::fmt::{Debug, Display};
+
+/// A `VectorType` defines the element type and capacity of a bounded vector.
+/// A vector holds any number of elements, up to its capacity.
+///
+/// A vector value is the plaintext `{ len: u32, elements: [T; capacity] }`, where the slots past `len`
+/// hold the default value of the element type. As such, the size of a vector (and of any circuit over it)
+/// depends only on its type, and its length is part of its bits and fields.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorType<N: Network> {
+    /// The element type.
+    element_type: Box<PlaintextType<N>>,
+    /// The maximum number of elements in the vector.
+    capacity: U32<N>,
+}
+
+impl<N: Network> VectorType<N> {
+    /// Initializes a new vector type.
+    pub fn new(element_type: PlaintextType<N>, capacity: U32<N>) -> Result<Self> {
+        // Ensure the element type is not a vector.
+        ensure!(!matches!(element_type, PlaintextType::Vector(..)), "A vector cannot contain vectors");
+        // Ensure the capacity is valid.
+        ensure!(
+            *capacity as usize >= N::MIN_ARRAY_ELEMENTS,
+            "A vector must have a capacity of at least {}",
+            N::MIN_ARRAY_ELEMENTS
+        );
+        ensure!(*capacity as usize <= N::MAX_ARRAY_ELEMENTS, "A vector can contain {} elements", N::MAX_ARRAY_ELEMENTS);
+        // Construct the vector type.
+        Ok(Self { element_type: Box::new(element_type), capacity })
+    }
+}
+
+impl<N: Network> VectorType<N> {
+    /// Returns the element type.
+    pub const fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the capacity of the vector.
+    pub const fn capacity(&self) -> &U32<N> {
+        &self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayType, Identifier, LiteralType};
+    use snarkvm_console_network::Testnet3;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_vector_type() -> Result<()> {
+        // Test literal vector types.
+        let vector = VectorType::<CurrentNetwork>::from_str("[field; <=4u32]")?;
+        assert_eq!(vector, VectorType::<CurrentNetwork>::new(PlaintextType::from_str("field")?, U32::new(4))?);
+        assert_eq!(
+            vector.to_bytes_le()?,
+            VectorType::<CurrentNetwork>::from_bytes_le(&vector.to_bytes_le()?)?.to_bytes_le()?
+        );
+        assert_eq!(vector.element_type(), &PlaintextType::Literal(LiteralType::Field));
+        assert_eq!(vector.capacity(), &U32::new(4));
+        assert_eq!(vector.to_string(), "[field; <=4u32]");
+
+        // Test struct vector types.
+        let vector = VectorType::<CurrentNetwork>::from_str("[foo; <=32u32]")?;
+        assert_eq!(vector.element_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+        assert_eq!(vector.capacity(), &U32::new(32));
+
+        // Test vectors of arrays.
+        let vector = VectorType::<CurrentNetwork>::from_str("[[u8; 2u32]; <=3u32]")?;
+        assert_eq!(vector.element_type(), &PlaintextType::Array(ArrayType::from_str("[u8; 2u32]")?));
+        assert_eq!(vector.capacity(), &U32::new(3));
+        assert_eq!(
+            vector.to_bytes_le()?,
+            VectorType::<CurrentNetwork>::from_bytes_le(&vector.to_bytes_le()?)?.to_bytes_le()?
+        );
+        assert_eq!(vector.to_string(), "[[u8; 2u32]; <=3u32]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_type_fails() {
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=0u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=33u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; <=1u8]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[field; 1u32]").is_err());
+        assert!(VectorType::<CurrentNetwork>::from_str("[[field; <=1u32]; <=2u32]").is_err());
+    }
+}
@@ -17,6 +17,7 @@
 
 mod bitwise;
 mod bytes;
+mod operations;
 mod parse;
 mod random;
 mod serialize;
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Result<Self> {
+        // Ensure the concatenated string is within the allowed capacity.
+        let num_bytes = self.len().saturating_add(other.len());
+        ensure!(
+            num_bytes <= E::MAX_STRING_BYTES as usize,
+            "Cannot concatenate strings: the result exceeds {} bytes",
+            E::MAX_STRING_BYTES
+        );
+        // Return the concatenated string.
+        Ok(Self::new(&format!("{}{}", self.string, other.string)))
+    }
+
+    /// Returns the size of the string in bytes.
+    pub fn size_in_bytes(&self) -> Integer<E, u32> {
+        // Note: This cast is safe, as the string is bounded by `E::MAX_STRING_BYTES`.
+        Integer::new(self.len() as u32)
+    }
+
+    /// Returns the byte of the string at the given index.
+    pub fn byte_at(&self, index: &Integer<E, u32>) -> Result<Integer<E, u8>> {
+        match self.as_bytes().get(**index as usize) {
+            Some(byte) => Ok(Integer::new(*byte)),
+            None => bail!("Index '{index}' is out of bounds for a string of {} bytes", self.len()),
+        }
+    }
+
+    /// Returns the bytes of the string, padded with trailing zeros to the given length.
+    pub fn to_padded_bytes(&self, length: u32) -> Result<Vec<Integer<E, u8>>> {
+        // Ensure the string fits within the given length.
+        ensure!(self.len() <= length as usize, "Cannot fit a string of {} bytes into {length} bytes", self.len());
+        // Return the padded bytes.
+        let mut bytes = self.as_bytes().iter().map(|byte| Integer::new(*byte)).collect::<Vec<_>>();
+        bytes.resize(length as usize, Integer::new(0));
+        Ok(bytes)
+    }
+
+    /// Initializes a new string from UTF-8 bytes, ignoring any trailing zeros.
+    pub fn from_padded_bytes(bytes: &[Integer<E, u8>]) -> Result<Self> {
+        // Remove the trailing zeros.
+        let num_bytes = bytes.iter().rposition(|byte| **byte != 0).map_or(0, |index| index + 1);
+        // Ensure the string is within the allowed capacity.
+        ensure!(
+            num_bytes <= E::MAX_STRING_BYTES as usize,
+            "Cannot initialize a string of {num_bytes} bytes, as it exceeds {} bytes",
+            E::MAX_STRING_BYTES
+        );
+        // Recover the string.
+        let string = String::from_utf8(bytes[..num_bytes].iter().map(|byte| **byte).collect())?;
+        Ok(Self::new(&string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_concat() -> Result<()> {
+        let a = StringType::<CurrentEnvironment>::new("hello ");
+        let b = StringType::<CurrentEnvironment>::new("world");
+        assert_eq!(StringType::new("hello world"), a.concat(&b)?);
+        assert_eq!(a, a.concat(&StringType::new(""))?);
+
+        // Ensure the concatenation is bounded by the maximum number of bytes.
+        let half = "a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize / 2 + 1);
+        let half = StringType::<CurrentEnvironment>::new(&half);
+        assert!(half.concat(&half).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_byte_at() -> Result<()> {
+        let string = StringType::<CurrentEnvironment>::new("aleo");
+        assert_eq!(Integer::new(4), string.size_in_bytes());
+        assert_eq!(Integer::new(b'a'), string.byte_at(&Integer::new(0))?);
+        assert_eq!(Integer::new(b'o'), string.byte_at(&Integer::new(3))?);
+        assert!(string.byte_at(&Integer::new(4)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_padded_bytes() -> Result<()> {
+        let string = StringType::<CurrentEnvironment>::new("aleo");
+        let bytes = string.to_padded_bytes(8)?;
+        assert_eq!(8, bytes.len());
+        assert_eq!(string, StringType::from_padded_bytes(&bytes)?);
+        assert_eq!(string, StringType::from_padded_bytes(&string.to_padded_bytes(4)?)?);
+        assert!(string.to_padded_bytes(3).is_err());
+
+        // Ensure invalid UTF-8 is rejected.
+        assert!(StringType::<CurrentEnvironment>::from_padded_bytes(&[Integer::new(0xff)]).is_err());
+        Ok(())
+    }
+}
@@ -25,6 +25,7 @@ mod bytes;
 mod merkle;
 mod serialize;
 mod string;
+mod verify;
 
 use crate::Transition;
 use console::{
@@ -32,6 +33,7 @@ use console::{
     program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
     types::{Field, Group, U64},
 };
+use synthesizer_snark::Proof;
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum Transaction<N: Network> {
@@ -139,6 +141,18 @@ impl<N: Network> Transaction<N> {
             _ => None,
         }
     }
+
+    /// Returns the proofs in the transaction, with the execution proof (if any) before the fee proof (if any).
+    #[inline]
+    pub fn proofs(&self) -> Vec<&Proof<N>> {
+        match self {
+            Self::Deploy(_, _, _, fee) => fee.proof().into_iter().collect(),
+            Self::Execute(_, execution, fee) => {
+                execution.proof().into_iter().chain(fee.as_ref().and_then(|fee| fee.proof())).collect()
+            }
+            Self::Fee(_, fee) => fee.proof().into_iter().collect(),
+        }
+    }
 }
 
 /// A helper enum for iterators and consuming iterators over a transaction.
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_snark::{VerifierInputs, VerifyingKey};
+
+impl<N: Network> Transaction<N> {
+    /// Checks the proofs of the given transactions, where each transaction is paired with the verifier inputs
+    /// for its proofs, in the order of `Transaction::proofs`.
+    ///
+    /// The proofs are first checked in aggregate. If the aggregate check fails,
+    /// each proof is checked individually, to identify the transaction with the invalid proof.
+    /// Note: This does *not* check that the transactions are otherwise well-formed.
+    pub fn verify_proofs<R: Rng + CryptoRng>(
+        instances: &[(&Transaction<N>, Vec<VerifierInputs<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        // Construct the list of verifier inputs and proofs.
+        let mut aggregate_instances = Vec::with_capacity(instances.len());
+        for (transaction, verifier_inputs) in instances {
+            // Retrieve the proofs.
+            let proofs = transaction.proofs();
+            // Ensure there are verifier inputs for each proof.
+            ensure!(
+                proofs.len() == verifier_inputs.len(),
+                "Transaction '{}' has {} proofs, but {} sets of verifier inputs were given",
+                transaction.id(),
+                proofs.len(),
+                verifier_inputs.len()
+            );
+            aggregate_instances.extend(verifier_inputs.iter().cloned().zip(proofs));
+        }
+
+        // If there are no proofs, return early.
+        if aggregate_instances.is_empty() {
+            return Ok(());
+        }
+
+        // Check the proofs in aggregate.
+        if VerifyingKey::verify_aggregate("transactions", aggregate_instances, rng) {
+            return Ok(());
+        }
+
+        // Otherwise, check each proof individually, to identify the invalid proof.
+        for (transaction, verifier_inputs) in instances {
+            for (inputs, proof) in verifier_inputs.iter().zip(transaction.proofs()) {
+                if !VerifyingKey::verify_batch("transaction", inputs.clone(), proof) {
+                    bail!("Transaction '{}' contains an invalid proof", transaction.id())
+                }
+            }
+        }
+        bail!("Failed to verify the transaction proofs in aggregate, though each proof is valid individually")
+    }
+}
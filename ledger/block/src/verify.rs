@@ -18,6 +18,7 @@
 use super::*;
 use ledger_coinbase::{CoinbasePuzzle, EpochChallenge};
 use synthesizer_program::FinalizeOperation;
+use synthesizer_snark::VerifierInputs;
 
 use std::collections::HashSet;
 
@@ -26,7 +27,8 @@ use rayon::prelude::*;
 
 impl<N: Network> Block<N> {
     /// Ensures the block is correct.
-    pub fn verify(
+    /// The given verifier inputs are for the proofs of each transaction in the block, in order.
+    pub fn verify<R: Rng + CryptoRng>(
         &self,
         previous_block: &Block<N>,
         current_state_root: N::StateRoot,
@@ -35,6 +37,8 @@ impl<N: Network> Block<N> {
         current_epoch_challenge: &EpochChallenge<N>,
         current_timestamp: i64,
         ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+        transaction_verifier_inputs: Vec<Vec<VerifierInputs<N>>>,
+        rng: &mut R,
     ) -> Result<()> {
         // Ensure the block hash is correct.
         self.verify_hash(previous_block.height(), previous_block.hash())?;
@@ -59,7 +63,7 @@ impl<N: Network> Block<N> {
         self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
 
         // Ensure the block transactions are correct.
-        self.verify_transactions()?;
+        self.verify_transactions(transaction_verifier_inputs, rng)?;
 
         // Set the expected previous state root.
         let expected_previous_state_root = current_state_root;
@@ -378,7 +382,11 @@ impl<N: Network> Block<N> {
     }
 
     /// Ensures the block transactions are correct.
-    fn verify_transactions(&self) -> Result<()> {
+    fn verify_transactions<R: Rng + CryptoRng>(
+        &self,
+        transaction_verifier_inputs: Vec<Vec<VerifierInputs<N>>>,
+        rng: &mut R,
+    ) -> Result<()> {
         let height = self.height();
 
         // Ensure there are transactions.
@@ -450,7 +458,25 @@ impl<N: Network> Block<N> {
         if has_duplicates(self.transition_commitments()) {
             bail!("Found a duplicate transition commitment in block {height}");
         }
-        Ok(())
+
+        /* Proofs */
+
+        // Ensure there are verifier inputs for each transaction.
+        ensure!(
+            transaction_verifier_inputs.len() == self.transactions.len(),
+            "Block {height} has {} transactions, but {} sets of verifier inputs were given",
+            self.transactions.len(),
+            transaction_verifier_inputs.len()
+        );
+        // Ensure the transaction proofs are valid.
+        let instances: Vec<_> = self
+            .transactions
+            .iter()
+            .map(|transaction| transaction.transaction())
+            .zip(transaction_verifier_inputs)
+            .collect();
+        Transaction::verify_proofs(&instances, rng)
+            .map_err(|e| anyhow!("Found an invalid transaction proof in block {height} - {e}"))
     }
 }
 impl<N: Network> Block<N> {
@@ -124,6 +124,17 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
         }
     }
 
+    /// Returns the program for the given program ID and edition.
+    /// Note: A REST query only serves the latest edition of a program, so it cannot fetch a given edition.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Program<N>> {
+        match self {
+            Self::VM(block_store) => block_store
+                .get_program_for_edition(program_id, edition)?
+                .ok_or_else(|| anyhow!("Program {program_id} (edition {edition}) not found in storage")),
+            Self::REST(..) => bail!("Cannot fetch edition {edition} of program {program_id} from a REST query"),
+        }
+    }
+
     /// Returns the program for the given program ID.
     #[cfg(feature = "async")]
     pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
@@ -14,8 +14,6 @@
 
 use super::*;
 
-use rand::{rngs::StdRng, SeedableRng};
-
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
@@ -41,13 +39,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // Ensure each transaction is well-formed and unique.
-        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
-        let transactions = block.transactions().iter().collect::<Vec<_>>();
-        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Note: The execution and fee proofs are checked in aggregate, when verifying the block.
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|transaction| Ok((transaction.transaction(), transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        let transaction_verifier_inputs = self.vm.check_transactions_deferred(&transactions, rng)?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
@@ -76,6 +74,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -94,6 +93,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             &self.latest_epoch_challenge()?,
             OffsetDateTime::now_utc().unix_timestamp(),
             ratified_finalize_operations,
+            transaction_verifier_inputs,
+            rng,
         )?;
 
         Ok(())
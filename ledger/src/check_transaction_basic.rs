@@ -22,6 +22,6 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         rejected_id: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<()> {
-        self.vm().check_transaction(transaction, rejected_id, rng)
+        Ok(self.vm().check_transaction(transaction, rejected_id, rng)?)
     }
 }
@@ -37,6 +37,12 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_length_map: MemoryMap<(ProgramID<N>, Identifier<N>), u32>,
+    /// The index-key map.
+    index_key_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>,
+    /// The key-index map.
+    key_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,6 +52,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLengthMap = MemoryMap<(ProgramID<N>, Identifier<N>), u32>;
+    type IndexKeyMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    type KeyIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,6 +65,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            mapping_length_map: MemoryMap::default(),
+            index_key_map: NestedMemoryMap::default(),
+            key_index_map: NestedMemoryMap::default(),
             dev,
         })
     }
@@ -81,6 +93,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the index-key map.
+    fn index_key_map(&self) -> &Self::IndexKeyMap {
+        &self.index_key_map
+    }
+
+    /// Returns the key-index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap {
+        &self.key_index_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -194,6 +194,9 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    MappingLength = DataID::MappingLengthMap as u16,
+    IndexKey = DataID::IndexKeyMap as u16,
+    KeyIndex = DataID::KeyIndexMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -285,6 +288,9 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    MappingLengthMap,
+    IndexKeyMap,
+    KeyIndexMap,
 
     // Testing
     #[cfg(test)]
@@ -37,6 +37,12 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The mapping length map.
+    mapping_length_map: DataMap<(ProgramID<N>, Identifier<N>), u32>,
+    /// The index-key map.
+    index_key_map: NestedDataMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>,
+    /// The key-index map.
+    key_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,6 +52,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type MappingLengthMap = DataMap<(ProgramID<N>, Identifier<N>), u32>;
+    type IndexKeyMap = NestedDataMap<(ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    type KeyIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,6 +65,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_length_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::MappingLength))?,
+            index_key_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::IndexKey))?,
+            key_index_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyIndex))?,
             dev,
         })
     }
@@ -70,6 +82,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            mapping_length_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::MappingLength))?,
+            index_key_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::IndexKey))?,
+            key_index_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyIndex))?,
             dev,
         })
     }
@@ -89,6 +104,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap {
+        &self.mapping_length_map
+    }
+
+    /// Returns the index-key map.
+    fn index_key_map(&self) -> &Self::IndexKeyMap {
+        &self.index_key_map
+    }
+
+    /// Returns the key-index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap {
+        &self.key_index_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -28,7 +28,7 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -59,6 +59,75 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the speculative number of entries in the key index for the given `program ID` and `mapping name`.
+fn get_length_speculative<N: Network, S: FinalizeStorage<N>>(
+    storage: &S,
+    program_id: ProgramID<N>,
+    mapping_name: Identifier<N>,
+) -> Result<u32> {
+    Ok(storage
+        .mapping_length_map()
+        .get_speculative(&(program_id, mapping_name))?
+        .map_or(0, |length| cow_to_copied!(length)))
+}
+
+/// Appends the given `key` to the key index for the given `program ID` and `mapping name`.
+/// Note: This method must be called within an atomic batch, for a key that is not yet in the index.
+fn push_key<N: Network, S: FinalizeStorage<N>>(
+    storage: &S,
+    program_id: ProgramID<N>,
+    mapping_name: Identifier<N>,
+    key: Plaintext<N>,
+) -> Result<()> {
+    // Retrieve the current length, which is the index of the new key.
+    let index = get_length_speculative(storage, program_id, mapping_name)?;
+    // Compute the new length.
+    let length = match index.checked_add(1) {
+        Some(length) => length,
+        None => bail!("Illegal operation: '{program_id}/{mapping_name}' has reached its maximum number of entries"),
+    };
+    // Insert the key at the end of the key index.
+    storage.index_key_map().insert((program_id, mapping_name), index, key.clone())?;
+    storage.key_index_map().insert((program_id, mapping_name), key, index)?;
+    // Update the mapping length.
+    storage.mapping_length_map().insert((program_id, mapping_name), length)
+}
+
+/// Removes the given `key` from the key index for the given `program ID` and `mapping name`,
+/// by moving the last key in the index into its position.
+/// Note: This method must be called within an atomic batch, for a key that is in the index.
+fn swap_remove_key<N: Network, S: FinalizeStorage<N>>(
+    storage: &S,
+    program_id: ProgramID<N>,
+    mapping_name: Identifier<N>,
+    key: &Plaintext<N>,
+) -> Result<()> {
+    // Retrieve the index of the key.
+    let index = match storage.key_index_map().get_value_speculative(&(program_id, mapping_name), key)? {
+        Some(index) => cow_to_copied!(index),
+        None => bail!("Missing index for key '{key}' in '{program_id}/{mapping_name}'"),
+    };
+    // Compute the index of the last key, which is also the new length.
+    let last = match get_length_speculative(storage, program_id, mapping_name)?.checked_sub(1) {
+        Some(last) => last,
+        None => bail!("Missing key index for '{program_id}/{mapping_name}'"),
+    };
+    // If the key is not the last key, move the last key into its position.
+    if index != last {
+        let last_key = match storage.index_key_map().get_value_speculative(&(program_id, mapping_name), &last)? {
+            Some(last_key) => cow_to_cloned!(last_key),
+            None => bail!("Missing key at index {last} in '{program_id}/{mapping_name}'"),
+        };
+        storage.index_key_map().insert((program_id, mapping_name), index, last_key.clone())?;
+        storage.key_index_map().insert((program_id, mapping_name), last_key, index)?;
+    }
+    // Remove the last position and the key from the key index.
+    storage.index_key_map().remove_key(&(program_id, mapping_name), &last)?;
+    storage.key_index_map().remove_key(&(program_id, mapping_name), key)?;
+    // Update the mapping length.
+    storage.mapping_length_map().insert((program_id, mapping_name), last)
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -76,6 +145,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(program ID, mapping name)` to the number of entries in the mapping.
+    type MappingLengthMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u32>;
+    /// The mapping of `(program ID, mapping name)` to `[(index, key)]`.
+    type IndexKeyMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), u32, Plaintext<N>>;
+    /// The mapping of `(program ID, mapping name)` to `[(key, index)]`.
+    type KeyIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -90,6 +165,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the mapping length map.
+    fn mapping_length_map(&self) -> &Self::MappingLengthMap;
+    /// Returns the index-key map.
+    fn index_key_map(&self) -> &Self::IndexKeyMap;
+    /// Returns the key-index map.
+    fn key_index_map(&self) -> &Self::KeyIndexMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -99,6 +180,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.mapping_length_map().start_atomic();
+        self.index_key_map().start_atomic();
+        self.key_index_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -106,6 +190,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.mapping_length_map().is_atomic_in_progress()
+            || self.index_key_map().is_atomic_in_progress()
+            || self.key_index_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -113,6 +200,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.mapping_length_map().atomic_checkpoint();
+        self.index_key_map().atomic_checkpoint();
+        self.key_index_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -120,6 +210,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.mapping_length_map().clear_latest_checkpoint();
+        self.index_key_map().clear_latest_checkpoint();
+        self.key_index_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -127,6 +220,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.mapping_length_map().atomic_rewind();
+        self.index_key_map().atomic_rewind();
+        self.key_index_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -134,13 +230,63 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.mapping_length_map().abort_atomic();
+        self.index_key_map().abort_atomic();
+        self.key_index_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.mapping_length_map().finish_atomic()?;
+        self.index_key_map().finish_atomic()?;
+        self.key_index_map().finish_atomic()
+    }
+
+    /// Rebuilds the key index for the mappings that were written before the key index was introduced.
+    /// The keys of such a mapping are indexed in the order in which the key-value map stores them.
+    fn backfill_key_index(&self) -> Result<()> {
+        // Retrieve the mappings without a mapping length.
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in self.program_id_map().iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                let mapping = (cow_to_copied!(program_id), *mapping_name);
+                if !self.mapping_length_map().contains_key_confirmed(&mapping)? {
+                    mappings.push(mapping);
+                }
+            }
+        }
+
+        // Retrieve the keys of each mapping, skipping the empty mappings, as they have nothing to index.
+        let mut legacy_mappings = Vec::new();
+        for mapping in mappings {
+            let keys: Vec<_> =
+                self.key_value_map().get_map_confirmed(&mapping)?.into_iter().map(|(key, _)| key).collect();
+            if !keys.is_empty() {
+                legacy_mappings.push((mapping, keys));
+            }
+        }
+        if legacy_mappings.is_empty() {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            for (mapping, keys) in legacy_mappings {
+                // Compute the length of the mapping.
+                let length = u32::try_from(keys.len())?;
+                // Insert the keys into the key index.
+                for (index, key) in (0u32..).zip(keys) {
+                    self.index_key_map().insert(mapping, index, key.clone())?;
+                    self.key_index_map().insert(mapping, key, index)?;
+                }
+                // Update the mapping length.
+                self.mapping_length_map().insert(mapping, length)?;
+            }
+
+            Ok(())
+        })
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -205,7 +351,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
-            self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            self.key_value_map().insert((program_id, mapping_name), key.clone(), value)?;
+            // Append the new key to the key index.
+            push_key(self, program_id, mapping_name, key)?;
 
             Ok(())
         })?;
@@ -230,6 +378,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot update key-value.")
         }
 
+        // Determine if the key is new to the mapping.
+        let is_new_key = !self.contains_key_speculative(program_id, mapping_name, &key)?;
+
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
@@ -237,7 +388,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
-            self.key_value_map().insert((program_id, mapping_name), key, value)?;
+            self.key_value_map().insert((program_id, mapping_name), key.clone(), value)?;
+            // If the key is new, append it to the key index.
+            if is_new_key {
+                push_key(self, program_id, mapping_name, key)?;
+            }
 
             Ok(())
         })?;
@@ -266,6 +421,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key.
             self.key_value_map().remove_key(&(program_id, mapping_name), key)?;
+            // Remove the key from the key index.
+            swap_remove_key(self, program_id, mapping_name, key)?;
 
             Ok(())
         })?;
@@ -287,15 +444,28 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot replace mapping.")
         }
 
+        // Deduplicate the entries, keeping the position of the first occurrence of each key.
+        let entries = entries.into_iter().collect::<IndexMap<_, _>>();
+        // Compute the new length of the mapping.
+        let length = u32::try_from(entries.len())?;
+
         atomic_batch_scope!(self, {
             // Remove the existing key-value entries.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the existing key index.
+            self.index_key_map().remove_map(&(program_id, mapping_name))?;
+            self.key_index_map().remove_map(&(program_id, mapping_name))?;
 
             // Insert the new key-value entries.
-            for (key, value) in entries {
+            for (index, (key, value)) in (0u32..).zip(entries) {
                 // Insert the key-value entry.
-                self.key_value_map().insert((program_id, mapping_name), key, value)?;
+                self.key_value_map().insert((program_id, mapping_name), key.clone(), value)?;
+                // Insert the key into the key index.
+                self.index_key_map().insert((program_id, mapping_name), index, key.clone())?;
+                self.key_index_map().insert((program_id, mapping_name), key, index)?;
             }
+            // Update the mapping length.
+            self.mapping_length_map().insert((program_id, mapping_name), length)?;
 
             Ok(())
         })?;
@@ -322,6 +492,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.program_id_map().insert(program_id, mapping_names)?;
             // Remove the mapping.
             self.key_value_map().remove_map(&(program_id, mapping_name))?;
+            // Remove the key index and mapping length.
+            self.index_key_map().remove_map(&(program_id, mapping_name))?;
+            self.key_index_map().remove_map(&(program_id, mapping_name))?;
+            self.mapping_length_map().remove(&(program_id, mapping_name))?;
 
             Ok(())
         })?;
@@ -346,6 +520,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             for mapping_name in mapping_names.iter() {
                 // Remove the mapping.
                 self.key_value_map().remove_map(&(*program_id, *mapping_name))?;
+                // Remove the key index and mapping length.
+                self.index_key_map().remove_map(&(*program_id, *mapping_name))?;
+                self.key_index_map().remove_map(&(*program_id, *mapping_name))?;
+                self.mapping_length_map().remove(&(*program_id, *mapping_name))?;
             }
             Ok(())
         })
@@ -424,6 +602,51 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping length.")
+        }
+        // Retrieve the mapping length.
+        Ok(get_length_speculative(self, program_id, mapping_name)? as usize)
+    }
+
+    /// Returns up to `limit` speculative entries for the given `program ID` and `mapping name`, skipping the first `start` entries.
+    /// The entries are in insertion order, except that removing an entry moves the last entry into its position.
+    fn get_mapping_entries_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_speculative(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping entries.")
+        }
+        // Retrieve the mapping length.
+        let length = get_length_speculative(self, program_id, mapping_name)? as usize;
+        // Retrieve the requested range of entries.
+        (start..start.saturating_add(limit).min(length))
+            .map(|index| {
+                // Retrieve the key at the given index.
+                let key = match self
+                    .index_key_map()
+                    .get_value_speculative(&(program_id, mapping_name), &u32::try_from(index)?)?
+                {
+                    Some(key) => cow_to_cloned!(key),
+                    None => bail!("Missing key at index {index} in '{program_id}/{mapping_name}'"),
+                };
+                // Retrieve the value for the key.
+                match self.get_value_speculative(program_id, mapping_name, &key)? {
+                    Some(value) => Ok((key, value)),
+                    None => bail!("Missing value for key '{key}' in '{program_id}/{mapping_name}'"),
+                }
+            })
+            .collect()
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -542,6 +765,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Rebuild the key index for the mappings that were written before the key index was introduced.
+        storage.backfill_key_index()?;
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -620,6 +845,23 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the speculative number of entries for the given `program ID` and `mapping name`.
+    fn get_mapping_length_speculative(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<usize> {
+        self.storage.get_mapping_length_speculative(program_id, mapping_name)
+    }
+
+    /// Returns up to `limit` speculative entries for the given `program ID` and `mapping name`, skipping the first `start` entries.
+    /// The entries are in insertion order, except that removing an entry moves the last entry into its position.
+    fn get_mapping_entries_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: usize,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_entries_speculative(program_id, mapping_name, start, limit)
+    }
+
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
     /// If the `mapping name` is not initialized, an error is returned.
     /// If the `key` already exists, the method returns an error.
@@ -1204,6 +1446,131 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_mapping_entries_speculative() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        // Ensure reading from an un-initialized mapping fails.
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        assert!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 1).is_err());
+
+        // Initialize the mapping.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+        assert!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 1).unwrap().is_empty());
+
+        // Insert the entries.
+        let mut entries = (0..100)
+            .map(|item| {
+                (Plaintext::from_str(&format!("{item}field")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+            })
+            .collect::<Vec<_>>();
+        for (key, value) in entries.iter() {
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 100);
+
+        // Ensure the entries are returned in insertion order.
+        let all = finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 100).unwrap();
+        assert_eq!(all, entries);
+
+        // Ensure the entries can be read in pages.
+        let mut pages = Vec::new();
+        for start in (0..100).step_by(32) {
+            pages.extend(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, start, 32).unwrap());
+        }
+        assert_eq!(all, pages);
+        // Ensure reading past the end returns no entries.
+        assert!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 100, 32).unwrap().is_empty());
+
+        // Update an existing entry, and ensure it keeps its position.
+        entries[10].1 = Value::from_str("1000u64").unwrap();
+        finalize_store
+            .update_key_value(program_id, mapping_name, entries[10].0.clone(), entries[10].1.clone())
+            .unwrap();
+        // Update a new entry, and ensure it is appended.
+        entries.push((Plaintext::from_str("100field").unwrap(), Value::from_str("100u64").unwrap()));
+        finalize_store
+            .update_key_value(program_id, mapping_name, entries[100].0.clone(), entries[100].1.clone())
+            .unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 101);
+        assert_eq!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 101).unwrap(), entries);
+
+        // Remove an entry, and ensure the last entry moves into its position.
+        finalize_store.remove_key_value(program_id, mapping_name, &entries[5].0).unwrap().unwrap();
+        entries.swap_remove(5);
+        // Remove the last entry.
+        finalize_store.remove_key_value(program_id, mapping_name, &entries[99].0).unwrap().unwrap();
+        entries.swap_remove(99);
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 99);
+        assert_eq!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 101).unwrap(), entries);
+
+        // Replace the mapping, and ensure the entries follow the new order.
+        let replacement = entries.iter().rev().take(10).cloned().collect::<Vec<_>>();
+        finalize_store.replace_mapping(program_id, mapping_name, replacement.clone()).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+        assert_eq!(
+            finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 20).unwrap(),
+            replacement
+        );
+
+        // Remove and re-initialize the mapping, and ensure it is empty.
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        assert!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).is_err());
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 0);
+        assert!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 20).unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_backfill_key_index() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize storage.
+        let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        let program_rocksdb = crate::helpers::rocksdb::FinalizeDB::open_testing(temp_dir, None).unwrap();
+
+        // Write a mapping without its key index, as it was stored before the key index was introduced.
+        let entries = (0..10)
+            .map(|item| {
+                (Plaintext::from_str(&format!("{item}field")).unwrap(), Value::from_str(&format!("{item}u64")).unwrap())
+            })
+            .collect::<Vec<_>>();
+        program_rocksdb.program_id_map().insert(program_id, IndexSet::from_iter([mapping_name])).unwrap();
+        for (key, value) in entries.iter() {
+            program_rocksdb.key_value_map().insert((program_id, mapping_name), key.clone(), value.clone()).unwrap();
+        }
+        assert!(!program_rocksdb.mapping_length_map().contains_key_confirmed(&(program_id, mapping_name)).unwrap());
+
+        // Open the finalize store, and ensure the key index is rebuilt.
+        let finalize_store = FinalizeStore::from(program_rocksdb.clone()).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+        let mut candidate = finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 10).unwrap();
+        assert_eq!(candidate.len(), 10);
+        assert!(entries.iter().all(|entry| candidate.contains(entry)));
+
+        // Ensure an existing key can be removed, and a new key can be inserted.
+        finalize_store.remove_key_value(program_id, mapping_name, &entries[3].0).unwrap().unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 9);
+        let (key, value) = (Plaintext::from_str("10field").unwrap(), Value::from_str("10u64").unwrap());
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+        assert_eq!(finalize_store.get_mapping_length_speculative(program_id, mapping_name).unwrap(), 10);
+
+        // Reopen the finalize store, and ensure the key index is kept as is.
+        candidate = finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 10).unwrap();
+        let finalize_store = FinalizeStore::from(program_rocksdb).unwrap();
+        assert_eq!(finalize_store.get_mapping_entries_speculative(program_id, mapping_name, 0, 10).unwrap(), candidate);
+        assert!(candidate.contains(&(key, value)));
+        assert!(!candidate.contains(&entries[3]));
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
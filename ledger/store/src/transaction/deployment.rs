@@ -181,6 +181,19 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // Ensure the edition follows the latest edition of the program, if it exists.
+        match self.get_edition(&program_id)? {
+            Some(latest) => ensure!(
+                latest.checked_add(1) == Some(edition),
+                "Failed to insert edition {edition} of program '{program_id}' (latest edition is {latest})"
+            ),
+            None => ensure!(
+                edition == N::EDITION,
+                "Failed to insert edition {edition} of program '{program_id}' (expected edition {})",
+                N::EDITION
+            ),
+        }
+
         atomic_batch_scope!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
@@ -210,17 +223,23 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Removes the deployment transaction for the given `transaction ID`.
+    /// Note: Only the latest edition of a program may be removed.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.get_program_id_and_edition(transaction_id)? {
+            Some((program_id, edition)) => (program_id, edition),
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
+        // Retrieve the latest edition.
+        let latest = match self.get_edition(&program_id)? {
+            Some(latest) => latest,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest edition.
+        ensure!(
+            edition == latest,
+            "Failed to remove edition {edition} of program '{program_id}' (latest edition is {latest})"
+        );
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -230,8 +249,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Revert the edition to the previous edition, or remove it if this was the first edition.
+            match edition > N::EDITION {
+                true => self.edition_map().insert(program_id, edition - 1)?,
+                false => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -293,7 +315,30 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
-    /// Returns the edition for the given `program ID`.
+    /// Returns the program ID and edition for the given `transaction ID`.
+    fn get_program_id_and_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<(ProgramID<N>, u16)>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest = match self.get_edition(&program_id)? {
+            Some(latest) => latest,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Find the edition that was deployed in the given transaction, starting from the latest edition.
+        for edition in (N::EDITION..=latest).rev() {
+            if let Some(candidate) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some((program_id, edition)));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
+    /// Returns the latest edition for the given `program ID`.
     fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
@@ -329,6 +374,24 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        // Check if the program ID is for 'credits.aleo'.
+        // This case is handled separately, as it is a default program of the VM.
+        if program_id == &ProgramID::from_str("credits.aleo")? {
+            return match edition == N::EDITION {
+                true => Ok(Some(Program::credits()?)),
+                false => Ok(None),
+            };
+        }
+
+        // Retrieve the program.
+        match self.program_map().get_confirmed(&(*program_id, edition))? {
+            Some(program) => Ok(Some(cow_to_cloned!(program))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the verifying key for the given `program ID` and `function name`.
     fn get_verifying_key(
         &self,
@@ -383,16 +446,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.get_program_id_and_edition(transaction_id)? {
+            Some((program_id, edition)) => (program_id, edition),
             None => return Ok(None),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -427,8 +485,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().get_fee(transaction_id)
     }
 
-    /// Returns the owner for the given `program ID`.
-    fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+    /// Returns the owner for the given `program ID` and `edition`.
+    fn get_owner(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<ProgramOwner<N>>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
@@ -436,12 +494,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
-        let edition = match self.get_edition(program_id)? {
-            Some(edition) => edition,
-            None => return Ok(None),
-        };
+        // Ensure the edition exists.
+        match self.get_edition(program_id)? {
+            Some(latest) if (N::EDITION..=latest).contains(&edition) => (),
+            _ => return Ok(None),
+        }
 
         // Retrieve the owner.
         match self.owner_map().get_confirmed(&(*program_id, edition))? {
@@ -464,7 +521,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         };
 
         // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
+        let owner = match self.get_owner(deployment.program_id(), deployment.edition())? {
             Some(owner) => owner,
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
@@ -564,11 +621,24 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_deployment(transaction_id)
     }
 
-    /// Returns the edition for the given `program ID`.
+    /// Returns the latest edition for the given `program ID`.
     pub fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the program ID and edition for the given `transaction ID`.
+    pub fn get_program_id_and_edition(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<(ProgramID<N>, u16)>> {
+        self.storage.get_program_id_and_edition(transaction_id)
+    }
+
+    /// Returns the owner for the given `program ID` and `edition`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id, edition)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -579,6 +649,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        self.storage.get_program_for_edition(program_id, edition)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -666,6 +741,9 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 mod tests {
     use super::*;
     use crate::{helpers::memory::DeploymentMemory, TransitionStore};
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::Testnet3;
 
     #[test]
     fn test_insert_get_remove() {
@@ -752,4 +830,91 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    /// Returns a deployment transaction for the given edition of the program in the given deployment transaction.
+    fn sample_upgrade_transaction(
+        transaction: &Transaction<CurrentNetwork>,
+        edition: u16,
+        rng: &mut TestRng,
+    ) -> Transaction<CurrentNetwork> {
+        // Retrieve the deployment.
+        let deployment = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment,
+            _ => panic!("Incorrect transaction type"),
+        };
+        // Construct the upgraded deployment.
+        let deployment =
+            Deployment::new(edition, deployment.program().clone(), deployment.verifying_keys().clone()).unwrap();
+        // Compute the deployment ID.
+        let deployment_id = deployment.to_deployment_id().unwrap();
+        // Construct a program owner.
+        let owner = ProgramOwner::new(&PrivateKey::new(rng).unwrap(), deployment_id, rng).unwrap();
+        // Sample the fee.
+        let fee = ledger_test_helpers::sample_fee_public(deployment_id, rng);
+        // Construct the deployment transaction.
+        Transaction::from_deployment(owner, deployment, fee).unwrap()
+    }
+
+    #[test]
+    fn test_insert_remove_editions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the first and second editions of a program.
+        let transaction_1 = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let transaction_2 = sample_upgrade_transaction(&transaction_1, CurrentNetwork::EDITION + 1, rng);
+        let transaction_3 = sample_upgrade_transaction(&transaction_1, CurrentNetwork::EDITION + 2, rng);
+        let (transaction_id_1, transaction_id_2) = (transaction_1.id(), transaction_2.id());
+        let program_id = match transaction_1 {
+            Transaction::Deploy(_, _, ref deployment, _) => *deployment.program_id(),
+            _ => panic!("Incorrect transaction type"),
+        };
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new fee store.
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentMemory::open(fee_store).unwrap();
+
+        // Ensure an upgrade cannot be inserted before the first edition.
+        assert!(deployment_store.insert(&transaction_2).is_err());
+
+        // Insert the first and second editions.
+        deployment_store.insert(&transaction_1).unwrap();
+        deployment_store.insert(&transaction_2).unwrap();
+        assert_eq!(Some(CurrentNetwork::EDITION + 1), deployment_store.get_edition(&program_id).unwrap());
+
+        // Ensure an edition cannot be inserted twice.
+        assert!(deployment_store.insert(&transaction_2).is_err());
+
+        // Ensure each transaction resolves to its own edition.
+        let candidate = deployment_store.get_program_id_and_edition(&transaction_id_1).unwrap();
+        assert_eq!(Some((program_id, CurrentNetwork::EDITION)), candidate);
+        let candidate = deployment_store.get_program_id_and_edition(&transaction_id_2).unwrap();
+        assert_eq!(Some((program_id, CurrentNetwork::EDITION + 1)), candidate);
+        assert_eq!(Some(transaction_1.clone()), deployment_store.get_transaction(&transaction_id_1).unwrap());
+        assert_eq!(Some(transaction_2.clone()), deployment_store.get_transaction(&transaction_id_2).unwrap());
+
+        // Ensure the latest edition is found for the program ID.
+        let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
+        assert_eq!(Some(transaction_id_2), candidate);
+
+        // Ensure the first edition cannot be removed while it is not the latest edition.
+        assert!(deployment_store.remove(&transaction_id_1).is_err());
+
+        // Remove the second edition, and ensure the first edition is the latest edition.
+        deployment_store.remove(&transaction_id_2).unwrap();
+        assert_eq!(None, deployment_store.get_transaction(&transaction_id_2).unwrap());
+        assert_eq!(Some(CurrentNetwork::EDITION), deployment_store.get_edition(&program_id).unwrap());
+        let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
+        assert_eq!(Some(transaction_id_1), candidate);
+
+        // Ensure an edition cannot be skipped.
+        assert!(deployment_store.insert(&transaction_3).is_err());
+
+        // Remove the first edition, and ensure the program no longer exists.
+        deployment_store.remove(&transaction_id_1).unwrap();
+        assert_eq!(None, deployment_store.get_transaction(&transaction_id_1).unwrap());
+        assert_eq!(None, deployment_store.get_edition(&program_id).unwrap());
+    }
 }
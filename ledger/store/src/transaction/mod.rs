@@ -364,11 +364,9 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
+                // Retrieve the program ID and edition.
+                match self.storage.deployment_store().get_program_id_and_edition(transaction_id)? {
+                    Some((_, edition)) => Ok(Some(edition)),
                     None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
                 }
             }
@@ -389,6 +387,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        self.storage.deployment_store().get_program_for_edition(program_id, edition)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
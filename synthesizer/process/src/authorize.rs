@@ -118,6 +118,9 @@ fn ensure_record_microcredits_is_sufficient<N: Network>(
         _ => bail!("The fee record does not contain a 'microcredits' entry"),
     };
     // Ensure the balance is sufficient to pay the fee.
-    ensure!(balance >= fee_in_microcredits, "Credits record balance is insufficient to pay the fee");
+    if balance < fee_in_microcredits {
+        let message = "Credits record balance is insufficient to pay the fee";
+        return Err(Failure::new(FailureKind::InsufficientBalance, message).into());
+    }
     Ok(())
 }
@@ -37,6 +37,33 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys an upgrade of the given program ID, as the given edition.
+    /// The program must already exist, and the upgraded program must be compatible with the existing program.
+    #[inline]
+    pub fn upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::upgrade");
+
+        // Ensure the edition is an upgrade.
+        ensure!(edition > N::EDITION, "Cannot upgrade '{}' to edition {edition}", program.id());
+
+        // Compute the stack.
+        let stack = Stack::upgrade(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Return the deployment.
+        let deployment = stack.deploy_edition::<A, R>(edition, rng);
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        deployment
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -44,7 +71,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: The latest edition of an upgraded program is loaded as a new program, if it does not exist yet.
+        let stack = match self.contains_program(deployment.program_id()) {
+            true => Stack::upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -60,4 +91,158 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Computes the stack for the given deployment.
+    /// If the deployment is an upgrade, the program must already exist in the process,
+    /// and the upgraded program must be compatible with the existing program.
+    #[inline]
+    pub(crate) fn compute_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        match deployment.is_upgrade() {
+            true => Stack::upgrade(self, deployment.program()),
+            false => Stack::new(self, deployment.program()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the upgradable program, with the given additional definitions.
+    fn sample_program(definitions: &str) -> Program<CurrentNetwork> {
+        Program::from_str(&format!(
+            r"
+program upgradable.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+struct point:
+    x as field;
+    y as field;
+
+{definitions}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_upgrade_compatibility() {
+        // Initialize the process with the original program.
+        let program = sample_program(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        );
+        let process = crate::test_helpers::sample_process(&program);
+
+        // Ensure an upgrade that adds a function is compatible.
+        let upgrade = sample_program(
+            r"
+function foo:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;
+
+function bar:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        );
+        assert!(Stack::upgrade(&process, &upgrade).is_ok());
+
+        // Ensure an upgrade that removes a function is incompatible.
+        let upgrade = sample_program(
+            r"
+function bar:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        );
+        assert!(Stack::upgrade(&process, &upgrade).is_err());
+
+        // Ensure an upgrade that changes the output types of a function is incompatible.
+        let upgrade = sample_program(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.public;",
+        );
+        assert!(Stack::upgrade(&process, &upgrade).is_err());
+
+        // Ensure an upgrade that changes a mapping is incompatible.
+        let upgrade = Program::<CurrentNetwork>::from_str(
+            r"
+program upgradable.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u128.public;
+
+struct point:
+    x as field;
+    y as field;
+
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        assert!(Stack::upgrade(&process, &upgrade).is_err());
+
+        // Ensure a program that does not exist cannot be upgraded.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        assert!(Stack::upgrade(&process, &program).is_err());
+    }
+
+    #[test]
+    fn test_upgrade_relinks_dependent_stacks() {
+        // Initialize the process with the original program.
+        let program = sample_program(
+            r"
+function foo:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+        );
+        let mut process = crate::test_helpers::sample_process(&program);
+
+        // Add a program that imports the original program.
+        let dependent = Program::<CurrentNetwork>::from_str(
+            r"
+import upgradable.aleo;
+
+program dependent.aleo;
+
+function baz:
+    input r0 as field.private;
+    call upgradable.aleo/foo r0 into r1;
+    output r1 as field.private;",
+        )
+        .unwrap();
+        process.add_program(&dependent).unwrap();
+
+        // Upgrade the original program.
+        let upgrade = sample_program(
+            r"
+function foo:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    output r1 as field.private;",
+        );
+        let stack = Stack::upgrade(&process, &upgrade).unwrap();
+        process.add_stack(stack);
+
+        // Ensure the process contains the upgraded program.
+        assert_eq!(process.get_program(upgrade.id()).unwrap(), &upgrade);
+        // Ensure the dependent stack is relinked to the upgraded program.
+        let dependent_stack = process.get_stack(dependent.id()).unwrap();
+        assert_eq!(dependent_stack.get_external_program(upgrade.id()).unwrap(), &upgrade);
+    }
 }
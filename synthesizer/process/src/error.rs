@@ -0,0 +1,262 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use synthesizer_program::{Failure, FailureKind, Opcode};
+
+/// The location of a failed instruction, or of a failed command in a finalize scope.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ErrorLocation<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The name of the function, closure, or finalize scope.
+    function_name: Identifier<N>,
+    /// Whether the failure is in a finalize scope.
+    is_finalize: bool,
+    /// The index of the instruction (or command).
+    index: usize,
+    /// The opcode of the instruction (or command).
+    opcode: Opcode,
+}
+
+impl<N: Network> ErrorLocation<N> {
+    /// Initializes a new error location.
+    pub const fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        is_finalize: bool,
+        index: usize,
+        opcode: Opcode,
+    ) -> Self {
+        Self { program_id, function_name, is_finalize, index, opcode }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the function, closure, or finalize scope.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns `true` if the failure is in a finalize scope.
+    pub const fn is_finalize(&self) -> bool {
+        self.is_finalize
+    }
+
+    /// Returns the index of the instruction (or command).
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the opcode of the instruction (or command).
+    pub const fn opcode(&self) -> Opcode {
+        self.opcode
+    }
+}
+
+/// An error from executing, verifying, or finalizing a program.
+/// This error converts into (and is recoverable from) an `anyhow::Error`, and keeps the error it was raised from.
+#[derive(Debug)]
+pub enum ExecutionError<N: Network> {
+    /// An `assert.eq` or `assert.neq` instruction failed.
+    AssertionFailed(Option<ErrorLocation<N>>, Error),
+    /// A balance was insufficient to pay a fee.
+    InsufficientBalance(Option<ErrorLocation<N>>, Error),
+    /// A `get` command found no value for the key.
+    MissingMappingKey(Option<ErrorLocation<N>>, Error),
+    /// Any other instruction (or command) failed.
+    InstructionFailed(ErrorLocation<N>, Error),
+    /// A proof failed to verify.
+    InvalidProof(Error),
+    /// Any other failure.
+    Other(Error),
+}
+
+impl<N: Network> ExecutionError<N> {
+    /// Returns an error for the failed instruction (or command) at the given location, with the given message.
+    /// The error is classified by the failure raised in its cause, if any. If the cause is already an execution
+    /// error (i.e. from a nested call), its classification and location are kept.
+    pub(crate) fn instruction(location: ErrorLocation<N>, message: String, cause: Option<Error>) -> Error {
+        let error = match cause {
+            Some(cause) => cause.context(message),
+            None => anyhow!(message),
+        };
+        Self::classify(error, Some(location)).into()
+    }
+
+    /// Returns an error for a proof that failed to verify.
+    pub(crate) fn invalid_proof(error: Error) -> Self {
+        Self::InvalidProof(error)
+    }
+
+    /// Classifies the given error by the execution error or failure in its chain.
+    /// If neither is found, the error is an instruction failure at the given location, if any.
+    fn classify(error: Error, location: Option<ErrorLocation<N>>) -> Self {
+        // If the chain contains an execution error, keep its classification and location.
+        if let Some(inner) = error.chain().find_map(|cause| cause.downcast_ref::<Self>()) {
+            return match inner {
+                Self::AssertionFailed(location, _) => Self::AssertionFailed(*location, error),
+                Self::InsufficientBalance(location, _) => Self::InsufficientBalance(*location, error),
+                Self::MissingMappingKey(location, _) => Self::MissingMappingKey(*location, error),
+                Self::InstructionFailed(location, _) => Self::InstructionFailed(*location, error),
+                Self::InvalidProof(_) => Self::InvalidProof(error),
+                Self::Other(_) => match location {
+                    Some(location) => Self::InstructionFailed(location, error),
+                    None => Self::Other(error),
+                },
+            };
+        }
+        // Otherwise, classify the error by the failure in its chain, if any.
+        match (error.chain().find_map(|cause| cause.downcast_ref::<Failure>()).map(Failure::kind), location) {
+            (Some(FailureKind::AssertionFailed), location) => Self::AssertionFailed(location, error),
+            (Some(FailureKind::InsufficientBalance), location) => Self::InsufficientBalance(location, error),
+            (Some(FailureKind::MissingMappingKey), location) => Self::MissingMappingKey(location, error),
+            (None, Some(location)) => Self::InstructionFailed(location, error),
+            (None, None) => Self::Other(error),
+        }
+    }
+
+    /// Returns the location of the failed instruction (or command), if any.
+    pub const fn location(&self) -> Option<&ErrorLocation<N>> {
+        match self {
+            Self::AssertionFailed(location, _)
+            | Self::InsufficientBalance(location, _)
+            | Self::MissingMappingKey(location, _) => location.as_ref(),
+            Self::InstructionFailed(location, _) => Some(location),
+            Self::InvalidProof(_) | Self::Other(_) => None,
+        }
+    }
+
+    /// Returns the underlying error.
+    pub const fn error(&self) -> &Error {
+        match self {
+            Self::AssertionFailed(_, error)
+            | Self::InsufficientBalance(_, error)
+            | Self::MissingMappingKey(_, error)
+            | Self::InstructionFailed(_, error)
+            | Self::InvalidProof(error)
+            | Self::Other(error) => error,
+        }
+    }
+
+    /// Returns the error wrapped with the given context, keeping its classification and location.
+    pub fn context<C: Display + Send + Sync + 'static>(self, context: C) -> Self {
+        match self {
+            Self::AssertionFailed(location, error) => Self::AssertionFailed(location, error.context(context)),
+            Self::InsufficientBalance(location, error) => Self::InsufficientBalance(location, error.context(context)),
+            Self::MissingMappingKey(location, error) => Self::MissingMappingKey(location, error.context(context)),
+            Self::InstructionFailed(location, error) => Self::InstructionFailed(location, error.context(context)),
+            Self::InvalidProof(error) => Self::InvalidProof(error.context(context)),
+            Self::Other(error) => Self::Other(error.context(context)),
+        }
+    }
+}
+
+impl<N: Network> From<Error> for ExecutionError<N> {
+    /// Recovers the execution error from the given error, or classifies the error by the failure in its chain.
+    fn from(error: Error) -> Self {
+        match error.downcast::<Self>() {
+            Ok(error) => error,
+            Err(error) => Self::classify(error, None),
+        }
+    }
+}
+
+impl<N: Network> Display for ExecutionError<N> {
+    /// Prints the error message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.error())
+    }
+}
+
+impl<N: Network> std::error::Error for ExecutionError<N> {
+    /// Returns the cause of the underlying error, if any.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error().chain().nth(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_location(program_id: &str, opcode: Opcode) -> ErrorLocation<CurrentNetwork> {
+        ErrorLocation::new(
+            ProgramID::from_str(program_id).unwrap(),
+            Identifier::from_str("foo").unwrap(),
+            false,
+            3,
+            opcode,
+        )
+    }
+
+    #[test]
+    fn test_instruction_error() {
+        // Ensure the errors are classified by the failure raised in their cause.
+        let location = sample_location("test.aleo", Opcode::Assert("assert.eq"));
+        let cause = Error::from(Failure::new(FailureKind::AssertionFailed, "a"));
+        let error =
+            ExecutionError::<CurrentNetwork>::from(ExecutionError::instruction(location, "b".into(), Some(cause)));
+        assert!(matches!(error, ExecutionError::AssertionFailed(Some(l), _) if l == location), "{error:?}");
+        assert_eq!(error.to_string(), "b");
+
+        // Ensure the source error is kept.
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(source.downcast_ref::<Failure>().unwrap().kind(), FailureKind::AssertionFailed);
+        assert_eq!(source.to_string(), "a");
+
+        // Ensure the classification does not depend on the opcode.
+        let location = sample_location("credits.aleo", Opcode::Literal("sub"));
+        let error = ExecutionError::<CurrentNetwork>::from(ExecutionError::instruction(location, "c".into(), None));
+        assert!(matches!(error, ExecutionError::InstructionFailed(l, _) if l == location), "{error:?}");
+        assert_eq!(error.location().unwrap().index(), 3);
+
+        // Ensure the classification and location of a nested error are kept, with the new message.
+        let inner = sample_location("test.aleo", Opcode::Command("get"));
+        let cause = Error::from(Failure::new(FailureKind::MissingMappingKey, "d"));
+        let cause = ExecutionError::instruction(inner, "e".into(), Some(cause));
+        let outer = sample_location("main.aleo", Opcode::Call);
+        let error = ExecutionError::<CurrentNetwork>::from(ExecutionError::instruction(outer, "f".into(), Some(cause)));
+        assert!(matches!(error, ExecutionError::MissingMappingKey(Some(l), _) if l == inner), "{error:?}");
+        assert_eq!(error.to_string(), "f");
+        assert_eq!(error.error().chain().map(|cause| cause.to_string()).collect::<Vec<_>>(), ["f", "e", "d"]);
+
+        // Ensure the context is added, keeping the classification.
+        let error = error.context("g");
+        assert!(matches!(error, ExecutionError::MissingMappingKey(Some(l), _) if l == inner), "{error:?}");
+        assert_eq!(error.to_string(), "g");
+
+        // Ensure a failure raised outside of an instruction is classified, without a location.
+        let error = ExecutionError::<CurrentNetwork>::from(
+            Error::from(Failure::new(FailureKind::InsufficientBalance, "h")).context("i"),
+        );
+        assert!(matches!(error, ExecutionError::InsufficientBalance(None, _)), "{error:?}");
+        assert_eq!(error.to_string(), "i");
+
+        // Ensure other errors are wrapped, keeping the source.
+        let error = ExecutionError::<CurrentNetwork>::from(anyhow!("j").context("k"));
+        assert!(matches!(error, ExecutionError::Other(_)), "{error:?}");
+        assert!(error.location().is_none());
+        assert_eq!(std::error::Error::source(&error).unwrap().to_string(), "j");
+    }
+}
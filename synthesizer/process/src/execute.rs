@@ -21,6 +21,16 @@ impl<N: Network> Process<N> {
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Trace<N>), ExecutionError<N>> {
+        self.execute_internal::<A, R>(authorization, rng).map_err(ExecutionError::from)
+    }
+
+    /// Executes the given authorization.
+    #[inline]
+    fn execute_internal<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
 
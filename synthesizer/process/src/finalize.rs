@@ -32,7 +32,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -60,6 +60,10 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // If the deployment is an upgrade, the existing mappings (and their entries) are kept.
+                if deployment.is_upgrade() && store.contains_mapping_confirmed(program_id, mapping.name())? {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -208,6 +212,9 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     // Initialize the top-level finalize state.
     states.push(initialize_finalize_state(state, future, stack, *transition.id())?);
 
+    // Retrieve the debugger.
+    let debugger = stack.debugger();
+
     // While there are active finalize states, finalize them.
     while let Some(FinalizeState {
         mut counter,
@@ -218,10 +225,24 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         mut recent_call_locator,
     }) = states.pop()
     {
+        // Prepare the locator of the finalize block.
+        let finalize_locator = Locator::new(*stack.program_id(), *finalize.name());
+
         // Evaluate the commands.
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Retrieve the index of the command, as the counter is updated during evaluation.
+            let index = counter;
+            // If a debugger is set, invoke it before evaluating the command.
+            if let Some(debugger) = &debugger {
+                debugger.before_command(&finalize_locator, index, command, registers.registers());
+            }
+            // Prepares the error for a failed command.
+            let command_error = |message: String, cause: Option<Error>| {
+                let location = ErrorLocation::new(*stack.program_id(), *finalize.name(), true, index, command.opcode());
+                ExecutionError::instruction(location, message, cause)
+            };
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -233,9 +254,15 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => {
+                            let message = format!("'finalize' failed to evaluate command ({command}): {error}");
+                            return Err(command_error(message, Some(error)));
+                        }
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => {
+                            let message = format!("'finalize' failed to evaluate command ({command})");
+                            return Err(command_error(message, None));
+                        }
                     }
                 }
                 Command::BranchNeq(branch_neq) => {
@@ -247,9 +274,15 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => {
+                            let message = format!("'finalize' failed to evaluate command ({command}): {error}");
+                            return Err(command_error(message, Some(error)));
+                        }
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => {
+                            let message = format!("'finalize' failed to evaluate command ({command})");
+                            return Err(command_error(message, None));
+                        }
                     }
                 }
                 Command::Await(await_) => {
@@ -283,11 +316,22 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     })) {
                         Ok(Ok(callee_state)) => callee_state,
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => {
+                            let message = format!("'finalize' failed to evaluate command ({command}): {error}");
+                            return Err(command_error(message, Some(error)));
+                        }
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => {
+                            let message = format!("'finalize' failed to evaluate command ({command})");
+                            return Err(command_error(message, None));
+                        }
                     };
 
+                    // If a debugger is set, invoke it after evaluating the command.
+                    if let Some(debugger) = &debugger {
+                        debugger.after_command(&finalize_locator, index, command, registers.registers());
+                    }
+
                     // Set the last seen call locator.
                     recent_call_locator = Some(locator);
                     // Increment the call counter.
@@ -315,13 +359,23 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation succeeds with no operation, continue.
                         Ok(Ok(None)) => {}
                         // If the evaluation fails, bail and return the error.
-                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        Ok(Err(error)) => {
+                            let message = format!("'finalize' failed to evaluate command ({command}): {error}");
+                            return Err(command_error(message, Some(error)));
+                        }
                         // If the evaluation fails, bail and return the error.
-                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                        Err(_) => {
+                            let message = format!("'finalize' failed to evaluate command ({command})");
+                            return Err(command_error(message, None));
+                        }
                     }
                     counter += 1;
                 }
             };
+            // If a debugger is set, invoke it after evaluating the command.
+            if let Some(debugger) = &debugger {
+                debugger.after_command(&finalize_locator, index, command, registers.registers());
+            }
         }
     }
 
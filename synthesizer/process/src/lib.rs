@@ -18,6 +18,9 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod error;
+pub use error::*;
+
 mod stack;
 pub use stack::*;
 
@@ -51,6 +54,8 @@ use synthesizer_program::{
     Branch,
     Closure,
     Command,
+    Failure,
+    FailureKind,
     Finalize,
     FinalizeGlobalState,
     FinalizeOperation,
@@ -60,7 +65,7 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{ProvingKey, UniversalSRS, VerifierInputs, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -76,6 +81,8 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The debugger, shared with every stack in the process.
+    debugger: Arc<RwLock<Option<Arc<dyn Debugger<N>>>>>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +92,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            debugger: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -125,11 +136,43 @@ impl<N: Network> Process<N> {
     }
 
     /// Adds a new stack to the process.
+    /// If the stack is an upgrade of an existing program, the stacks that import it are relinked to the upgrade.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
     pub fn add_stack(&mut self, stack: Stack<N>) {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        let previous = self.stacks.insert(program_id, Arc::new(stack));
+        // If the program was upgraded, relink the stacks that import it.
+        if previous.is_some() {
+            self.relink_dependent_stacks(program_id);
+        }
+    }
+
+    /// Relinks the stacks that import the given program ID, directly or transitively, to the latest stacks.
+    fn relink_dependent_stacks(&mut self, program_id: ProgramID<N>) {
+        // Initialize the list of relinked program IDs.
+        let mut relinked = vec![program_id];
+        // Note: A stack is always added after its imports, so a single pass relinks every dependent stack.
+        for index in 0..self.stacks.len() {
+            // Retrieve the stack.
+            let Some((stack_id, stack)) = self.stacks.get_index(index) else { continue };
+            let stack_id = *stack_id;
+            // Relink the stack to the latest stacks of its relinked imports.
+            let mut updated: Option<Stack<N>> = None;
+            for import in stack.program().imports().keys().filter(|import| relinked.contains(import)) {
+                if let Some(external_stack) = self.stacks.get(import) {
+                    let current = updated.as_ref().unwrap_or(&**stack);
+                    updated = Some(current.with_external_stack(external_stack.clone()));
+                }
+            }
+            // If the stack was relinked, replace it in the process.
+            if let Some(updated) = updated {
+                self.stacks.insert(stack_id, Arc::new(updated));
+                relinked.push(stack_id);
+            }
+        }
     }
 }
 
@@ -140,7 +183,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            debugger: Default::default(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +220,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            debugger: Default::default(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -194,6 +245,13 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Sets the debugger that is invoked while evaluating closures, functions, and finalize blocks.
+    /// The debugger applies to every program in the process, including programs that are added later.
+    #[inline]
+    pub fn set_debugger(&self, debugger: Option<Arc<dyn Debugger<N>>>) {
+        *self.debugger.write() = debugger;
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -20,6 +20,16 @@ impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Deployment<N>> {
+        self.deploy_edition::<A, R>(N::EDITION, rng)
+    }
+
+    /// Deploys the given program ID, as the given edition.
+    #[inline]
+    pub fn deploy_edition<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
         let timer = timer!("Stack::deploy");
 
         // Ensure the program contains functions.
@@ -50,7 +60,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+        Deployment::new(edition, self.program.clone(), verifying_keys)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -53,11 +53,37 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the debugger, and prepare the locator of the closure.
+        let debugger = self.debugger();
+        let locator = Locator::new(*self.program.id(), *closure.name());
+
         // Evaluate the instructions.
-        for instruction in closure.instructions() {
+        // Note: We handle the `repeat` instruction separately, as it requires special handling.
+        for (index, instruction) in closure.instructions().iter().enumerate() {
+            // If a debugger is set, invoke it before evaluating the instruction.
+            if let Some(debugger) = &debugger {
+                debugger.before_instruction(&locator, index, instruction, registers.console_registers());
+            }
+            // Evaluate the instruction.
+            let result = match instruction {
+                // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                Instruction::Repeat(repeat) => {
+                    let debugger = debugger.as_deref().map(|debugger| (debugger, &locator, index));
+                    RepeatTrait::evaluate(repeat, self, &mut registers, debugger)
+                }
+                // Otherwise, evaluate the instruction normally.
+                _ => instruction.evaluate(self, &mut registers),
+            };
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+            if let Err(error) = result {
+                let location =
+                    ErrorLocation::new(*self.program_id(), *closure.name(), false, index, instruction.opcode());
+                let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                return Err(ExecutionError::instruction(location, message, Some(error)));
+            }
+            // If a debugger is set, invoke it after evaluating the instruction.
+            if let Some(debugger) = &debugger {
+                debugger.after_instruction(&locator, index, instruction, registers.console_registers());
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -82,6 +108,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is any other block metadata or the `repeat` counter, throw an error.
+                    operand @ (Operand::BlockTimestamp
+                    | Operand::BlockRound
+                    | Operand::BlockPreviousHash
+                    | Operand::RepeatIndex) => {
+                        bail!("Cannot retrieve '{operand}' from a closure scope.")
+                    }
                 }
             })
             .collect();
@@ -173,19 +206,39 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Retrieve the debugger, and prepare the locator of the function.
+        let debugger = self.debugger();
+        let locator = Locator::new(*self.program.id(), *function.name());
+
         // Evaluate the instructions.
-        // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
+        // Note: We handle the `call` and `repeat` instructions separately, as they require special handling.
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If a debugger is set, invoke it before evaluating the instruction.
+            if let Some(debugger) = &debugger {
+                debugger.before_instruction(&locator, index, instruction, registers.console_registers());
+            }
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                Instruction::Repeat(repeat) => {
+                    let debugger = debugger.as_deref().map(|debugger| (debugger, &locator, index));
+                    RepeatTrait::evaluate(repeat, self, &mut registers, debugger)
+                }
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
             // If the evaluation fails, bail and return the error.
             if let Err(error) = result {
-                bail!("Failed to evaluate instruction ({instruction}): {error}");
+                let location =
+                    ErrorLocation::new(*self.program_id(), *function.name(), false, index, instruction.opcode());
+                let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                return Err(ExecutionError::instruction(location, message, Some(error)));
+            }
+            // If a debugger is set, invoke it after evaluating the instruction.
+            if let Some(debugger) = &debugger {
+                debugger.after_instruction(&locator, index, instruction, registers.console_registers());
             }
         }
         lap!(timer, "Evaluate the instructions");
@@ -213,6 +266,13 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is any other block metadata or the `repeat` counter, throw an error.
+                    Operand::BlockTimestamp
+                    | Operand::BlockRound
+                    | Operand::BlockPreviousHash
+                    | Operand::RepeatIndex => {
+                        bail!("Cannot retrieve '{operand}' from a function scope.")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
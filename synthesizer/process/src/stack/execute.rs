@@ -67,16 +67,32 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        // Note: We handle the `repeat` instruction separately, as it requires special handling.
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
+                // Evaluate the instruction.
+                let result = match instruction {
+                    // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers, None),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.evaluate(self, &mut registers),
+                };
                 // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                if let Err(error) = result {
+                    let location =
+                        ErrorLocation::new(*self.program_id(), *closure.name(), false, index, instruction.opcode());
+                    let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                    return Err(ExecutionError::instruction(location, message, Some(error)));
                 }
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            match instruction {
+                // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers)?,
+                // Otherwise, execute the instruction normally.
+                _ => instruction.execute(self, &mut registers)?,
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -115,6 +131,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is any other block metadata or the `repeat` counter, throw an error.
+                    operand @ (Operand::BlockTimestamp
+                    | Operand::BlockRound
+                    | Operand::BlockPreviousHash
+                    | Operand::RepeatIndex) => {
+                        bail!("Illegal operation: cannot retrieve '{operand}' in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -254,19 +277,24 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers, None),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
                 // If the evaluation fails, bail and return the error.
                 if let Err(error) = result {
-                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                    let location =
+                        ErrorLocation::new(*self.program_id(), *function.name(), false, index, instruction.opcode());
+                    let message = format!("Failed to evaluate instruction ({instruction}): {error}");
+                    return Err(ExecutionError::instruction(location, message, Some(error)));
                 }
             }
 
@@ -274,12 +302,17 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `repeat` instruction, we need to unroll it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
-                bail!("Failed to execute instruction ({instruction}): {error}");
+                let location =
+                    ErrorLocation::new(*self.program_id(), *function.name(), false, index, instruction.opcode());
+                let message = format!("Failed to execute instruction ({instruction}): {error}");
+                return Err(ExecutionError::instruction(location, message, Some(error)));
             }
 
             // If the instruction was a function call, then set the tracker to `true`.
@@ -322,6 +355,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is any other block metadata or the `repeat` counter, throw an error.
+                    Operand::BlockTimestamp
+                    | Operand::BlockRound
+                    | Operand::BlockPreviousHash
+                    | Operand::RepeatIndex => {
+                        bail!("Illegal operation: cannot retrieve '{operand}' in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -37,10 +37,25 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::Signer => bail!("Forbidden operation: Cannot use 'self.signer' in 'finalize'"),
             // If the operand is the caller, throw an error.
             Operand::Caller => bail!("Forbidden operation: Cannot use 'self.caller' in 'finalize'"),
+            // If the operand is the `repeat` counter, throw an error.
+            Operand::RepeatIndex => bail!("Forbidden operation: Cannot use 'repeat.index' in 'finalize'"),
             // If the operand is the block height, load the block height.
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the block round, load the block round.
+            Operand::BlockRound => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::U64(U64::new(self.state.block_round())))));
+            }
+            // If the operand is the previous block hash, load the previous block hash.
+            Operand::BlockPreviousHash => {
+                let previous_block_hash = Field::from_bytes_le(self.state.previous_block_hash())?;
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(previous_block_hash))));
+            }
         };
 
         // Retrieve the value.
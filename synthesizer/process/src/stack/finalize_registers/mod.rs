@@ -19,7 +19,7 @@ use crate::FinalizeTypes;
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
-    types::U32,
+    types::{Field, I64, U32, U64},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -60,6 +60,12 @@ impl<N: Network> FinalizeRegisters<N> {
     ) -> Self {
         Self { state, transition_id, finalize_types, function_name, registers: IndexMap::new(), last_register: None }
     }
+
+    /// Returns the mapping of assigned registers to their values.
+    #[inline]
+    pub const fn registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.registers
+    }
 }
 
 impl<N: Network> FinalizeRegistersState<N> for FinalizeRegisters<N> {
@@ -21,6 +21,9 @@ use synthesizer_program::{
     Contains,
     Get,
     GetOrUse,
+    Mapping,
+    MappingIterate,
+    MappingLength,
     MappingLocator,
     RandChaCha,
     Remove,
@@ -149,6 +152,9 @@ impl<N: Network> FinalizeTypes<N> {
                 RegisterTypes::check_struct(stack, struct_name)?
             }
             FinalizeType::Plaintext(PlaintextType::Array(array_type)) => RegisterTypes::check_array(stack, array_type)?,
+            FinalizeType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                RegisterTypes::check_vector(stack, vector_type)?
+            }
             FinalizeType::Future(..) => (),
         };
 
@@ -174,9 +180,11 @@ impl<N: Network> FinalizeTypes<N> {
         match command {
             Command::Instruction(instruction) => self.check_instruction(stack, finalize.name(), instruction)?,
             Command::Await(await_) => self.check_await(stack, await_)?,
-            Command::Contains(contains) => self.check_contains(stack, finalize.name(), contains)?,
+            Command::Contains(contains) => self.check_contains(stack, contains)?,
             Command::Get(get) => self.check_get(stack, get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, get_or_use)?,
+            Command::MappingIterate(iterate) => self.check_mapping_iterate(stack, iterate)?,
+            Command::MappingLength(length) => self.check_mapping_length(stack, length)?,
             Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
             Command::Remove(remove) => self.check_remove(stack, finalize.name(), remove)?,
             Command::Set(set) => self.check_set(stack, finalize.name(), set)?,
@@ -188,6 +196,48 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Returns the mapping referenced by the given mapping locator.
+    /// An external mapping must be defined in a program that is imported by the current program.
+    #[inline]
+    fn get_mapping(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        mapping: &MappingLocator<N>,
+    ) -> Result<Mapping<N>> {
+        match mapping {
+            MappingLocator::Locator(locator) => {
+                // Retrieve the program ID.
+                let program_id = locator.program_id();
+                // Retrieve the mapping_name.
+                let mapping_name = locator.resource();
+
+                // Ensure the locator does not reference the current program.
+                if stack.program_id() == program_id {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Ensure the current program contains an import for this external program.
+                if !stack.program().imports().keys().contains(program_id) {
+                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
+                }
+                // Retrieve the program.
+                let external = stack.get_external_program(program_id)?;
+                // Ensure the mapping exists in the program.
+                if !external.contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
+                }
+                // Retrieve the mapping from the program.
+                external.get_mapping(mapping_name)
+            }
+            MappingLocator::Resource(mapping_name) => {
+                // Ensure the mapping is defined in the current program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)
+            }
+        }
+    }
+
     /// Checks that the given `await` command is well-formed.
     #[inline]
     fn check_await(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), await_: &Await<N>) -> Result<()> {
@@ -252,16 +302,10 @@ impl<N: Network> FinalizeTypes<N> {
     fn check_contains(
         &mut self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
-        finalize_name: &Identifier<N>,
         contains: &Contains<N>,
     ) -> Result<()> {
-        // Ensure the declared mapping in `contains` is defined in the program.
-        if !stack.program().contains_mapping(contains.mapping_name()) {
-            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
-        }
-        // Retrieve the mapping from the program.
-        // Note that the unwrap is safe, as we have already checked the mapping exists.
-        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Retrieve the mapping.
+        let mapping = Self::get_mapping(stack, contains.mapping())?;
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
         // Retrieve the register type of the key.
@@ -290,39 +334,7 @@ impl<N: Network> FinalizeTypes<N> {
     #[inline]
     fn check_get(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), get: &Get<N>) -> Result<()> {
         // Retrieve the mapping.
-        let mapping = match get.mapping() {
-            MappingLocator::Locator(locator) => {
-                // Retrieve the program ID.
-                let program_id = locator.program_id();
-                // Retrieve the mapping_name.
-                let mapping_name = locator.resource();
-
-                // Ensure the locator does not reference the current program.
-                if stack.program_id() == program_id {
-                    bail!("Locator '{locator}' does not reference an external mapping.");
-                }
-                // Ensure the current program contains an import for this external program.
-                if !stack.program().imports().keys().contains(program_id) {
-                    bail!("External program '{program_id}' is not imported by '{}'.", stack.program_id());
-                }
-                // Retrieve the program.
-                let external = stack.get_external_program(program_id)?;
-                // Ensure the mapping exists in the program.
-                if !external.contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
-                }
-                // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
-            }
-            MappingLocator::Resource(mapping_name) => {
-                // Ensure the declared mapping in `get` is defined in the current program.
-                if !stack.program().contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
-                }
-                // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
-            }
-        };
+        let mapping = Self::get_mapping(stack, get.mapping())?;
 
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
@@ -356,39 +368,7 @@ impl<N: Network> FinalizeTypes<N> {
         get_or_use: &GetOrUse<N>,
     ) -> Result<()> {
         // Retrieve the mapping.
-        let mapping = match get_or_use.mapping() {
-            MappingLocator::Locator(locator) => {
-                // Retrieve the program ID.
-                let program_id = locator.program_id();
-                // Retrieve the mapping_name.
-                let mapping_name = locator.resource();
-
-                // Ensure the locator does not reference the current program.
-                if stack.program_id() == program_id {
-                    bail!("Locator '{locator}' does not reference an external mapping.");
-                }
-                // Ensure the current program contains an import for this external program.
-                if !stack.program().imports().keys().contains(program_id) {
-                    bail!("External program '{locator}' is not imported by '{program_id}'.");
-                }
-                // Retrieve the program.
-                let external = stack.get_external_program(program_id)?;
-                // Ensure the mapping exists in the program.
-                if !external.contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
-                }
-                // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
-            }
-            MappingLocator::Resource(mapping_name) => {
-                // Ensure the declared mapping in `get.or_use` is defined in the current program.
-                if !stack.program().contains_mapping(mapping_name) {
-                    bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
-                }
-                // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
-            }
-        };
+        let mapping = Self::get_mapping(stack, get_or_use.mapping())?;
 
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
@@ -429,6 +409,60 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `mapping.iterate` command is well-formed.
+    #[inline]
+    fn check_mapping_iterate(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        iterate: &MappingIterate<N>,
+    ) -> Result<()> {
+        // Retrieve the mapping.
+        let mapping = Self::get_mapping(stack, iterate.mapping())?;
+        // Retrieve the register type of the start index.
+        match self.get_type_from_operand(stack, iterate.start())? {
+            // Ensure the start index is a `u32`.
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)) => (),
+            FinalizeType::Plaintext(plaintext_type) => {
+                bail!("The start index in `mapping.iterate` must be a 'u32', found '{plaintext_type}'.")
+            }
+            // If the register is a future, throw an error.
+            FinalizeType::Future(..) => {
+                bail!("A future cannot be used as a start index in a `mapping.iterate` command")
+            }
+        };
+        // Construct the vector types of the keys and values.
+        // Note that this ensures the limit is a valid vector capacity.
+        let keys_type = VectorType::new(mapping.key().plaintext_type().clone(), *iterate.limit())?;
+        let values_type = VectorType::new(mapping.value().plaintext_type().clone(), *iterate.limit())?;
+
+        // Insert the destination registers.
+        for (destination, vector_type) in [(iterate.keys(), keys_type), (iterate.values(), values_type)] {
+            // Ensure the destination register is a locator (and does not reference an access).
+            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+            // Insert the destination register.
+            self.add_destination(destination.clone(), FinalizeType::Plaintext(PlaintextType::Vector(vector_type)))?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `mapping.length` command is well-formed.
+    #[inline]
+    fn check_mapping_length(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        length: &MappingLength<N>,
+    ) -> Result<()> {
+        // Ensure the mapping is defined.
+        Self::get_mapping(stack, length.mapping())?;
+        // Get the destination register.
+        let destination = length.destination().clone();
+        // Ensure the destination register is a locator (and does not reference an access).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)))?;
+        Ok(())
+    }
+
     /// Ensure the given `rand.chacha` command is well-formed.
     #[inline]
     fn check_rand_chacha(
@@ -652,6 +686,12 @@ impl<N: Network> FinalizeTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                            // Ensure that the vector type is valid.
+                            RegisterTypes::check_vector(stack, vector_type)?;
+                            // Ensure the operand types match the element type.
+                            self.matches_vector(stack, instruction.operands(), vector_type)?;
+                        }
                         CastType::Record(..) => {
                             bail!("Illegal operation: Cannot cast to a record.")
                         }
@@ -667,6 +707,28 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::Ecdsa(opcode) => match opcode {
+                "ecdsa.verify.secp256k1" => ensure!(
+                    matches!(instruction, Instruction::EcdsaVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Group(opcode) => match opcode {
+                "group.gen" => ensure!(
+                    matches!(instruction, Instruction::GroupGen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "hash_to_group.psd2" => ensure!(
+                    matches!(instruction, Instruction::HashToGroupPSD2(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "msm" => ensure!(
+                    matches!(instruction, Instruction::Msm(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
             Opcode::Hash(opcode) => RegisterTypes::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -679,13 +741,62 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
-                // Ensure the instruction has one destination register.
+            Opcode::Repeat => {
+                bail!("Instruction 'repeat' is not allowed in 'finalize'");
+            }
+            Opcode::Sign(opcode) => match opcode {
+                "sign.verify" => ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "sign.schnorr" => bail!("Instruction '{instruction}' is not allowed in 'finalize'"),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::String(opcode) => match opcode {
+                "string.concat" => ensure!(
+                    matches!(instruction, Instruction::StringConcat(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.from_bytes" => ensure!(
+                    matches!(instruction, Instruction::StringFromBytes(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.get" => ensure!(
+                    matches!(instruction, Instruction::StringGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.len" => ensure!(
+                    matches!(instruction, Instruction::StringLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.to_bytes" => ensure!(
+                    matches!(instruction, Instruction::StringToBytes(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Switch => {
+                // Ensure the instruction is a `switch` instruction.
                 ensure!(
-                    instruction.destinations().len() == 1,
-                    "Instruction '{instruction}' has multiple destinations."
+                    matches!(instruction, Instruction::Switch(..)),
+                    "Instruction '{instruction}' is not for opcode 'switch'."
                 );
             }
+            Opcode::Vector(opcode) => match opcode {
+                "vec.get" => ensure!(
+                    matches!(instruction, Instruction::VecGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vec.len" => ensure!(
+                    matches!(instruction, Instruction::VecLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vec.push" => ensure!(
+                    matches!(instruction, Instruction::VecPush(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
@@ -96,11 +96,41 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block metadata type matches the member type.
+                Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                    // Retrieve the block metadata type.
+                    let block_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the block metadata type matches the member type.
+                    ensure!(
+                        block_type == FinalizeType::Plaintext(member_type.clone()),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_type}' in the operand '{operand}'.",
+                    )
+                }
+                // If the operand is the `repeat` counter, throw an error.
+                Operand::RepeatIndex => bail!("Forbidden operation: Cannot use 'repeat.index' in 'finalize'"),
             }
         }
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the vector.
+    pub fn matches_vector(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+        vector_type: &VectorType<N>,
+    ) -> Result<()> {
+        // Ensure the number of operands does not exceed the capacity of the vector.
+        let num_elements = operands.len();
+        let capacity = **vector_type.capacity() as usize;
+        if num_elements > capacity {
+            bail!("'{vector_type}' expected at most {capacity} elements, found {num_elements} elements")
+        }
+        // Ensure the operands match the layout of an array with the same number of elements.
+        let array_type = ArrayType::new(vector_type.element_type().clone(), vec![U32::new(num_elements as u32)])?;
+        self.matches_array(stack, operands, &array_type)
+    }
+
     /// Checks that the given operands matches the layout of the array.
     pub fn matches_array(
         &self,
@@ -177,6 +207,19 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the block metadata type matches the element type.
+                Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                    // Retrieve the block metadata type.
+                    let block_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the block metadata type matches the element type.
+                    ensure!(
+                        block_type == FinalizeType::Plaintext(array_type.next_element_type().clone()),
+                        "Array element expects {}, but found '{block_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
+                // If the operand is the `repeat` counter, throw an error.
+                Operand::RepeatIndex => bail!("Forbidden operation: Cannot use 'repeat.index' in 'finalize'"),
             }
         }
         Ok(())
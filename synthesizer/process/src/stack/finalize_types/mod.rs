@@ -17,7 +17,8 @@ mod matches;
 
 use console::{
     network::prelude::*,
-    program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType},
+    program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType, VectorType},
+    types::U32,
 };
 use synthesizer_program::{
     Command,
@@ -79,6 +80,10 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::BlockRound => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U64)),
+            Operand::BlockPreviousHash => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+            Operand::RepeatIndex => bail!("'repeat.index' is not a valid operand in a finalize context."),
         })
     }
 
@@ -176,6 +181,8 @@ impl<N: Network> FinalizeTypes<N> {
                 }
                 (FinalizeRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (FinalizeRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                // Note: Vector elements are accessed with `vec.get`, as the vector length is not known statically.
+                | (FinalizeRefType::Plaintext(PlaintextType::Vector(..)), _)
                 | (FinalizeRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
@@ -282,6 +282,27 @@ impl<N: Network> Stack<N> {
                     Ok(())
                 }
             },
+            PlaintextType::Vector(vector_type) => {
+                // Retrieve the length and the padded elements of the vector.
+                let Ok((length, elements)) = plaintext.to_vector() else {
+                    bail!("'{plaintext_type}' is invalid: expected vector, found '{plaintext}'")
+                };
+                // Ensure the vector is padded to its capacity.
+                let (num_elements, capacity) = (elements.len(), vector_type.capacity());
+                if num_elements != **capacity as usize {
+                    bail!("'{plaintext_type}' is invalid: expected {capacity} slots, found {num_elements} slots")
+                }
+                // Ensure the vector elements match.
+                for element in elements[..*length as usize].iter() {
+                    self.matches_plaintext_internal(element, vector_type.element_type(), depth + 1)?;
+                }
+                // Ensure the unused slots hold the default value, so that equal vectors have equal representations.
+                let padding = self.program().default_plaintext(vector_type.element_type())?;
+                if elements[*length as usize..].iter().any(|element| element != &padding) {
+                    bail!("'{plaintext_type}' is invalid: expected the unused slots to hold '{padding}'")
+                }
+                Ok(())
+            }
         }
     }
 
@@ -178,6 +178,23 @@ impl<N: Network> Stack<N> {
 
                 Plaintext::Array(elements, Default::default())
             }
+            // Sample a vector.
+            PlaintextType::Vector(vector_type) => {
+                // Sample the length of the vector.
+                let capacity = **vector_type.capacity();
+                let length = rng.gen_range(0..=capacity);
+                // Sample each element of the vector, up to its length.
+                let mut elements = (0..length)
+                    .map(|_| {
+                        // Sample the element value.
+                        self.sample_plaintext_internal(vector_type.element_type(), depth + 1, rng)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // Pad the elements to the capacity of the vector.
+                elements.resize(capacity as usize, self.program.default_plaintext(vector_type.element_type())?);
+
+                Plaintext::new_vector(U32::new(length), elements)?
+            }
         };
         // Return the plaintext.
         Ok(plaintext)
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Initializes a new stack for an upgrade of an existing program, given the process and the program.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Retrieve the existing stack for the program.
+        let Ok(existing_stack) = process.get_stack(program_id) else {
+            bail!("Cannot upgrade program '{program_id}' because it does not exist")
+        };
+        // Ensure the upgraded program is compatible with the existing program.
+        existing_stack.check_upgrade_is_compatible(program)?;
+        // Ensure the upgraded program does not introduce a cyclic import.
+        for import in program.imports().keys() {
+            // Retrieve the external stack for the import program ID.
+            let external_stack = process.get_stack(import)?;
+            // Ensure the import does not depend on the upgraded program.
+            if external_stack.is_dependent_on(program_id) {
+                bail!("Cannot upgrade program '{program_id}' because its import '{import}' depends on it")
+            }
+        }
+        // Return the stack.
+        Self::check_and_initialize(process, program)
+    }
+
+    /// Checks that the given program is a compatible upgrade of the program in this stack.
+    /// A compatible upgrade keeps all of the existing mappings, structs, and records unchanged,
+    /// and keeps all of the existing functions with their input, output, and finalize input types.
+    #[inline]
+    pub fn check_upgrade_is_compatible(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+        // Ensure the program ID matches.
+        ensure!(program.id() == program_id, "Cannot upgrade program '{program_id}' with program '{}'", program.id());
+
+        // Ensure the existing mappings are unchanged.
+        for (name, mapping) in self.program.mappings() {
+            match program.mappings().get(name) {
+                Some(candidate) if candidate == mapping => (),
+                Some(_) => bail!("Upgrade of '{program_id}' changes the type of mapping '{name}'"),
+                None => bail!("Upgrade of '{program_id}' removes mapping '{name}'"),
+            }
+        }
+        // Ensure the existing structs are unchanged.
+        for (name, struct_) in self.program.structs() {
+            match program.structs().get(name) {
+                Some(candidate) if candidate == struct_ => (),
+                Some(_) => bail!("Upgrade of '{program_id}' changes the layout of struct '{name}'"),
+                None => bail!("Upgrade of '{program_id}' removes struct '{name}'"),
+            }
+        }
+        // Ensure the existing records are unchanged.
+        for (name, record) in self.program.records() {
+            match program.records().get(name) {
+                Some(candidate) if candidate == record => (),
+                Some(_) => bail!("Upgrade of '{program_id}' changes the layout of record '{name}'"),
+                None => bail!("Upgrade of '{program_id}' removes record '{name}'"),
+            }
+        }
+        // Ensure the existing functions are present, with the same signatures.
+        for (name, function) in self.program.functions() {
+            let Some(candidate) = program.functions().get(name) else {
+                bail!("Upgrade of '{program_id}' removes function '{name}'")
+            };
+            // Ensure the input types are unchanged.
+            if candidate.input_types() != function.input_types() {
+                bail!("Upgrade of '{program_id}' changes the input types of function '{name}'")
+            }
+            // Ensure the output types are unchanged.
+            if candidate.output_types() != function.output_types() {
+                bail!("Upgrade of '{program_id}' changes the output types of function '{name}'")
+            }
+            // Ensure the finalize input types are unchanged.
+            let finalize_input_types = |function: &Function<N>| function.finalize_logic().map(|f| f.input_types());
+            if finalize_input_types(candidate) != finalize_input_types(function) {
+                bail!("Upgrade of '{program_id}' changes the finalize input types of function '{name}'")
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the stack imports the given program ID, directly or transitively.
+    #[inline]
+    pub fn is_dependent_on(&self, program_id: &ProgramID<N>) -> bool {
+        self.program.contains_import(program_id)
+            || self.external_stacks.values().any(|external_stack| external_stack.is_dependent_on(program_id))
+    }
+
+    /// Returns a copy of the stack, with the given stack replacing the external stack of the same program ID.
+    /// Note: The returned stack shares its proving and verifying keys with this stack.
+    #[inline]
+    pub(crate) fn with_external_stack(&self, external_stack: Arc<Stack<N>>) -> Self {
+        // Clone the stack.
+        let mut stack = self.clone();
+        // Replace the external stack.
+        stack.external_stacks.insert(*external_stack.program_id(), external_stack);
+        // Return the stack.
+        stack
+    }
+}
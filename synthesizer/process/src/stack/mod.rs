@@ -30,13 +30,16 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod repeat;
+pub use repeat::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{traits::*, CallMetrics, ErrorLocation, ExecutionError, Process, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -59,7 +62,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::{Field, Group},
+    types::{Field, Group, U32},
 };
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
@@ -180,6 +183,8 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The debugger, shared with the process.
+    debugger: Arc<RwLock<Option<Arc<dyn Debugger<N>>>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -190,6 +195,15 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Self::check_and_initialize(process, program)
+    }
+
+    /// Checks the given program is well-formed, and initializes its stack.
+    #[inline]
+    fn check_and_initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -384,6 +398,12 @@ impl<N: Network> Stack<N> {
     pub fn remove_verifying_key(&self, function_name: &Identifier<N>) {
         self.verifying_keys.write().remove(function_name);
     }
+
+    /// Returns the debugger, if one is set.
+    #[inline]
+    pub(crate) fn debugger(&self) -> Option<Arc<dyn Debugger<N>>> {
+        self.debugger.read().clone()
+    }
 }
 
 impl<N: Network> Stack<N> {
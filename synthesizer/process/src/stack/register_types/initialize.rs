@@ -38,6 +38,11 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(instruction.opcode() != Opcode::Async, "An 'async' instruction is not allowed in closures");
             // Ensure the closure contains no call instructions.
             ensure!(instruction.opcode() != Opcode::Call, "A 'call' instruction is not allowed in closures");
+            // Ensure the `repeat` counter is only used in the body of a `repeat` block.
+            ensure!(
+                !instruction.operands().contains(&Operand::RepeatIndex),
+                "The operand 'repeat.index' is only allowed in a 'repeat' block"
+            );
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
@@ -87,6 +92,11 @@ impl<N: Network> RegisterTypes<N> {
 
         let mut async_ = None;
         for instruction in function.instructions() {
+            // Ensure the `repeat` counter is only used in the body of a `repeat` block.
+            ensure!(
+                !instruction.operands().contains(&Operand::RepeatIndex),
+                "The operand 'repeat.index' is only allowed in a 'repeat' block"
+            );
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, function.name(), instruction)?;
             // Additional validation.
@@ -267,11 +277,15 @@ impl<N: Network> RegisterTypes<N> {
         register: &Register<N>,
         register_type: &RegisterType<N>,
     ) -> Result<()> {
+        // Ensure the `repeat` counter is not used as an output.
+        ensure!(*operand != Operand::RepeatIndex, "The operand 'repeat.index' is only allowed in a 'repeat' block");
+
         // Ensure the register type is defined in the program.
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -319,11 +333,15 @@ impl<N: Network> RegisterTypes<N> {
             _ => (),
         }
 
+        // Ensure the `repeat` counter is not used as an output.
+        ensure!(*operand != Operand::RepeatIndex, "The operand 'repeat.index' is only allowed in a 'repeat' block");
+
         // Ensure the register type is defined in the program.
         match register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => Self::check_struct(stack, struct_name)?,
             RegisterType::Plaintext(PlaintextType::Array(array_type)) => Self::check_array(stack, array_type)?,
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => Self::check_vector(stack, vector_type)?,
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -367,6 +385,11 @@ impl<N: Network> RegisterTypes<N> {
         // Ensure the opcode is well-formed.
         self.check_instruction_opcode(stack, closure_or_function_name, instruction)?;
 
+        // If the instruction is a `repeat` block, check the body and unroll its destinations.
+        if let Instruction::Repeat(repeat) = instruction {
+            return self.check_repeat(stack, closure_or_function_name, repeat);
+        }
+
         // Initialize a vector to store the register types of the operands.
         let mut operand_types = Vec::with_capacity(instruction.operands().len());
         // Iterate over the operands, and retrieve the register type of each operand.
@@ -390,6 +413,65 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `repeat` block is well-formed.
+    /// This method checks the body as the first iteration, and inserts the destinations of the remaining iterations.
+    #[inline]
+    fn check_repeat(
+        &mut self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure_or_function_name: &Identifier<N>,
+        repeat: &Repeat<N>,
+    ) -> Result<()> {
+        // Retrieve the locator of the first destination register in the block.
+        let start = (self.inputs.len() + self.destinations.len()) as u64;
+
+        // Check the body instructions, as the first iteration.
+        for instruction in repeat.instructions() {
+            // Ensure the body contains no `async`, `call`, or nested `repeat` instructions.
+            ensure!(
+                !matches!(instruction.opcode(), Opcode::Async | Opcode::Call | Opcode::Repeat),
+                "Instruction '{instruction}' is not allowed in a 'repeat' block"
+            );
+            // Check the instruction opcode, operands, and destinations.
+            self.check_instruction(stack, closure_or_function_name, instruction)?;
+        }
+
+        // Ensure the body assigns at least one destination register.
+        let stride = repeat.stride();
+        ensure!(stride > 0, "A 'repeat' block must assign at least one destination register");
+        // Ensure the shifted registers start at the body destinations.
+        ensure!(repeat.start() == start, "The destinations of the 'repeat' block are out of order");
+
+        // Ensure the registers carried into the next iteration precede the block, and match the type of their counterpart in the body.
+        for register in repeat.carries() {
+            // Retrieve the register locator.
+            let locator = register.locator();
+            ensure!(
+                (start.saturating_sub(stride)..start).contains(&locator),
+                "Register '{register}' cannot be carried into the next iteration of 'repeat', as it is not one of the {stride} registers preceding the block"
+            );
+            let carried_type = self.get_type(stack, register)?;
+            let expected_type = self.get_type(stack, &Register::Locator(locator + stride))?;
+            ensure!(
+                carried_type == expected_type,
+                "Register '{register}' is carried into the next iteration of 'repeat', but its type '{carried_type}' does not match 'r{}' ('{expected_type}')",
+                locator + stride
+            );
+        }
+
+        // Retrieve the destination types of a single iteration.
+        let destination_types = (start..start + stride)
+            .map(|locator| self.get_type(stack, &Register::Locator(locator)))
+            .collect::<Result<Vec<_>>>()?;
+        // Insert the destination registers of the remaining iterations.
+        for iteration in 1..**repeat.count() as u64 {
+            for (locator, destination_type) in (start + iteration * stride..).zip(destination_types.iter()) {
+                self.add_destination(Register::Locator(locator), destination_type.clone())?;
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the correct instruction.
     /// This method is called when adding a new closure or function to the program.
     #[inline]
@@ -539,6 +621,12 @@ impl<N: Network> RegisterTypes<N> {
                             // Ensure the operand types match the element type.
                             self.matches_array(stack, instruction.operands(), array_type)?;
                         }
+                        CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                            // Ensure that the vector type is valid.
+                            RegisterTypes::check_vector(stack, vector_type)?;
+                            // Ensure the operand types match the element type.
+                            self.matches_vector(stack, instruction.operands(), vector_type)?;
+                        }
                         CastType::Record(record_name) => {
                             // Ensure the record type is defined in the program.
                             if !stack.program().contains_record(record_name) {
@@ -561,6 +649,28 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::Ecdsa(opcode) => match opcode {
+                "ecdsa.verify.secp256k1" => ensure!(
+                    matches!(instruction, Instruction::EcdsaVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Group(opcode) => match opcode {
+                "group.gen" => ensure!(
+                    matches!(instruction, Instruction::GroupGen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "hash_to_group.psd2" => ensure!(
+                    matches!(instruction, Instruction::HashToGroupPSD2(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "msm" => ensure!(
+                    matches!(instruction, Instruction::Msm(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -573,13 +683,90 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
-                // Ensure the instruction has one destination register.
+            Opcode::Repeat => {
+                // Ensure the instruction is a `repeat` block.
                 ensure!(
-                    instruction.destinations().len() == 1,
-                    "Instruction '{instruction}' has multiple destinations."
+                    matches!(instruction, Instruction::Repeat(..)),
+                    "Instruction '{instruction}' is not for opcode 'repeat'."
                 );
             }
+            Opcode::Sign(opcode) => match opcode {
+                "sign.verify" => ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "sign.schnorr" => {
+                    ensure!(
+                        matches!(instruction, Instruction::SignSchnorr(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    );
+                    // Retrieve the function, as closure inputs do not have a visibility.
+                    let program = stack.program();
+                    if program.contains_closure(closure_or_function_name) {
+                        bail!("Instruction '{instruction}' is not allowed in closures");
+                    }
+                    let function = program.get_function_ref(closure_or_function_name)?;
+                    // Ensure the signature secret key is (a member of) a private input.
+                    // Note: The visibility of a derived register is not tracked, so the key may not be copied.
+                    let is_private_input = match instruction.operands().first() {
+                        Some(Operand::Register(register)) => function.inputs().iter().any(|input| {
+                            input.register().locator() == register.locator()
+                                && matches!(input.value_type(), ValueType::Private(..))
+                        }),
+                        _ => false,
+                    };
+                    ensure!(
+                        is_private_input,
+                        "Instruction '{instruction}' expects the signature secret key to be a private input"
+                    );
+                }
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::String(opcode) => match opcode {
+                "string.concat" => ensure!(
+                    matches!(instruction, Instruction::StringConcat(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.from_bytes" => ensure!(
+                    matches!(instruction, Instruction::StringFromBytes(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.get" => ensure!(
+                    matches!(instruction, Instruction::StringGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.len" => ensure!(
+                    matches!(instruction, Instruction::StringLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.to_bytes" => ensure!(
+                    matches!(instruction, Instruction::StringToBytes(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
+            Opcode::Switch => {
+                // Ensure the instruction is a `switch` instruction.
+                ensure!(
+                    matches!(instruction, Instruction::Switch(..)),
+                    "Instruction '{instruction}' is not for opcode 'switch'."
+                );
+            }
+            Opcode::Vector(opcode) => match opcode {
+                "vec.get" => ensure!(
+                    matches!(instruction, Instruction::VecGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vec.len" => ensure!(
+                    matches!(instruction, Instruction::VecLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "vec.push" => ensure!(
+                    matches!(instruction, Instruction::VecPush(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
@@ -649,6 +836,7 @@ impl<N: Network> RegisterTypes<N> {
                 PlaintextType::Literal(..) => (),
                 PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
                 PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+                PlaintextType::Vector(vector_type) => Self::check_vector(stack, vector_type)?,
             }
         }
         Ok(())
@@ -669,6 +857,20 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensure the element type of the vector is defined in the program.
+    pub(crate) fn check_vector(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        vector_type: &VectorType<N>,
+    ) -> Result<()> {
+        match vector_type.element_type() {
+            PlaintextType::Literal(..) => (),
+            PlaintextType::Struct(struct_name) => Self::check_struct(stack, struct_name)?,
+            PlaintextType::Array(array_type) => Self::check_array(stack, array_type)?,
+            PlaintextType::Vector(..) => bail!("A vector cannot contain vectors"),
+        }
+        Ok(())
+    }
+
     /// Ensures the opcode is a valid opcode and corresponds to the `commit` instruction.
     #[inline]
     pub(crate) fn check_commit_opcode(opcode: &str, instruction: &Instruction<N>) -> Result<()> {
@@ -756,6 +958,10 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.sha256" => ensure!(
+                matches!(instruction, Instruction::HashSha256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash.sha3_256" => ensure!(
                 matches!(instruction, Instruction::HashSha3_256(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
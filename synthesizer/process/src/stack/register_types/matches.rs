@@ -88,11 +88,43 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a block height in a non-finalize scope"
                 ),
+                // If the operand is any other block metadata, throw an error.
+                Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from '{operand}' in a non-finalize scope"
+                ),
+                // Ensure the `repeat` counter type (u32) matches the member type.
+                Operand::RepeatIndex => {
+                    // Retrieve the operand type.
+                    let operand_type = PlaintextType::Literal(LiteralType::U32);
+                    // Ensure the operand type matches the member type.
+                    ensure!(
+                        &operand_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{operand_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the vector.
+    pub fn matches_vector(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+        vector_type: &VectorType<N>,
+    ) -> Result<()> {
+        // Ensure the number of operands does not exceed the capacity of the vector.
+        let num_elements = operands.len();
+        let capacity = **vector_type.capacity() as usize;
+        if num_elements > capacity {
+            bail!("'{vector_type}' expected at most {capacity} elements, found {num_elements} elements")
+        }
+        // Ensure the operands match the layout of an array with the same number of elements.
+        let array_type = ArrayType::new(vector_type.element_type().clone(), vec![U32::new(num_elements as u32)])?;
+        self.matches_array(stack, operands, &array_type)
+    }
+
     /// Checks that the given operands matches the layout of the array.
     pub fn matches_array(
         &self,
@@ -162,6 +194,21 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 // If the operand is a block height type, throw an error.
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
+                // If the operand is any other block metadata, throw an error.
+                Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                    bail!("Array element cannot be from '{operand}' in a non-finalize scope")
+                }
+                // Ensure the `repeat` counter type (u32) matches the element type.
+                Operand::RepeatIndex => {
+                    // Retrieve the operand type.
+                    let operand_type = PlaintextType::Literal(LiteralType::U32);
+                    // Ensure the operand type matches the element type.
+                    ensure!(
+                        &operand_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{operand_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -224,6 +271,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::BlockHeight => {
                 bail!("Forbidden operation: Cannot cast a block height as a record owner")
             }
+            Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash | Operand::RepeatIndex => {
+                bail!("Forbidden operation: Cannot cast '{}' as a record owner", operands[0])
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -279,6 +329,22 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block height in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is any other block metadata.
+                        Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found block metadata in the operand '{operand}'."
+                            )
+                        }
+                        // Ensure the `repeat` counter type (u32) matches the entry type.
+                        Operand::RepeatIndex => {
+                            // Retrieve the operand type.
+                            let operand_type = &PlaintextType::Literal(LiteralType::U32);
+                            // Ensure the operand type matches the entry type.
+                            ensure!(
+                                operand_type == plaintext_type,
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{operand_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
@@ -29,7 +29,9 @@ use console::{
         RegisterType,
         StructType,
         ValueType,
+        VectorType,
     },
+    types::U32,
 };
 use synthesizer_program::{
     CallOperator,
@@ -40,6 +42,7 @@ use synthesizer_program::{
     Opcode,
     Operand,
     Program,
+    Repeat,
     StackMatches,
     StackProgram,
 };
@@ -97,6 +100,10 @@ impl<N: Network> RegisterTypes<N> {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                bail!("'{operand}' is not a valid operand in a non-finalize context.")
+            }
+            Operand::RepeatIndex => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
         })
     }
 
@@ -249,6 +256,8 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 (RegisterRefType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
                 | (RegisterRefType::Plaintext(PlaintextType::Array(..)), Access::Member(..))
+                // Note: Vector elements are accessed with `vec.get`, as the vector length is not known statically.
+                | (RegisterRefType::Plaintext(PlaintextType::Vector(..)), _)
                 | (RegisterRefType::Future(..), Access::Member(..)) => {
                     bail!("Invalid access `{access}`")
                 }
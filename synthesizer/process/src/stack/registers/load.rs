@@ -27,7 +27,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             // If the operand is a literal, return the literal.
             Operand::Literal(literal) => return Ok(Value::Plaintext(Plaintext::from(literal))),
             // If the operand is a register, load the value from the register.
-            Operand::Register(register) => register,
+            // Note: The register is shifted into the current iteration, if a `repeat` block is being unrolled.
+            Operand::Register(register) => self.resolve(register),
             // If the operand is the program ID, load the program address.
             Operand::ProgramID(program_id) => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::Address(program_id.to_address()?))));
@@ -38,6 +39,12 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is any other block metadata, throw an error.
+            Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                bail!("Cannot load '{operand}' in a non-finalize context")
+            }
+            // If the operand is the `repeat` counter, load the current iteration.
+            Operand::RepeatIndex => return Ok(Value::Plaintext(Plaintext::from(Literal::U32(self.repeat_index()?)))),
         };
 
         // Retrieve the stack value.
@@ -45,11 +52,11 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
 
         // Return the value for the given register or register access.
-        let stack_value = match register {
+        let stack_value = match &*register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => stack_value.clone(),
             // If the register is a register access, then load the specific stack value.
-            Register::Access(_, ref path) => {
+            Register::Access(_, path) => {
                 match stack_value {
                     // Retrieve the plaintext member from the path.
                     Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
@@ -66,7 +73,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
         };
 
         // Retrieve the register type.
-        match self.register_types.get_type(stack, register) {
+        match self.register_types.get_type(stack, &register) {
             // Ensure the stack value matches the register type.
             Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
             // Ensure the register is defined.
@@ -100,7 +107,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
                 ))));
             }
             // If the operand is a register, load the value from the register.
-            Operand::Register(register) => register,
+            // Note: The register is shifted into the current iteration, if a `repeat` block is being unrolled.
+            Operand::Register(register) => self.resolve(register),
             // If the operand is the program ID, load the program address.
             Operand::ProgramID(program_id) => {
                 return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
@@ -121,6 +129,16 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is any other block metadata, throw an error.
+            Operand::BlockTimestamp | Operand::BlockRound | Operand::BlockPreviousHash => {
+                bail!("Cannot load '{operand}' in a non-finalize context")
+            }
+            // If the operand is the `repeat` counter, load the current iteration as a constant.
+            Operand::RepeatIndex => {
+                return Ok(circuit::Value::Plaintext(circuit::Plaintext::from(circuit::Literal::constant(
+                    Literal::U32(self.repeat_index()?),
+                ))));
+            }
         };
 
         // Retrieve the circuit value.
@@ -128,11 +146,11 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             self.circuit_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
 
         // Return the value for the given register or register access.
-        let circuit_value = match register {
+        let circuit_value = match &*register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => circuit_value.clone(),
             // If the register is a register access, then load the specific stack value.
-            Register::Access(_, ref path) => {
+            Register::Access(_, path) => {
                 // Inject the path.
                 let path = path.iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
 
@@ -152,7 +170,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
         };
 
         // Retrieve the register type.
-        match self.register_types.get_type(stack, register) {
+        match self.register_types.get_type(stack, &register) {
             // Ensure the stack value matches the register type.
             Ok(register_type) => {
                 stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
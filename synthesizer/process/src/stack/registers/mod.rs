@@ -21,7 +21,7 @@ use crate::{CallStack, RegisterTypes, RegistersCall};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
-    types::{Address, Field},
+    types::{Address, Field, U32},
 };
 use synthesizer_program::{
     Operand,
@@ -36,6 +36,7 @@ use synthesizer_program::{
 };
 
 use indexmap::IndexMap;
+use std::borrow::Cow;
 
 #[derive(Clone)]
 pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
@@ -59,6 +60,8 @@ pub struct Registers<N: Network, A: circuit::Aleo<Network = N>> {
     tvk: Option<Field<N>>,
     /// The transition view key, as a circuit.
     tvk_circuit: Option<circuit::Field<A>>,
+    /// The `repeat` iteration being unrolled, as `(start, stride, carries, iteration)`.
+    repeat_iteration: Option<(u64, u64, Vec<u64>, u32)>,
 }
 
 impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
@@ -76,6 +79,47 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             caller_circuit: None,
             tvk: None,
             tvk_circuit: None,
+            repeat_iteration: None,
+        }
+    }
+
+    /// Returns the mapping of assigned console registers to their values.
+    #[inline]
+    pub const fn console_registers(&self) -> &IndexMap<u64, Value<N>> {
+        &self.console_registers
+    }
+
+    /// Sets the `repeat` iteration being unrolled, as `(start, stride, carries, iteration)`.
+    /// Every register at or above `start`, and every carried register, is shifted by `stride * iteration`,
+    /// until the iteration is cleared.
+    #[inline]
+    pub fn set_repeat_iteration(&mut self, repeat_iteration: Option<(u64, u64, Vec<u64>, u32)>) {
+        self.repeat_iteration = repeat_iteration;
+    }
+
+    /// Returns the counter of the `repeat` iteration being unrolled.
+    #[inline]
+    pub fn repeat_index(&self) -> Result<U32<N>> {
+        match self.repeat_iteration {
+            Some((_, _, _, iteration)) => Ok(U32::new(iteration)),
+            None => bail!("Cannot load 'repeat.index' outside of a 'repeat' block"),
+        }
+    }
+
+    /// Returns the given register, shifted into the `repeat` iteration being unrolled (if any).
+    #[inline]
+    fn resolve<'a>(&self, register: &'a Register<N>) -> Cow<'a, Register<N>> {
+        match &self.repeat_iteration {
+            Some((start, stride, carries, iteration))
+                if register.locator() >= *start || carries.contains(&register.locator()) =>
+            {
+                let offset = stride * *iteration as u64;
+                Cow::Owned(match register {
+                    Register::Locator(locator) => Register::Locator(locator + offset),
+                    Register::Access(locator, path) => Register::Access(locator + offset, path.clone()),
+                })
+            }
+            _ => Cow::Borrowed(register),
         }
     }
 
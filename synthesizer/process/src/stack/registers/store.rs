@@ -28,7 +28,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
         register: &Register<N>,
         stack_value: Value<N>,
     ) -> Result<()> {
-        match register {
+        // Shift the register into the current iteration, if a `repeat` block is being unrolled.
+        let register = self.resolve(register);
+
+        match &*register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.console_registers.len() as u64;
@@ -40,7 +43,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStore<N> for Registers<
                 );
 
                 // Ensure the register type is valid.
-                match self.register_types.get_type(stack, register) {
+                match self.register_types.get_type(stack, &register) {
                     // Ensure the stack value matches the register type.
                     Ok(register_type) => stack.matches_register_type(&stack_value, &register_type)?,
                     // Ensure the register is defined.
@@ -75,7 +78,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
         register: &Register<N>,
         circuit_value: circuit::Value<A>,
     ) -> Result<()> {
-        match register {
+        // Shift the register into the current iteration, if a `repeat` block is being unrolled.
+        let register = self.resolve(register);
+
+        match &*register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
                 let expected_locator = self.circuit_registers.len() as u64;
@@ -87,7 +93,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersStoreCircuit<N, A> for
                 );
 
                 // Ensure the register type is valid.
-                match self.register_types.get_type(stack, register) {
+                match self.register_types.get_type(stack, &register) {
                     // Ensure the stack value matches the register type.
                     Ok(register_type) => {
                         stack.matches_register_type(&circuit::Eject::eject_value(&circuit_value), &register_type)?
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Debugger, Registers};
+use console::{network::prelude::*, program::Locator};
+use synthesizer_program::{Instruction, Repeat, StackMatches, StackProgram};
+
+pub trait RepeatTrait<N: Network> {
+    /// Evaluates the instruction.
+    /// If a debugger is given, along with the locator and index of the `repeat` block,
+    /// it is invoked around each unrolled instruction.
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+        debugger: Option<(&dyn Debugger<N>, &Locator<N>, usize)>,
+    ) -> Result<()>;
+
+    /// Executes the instruction.
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()>;
+}
+
+impl<N: Network> RepeatTrait<N> for Repeat<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+        debugger: Option<(&dyn Debugger<N>, &Locator<N>, usize)>,
+    ) -> Result<()> {
+        unroll(self, registers, |iteration, body_index, instruction, registers| {
+            // If a debugger is set, invoke it before evaluating the instruction.
+            if let Some((debugger, locator, index)) = debugger {
+                let console_registers = registers.console_registers();
+                debugger.before_repeat_instruction(
+                    locator,
+                    index,
+                    iteration,
+                    body_index,
+                    instruction,
+                    console_registers,
+                );
+            }
+            // Evaluate the instruction.
+            instruction.evaluate(stack, registers)?;
+            // If a debugger is set, invoke it after evaluating the instruction.
+            if let Some((debugger, locator, index)) = debugger {
+                let console_registers = registers.console_registers();
+                debugger.after_repeat_instruction(
+                    locator,
+                    index,
+                    iteration,
+                    body_index,
+                    instruction,
+                    console_registers,
+                );
+            }
+            Ok(())
+        })
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        unroll(self, registers, |_, _, instruction, registers| instruction.execute::<A>(stack, registers))
+    }
+}
+
+/// Unrolls the `repeat` block, applying the given operation to the body instructions of each iteration.
+/// The operation is given the iteration, and the index of the instruction in the body.
+fn unroll<N: Network, A: circuit::Aleo<Network = N>>(
+    repeat: &Repeat<N>,
+    registers: &mut Registers<N, A>,
+    mut operation: impl FnMut(u32, usize, &Instruction<N>, &mut Registers<N, A>) -> Result<()>,
+) -> Result<()> {
+    // Retrieve the first shifted register, the number of registers assigned per iteration, and the carried registers.
+    let (start, stride) = (repeat.start(), repeat.stride());
+    let carries = repeat.carries().iter().map(|register| register.locator()).collect::<Vec<_>>();
+
+    // Unroll each iteration.
+    let result = (0..**repeat.count()).try_for_each(|iteration| {
+        // Shift the registers into the current iteration, and expose its counter as `repeat.index`.
+        registers.set_repeat_iteration(Some((start, stride, carries.clone(), iteration)));
+        // Apply the operation to each body instruction.
+        repeat.instructions().iter().enumerate().try_for_each(|(body_index, instruction)| {
+            operation(iteration, body_index, instruction, registers)
+                .map_err(|error| anyhow!("Failed on iteration {iteration} at ({instruction}): {error}"))
+        })
+    });
+
+    // Clear the current iteration.
+    registers.set_repeat_iteration(None);
+    result
+}
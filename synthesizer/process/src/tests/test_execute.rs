@@ -23,7 +23,7 @@ use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    types::{Field, Scalar, U64},
 };
 use ledger_block::Fee;
 use ledger_query::Query;
@@ -46,7 +46,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -1037,204 +1037,1132 @@ function compute:
     // assert_eq!(159387, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_repeat() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program looping.aleo;
+
+// b + 4a
+closure accumulate:
+    input r0 as field;
+    input r1 as field;
+    repeat 4u32 carry r1:
+        add r1 r0 into r2;
+    end;
+    output r5 as field;
+
+// (a + b) + 3b, followed by the closure on (a, b)
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    repeat 3u32 carry r2:
+        add r2 r1 into r3;
+    end;
+    call accumulate r0 r1 into r6;
+    output r5 as field.private;
+    output r6 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Declare the expected output values.
+    let r5 = Value::from_str("23field").unwrap();
+    let r6 = Value::from_str("17field").unwrap();
+
+    // Compute the output values.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r5, candidate[0]);
+    assert_eq!(r6, candidate[1]);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(r5, candidate[0]);
+    assert_eq!(r6, candidate[1]);
+}
+
+#[test]
+fn test_process_execute_repeat_index() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looping_index.aleo;
+
+// a + (0 + 1 + 2 + 3)
+function sum_index:
+    input r0 as u32.private;
+    repeat 4u32 carry r0:
+        add r0 repeat.index into r1;
+    end;
+    output r4 as u32.private;",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("sum_index").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("5u32").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+
+    // Declare the expected output value.
+    let r4 = Value::from_str("11u32").unwrap();
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(vec![r4.clone()], response.outputs());
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(vec![r4], response.outputs());
+
+    // Ensure the counter cannot be used outside of a `repeat` block.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looping_index.aleo;
+
+function outside:
+    input r0 as u32.private;
+    add r0 repeat.index into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_repeat_carry() {
+    // Initialize a new program, whose block reads the input on every iteration, as it is not carried.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looping_carry.aleo;
+
+// a + 3
+function add_index:
+    input r0 as u32.private;
+    repeat 4u32:
+        add r0 repeat.index into r1;
+    end;
+    output r4 as u32.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("5u32").unwrap();
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "add_index", [r0].iter(), rng).unwrap();
+
+    // Ensure the input is not carried across iterations.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(vec![Value::from_str("8u32").unwrap()], response.outputs());
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(vec![Value::from_str("8u32").unwrap()], response.outputs());
+
+    // Ensure a register that does not precede the block cannot be carried.
+    assert!(Program::<CurrentNetwork>::from_str(
+        r"
+program looping_carry.aleo;
+
+function outside_window:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    repeat 4u32 carry r0:
+        add r0 repeat.index into r2;
+    end;
+    output r5 as u32.private;",
+    )
+    .is_err());
+
+    // Ensure a block that is not aligned with the registers of its scope fails to type check.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looping_carry.aleo;
+
+function misaligned:
+    input r0 as u32.private;
+    repeat 4u32 carry r2:
+        add r2 repeat.index into r3;
+    end;
+    output r6 as u32.private;",
+    )
+    .unwrap();
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure a carried register must match the type of its counterpart in the body.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looping_carry.aleo;
+
+function mismatched:
+    input r0 as u32.private;
+    input r1 as field.private;
+    repeat 4u32 carry r1:
+        add r0 repeat.index into r2;
+    end;
+    output r5 as u32.private;",
+    )
+    .unwrap();
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_vector() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program vectors.aleo;
+
+function append:
+    input r0 as [u8; <=4u32].private;
+    input r1 as u8.private;
+    vec.push r0 r1 into r2;
+    vec.len r2 into r3;
+    vec.get r2 0u32 into r4;
+    output r2 as [u8; <=4u32].private;
+    output r3 as u32.private;
+    output r4 as u8.private;
+
+function get:
+    input r0 as [u8; <=4u32].private;
+    input r1 as u32.private;
+    vec.get r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns a vector of the given elements, padded to a capacity of 4.
+    let vector = |elements: &[u8]| {
+        let mut slots = elements.iter().map(|element| format!("{element}u8")).collect::<Vec<_>>();
+        slots.resize(4, "0u8".to_string());
+        Value::<CurrentNetwork>::from_str(&format!(
+            "{{ len: {}u32, elements: [{}] }}",
+            elements.len(),
+            slots.join(", ")
+        ))
+        .unwrap()
+    };
+
+    // Push onto vectors of every length below the capacity.
+    // Note: The vector is padded to its capacity, so every length is proven and verified with the same keys.
+    let function_name = Identifier::from_str("append").unwrap();
+    for length in 0..4u8 {
+        // Declare the input values.
+        let elements = (1..=length).collect::<Vec<_>>();
+        let r0 = vector(&elements);
+        let r1 = Value::<CurrentNetwork>::from_str("9u8").unwrap();
+
+        // Declare the expected output values.
+        let r2 = vector(&[elements.clone(), vec![9]].concat());
+        let r3 = Value::from_str(&format!("{}u32", length + 1)).unwrap();
+        let r4 = Value::from_str(&format!("{}u8", if length == 0 { 9 } else { 1 })).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(vec![r2.clone(), r3.clone(), r4.clone()], response.outputs());
+
+        // Execute the request.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(vec![r2, r3, r4], response.outputs());
+
+        // Prove and verify the execution.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("vectors", rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+    }
+
+    // Ensure pushing onto a full vector fails.
+    let r0 = vector(&[1, 2, 3, 4]);
+    let r1 = Value::<CurrentNetwork>::from_str("5u8").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+    assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+
+    // Ensure a vector whose unused slots are not the default value is rejected.
+    let r0 = Value::<CurrentNetwork>::from_str("{ len: 1u32, elements: [1u8, 2u8, 0u8, 0u8] }").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u8").unwrap();
+    assert!(process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .is_err());
+
+    // Ensure an element past the length cannot be read, even though its slot exists.
+    let function_name = Identifier::from_str("get").unwrap();
+    for (index, expected) in [(0u32, Some("1u8")), (1, Some("2u8")), (2, None), (3, None)] {
+        let r0 = vector(&[1, 2]);
+        let r1 = Value::<CurrentNetwork>::from_str(&format!("{index}u32")).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        match expected {
+            Some(expected) => {
+                let (response, _) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+                assert_eq!(vec![Value::from_str(expected).unwrap()], response.outputs());
+            }
+            None => {
+                assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+                assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_execute_switch() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program switches.aleo;
+
+function select:
+    input r0 as u8.private;
+    input r1 as field.private;
+    switch r0 1field r1 3field 4field into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("select").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    for (selector, expected) in [("0u8", "1field"), ("1u8", "2field"), ("3u8", "4field")] {
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str(selector).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("2field").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Declare the expected output value.
+        let r2 = Value::from_str(expected).unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(r2, candidate[0]);
+
+        // Execute the request.
+        let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(r2, candidate[0]);
+    }
+
+    // Ensure an out-of-bounds selector fails.
+    let r0 = Value::<CurrentNetwork>::from_str("4u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("2field").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+    assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+
+    // Ensure the cases must be of the same type.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program switches.aleo;
+
+function select:
+    input r0 as u8.private;
+    switch r0 1field 2u8 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_group_operations() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program pedersen.aleo;
+
+function commit:
+    input r0 as scalar.private;
+    input r1 as scalar.private;
+    group.gen 1scalar into r2;
+    hash_to_group.psd2 1field into r3;
+    cast r2 r3 into r4 as [group; 2u32];
+    cast r0 r1 into r5 as [scalar; 2u32];
+    msm r4 r5 into r6;
+    output r6 as group.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("commit").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let value = Scalar::<CurrentNetwork>::rand(rng);
+    let randomizer = Scalar::<CurrentNetwork>::rand(rng);
+    let r0 = Value::<CurrentNetwork>::from(Literal::Scalar(value));
+    let r1 = Value::<CurrentNetwork>::from(Literal::Scalar(randomizer));
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Declare the expected output value, as `value * G + randomizer * H`.
+    let h = CurrentNetwork::hash_to_group_psd2(&[Field::one()]).unwrap();
+    let r6 = Value::from(Literal::Group(CurrentNetwork::g_scalar_multiply(&value) + h * randomizer));
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(r6, candidate[0]);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(r6, candidate[0]);
+
+    // Ensure `msm` requires the same number of groups and scalars.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program pedersen.aleo;
+
+function commit:
+    input r0 as [group; 2u32].private;
+    input r1 as [scalar; 3u32].private;
+    msm r0 r1 into r2;
+    output r2 as group.private;",
+    )
+    .unwrap();
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_string() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r#"
+program strings.aleo;
+
+function greet:
+    input r0 as string.private;
+    string.concat "hello " r0 into r1;
+    string.len r1 into r2;
+    string.get r1 6u32 into r3;
+    string.to_bytes r1 into r4 as [u8; 16u32];
+    string.from_bytes r4 into r5;
+    is.eq r1 r5 into r6;
+    output r1 as string.private;
+    output r2 as u32.private;
+    output r3 as u8.private;
+    output r6 as boolean.private;"#,
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("greet").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("\"aleo\"").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Declare the expected output values.
+    let expected = [
+        Value::from_str("\"hello aleo\"").unwrap(),
+        Value::from_str("10u32").unwrap(),
+        Value::from_str("97u8").unwrap(),
+        Value::from_str("true").unwrap(),
+    ];
+
+    // Compute the output values.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(expected.as_slice(), response.outputs());
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(expected.as_slice(), response.outputs());
+}
+
 #[test]
 fn test_process_execute_call_external_function() {
     // Initialize a new program.
-    let (string, program0) = Program::<CurrentNetwork>::parse(
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function produce_magic_number:
+    add 1234u64 0u64 into r0;
+    output r0 as u64.private;
+
+function check_magic_number:
+    input r0 as u64.private;
+    assert.eq r0 1234u64;
+
+function noop:
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function transfer:
+    input r0 as token.aleo/token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    call token.aleo/noop;
+    call token.aleo/produce_magic_number into r3;
+    call token.aleo/check_magic_number r3;
+    call token.aleo/transfer r0 r1 r2 into r4 r5;
+    output r4 as token.aleo/token.record;
+    output r5 as token.aleo/token.record;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller 0.
+    let caller0_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller0 = Address::try_from(&caller0_private_key).unwrap();
+
+    // Initialize caller 1.
+    let caller1_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller1 = Address::try_from(&caller1_private_key).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller0}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str(&caller1.to_string()).unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("99u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller0_private_key, program1.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 5);
+    println!("\nAuthorize\n{:#?}\n\n", authorization.to_vec_deque());
+
+    let (output_a, output_b) = {
+        // Fetch the first request.
+        let request = authorization.to_vec_deque().pop_back().unwrap();
+
+        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+        let randomizer_a = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(4)]).unwrap();
+        let nonce_a = CurrentNetwork::g_scalar_multiply(&randomizer_a);
+
+        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+        let randomizer_b = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(5)]).unwrap();
+        let nonce_b = CurrentNetwork::g_scalar_multiply(&randomizer_b);
+
+        // Declare the expected output value.
+        let output_a = Value::from_str(&format!(
+            "{{ owner: {caller1}.private, amount: 99u64.private, _nonce: {nonce_a}.public }}"
+        ))
+        .unwrap();
+        let output_b =
+            Value::from_str(&format!("{{ owner: {caller0}.private, amount: 1u64.private, _nonce: {nonce_b}.public }}"))
+                .unwrap();
+
+        (output_a, output_b)
+    };
+
+    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
+    assert_eq!(authorization.len(), 5);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(output_a, candidate[0]);
+    assert_eq!(output_b, candidate[1]);
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 5);
+
+    // Execute the request.
+    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(2, candidate.len());
+    assert_eq!(output_a, candidate[0]);
+    assert_eq!(output_b, candidate[1]);
+
+    // use circuit::Environment;
+    //
+    // assert_eq!(6427, CurrentAleo::num_constants());
+    // assert_eq!(8, CurrentAleo::num_public());
+    // assert_eq!(21264, CurrentAleo::num_private());
+    // assert_eq!(21279, CurrentAleo::num_constraints());
+    // assert_eq!(81872, CurrentAleo::num_gates());
+    //
+    // assert_eq!(18504, CurrentAleo::num_constants());
+    // assert_eq!(17, CurrentAleo::num_public());
+    // assert_eq!(58791, CurrentAleo::num_private());
+    // assert_eq!(58855, CurrentAleo::num_constraints());
+    // assert_eq!(215810, CurrentAleo::num_gates());
+}
+
+#[test]
+fn test_process_execute_and_finalize_get_add_set() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program token.aleo;
+program testing.aleo;
 
-record token:
-    owner as address.private;
-    amount as u64.private;
+mapping account:
+    key as address.public;
+    value as u64.public;
 
-function mint:
-    input r0 as address.private;
-    input r1 as u64.private;
-    cast r0 r1 into r2 as token.record;
-    output r2 as token.record;
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
 
-function produce_magic_number:
-    add 1234u64 0u64 into r0;
-    output r0 as u64.private;
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-function check_magic_number:
-    input r0 as u64.private;
-    assert.eq r0 1234u64;
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
 
-function noop:
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
 
-function transfer:
-    input r0 as token.record;
-    input r1 as address.private;
-    input r2 as u64.private;
-    sub r0.amount r2 into r3;
-    cast r1 r2 into r4 as token.record;
-    cast r0.owner r3 into r5 as token.record;
-    output r4 as token.record;
-    output r5 as token.record;",
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the account balance is now 8.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    sub r3 r1 into r4;
+    set r4 into account[r0];
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
-    // Initialize another program.
-    let (string, program1) = Program::<CurrentNetwork>::parse(
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+    // Reset the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Compute the output value.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check that the account balance is now 0.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("0u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_mint_public() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-import token.aleo;
+program token.aleo;
+
+// On-chain storage of an `account` map, with `owner` as the key,
+// and `amount` as the value.
+mapping account:
+    // The token owner.
+    key as address.public;
+    // The token amount.
+    value as u64.public;
 
-program wallet.aleo;
+// The function `mint_public` issues the specified token amount
+// for the token receiver publicly on the network.
+function mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+    // Mint the tokens publicly.
+    async mint_public r0 r1 into r2;
+    output r2 as token.aleo/mint_public.future;
 
-function transfer:
-    input r0 as token.aleo/token.record;
-    input r1 as address.private;
-    input r2 as u64.private;
-    call token.aleo/noop;
-    call token.aleo/produce_magic_number into r3;
-    call token.aleo/check_magic_number r3;
-    call token.aleo/transfer r0 r1 r2 into r4 r5;
-    output r4 as token.aleo/token.record;
-    output r5 as token.aleo/token.record;",
+// The finalize scope of `mint_public` increments the
+// `account` of the token receiver by the specified amount.
+finalize mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+
+    // Get `account[r0]` into `r2`, defaulting to 0u64 if the entry does not exist.
+    get.or_use account[r0] 0u64 into r2;
+    // Add `r1` to `r2`. If the operation overflows, `mint_public` is reverted.
+    add r2 r1 into r3;
+    // Set `r3` into `account[r0]`.
+    set r3 into account[r0];
+",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Add the program to the process.
-    process.add_program(&program1).unwrap();
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the mapping.
+    let mapping_name = Identifier::from_str("account").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("mint_public").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
-    // Initialize caller 0.
-    let caller0_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let caller0 = Address::try_from(&caller0_private_key).unwrap();
-
-    // Initialize caller 1.
-    let caller1_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let caller1 = Address::try_from(&caller1_private_key).unwrap();
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Check that the circuit key can be synthesized.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
-    // Declare the function name.
-    let function_name = Identifier::from_str("transfer").unwrap();
+    // Reset the process.
+    let mut process = Process::load().unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str(&format!(
-        "{{ owner: {caller0}.private, amount: 100u64.private, _nonce: 0group.public }}"
-    ))
-    .unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str(&caller1.to_string()).unwrap();
-    let r2 = Value::<CurrentNetwork>::from_str("99u64").unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
 
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller0_private_key, program1.id(), function_name, [r0, r1, r2].iter(), rng)
-        .unwrap();
-    assert_eq!(authorization.len(), 5);
-    println!("\nAuthorize\n{:#?}\n\n", authorization.to_vec_deque());
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
 
-    let (output_a, output_b) = {
-        // Fetch the first request.
-        let request = authorization.to_vec_deque().pop_back().unwrap();
+    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
+    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
+    //  the correctness of the synthesizer.
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
-        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-        let randomizer_a = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(4)]).unwrap();
-        let nonce_a = CurrentNetwork::g_scalar_multiply(&randomizer_a);
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
 
-        // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
-        let randomizer_b = CurrentNetwork::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(5)]).unwrap();
-        let nonce_b = CurrentNetwork::g_scalar_multiply(&randomizer_b);
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
 
-        // Declare the expected output value.
-        let output_a = Value::from_str(&format!(
-            "{{ owner: {caller1}.private, amount: 99u64.private, _nonce: {nonce_a}.public }}"
-        ))
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
         .unwrap();
-        let output_b =
-            Value::from_str(&format!("{{ owner: {caller0}.private, amount: 1u64.private, _nonce: {nonce_b}.public }}"))
-                .unwrap();
-
-        (output_a, output_b)
-    };
-
-    // Check again to make sure we didn't modify the authorization before calling `evaluate`.
-    assert_eq!(authorization.len(), 5);
+    assert_eq!(authorization.len(), 1);
 
     // Compute the output value.
     let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
     let candidate = response.outputs();
-    assert_eq!(2, candidate.len());
-    assert_eq!(output_a, candidate[0]);
-    assert_eq!(output_b, candidate[1]);
+    assert_eq!(1, candidate.len());
 
     // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 5);
+    assert_eq!(authorization.len(), 1);
 
     // Execute the request.
-    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
     let candidate = response.outputs();
-    assert_eq!(2, candidate.len());
-    assert_eq!(output_a, candidate[0]);
-    assert_eq!(output_b, candidate[1]);
+    assert_eq!(1, candidate.len());
 
-    // use circuit::Environment;
-    //
-    // assert_eq!(6427, CurrentAleo::num_constants());
-    // assert_eq!(8, CurrentAleo::num_public());
-    // assert_eq!(21264, CurrentAleo::num_private());
-    // assert_eq!(21279, CurrentAleo::num_constraints());
-    // assert_eq!(81872, CurrentAleo::num_gates());
-    //
-    // assert_eq!(18504, CurrentAleo::num_constants());
-    // assert_eq!(17, CurrentAleo::num_public());
-    // assert_eq!(58791, CurrentAleo::num_private());
-    // assert_eq!(58855, CurrentAleo::num_constraints());
-    // assert_eq!(215810, CurrentAleo::num_gates());
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("token", rng).unwrap();
+
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Now, finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+
+    // Check the account balance.
+    let candidate = finalize_store
+        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .unwrap()
+        .unwrap();
+    assert_eq!(candidate, Value::from_str("3u64").unwrap());
 }
 
 #[test]
-fn test_process_execute_and_finalize_get_add_set() {
+fn test_process_execute_call_mint_public() {
     // Initialize a new program.
-    let (string, program) = Program::<CurrentNetwork>::parse(
+    let (string, program0) = Program::<CurrentNetwork>::parse(
         r"
-program testing.aleo;
+program token.aleo;
 
+// On-chain storage of an `account` map, with `owner` as the key,
+// and `amount` as the value.
 mapping account:
+    // The token owner.
     key as address.public;
+    // The token amount.
     value as u64.public;
 
-function compute:
+// The function `mint_public` issues the specified token amount
+// for the token receiver publicly on the network.
+function mint_public:
+    // Input the token receiver.
     input r0 as address.public;
+    // Input the token amount.
     input r1 as u64.public;
-    input r2 as u64.public;
-    add r1 r2 into r3;
-    async compute r0 r3 into r4;
-    output r4 as testing.aleo/compute.future;
+    // Mint the tokens publicly.
+    async mint_public r0 r1 into r2;
+    output r2 as token.aleo/mint_public.future;
 
-finalize compute:
+// The finalize scope of `mint_public` increments the
+// `account` of the token receiver by the specified amount.
+finalize mint_public:
+    // Input the token receiver.
     input r0 as address.public;
+    // Input the token amount.
     input r1 as u64.public;
+
+    // Get `account[r0]` into `r2`, defaulting to 0u64 if the entry does not exist.
     get.or_use account[r0] 0u64 into r2;
+    // Add `r1` to `r2`. If the operation overflows, `mint_public` is reverted.
     add r2 r1 into r3;
+    // Set `r3` into `account[r0]`.
     set r3 into account[r0];
 ",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Declare the program ID.
-    let program_id = program.id();
     // Declare the mapping.
     let mapping_name = Identifier::from_str("account").unwrap();
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("mint_public").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
+    let process = crate::test_helpers::sample_process(&program0);
     // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
 
     // Reset the process.
     let mut process = Process::load().unwrap();
@@ -1245,7 +2173,7 @@ finalize compute:
     let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
 
     // Add the program to the process.
-    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    let deployment = process.deploy::<CurrentAleo, _>(&program0, rng).unwrap();
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Compute the fee.
@@ -1255,21 +2183,64 @@ finalize compute:
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
-    // Initialize a new caller account.
+    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
+    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
+    //  the correctness of the synthesizer.
+    process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program public_wallet.aleo;
+
+function init:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call token.aleo/mint_public r0 r1 into r2;
+    async init r2 into r3;
+    output r3 as public_wallet.aleo/init.future;
+finalize init:
+    input r0 as token.aleo/mint_public.future;
+    await r0;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("init").unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program1, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack);
+
+    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
+    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
+    //  the correctness of the synthesizer.
+    process.synthesize_key::<CurrentAleo, _>(program1.id(), &function_name, rng).unwrap();
+
+    // Initialize caller.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
     let caller = Address::try_from(&caller_private_key).unwrap();
 
     // Declare the input value.
     let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
-    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
 
     // Authorize the function call.
     let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
         .unwrap();
-    assert_eq!(authorization.len(), 1);
+    assert_eq!(authorization.len(), 2);
 
     // Compute the output value.
     let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
@@ -1277,7 +2248,7 @@ finalize compute:
     assert_eq!(1, candidate.len());
 
     // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
+    assert_eq!(authorization.len(), 2);
 
     // Execute the request.
     let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
@@ -1287,7 +2258,7 @@ finalize compute:
     // Prepare the trace.
     trace.prepare(Query::from(block_store)).unwrap();
     // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("public_wallet", rng).unwrap();
 
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
@@ -1295,16 +2266,16 @@ finalize compute:
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
 
-    // Check that the account balance is now 8.
+    // Check the account balance.
     let candidate = finalize_store
-        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .get_value_speculative(*program0.id(), mapping_name, &Plaintext::from(Literal::Address(caller)))
         .unwrap()
         .unwrap();
-    assert_eq!(candidate, Value::from_str("8u64").unwrap());
+    assert_eq!(candidate, Value::from_str("100u64").unwrap());
 }
 
 #[test]
-fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
+fn test_process_execute_and_finalize_get_set() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
@@ -1326,9 +2297,11 @@ finalize compute:
     input r0 as address.public;
     input r1 as u64.public;
     get.or_use account[r0] 0u64 into r2;
-    add r2 r1 into r3;
-    sub r3 r1 into r4;
-    set r4 into account[r0];
+    add r1 r2 into r3;
+    set r3 into account[r0];
+    get account[r0] into r4;
+    add r1 r4 into r5;
+    set r5 into account[r0];
 ",
     )
     .unwrap();
@@ -1408,75 +2381,79 @@ finalize compute:
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
 
-    // Check that the account balance is now 0.
+    // Check that the account balance is now 8.
     let candidate = finalize_store
         .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
         .unwrap()
         .unwrap();
-    assert_eq!(candidate, Value::from_str("0u64").unwrap());
+    assert_eq!(candidate, Value::from_str("16u64").unwrap());
 }
 
 #[test]
-fn test_process_execute_mint_public() {
-    // Initialize a new program.
-    let (string, program) = Program::<CurrentNetwork>::parse(
+fn test_process_execute_and_finalize_read_external_mapping() {
+    // Initialize the program that owns the mapping.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
         r"
 program token.aleo;
 
-// On-chain storage of an `account` map, with `owner` as the key,
-// and `amount` as the value.
 mapping account:
-    // The token owner.
     key as address.public;
-    // The token amount.
     value as u64.public;
 
-// The function `mint_public` issues the specified token amount
-// for the token receiver publicly on the network.
-function mint_public:
-    // Input the token receiver.
-    input r0 as address.public;
-    // Input the token amount.
-    input r1 as u64.public;
-    // Mint the tokens publicly.
-    async mint_public r0 r1 into r2;
-    output r2 as token.aleo/mint_public.future;
+function noop:
+    input r0 as u64.public;
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-// The finalize scope of `mint_public` increments the
-// `account` of the token receiver by the specified amount.
-finalize mint_public:
-    // Input the token receiver.
+    // Initialize the program that reads the mapping.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import token.aleo;
+
+program reader.aleo;
+
+mapping found:
+    key as address.public;
+    value as boolean.public;
+
+mapping count:
+    key as u8.public;
+    value as u32.public;
+
+mapping balance:
+    key as address.public;
+    value as u64.public;
+
+function check:
     input r0 as address.public;
-    // Input the token amount.
-    input r1 as u64.public;
+    async check r0 into r1;
+    output r1 as reader.aleo/check.future;
 
-    // Get `account[r0]` into `r2`, defaulting to 0u64 if the entry does not exist.
-    get.or_use account[r0] 0u64 into r2;
-    // Add `r1` to `r2`. If the operation overflows, `mint_public` is reverted.
-    add r2 r1 into r3;
-    // Set `r3` into `account[r0]`.
-    set r3 into account[r0];
+finalize check:
+    input r0 as address.public;
+    contains token.aleo/account[r0] into r1;
+    set r1 into found[r0];
+    mapping.length token.aleo/account into r2;
+    set r2 into count[0u8];
+    get token.aleo/account[r0] into r3;
+    set r3 into balance[r0];
+    mapping.iterate token.aleo/account from 0u32 limit 4u32 into r4 r5;
+    vec.len r5 into r6;
+    set r6 into count[1u8];
 ",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Declare the program ID.
-    let program_id = program.id();
-    // Declare the mapping.
-    let mapping_name = Identifier::from_str("account").unwrap();
     // Declare the function name.
-    let function_name = Identifier::from_str("mint_public").unwrap();
+    let function_name = Identifier::from_str("check").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
-
-    // Reset the process.
     let mut process = Process::load().unwrap();
 
     // Initialize a new block store.
@@ -1484,128 +2461,132 @@ finalize mint_public:
     // Initialize a new finalize store.
     let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
 
-    // Add the program to the process.
-    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
-    // Check that the deployment verifies.
-    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
-    // Compute the fee.
-    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
-    // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
-    // Add the stack *manually* to the process.
-    process.add_stack(stack);
-
-    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
-    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
-    //  the correctness of the synthesizer.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    // Deploy both programs.
+    for (height, program) in [(1, &program0), (2, &program1)] {
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Compute the fee.
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        // Finalize the deployment.
+        let (stack, _) =
+            process.finalize_deployment(sample_finalize_state(height), &finalize_store, &deployment, &fee).unwrap();
+        // Add the stack *manually* to the process.
+        process.add_stack(stack);
+    }
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
     let caller = Address::try_from(&caller_private_key).unwrap();
+    // Initialize another account.
+    let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
 
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+    // Populate the external mapping.
+    let account_mapping = Identifier::from_str("account").unwrap();
+    for (address, amount) in [(caller, "50u64"), (other, "7u64")] {
+        let key = Plaintext::from(Literal::Address(address));
+        finalize_store
+            .update_key_value(*program0.id(), account_mapping, key, Value::from_str(amount).unwrap())
+            .unwrap();
+    }
 
     // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
     let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0].iter(), rng)
         .unwrap();
     assert_eq!(authorization.len(), 1);
 
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
-
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
-
     // Execute the request.
     let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    assert_eq!(1, response.outputs().len());
 
     // Prepare the trace.
     trace.prepare(Query::from(block_store)).unwrap();
     // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("token", rng).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("reader", rng).unwrap();
 
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
 
     // Now, finalize the execution.
-    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    process.finalize_execution(sample_finalize_state(3), &finalize_store, &execution, None).unwrap();
 
-    // Check the account balance.
-    let candidate = finalize_store
-        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
-        .unwrap()
-        .unwrap();
-    assert_eq!(candidate, Value::from_str("3u64").unwrap());
+    // Check the values read from the external mapping.
+    let get = |mapping: &str, key: Plaintext<CurrentNetwork>| {
+        let mapping_name = Identifier::from_str(mapping).unwrap();
+        finalize_store.get_value_speculative(*program1.id(), mapping_name, &key).unwrap().unwrap()
+    };
+    let caller_key = Plaintext::from(Literal::Address(caller));
+    assert_eq!(get("found", caller_key.clone()), Value::from_str("true").unwrap());
+    assert_eq!(get("balance", caller_key), Value::from_str("50u64").unwrap());
+    assert_eq!(get("count", Plaintext::from_str("0u8").unwrap()), Value::from_str("2u32").unwrap());
+    assert_eq!(get("count", Plaintext::from_str("1u8").unwrap()), Value::from_str("2u32").unwrap());
+
+    // Ensure the external mapping is unchanged.
+    assert_eq!(finalize_store.get_mapping_length_speculative(*program0.id(), account_mapping).unwrap(), 2);
 }
 
 #[test]
-fn test_process_execute_call_mint_public() {
+fn test_process_execute_and_finalize_mapping_iterate_and_length() {
     // Initialize a new program.
-    let (string, program0) = Program::<CurrentNetwork>::parse(
+    let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program token.aleo;
+program paging.aleo;
 
-// On-chain storage of an `account` map, with `owner` as the key,
-// and `amount` as the value.
 mapping account:
-    // The token owner.
-    key as address.public;
-    // The token amount.
+    key as u8.public;
     value as u64.public;
 
-// The function `mint_public` issues the specified token amount
-// for the token receiver publicly on the network.
-function mint_public:
-    // Input the token receiver.
-    input r0 as address.public;
-    // Input the token amount.
+mapping total:
+    key as u32.public;
+    value as u32.public;
+
+mapping pages:
+    key as u32.public;
+    value as [u64; <=2u32].public;
+
+function deposit:
+    input r0 as u8.public;
     input r1 as u64.public;
-    // Mint the tokens publicly.
-    async mint_public r0 r1 into r2;
-    output r2 as token.aleo/mint_public.future;
+    async deposit r0 r1 into r2;
+    output r2 as paging.aleo/deposit.future;
 
-// The finalize scope of `mint_public` increments the
-// `account` of the token receiver by the specified amount.
-finalize mint_public:
-    // Input the token receiver.
-    input r0 as address.public;
-    // Input the token amount.
+finalize deposit:
+    input r0 as u8.public;
     input r1 as u64.public;
+    set r1 into account[r0];
 
-    // Get `account[r0]` into `r2`, defaulting to 0u64 if the entry does not exist.
-    get.or_use account[r0] 0u64 into r2;
-    // Add `r1` to `r2`. If the operation overflows, `mint_public` is reverted.
-    add r2 r1 into r3;
-    // Set `r3` into `account[r0]`.
-    set r3 into account[r0];
+function withdraw:
+    input r0 as u8.public;
+    async withdraw r0 into r1;
+    output r1 as paging.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as u8.public;
+    remove account[r0];
+
+function page:
+    input r0 as u32.public;
+    async page r0 into r1;
+    output r1 as paging.aleo/page.future;
+
+finalize page:
+    input r0 as u32.public;
+    mapping.length account into r1;
+    set r1 into total[r0];
+    mapping.iterate account from r0 limit 2u32 into r2 r3;
+    set r3 into pages[r0];
 ",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Declare the mapping.
-    let mapping_name = Identifier::from_str("account").unwrap();
-    // Declare the function name.
-    let function_name = Identifier::from_str("mint_public").unwrap();
-
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program0);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
-
-    // Reset the process.
     let mut process = Process::load().unwrap();
 
     // Initialize a new block store.
@@ -1614,7 +2595,7 @@ finalize mint_public:
     let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
 
     // Add the program to the process.
-    let deployment = process.deploy::<CurrentAleo, _>(&program0, rng).unwrap();
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
     // Check that the deployment verifies.
     process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
     // Compute the fee.
@@ -1622,148 +2603,107 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
-
-    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
-    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
-    //  the correctness of the synthesizer.
-    process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
-
-    // Initialize another program.
-    let (string, program1) = Program::<CurrentNetwork>::parse(
-        r"
-import token.aleo;
-
-program public_wallet.aleo;
-
-function init:
-    input r0 as address.public;
-    input r1 as u64.public;
-    call token.aleo/mint_public r0 r1 into r2;
-    async init r2 into r3;
-    output r3 as public_wallet.aleo/init.future;
-finalize init:
-    input r0 as token.aleo/mint_public.future;
-    await r0;
-",
-    )
-    .unwrap();
-    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-
-    // Declare the function name.
-    let function_name = Identifier::from_str("init").unwrap();
-
-    // Add the program to the process.
-    let deployment = process.deploy::<CurrentAleo, _>(&program1, rng).unwrap();
-    // Check that the deployment verifies.
-    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
-    // Compute the fee.
-    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
-    // Finalize the deployment.
-    let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
-    // Add the stack *manually* to the process.
-    process.add_stack(stack);
-
-    // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
-    //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
-    //  the correctness of the synthesizer.
-    process.synthesize_key::<CurrentAleo, _>(program1.id(), &function_name, rng).unwrap();
-
-    // Initialize caller.
-    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let caller = Address::try_from(&caller_private_key).unwrap();
-
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
-
-    // Authorize the function call.
-    let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
-        .unwrap();
-    assert_eq!(authorization.len(), 2);
-
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
-
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 2);
+    process.add_stack(stack);
 
-    // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
 
-    // Prepare the trace.
-    trace.prepare(Query::from(block_store)).unwrap();
-    // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("public_wallet", rng).unwrap();
+    // Executes and finalizes the given function, with the given inputs.
+    let mut height = 1;
+    let mut run = |function: &str, inputs: &[&str]| {
+        // Authorize the function call.
+        let inputs = inputs.iter().map(|input| Value::<CurrentNetwork>::from_str(input).unwrap()).collect::<Vec<_>>();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function, inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>("paging", rng).unwrap();
+        // Verify the execution.
+        process.verify_execution(&execution).unwrap();
+        // Finalize the execution.
+        height += 1;
+        process.finalize_execution(sample_finalize_state(height), &finalize_store, &execution, None).unwrap();
+    };
 
-    // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    // Returns the value stored in the given mapping at the given page.
+    let get = |mapping: &str, start: u32| {
+        let mapping_name = Identifier::from_str(mapping).unwrap();
+        let key = Plaintext::from_str(&format!("{start}u32")).unwrap();
+        finalize_store.get_value_speculative(*program.id(), mapping_name, &key).unwrap().unwrap()
+    };
 
-    // Now, finalize the execution.
-    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    // Populate the mapping.
+    for (key, amount) in [("1u8", "10u64"), ("2u8", "20u64"), ("3u8", "30u64")] {
+        run("deposit", &[key, amount]);
+    }
+    // Update an existing entry, which must not change its position.
+    run("deposit", &["2u8", "25u64"]);
 
-    // Check the account balance.
-    let candidate = finalize_store
-        .get_value_speculative(*program0.id(), mapping_name, &Plaintext::from(Literal::Address(caller)))
-        .unwrap()
-        .unwrap();
-    assert_eq!(candidate, Value::from_str("100u64").unwrap());
+    // Read the mapping in pages of 2 entries, including a page past the end.
+    for start in ["0u32", "2u32", "4u32"] {
+        run("page", &[start]);
+    }
+    assert_eq!(get("total", 0), Value::from_str("3u32").unwrap());
+    assert_eq!(get("pages", 0), Value::from_str("{ len: 2u32, elements: [10u64, 25u64] }").unwrap());
+    assert_eq!(get("pages", 2), Value::from_str("{ len: 1u32, elements: [30u64, 0u64] }").unwrap());
+    assert_eq!(get("pages", 4), Value::from_str("{ len: 0u32, elements: [0u64, 0u64] }").unwrap());
+
+    // Remove the first entry, which moves the last entry into its position.
+    run("withdraw", &["1u8"]);
+    run("page", &["1u32"]);
+    assert_eq!(get("total", 1), Value::from_str("2u32").unwrap());
+    assert_eq!(get("pages", 1), Value::from_str("{ len: 1u32, elements: [25u64, 0u64] }").unwrap());
+
+    // Ensure the entries read through the store match.
+    let account = Identifier::from_str("account").unwrap();
+    assert_eq!(finalize_store.get_mapping_length_speculative(*program.id(), account).unwrap(), 2);
+    let entries = finalize_store.get_mapping_entries_speculative(*program.id(), account, 0, 2).unwrap();
+    assert_eq!(entries, vec![
+        (Plaintext::from_str("3u8").unwrap(), Value::from_str("30u64").unwrap()),
+        (Plaintext::from_str("2u8").unwrap(), Value::from_str("25u64").unwrap()),
+    ]);
 }
 
 #[test]
-fn test_process_execute_and_finalize_get_set() {
+fn test_process_execute_and_finalize_block_operands() {
     // Initialize a new program.
     let (string, program) = Program::<CurrentNetwork>::parse(
         r"
-program testing.aleo;
+program block_info.aleo;
 
-mapping account:
-    key as address.public;
-    value as u64.public;
+struct info:
+    height as u32;
+    timestamp as i64;
+    round as u64;
+    previous_hash as field;
 
-function compute:
-    input r0 as address.public;
-    input r1 as u64.public;
-    input r2 as u64.public;
-    add r1 r2 into r3;
-    async compute r0 r3 into r4;
-    output r4 as testing.aleo/compute.future;
+mapping latest:
+    key as boolean.public;
+    value as info.public;
 
-finalize compute:
-    input r0 as address.public;
-    input r1 as u64.public;
-    get.or_use account[r0] 0u64 into r2;
-    add r1 r2 into r3;
-    set r3 into account[r0];
-    get account[r0] into r4;
-    add r1 r4 into r5;
-    set r5 into account[r0];
+function record_block:
+    async record_block into r0;
+    output r0 as block_info.aleo/record_block.future;
+
+finalize record_block:
+    cast block.height block.timestamp block.round block.previous_hash into r0 as info;
+    set r0 into latest[true];
 ",
     )
     .unwrap();
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-    // Declare the program ID.
-    let program_id = program.id();
-    // Declare the mapping.
-    let mapping_name = Identifier::from_str("account").unwrap();
     // Declare the function name.
-    let function_name = Identifier::from_str("compute").unwrap();
+    let function_name = Identifier::from_str("record_block").unwrap();
 
     // Initialize the RNG.
     let rng = &mut TestRng::default();
 
     // Construct the process.
-    let process = crate::test_helpers::sample_process(&program);
-    // Check that the circuit key can be synthesized.
-    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
-
-    // Reset the process.
     let mut process = Process::load().unwrap();
 
     // Initialize a new block store.
@@ -1782,52 +2722,43 @@ finalize compute:
     // Add the stack *manually* to the process.
     process.add_stack(stack);
 
-    // Initialize a new caller account.
-    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-    let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-    let caller = Address::try_from(&caller_private_key).unwrap();
-
-    // Declare the input value.
-    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-    let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
-    let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
-
     // Authorize the function call.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs: [Value<CurrentNetwork>; 0] = [];
     let authorization = process
-        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
         .unwrap();
-    assert_eq!(authorization.len(), 1);
-
-    // Compute the output value.
-    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
-
-    // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-    assert_eq!(authorization.len(), 1);
 
     // Execute the request.
-    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-    let candidate = response.outputs();
-    assert_eq!(1, candidate.len());
-
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
     // Prepare the trace.
     trace.prepare(Query::from(block_store)).unwrap();
     // Prove the execution.
-    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
-
+    let execution = trace.prove_execution::<CurrentAleo, _>("block_info", rng).unwrap();
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
 
-    // Now, finalize the execution.
-    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+    // Finalize the execution, with a given block timestamp, round, and previous block hash.
+    let previous_hash = Field::<CurrentNetwork>::from_u64(12345);
+    let mut previous_hash_bytes = [0u8; 32];
+    previous_hash_bytes.copy_from_slice(&previous_hash.to_bytes_le().unwrap());
+    let state = FinalizeGlobalState::from(7, 2, 1_700_000_000, previous_hash_bytes, [0u8; 32]);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
 
-    // Check that the account balance is now 8.
+    // Check the recorded block information.
     let candidate = finalize_store
-        .get_value_speculative(*program_id, mapping_name, &Plaintext::from(Literal::Address(caller)))
+        .get_value_speculative(
+            *program.id(),
+            Identifier::from_str("latest").unwrap(),
+            &Plaintext::from_str("true").unwrap(),
+        )
         .unwrap()
         .unwrap();
-    assert_eq!(candidate, Value::from_str("16u64").unwrap());
+    let expected = Value::from_str(&format!(
+        "{{ height: 2u32, timestamp: 1700000000i64, round: 7u64, previous_hash: {previous_hash} }}"
+    ))
+    .unwrap();
+    assert_eq!(candidate, expected);
 }
 
 #[test]
@@ -2483,3 +3414,341 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_evaluate_with_debugger() {
+    use crate::Debugger;
+    use console::program::Locator;
+    use synthesizer_program::Instruction;
+
+    /// A debugger that records the register values after each instruction.
+    #[derive(Default)]
+    struct RecordingDebugger {
+        steps: parking_lot::Mutex<Vec<(Locator<CurrentNetwork>, usize, IndexMap<u64, Value<CurrentNetwork>>)>>,
+    }
+
+    impl Debugger<CurrentNetwork> for RecordingDebugger {
+        fn after_instruction(
+            &self,
+            locator: &Locator<CurrentNetwork>,
+            index: usize,
+            _instruction: &Instruction<CurrentNetwork>,
+            registers: &IndexMap<u64, Value<CurrentNetwork>>,
+        ) {
+            self.steps.lock().push((*locator, index, registers.clone()));
+        }
+    }
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program debug.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as field.public;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Set the debugger.
+    let debugger = Arc::new(RecordingDebugger::default());
+    process.set_debugger(Some(debugger.clone()));
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str("3field").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5field").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compute", [r0, r1].iter(), rng)
+        .unwrap();
+    // Evaluate the function call.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("40field").unwrap()]);
+
+    // Ensure the debugger observed each instruction, along with the registers.
+    let steps = debugger.steps.lock();
+    let locator = Locator::from_str("debug.aleo/compute").unwrap();
+    assert_eq!(steps.len(), 2);
+    assert_eq!(steps[0].0, locator);
+    assert_eq!(steps[0].1, 0);
+    assert_eq!(steps[0].2.get(&2), Some(&Value::from_str("8field").unwrap()));
+    assert_eq!(steps[1].0, locator);
+    assert_eq!(steps[1].1, 1);
+    assert_eq!(steps[1].2.get(&3), Some(&Value::from_str("40field").unwrap()));
+}
+
+#[test]
+fn test_process_evaluate_with_debugger_in_repeat() {
+    use crate::Debugger;
+    use console::program::Locator;
+    use synthesizer_program::Instruction;
+
+    /// A debugger that records the position and register values after each unrolled instruction.
+    #[derive(Default)]
+    struct RecordingDebugger {
+        steps: parking_lot::Mutex<Vec<(usize, u32, usize, IndexMap<u64, Value<CurrentNetwork>>)>>,
+    }
+
+    impl Debugger<CurrentNetwork> for RecordingDebugger {
+        fn after_repeat_instruction(
+            &self,
+            _locator: &Locator<CurrentNetwork>,
+            index: usize,
+            iteration: u32,
+            body_index: usize,
+            _instruction: &Instruction<CurrentNetwork>,
+            registers: &IndexMap<u64, Value<CurrentNetwork>>,
+        ) {
+            self.steps.lock().push((index, iteration, body_index, registers.clone()));
+        }
+    }
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program debug_repeat.aleo;
+
+function sum_index:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    repeat 2u32 carry r1:
+        add r1 repeat.index into r2;
+        mul r2 2u32 into r3;
+    end;
+    output r5 as u32.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Set the debugger.
+    let debugger = Arc::new(RecordingDebugger::default());
+    process.set_debugger(Some(debugger.clone()));
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str("1u32").unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "sum_index", [r0].iter(), rng)
+        .unwrap();
+    // Evaluate the function call.
+    let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), &[Value::from_str("10u32").unwrap()]);
+
+    // Ensure the debugger observed each unrolled instruction, at its shifted register.
+    let steps = debugger.steps.lock();
+    let positions = steps.iter().map(|(index, iteration, body_index, _)| (*index, *iteration, *body_index));
+    assert_eq!(positions.collect::<Vec<_>>(), vec![(1, 0, 0), (1, 0, 1), (1, 1, 0), (1, 1, 1)]);
+    assert_eq!(steps[0].3.get(&2), Some(&Value::from_str("2u32").unwrap()));
+    assert_eq!(steps[1].3.get(&3), Some(&Value::from_str("4u32").unwrap()));
+    assert_eq!(steps[2].3.get(&4), Some(&Value::from_str("5u32").unwrap()));
+    assert_eq!(steps[3].3.get(&5), Some(&Value::from_str("10u32").unwrap()));
+}
+
+#[test]
+fn test_process_execute_assertion_error() {
+    use crate::ExecutionError;
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program assertion.aleo;
+
+function compare:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    assert.eq r0 r1;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call, with unequal inputs.
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap(), Value::from_str("2u8").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "compare", inputs.iter(), rng).unwrap();
+
+    // Ensure the execution fails with a typed error, at the assertion.
+    let error = process.execute::<CurrentAleo, _>(authorization, rng).unwrap_err();
+    assert!(matches!(error, ExecutionError::AssertionFailed(..)), "Unexpected error: {error:?}");
+    let location = error.location().unwrap();
+    assert_eq!(location.program_id(), program.id());
+    assert_eq!(location.function_name(), &Identifier::from_str("compare").unwrap());
+    assert!(!location.is_finalize());
+    assert_eq!(location.index(), 1);
+    assert_eq!(*location.opcode(), "assert.eq");
+    assert!(error.to_string().starts_with("Failed to evaluate instruction (assert.eq r0 r1;)"));
+    // Ensure the error keeps the failure raised by the assertion as its source.
+    let source = std::error::Error::source(&error).unwrap();
+    assert!(source.is::<synthesizer_program::Failure>(), "Unexpected source: {source:?}");
+
+    // Ensure the error converts into (and is recoverable from) an `anyhow::Error`.
+    let error = Error::from(error);
+    assert!(matches!(ExecutionError::<CurrentNetwork>::from(error), ExecutionError::AssertionFailed(..)));
+}
+
+#[test]
+fn test_process_execute_ecdsa_verify() {
+    use console::algorithms::EcdsaSecp256k1;
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program signatures.aleo;
+
+function verify:
+    input r0 as [u8; 64u32].private;
+    input r1 as [u8; 64u32].public;
+    input r2 as [u8; 32u32].public;
+    ecdsa.verify.secp256k1 r0 r1 r2 into r3;
+    output r3 as boolean.public;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Returns the given bits (as the little-endian bits of each byte in order) as a `u8` array.
+    let to_bytes = |bits: &[bool]| {
+        let bytes = bits.chunks(8).map(|byte| format!("{}u8", u8::from_bits_le(byte).unwrap())).collect::<Vec<_>>();
+        Value::<CurrentNetwork>::from_str(&format!("[{}]", bytes.join(", "))).unwrap()
+    };
+
+    // Sign a message with a secp256k1 secret key.
+    let secret_key = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+    let message = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+    let public_key = EcdsaSecp256k1.public_key(&secret_key).unwrap();
+    let digest = CurrentNetwork::hash_keccak256(&message).unwrap();
+    let signature = EcdsaSecp256k1.sign(&secret_key, &digest, rng).unwrap();
+
+    // Flip a bit of the message, for an invalid signature.
+    let mut invalid_message = message.clone();
+    invalid_message[0] = !invalid_message[0];
+
+    for (message, expected) in [(message, "true"), (invalid_message, "false")] {
+        // Authorize the function call.
+        let inputs = [to_bytes(&signature), to_bytes(&public_key), to_bytes(&message)];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "verify", inputs.iter(), rng)
+            .unwrap();
+
+        // Ensure the evaluation is as expected.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str(expected).unwrap()]);
+
+        // Ensure the execution is as expected.
+        let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), &[Value::from_str(expected).unwrap()]);
+
+        // Prove and verify the execution.
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("signatures", rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+    }
+}
+
+#[test]
+fn test_process_execute_sign_schnorr() {
+    use console::{account::Signature, program::ToFields};
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program schnorr.aleo;
+
+function sign:
+    input r0 as scalar.private;
+    input r1 as field.public;
+    sign.schnorr r0 r1 into r2;
+    output r2 as signature.public;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Sample a signature secret key and a message.
+    let sk_sig = Scalar::<CurrentNetwork>::rand(rng);
+    let message = Value::<CurrentNetwork>::from(Literal::Field(Field::rand(rng)));
+
+    // Compute the expected signature, and ensure it verifies against the address of the deterministic compute key.
+    let signature = Signature::sign_deterministic(&sk_sig, &message.to_fields().unwrap()).unwrap();
+    let address = Address::try_from(Signature::deterministic_compute_key(&sk_sig).unwrap()).unwrap();
+    assert!(signature.verify(&address, &message.to_fields().unwrap()));
+    // Ensure the signer address is not the address of the account with the same signature secret key.
+    assert_ne!(address, Address::try_from(&caller_private_key).unwrap());
+    let expected = Value::from(Literal::Signature(Box::new(signature)));
+
+    // Authorize the function call.
+    let inputs = [Value::from(Literal::Scalar(sk_sig)), message];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "sign", inputs.iter(), rng).unwrap();
+
+    // Ensure the evaluation is as expected.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    assert_eq!(response.outputs(), &[expected.clone()]);
+
+    // Ensure the execution is as expected.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    assert_eq!(response.outputs(), &[expected]);
+
+    // Prove and verify the execution.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("schnorr", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure a signature secret key that is public, or copied into a derived register, is rejected.
+    for body in [
+        "input r0 as scalar.public;\n    sign.schnorr r0 r0 into r1;",
+        "input r0 as scalar.private;\n    add r0 0scalar into r1;\n    sign.schnorr r1 r0 into r2;",
+    ] {
+        let program =
+            Program::<CurrentNetwork>::from_str(&format!("program schnorr.aleo;\n\nfunction sign:\n    {body}"))
+                .unwrap();
+        let mut process = Process::load().unwrap();
+        let error = process.add_program(&program).unwrap_err();
+        assert!(format!("{error:#}").contains("expects the signature secret key to be a private input"), "{error:#}");
+    }
+}
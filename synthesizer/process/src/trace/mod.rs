@@ -25,7 +25,7 @@ use console::{
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use synthesizer_snark::{Proof, ProvingKey, VerifierInputs, VerifyingKey};
 
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
@@ -229,6 +229,24 @@ impl<N: Network> Trace<N> {
         }
     }
 
+    /// Returns the verifier inputs for the proof of the execution, including the inclusion verifier inputs.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn to_execution_verifier_inputs(
+        verifier_inputs: VerifierInputs<N>,
+        execution: &Execution<N>,
+    ) -> Result<VerifierInputs<N>> {
+        Self::to_verifier_inputs(verifier_inputs, execution.global_state_root(), execution.transitions())
+    }
+
+    /// Returns the verifier inputs for the proof of the fee, including the inclusion verifier inputs.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn to_fee_verifier_inputs(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+    ) -> Result<VerifierInputs<N>> {
+        Self::to_verifier_inputs(vec![verifier_inputs], fee.global_state_root(), [fee.transition()].into_iter())
+    }
+
     /// Checks the proof for the fee.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
@@ -299,11 +317,30 @@ impl<N: Network> Trace<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
-        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        verifier_inputs: VerifierInputs<N>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
     ) -> Result<()> {
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let verifier_inputs = Self::to_verifier_inputs(verifier_inputs, global_state_root, transitions)?;
+        // Verify the proof.
+        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Failed to verify proof"),
+        }
+    }
+
+    /// Returns the given verifier inputs, with the inclusion verifier inputs for the given transitions.
+    fn to_verifier_inputs<'a>(
+        mut verifier_inputs: VerifierInputs<N>,
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+    ) -> Result<VerifierInputs<N>> {
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root to *not* be zero")
+        }
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
@@ -313,10 +350,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
-            true => Ok(()),
-            false => bail!("Failed to verify proof"),
-        }
+        Ok(verifier_inputs)
     }
 }
@@ -17,9 +17,12 @@ use console::{
     account::Address,
     network::Network,
     prelude::{CryptoRng, Result, Rng},
-    program::{Identifier, ProgramID, Response, Value},
+    program::{Identifier, Locator, ProgramID, Response, Value},
     types::Field,
 };
+use synthesizer_program::{Command, Instruction};
+
+use indexmap::IndexMap;
 
 pub trait StackEvaluate<N: Network>: Clone {
     /// Evaluates a program closure on the given inputs.
@@ -88,3 +91,77 @@ pub trait RegistersCall<N: Network> {
     /// Returns the current call stack.
     fn call_stack(&self) -> CallStack<N>;
 }
+
+/// A hook that is invoked before and after each instruction is evaluated in a closure or function,
+/// and before and after each command is evaluated in a finalize block.
+///
+/// The registers are given as a mapping of register locators to their assigned values.
+/// Note: A `repeat` instruction is reported as a single instruction, and a command that halts
+/// or an instruction that fails does not invoke the `after_*` hook.
+pub trait Debugger<N: Network>: Send + Sync {
+    /// Invoked before the instruction at `index` in the closure or function `locator` is evaluated.
+    fn before_instruction(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _instruction: &Instruction<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked after the instruction at `index` in the closure or function `locator` is evaluated.
+    fn after_instruction(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _instruction: &Instruction<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked before the body instruction at `body_index` is evaluated, in the given `iteration`
+    /// of the `repeat` block at `index` in the closure or function `locator`.
+    fn before_repeat_instruction(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _iteration: u32,
+        _body_index: usize,
+        _instruction: &Instruction<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked after the body instruction at `body_index` is evaluated, in the given `iteration`
+    /// of the `repeat` block at `index` in the closure or function `locator`.
+    fn after_repeat_instruction(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _iteration: u32,
+        _body_index: usize,
+        _instruction: &Instruction<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked before the command at `index` in the finalize block of `locator` is evaluated.
+    fn before_command(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _command: &Command<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+
+    /// Invoked after the command at `index` in the finalize block of `locator` is evaluated.
+    fn after_command(
+        &self,
+        _locator: &Locator<N>,
+        _index: usize,
+        _command: &Command<N>,
+        _registers: &IndexMap<u64, Value<N>>,
+    ) {
+    }
+}
@@ -18,9 +18,48 @@ impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
-    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<(), ExecutionError<N>> {
+        self.verify_execution_internal(execution).map_err(ExecutionError::from)
+    }
+
+    /// Verifies the given execution is valid, except for its proof, and returns the verifier inputs for its proof.
+    /// The proof may then be checked in aggregate with other proofs, via `VerifyingKey::verify_aggregate`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_deferred(&self, execution: &Execution<N>) -> Result<VerifierInputs<N>, ExecutionError<N>> {
+        // Ensure the execution contains a proof.
+        if execution.proof().is_none() {
+            return Err(ExecutionError::invalid_proof(anyhow!("Expected the execution to contain a proof")));
+        }
+        // Construct the verifier inputs for the execution.
+        let (_, verifier_inputs) = self.to_execution_verifier_inputs(execution).map_err(ExecutionError::from)?;
+        // Add the inclusion verifier inputs.
+        Trace::to_execution_verifier_inputs(verifier_inputs, execution).map_err(ExecutionError::invalid_proof)
+    }
+
+    /// Verifies the given execution is valid.
+    #[inline]
+    fn verify_execution_internal(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = self.to_execution_verifier_inputs(execution)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)
+            .map_err(ExecutionError::<N>::invalid_proof)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the transitions in the given execution are valid,
+    /// and returns the locator of the main function and the verifier inputs for the execution proof.
+    fn to_execution_verifier_inputs(&self, execution: &Execution<N>) -> Result<(String, VerifierInputs<N>)> {
+        let timer = timer!("Process::to_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -140,13 +179,9 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
 
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
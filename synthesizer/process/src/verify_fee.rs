@@ -19,6 +19,31 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+        // Construct the verifier inputs for the fee.
+        let verifier_inputs = self.to_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        // Ensure the fee proof is valid.
+        Trace::verify_fee_proof(verifier_inputs, fee)
+    }
+
+    /// Verifies the given fee is valid, except for its proof, and returns the verifier inputs for its proof.
+    /// The proof may then be checked in aggregate with other proofs, via `VerifyingKey::verify_aggregate`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_fee_deferred(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<VerifierInputs<N>> {
+        // Ensure the fee contains a proof.
+        ensure!(fee.proof().is_some(), "Expected the fee to contain a proof");
+        // Construct the verifier inputs for the fee.
+        let verifier_inputs = self.to_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        // Add the inclusion verifier inputs.
+        Trace::to_fee_verifier_inputs(verifier_inputs, fee)
+    }
+
+    /// Verifies the fee transition is well-formed, and returns the verifying key and verifier inputs for its proof.
+    fn to_fee_verifier_inputs(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee");
 
         #[cfg(debug_assertions)]
@@ -62,18 +87,19 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the deployment or execution ID");
 
         // Verify the fee transition is well-formed.
-        match is_fee_private {
+        let verifier_inputs = match is_fee_private {
             true => self.verify_fee_private(&fee)?,
             false => self.verify_fee_public(&fee)?,
-        }
+        };
         finish!(timer, "Verify the fee transition");
-        Ok(())
+        Ok(verifier_inputs)
     }
 }
 
 impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    /// Verifies the transition for `credits.aleo/fee_private` is well-formed,
+    /// and returns the verifying key and verifier inputs for its proof.
+    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_private");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -135,15 +161,14 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Verifies the transition for `credits.aleo/fee_public` is well-formed,
+    /// and returns the verifying key and verifier inputs for its proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_public");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -205,10 +230,8 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 }
 
@@ -249,10 +272,13 @@ mod tests {
                     // Verify the fee.
                     assert!(process.verify_fee(&fee.unwrap(), execution_id).is_ok());
                 }
-                Transaction::Fee(_, fee) => match fee.is_fee_private() {
-                    true => assert!(process.verify_fee_private(&&fee).is_ok()),
-                    false => assert!(process.verify_fee_public(&&fee).is_ok()),
-                },
+                Transaction::Fee(_, fee) => {
+                    let verifier_inputs = match fee.is_fee_private() {
+                        true => process.verify_fee_private(&&fee).unwrap(),
+                        false => process.verify_fee_public(&&fee).unwrap(),
+                    };
+                    assert!(Trace::verify_fee_proof(verifier_inputs, &fee).is_ok());
+                }
             }
         }
     }
@@ -63,6 +63,11 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
         &self.instructions
     }
 
+    /// Returns the number of closure instructions, once every `repeat` block is unrolled.
+    pub fn num_unrolled_instructions(&self) -> usize {
+        self.instructions.iter().map(|instruction| instruction.num_unrolled_instructions()).sum()
+    }
+
     /// Returns the closure outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -106,8 +111,9 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
         ensure!(self.outputs.is_empty(), "Cannot add instructions after outputs have been added");
 
         // Ensure the maximum number of instructions has not been exceeded.
+        // Note: A `repeat` block counts as each of the instructions it unrolls into.
         ensure!(
-            self.instructions.len() < N::MAX_INSTRUCTIONS,
+            self.num_unrolled_instructions() + instruction.num_unrolled_instructions() <= N::MAX_INSTRUCTIONS,
             "Cannot add more than {} instructions",
             N::MAX_INSTRUCTIONS
         );
@@ -201,6 +207,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_repeat_instruction() {
+        // Initialize a new closure instance.
+        let name = Identifier::from_str("closure_core_test").unwrap();
+        let mut closure = Closure::<CurrentNetwork>::new(name);
+
+        // Fill the closure up to one instruction below the maximum.
+        let instruction = Instruction::<CurrentNetwork>::from_str("add r0 r1 into r2;").unwrap();
+        for _ in 1..CurrentNetwork::MAX_INSTRUCTIONS {
+            assert!(closure.add_instruction(instruction.clone()).is_ok());
+        }
+
+        // Ensure a `repeat` block that unrolls past the maximum will fail.
+        let repeat = Instruction::<CurrentNetwork>::from_str("repeat 2u32: add r0 r1 into r2; end;").unwrap();
+        assert_eq!(repeat.num_unrolled_instructions(), 2);
+        assert!(closure.add_instruction(repeat).is_err());
+
+        // Ensure a single instruction can still be added.
+        assert!(closure.add_instruction(instruction).is_ok());
+        assert_eq!(closure.num_unrolled_instructions(), CurrentNetwork::MAX_INSTRUCTIONS);
+    }
+
     #[test]
     fn test_add_output() {
         // Initialize a new closure instance.
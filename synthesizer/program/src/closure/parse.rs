@@ -79,7 +79,10 @@ impl<N: Network, Instruction: InstructionTrait<N>> Display for ClosureCore<N, In
         // Write the closure to a string.
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
-        self.instructions.iter().try_for_each(|instruction| write!(f, "\n    {instruction}"))?;
+        // Note: Every line of an instruction is indented, so a `repeat` body is nested under its block.
+        self.instructions
+            .iter()
+            .try_for_each(|instruction| write!(f, "\n    {}", instruction.to_string().replace('\n', "\n    ")))?;
         self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))
     }
 }
@@ -138,6 +141,19 @@ closure foo:
         assert_eq!(expected, format!("{closure}"),);
     }
 
+    #[test]
+    fn test_closure_display_repeat() {
+        let expected = r"closure foo:
+    input r0 as field;
+    input r1 as field;
+    repeat 4u32 carry r1:
+        add r1 r0 into r2;
+    end;
+    output r5 as field;";
+        let closure = Closure::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{closure}"));
+    }
+
     #[test]
     fn test_closure_parse_output_function() {
         let result = Closure::<CurrentNetwork>::parse(
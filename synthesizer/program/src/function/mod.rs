@@ -73,6 +73,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
         &self.instructions
     }
 
+    /// Returns the number of function instructions, once every `repeat` block is unrolled.
+    pub fn num_unrolled_instructions(&self) -> usize {
+        self.instructions.iter().map(|instruction| instruction.num_unrolled_instructions()).sum()
+    }
+
     /// Returns the function outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -131,8 +136,9 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
         ensure!(self.outputs.is_empty(), "Cannot add instructions after outputs have been added");
 
         // Ensure the maximum number of instructions has not been exceeded.
+        // Note: A `repeat` block counts as each of the instructions it unrolls into.
         ensure!(
-            self.instructions.len() < N::MAX_INSTRUCTIONS,
+            self.num_unrolled_instructions() + instruction.num_unrolled_instructions() <= N::MAX_INSTRUCTIONS,
             "Cannot add more than {} instructions",
             N::MAX_INSTRUCTIONS
         );
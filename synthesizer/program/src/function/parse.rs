@@ -107,7 +107,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
         // Write the function to a string.
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
-        self.instructions.iter().try_for_each(|instruction| write!(f, "\n    {instruction}"))?;
+        // Note: Every line of an instruction is indented, so a `repeat` body is nested under its block.
+        self.instructions
+            .iter()
+            .try_for_each(|instruction| write!(f, "\n    {}", instruction.to_string().replace('\n', "\n    ")))?;
         self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))?;
 
         // If finalize exists, write it out.
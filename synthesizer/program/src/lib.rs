@@ -84,7 +84,8 @@ use console::{
         TypeName,
         Write,
     },
-    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType},
+    program::{Identifier, Literal, Plaintext, PlaintextType, ProgramID, RecordType, StructType},
+    types::U32,
 };
 
 use indexmap::IndexMap;
@@ -277,12 +278,39 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Ensure the number of inputs is within the allowed range.
         ensure!(function.inputs().len() <= N::MAX_INPUTS, "Function exceeds maximum number of inputs");
         // Ensure the number of instructions is within the allowed range.
-        ensure!(function.instructions().len() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
+        ensure!(function.num_unrolled_instructions() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the default value of the given plaintext type, which fills the unused slots of a vector.
+    /// The default value of a literal is its zero value, and the default value of a struct, array,
+    /// or vector is composed of the default values of its members or elements.
+    pub fn default_plaintext(&self, plaintext_type: &PlaintextType<N>) -> Result<Plaintext<N>> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(Plaintext::from(Literal::zero(*literal_type)?)),
+            PlaintextType::Struct(struct_name) => {
+                // Compute the default value of each member.
+                let members = self
+                    .get_struct(struct_name)?
+                    .members()
+                    .iter()
+                    .map(|(member_name, member_type)| Ok((*member_name, self.default_plaintext(member_type)?)))
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                Ok(Plaintext::Struct(members, Default::default()))
+            }
+            PlaintextType::Array(array_type) => {
+                let element = self.default_plaintext(array_type.next_element_type())?;
+                Ok(Plaintext::Array(vec![element; **array_type.length() as usize], Default::default()))
+            }
+            PlaintextType::Vector(vector_type) => {
+                let element = self.default_plaintext(vector_type.element_type())?;
+                Plaintext::new_vector(U32::new(0), vec![element; **vector_type.capacity() as usize])
+            }
+        }
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -391,6 +419,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Vector(vector_type) => {
+                    let element_type = match vector_type.element_type() {
+                        PlaintextType::Array(array_type) => array_type.base_element_type(),
+                        element_type => element_type,
+                    };
+                    if let PlaintextType::Struct(struct_name) = element_type {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
@@ -445,6 +485,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
                         }
                     }
                 }
+                PlaintextType::Vector(vector_type) => {
+                    let element_type = match vector_type.element_type() {
+                        PlaintextType::Array(array_type) => array_type.base_element_type(),
+                        element_type => element_type,
+                    };
+                    if let PlaintextType::Struct(struct_name) = element_type {
+                        // Ensure the member struct name exists in the program.
+                        if !self.structs.contains_key(struct_name) {
+                            bail!("'{struct_name}' in vector '{vector_type}' is not defined.")
+                        }
+                    }
+                }
             }
         }
 
@@ -535,7 +587,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Ensure the number of inputs is within the allowed range.
         ensure!(function.inputs().len() <= N::MAX_INPUTS, "Function exceeds maximum number of inputs");
         // Ensure the number of instructions is within the allowed range.
-        ensure!(function.instructions().len() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
+        ensure!(function.num_unrolled_instructions() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
 
@@ -706,6 +758,35 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_default_plaintext() -> Result<()> {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct message:
+    first as field;
+    second as [boolean; 2u32];",
+        )?;
+
+        // Ensure the default values are composed of zero values.
+        let default = |plaintext_type: &str| program.default_plaintext(&PlaintextType::from_str(plaintext_type)?);
+        assert_eq!(default("u8")?, Plaintext::from_str("0u8")?);
+        assert_eq!(default("message")?, Plaintext::from_str("{ first: 0field, second: [false, false] }")?);
+        assert_eq!(default("[u8; 2u32]")?, Plaintext::from_str("[0u8, 0u8]")?);
+        assert_eq!(
+            default("[message; <=2u32]")?,
+            Plaintext::from_str(
+                "{ len: 0u32, elements: [{ first: 0field, second: [false, false] }, { first: 0field, second: [false, false] }] }"
+            )?
+        );
+        // Ensure the struct must be defined.
+        assert!(default("unknown").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
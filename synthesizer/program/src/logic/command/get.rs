@@ -14,6 +14,8 @@
 
 use crate::{
     traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    Failure,
+    FailureKind,
     Opcode,
     Operand,
 };
@@ -217,7 +219,10 @@ impl<N: Network> Get<N> {
             Some(Value::Record(..)) => bail!("Cannot 'get' a 'record'"),
             Some(Value::Future(..)) => bail!("Cannot 'get' a 'future'",),
             // If a key does not exist, then bail.
-            None => bail!("Key '{key}' does not exist in mapping '{program_id}/{mapping_name}'"),
+            None => {
+                let message = format!("Key '{key}' does not exist in mapping '{program_id}/{mapping_name}'");
+                return Err(Failure::new(FailureKind::MissingMappingKey, message).into());
+            }
         };
 
         // Assign the value to the destination register.
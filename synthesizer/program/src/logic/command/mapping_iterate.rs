@@ -0,0 +1,325 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    MappingLocator,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Register, Value},
+    types::U32,
+};
+
+/// A mapping iterate command, e.g. `mapping.iterate accounts from r0 limit 8u32 into r1 r2;`.
+/// Reads up to `limit` (`key`, `value`) entries from `mapping`, starting at the `start` index,
+/// and stores the keys and values as vectors of capacity `limit` into the `keys` and `values` registers.
+/// The entries are in insertion order, except that removing an entry moves the last entry into its position.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MappingIterate<N: Network> {
+    /// The mapping.
+    mapping: MappingLocator<N>,
+    /// The index of the first entry to read.
+    start: Operand<N>,
+    /// The maximum number of entries to read.
+    limit: U32<N>,
+    /// The destination register for the keys.
+    keys: Register<N>,
+    /// The destination register for the values.
+    values: Register<N>,
+}
+
+impl<N: Network> MappingIterate<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("mapping.iterate")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.start.clone()]
+    }
+
+    /// Returns the mapping.
+    #[inline]
+    pub const fn mapping(&self) -> &MappingLocator<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the start index.
+    #[inline]
+    pub const fn start(&self) -> &Operand<N> {
+        &self.start
+    }
+
+    /// Returns the maximum number of entries to read.
+    #[inline]
+    pub const fn limit(&self) -> &U32<N> {
+        &self.limit
+    }
+
+    /// Returns the destination register for the keys.
+    #[inline]
+    pub const fn keys(&self) -> &Register<N> {
+        &self.keys
+    }
+
+    /// Returns the destination register for the values.
+    #[inline]
+    pub const fn values(&self) -> &Register<N> {
+        &self.values
+    }
+
+    /// Returns `true` if the given limit is within the bounds of a vector capacity.
+    #[inline]
+    fn is_valid_limit(limit: &U32<N>) -> bool {
+        (N::MIN_ARRAY_ELEMENTS..=N::MAX_ARRAY_ELEMENTS).contains(&(**limit as usize))
+    }
+}
+
+impl<N: Network> MappingIterate<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the limit is valid.
+        ensure!(Self::is_valid_limit(&self.limit), "The limit of '{}' is out of bounds", Self::opcode());
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+        };
+
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
+        }
+
+        // Load the start index.
+        let start = match registers.load_literal(stack, &self.start)? {
+            Literal::U32(start) => *start as usize,
+            literal => bail!("Command '{}' expects a 'u32' start index, found '{literal}'", Self::opcode()),
+        };
+
+        // Retrieve up to `limit` entries from the mapping.
+        let entries = store.get_mapping_entries_speculative(program_id, mapping_name, start, *self.limit as usize)?;
+
+        // Split the entries into keys and values.
+        let length = U32::new(entries.len() as u32);
+        let mut keys = Vec::with_capacity(*self.limit as usize);
+        let mut values = Vec::with_capacity(*self.limit as usize);
+        for (key, value) in entries {
+            match value {
+                Value::Plaintext(value) => {
+                    keys.push(key);
+                    values.push(value);
+                }
+                value => {
+                    bail!("Mapping '{program_id}/{mapping_name}' contains a non-plaintext value '{value}'")
+                }
+            }
+        }
+
+        // Pad the keys and values to the capacity of the vectors.
+        let program = match self.mapping {
+            MappingLocator::Locator(..) => stack.get_external_program(&program_id)?,
+            MappingLocator::Resource(..) => stack.program(),
+        };
+        let mapping = program.get_mapping(&mapping_name)?;
+        keys.resize(*self.limit as usize, program.default_plaintext(mapping.key().plaintext_type())?);
+        values.resize(*self.limit as usize, program.default_plaintext(mapping.value().plaintext_type())?);
+
+        // Assign the keys and values to the destination registers.
+        registers.store(stack, &self.keys, Value::Plaintext(Plaintext::new_vector(length, keys)?))?;
+        registers.store(stack, &self.values, Value::Plaintext(Plaintext::new_vector(length, values)?))?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for MappingIterate<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = MappingLocator::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "from" keyword from the string.
+        let (string, _) = tag("from")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the start operand from the string.
+        let (string, start) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "limit" keyword from the string.
+        let (string, _) = tag("limit")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the limit from the string.
+        let (string, limit) = U32::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the keys register from the string.
+        let (string, keys) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the values register from the string.
+        let (string, values) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        match Self::is_valid_limit(&limit) {
+            true => Ok((string, Self { mapping, start, limit, keys, values })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'mapping.iterate': the limit '{limit}' is out of bounds")))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for MappingIterate<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MappingIterate<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MappingIterate<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping, start operand, and limit.
+        write!(f, "{} from {} limit {} into ", self.mapping, self.start, self.limit)?;
+        // Print the destination registers.
+        write!(f, "{} {};", self.keys, self.values)
+    }
+}
+
+impl<N: Network> FromBytes for MappingIterate<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = MappingLocator::read_le(&mut reader)?;
+        // Read the start operand.
+        let start = Operand::read_le(&mut reader)?;
+        // Read the limit.
+        let limit = U32::read_le(&mut reader)?;
+        // Ensure the limit is valid.
+        if !Self::is_valid_limit(&limit) {
+            return Err(error(format!("The limit '{limit}' of 'mapping.iterate' is out of bounds")));
+        }
+        // Read the keys register.
+        let keys = Register::read_le(&mut reader)?;
+        // Read the values register.
+        let values = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, start, limit, keys, values })
+    }
+}
+
+impl<N: Network> ToBytes for MappingIterate<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le(&mut writer)?;
+        // Write the start operand.
+        self.start.write_le(&mut writer)?;
+        // Write the limit.
+        self.limit.write_le(&mut writer)?;
+        // Write the keys register.
+        self.keys.write_le(&mut writer)?;
+        // Write the values register.
+        self.values.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, iterate) =
+            MappingIterate::<CurrentNetwork>::parse("mapping.iterate account from r0 limit 8u32 into r1 r2;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(iterate.mapping, MappingLocator::from_str("account").unwrap());
+        assert_eq!(iterate.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(iterate.start, Operand::Register(Register::Locator(0)), "The start operand is incorrect");
+        assert_eq!(*iterate.limit, 8, "The limit is incorrect");
+        assert_eq!(iterate.keys, Register::Locator(1), "The keys register is incorrect");
+        assert_eq!(iterate.values, Register::Locator(2), "The values register is incorrect");
+
+        let string = "mapping.iterate token.aleo/balances from 0u32 limit 8u32 into r1 r2;";
+        let (string, iterate) = MappingIterate::<CurrentNetwork>::parse(string).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(iterate.mapping, MappingLocator::from_str("token.aleo/balances").unwrap());
+
+        // Ensure the limit is bounded.
+        let limit = CurrentNetwork::MAX_ARRAY_ELEMENTS + 1;
+        let string = format!("mapping.iterate account from r0 limit {limit}u32 into r1 r2;");
+        assert!(MappingIterate::<CurrentNetwork>::parse(&string).is_err());
+        assert!(
+            MappingIterate::<CurrentNetwork>::parse("mapping.iterate account from r0 limit 0u32 into r1 r2;").is_err()
+        );
+    }
+}
@@ -27,6 +27,12 @@ pub use get::*;
 mod get_or_use;
 pub use get_or_use::*;
 
+mod mapping_iterate;
+pub use mapping_iterate::*;
+
+mod mapping_length;
+pub use mapping_length::*;
+
 mod rand_chacha;
 pub use crate::command::rand_chacha::*;
 
@@ -53,6 +59,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
 };
 use console::{
     network::prelude::*,
@@ -72,6 +79,10 @@ pub enum Command<N: Network> {
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     /// If the key is not present, `default` is stored `destination`.
     GetOrUse(GetOrUse<N>),
+    /// Reads up to `limit` entries from `mapping`, starting at the `start` operand, and stores the keys and values into `keys` and `values`.
+    MappingIterate(MappingIterate<N>),
+    /// Stores the number of entries in `mapping` into `destination`.
+    MappingLength(MappingLength<N>),
     /// Generates a random value using the `rand.chacha` command and stores the result into `destination`.
     RandChaCha(RandChaCha<N>),
     /// Removes the (`key`, `value`) entry from the `mapping`.
@@ -95,6 +106,8 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             Command::Contains(contains) => vec![contains.destination().clone()],
             Command::Get(get) => vec![get.destination().clone()],
             Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::MappingIterate(iterate) => vec![iterate.keys().clone(), iterate.values().clone()],
+            Command::MappingLength(length) => vec![length.destination().clone()],
             Command::RandChaCha(rand_chacha) => vec![rand_chacha.destination().clone()],
             Command::Await(_)
             | Command::BranchEq(_)
@@ -145,6 +158,26 @@ impl<N: Network> CommandTrait<N> for Command<N> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub const fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Await(_) => Await::<N>::opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::MappingIterate(_) => MappingIterate::<N>::opcode(),
+            Command::MappingLength(_) => MappingLength::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+        }
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize(
@@ -164,6 +197,10 @@ impl<N: Network> Command<N> {
             Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
             // Finalize the 'get.or_use' command, and return no finalize operation.
             Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'mapping.iterate' command, and return no finalize operation.
+            Command::MappingIterate(iterate) => iterate.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'mapping.length' command, and return no finalize operation.
+            Command::MappingLength(length) => length.finalize(stack, store, registers).map(|_| None),
             // Finalize the `rand.chacha` command, and return no finalize operation.
             Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
             // Finalize the 'remove' command, and return the finalize operation.
@@ -208,8 +245,12 @@ impl<N: Network> FromBytes for Command<N> {
             9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
             // Read the `position` command.
             10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `mapping.iterate` command.
+            11 => Ok(Self::MappingIterate(MappingIterate::read_le(&mut reader)?)),
+            // Read the `mapping.length` command.
+            12 => Ok(Self::MappingLength(MappingLength::read_le(&mut reader)?)),
             // Invalid variant.
-            11.. => Err(error(format!("Invalid command variant: {variant}"))),
+            13.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -284,6 +325,18 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::MappingIterate(iterate) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the `mapping.iterate` command.
+                iterate.write_le(&mut writer)
+            }
+            Self::MappingLength(length) => {
+                // Write the variant.
+                12u8.write_le(&mut writer)?;
+                // Write the `mapping.length` command.
+                length.write_le(&mut writer)
+            }
         }
     }
 }
@@ -299,6 +352,8 @@ impl<N: Network> Parser for Command<N> {
             map(Contains::parse, |contains| Self::Contains(contains)),
             map(GetOrUse::parse, |get_or_use| Self::GetOrUse(get_or_use)),
             map(Get::parse, |get| Self::Get(get)),
+            map(MappingIterate::parse, |iterate| Self::MappingIterate(iterate)),
+            map(MappingLength::parse, |length| Self::MappingLength(length)),
             map(RandChaCha::parse, |rand_chacha| Self::RandChaCha(rand_chacha)),
             map(Remove::parse, |remove| Self::Remove(remove)),
             map(Set::parse, |set| Self::Set(set)),
@@ -344,6 +399,8 @@ impl<N: Network> Display for Command<N> {
             Self::Contains(contains) => Display::fmt(contains, f),
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrUse(get_or_use) => Display::fmt(get_or_use, f),
+            Self::MappingIterate(iterate) => Display::fmt(iterate, f),
+            Self::MappingLength(length) => Display::fmt(length, f),
             Self::RandChaCha(rand_chacha) => Display::fmt(rand_chacha, f),
             Self::Remove(remove) => Display::fmt(remove, f),
             Self::Set(set) => Display::fmt(set, f),
@@ -401,6 +458,18 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // MappingIterate
+        let expected = "mapping.iterate object from r0 limit 4u32 into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // MappingLength
+        let expected = "mapping.length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -478,6 +547,18 @@ mod tests {
         assert_eq!(Command::GetOrUse(GetOrUse::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // MappingIterate
+        let expected = "mapping.iterate object from r0 limit 4u32 into r1 r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::MappingIterate(MappingIterate::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // MappingLength
+        let expected = "mapping.length object into r0;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::MappingLength(MappingLength::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // RandChaCha
         let expected = "rand.chacha into r1 as field;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
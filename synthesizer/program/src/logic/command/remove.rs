@@ -178,5 +178,8 @@ mod tests {
         assert_eq!(remove.mapping, Identifier::from_str("account").unwrap());
         assert_eq!(remove.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(remove.key, Operand::Register(Register::Locator(1)), "The first operand is incorrect");
+
+        // Ensure removals from an external mapping are rejected.
+        assert!(Remove::<CurrentNetwork>::parse("remove token.aleo/account[r1];").is_err());
     }
 }
@@ -211,5 +211,8 @@ mod tests {
         assert_eq!(set.operands().len(), 2, "The number of operands is incorrect");
         assert_eq!(set.value, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(set.key, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+
+        // Ensure writes to an external mapping are rejected.
+        assert!(Set::<CurrentNetwork>::parse("set r0 into token.aleo/account[r1];").is_err());
     }
 }
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The kind of a failure raised while evaluating, executing, or finalizing a program.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailureKind {
+    /// An `assert.eq` or `assert.neq` instruction failed.
+    AssertionFailed,
+    /// A balance was insufficient to pay a fee.
+    /// Note: An underflow in a `credits.aleo` function (e.g. `transfer_public`) is an instruction failure instead.
+    InsufficientBalance,
+    /// A `get` command found no value for the key.
+    MissingMappingKey,
+}
+
+/// A failure raised while evaluating, executing, or finalizing a program.
+/// This failure is raised (as an `anyhow::Error`) where it occurs, so that callers can classify it from the error chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The kind of failure.
+    kind: FailureKind,
+    /// The failure message.
+    message: String,
+}
+
+impl Failure {
+    /// Initializes a new failure.
+    pub fn new(kind: FailureKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+
+    /// Returns the kind of failure.
+    pub const fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// Returns the failure message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Failure {
+    /// Prints the failure message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Failure {}
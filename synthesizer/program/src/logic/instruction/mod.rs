@@ -105,10 +105,14 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `public_key` and `message`.
+    EcdsaVerify(EcdsaVerify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
     GreaterThanOrEqual(GreaterThanOrEqual<N>),
+    /// Multiplies the group generator by the scalar `first`, storing the outcome in `destination`.
+    GroupGen(GroupGen<N>),
     /// Performs a BHP hash on inputs of 256-bit chunks.
     HashBHP256(HashBHP256<N>),
     /// Performs a BHP hash on inputs of 512-bit chunks.
@@ -133,6 +137,8 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a SHA-256 hash, outputting 256 bits.
+    HashSha256(HashSha256<N>),
     /// Performs a SHA-3 hash, outputting 256 bits.
     HashSha3_256(HashSha3_256<N>),
     /// Performs a SHA-3 hash, outputting 384 bits.
@@ -145,6 +151,8 @@ pub enum Instruction<N: Network> {
     HashManyPSD4(HashManyPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashManyPSD8(HashManyPSD8<N>),
+    /// Hashes the field `first` to a group element using Poseidon with an input rate of 2.
+    HashToGroupPSD2(HashToGroupPSD2<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -157,6 +165,8 @@ pub enum Instruction<N: Network> {
     LessThanOrEqual(LessThanOrEqual<N>),
     /// Computes `first` mod `second`, storing the outcome in `destination`.
     Modulo(Modulo<N>),
+    /// Computes the multi-scalar multiplication of the groups `first` and the scalars `second`, storing the outcome in `destination`.
+    Msm(Msm<N>),
     /// Multiplies `first` with `second`, storing the outcome in `destination`.
     Mul(Mul<N>),
     /// Multiplies `first` with `second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
@@ -179,6 +189,8 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Repeats the body instructions a fixed number of times.
+    Repeat(Repeat<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -187,18 +199,38 @@ pub enum Instruction<N: Network> {
     Shr(Shr<N>),
     /// Shifts `first` right by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
     ShrWrapped(ShrWrapped<N>),
+    /// Computes a deterministic signature for `message`, using the signature secret key `first`.
+    SignSchnorr(SignSchnorr<N>),
     /// Computes whether `signature` is valid for the given `address` and `message`.
     SignVerify(SignVerify<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
     SquareRoot(SquareRoot<N>),
+    /// Concatenates the strings `first` and `second`, storing the outcome in `destination`.
+    StringConcat(StringConcat<N>),
+    /// Converts the `u8` array `first` into a string, storing the outcome in `destination`.
+    StringFromBytes(StringFromBytes<N>),
+    /// Retrieves the byte of string `first` at index `second`, storing the outcome in `destination`.
+    StringGet(StringGet<N>),
+    /// Computes the size of string `first` in bytes, storing the outcome in `destination`.
+    StringLen(StringLen<N>),
+    /// Converts the string `first` into a `u8` array, storing the outcome in `destination`.
+    StringToBytes(StringToBytes<N>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<N>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
     SubWrapped(SubWrapped<N>),
+    /// Selects the case at the index given by the selector `first`, storing the outcome in `destination`.
+    Switch(Switch<N>),
     /// Selects `first`, if `condition` is true, otherwise selects `second`, storing the result in `destination`.
     Ternary(Ternary<N>),
+    /// Retrieves the element of vector `first` at index `second`, storing the outcome in `destination`.
+    VecGet(VecGet<N>),
+    /// Computes the length of vector `first`, storing the outcome in `destination`.
+    VecLen(VecLen<N>),
+    /// Appends `second` to vector `first`, storing the outcome in `destination`.
+    VecPush(VecPush<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
 }
@@ -252,8 +284,10 @@ macro_rules! instruction {
             Div,
             DivWrapped,
             Double,
+            EcdsaVerify,
             GreaterThan,
             GreaterThanOrEqual,
+            GroupGen,
             HashBHP256,
             HashBHP512,
             HashBHP768,
@@ -266,18 +300,21 @@ macro_rules! instruction {
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashSha256,
             HashSha3_256,
             HashSha3_384,
             HashSha3_512,
             HashManyPSD2,
             HashManyPSD4,
             HashManyPSD8,
+            HashToGroupPSD2,
             Inv,
             IsEq,
             IsNeq,
             LessThan,
             LessThanOrEqual,
             Modulo,
+            Msm,
             Mul,
             MulWrapped,
             Nand,
@@ -289,16 +326,27 @@ macro_rules! instruction {
             PowWrapped,
             Rem,
             RemWrapped,
+            Repeat,
             Shl,
             ShlWrapped,
             Shr,
             ShrWrapped,
+            SignSchnorr,
             SignVerify,
             Square,
             SquareRoot,
+            StringConcat,
+            StringFromBytes,
+            StringGet,
+            StringLen,
+            StringToBytes,
             Sub,
             SubWrapped,
+            Switch,
             Ternary,
+            VecGet,
+            VecLen,
+            VecPush,
             Xor,
         }}
     };
@@ -379,6 +427,16 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the number of instructions this instruction unrolls into.
+    /// Note: A `repeat` block unrolls into its body instructions, once per iteration.
+    #[inline]
+    fn num_unrolled_instructions(&self) -> usize {
+        match self {
+            Self::Repeat(repeat) => repeat.num_unrolled_instructions(),
+            _ => 1,
+        }
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     #[inline]
     fn is_reserved_opcode(name: &str) -> bool {
@@ -469,7 +527,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            84,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
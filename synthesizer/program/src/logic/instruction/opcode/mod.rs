@@ -29,14 +29,26 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for ECDSA signature verification (i.e. `ecdsa.verify.secp256k1`).
+    Ecdsa(&'static str),
+    /// The opcode is for a group operation (i.e. `group.gen`, `msm`).
+    Group(&'static str),
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
-    /// The opcode is for signature verification (i.e. `sign.verify`).
-    Sign,
+    /// The opcode is for a bounded loop (i.e. `repeat`).
+    Repeat,
+    /// The opcode is for a signature operation (i.e. `sign.verify`, `sign.schnorr`).
+    Sign(&'static str),
+    /// The opcode is for a string operation (i.e. `string.concat`).
+    String(&'static str),
+    /// The opcode is for a multi-way selection (i.e. `switch`).
+    Switch,
+    /// The opcode is for a vector operation (i.e. `vec.push`).
+    Vector(&'static str),
 }
 
 impl Deref for Opcode {
@@ -51,10 +63,16 @@ impl Deref for Opcode {
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::Ecdsa(opcode) => opcode,
+            Opcode::Group(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
-            Opcode::Sign => &"sign.verify",
+            Opcode::Repeat => &"repeat",
+            Opcode::Sign(opcode) => opcode,
+            Opcode::String(opcode) => opcode,
+            Opcode::Switch => &"switch",
+            Opcode::Vector(opcode) => opcode,
         }
     }
 }
@@ -76,10 +94,16 @@ impl Display for Opcode {
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::Ecdsa(opcode) => write!(f, "{opcode}"),
+            Self::Group(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
-            Self::Sign => write!(f, "{}", self.deref()),
+            Self::Repeat => write!(f, "{}", self.deref()),
+            Self::Sign(opcode) => write!(f, "{opcode}"),
+            Self::String(opcode) => write!(f, "{opcode}"),
+            Self::Switch => write!(f, "{}", self.deref()),
+            Self::Vector(opcode) => write!(f, "{opcode}"),
         }
     }
 }
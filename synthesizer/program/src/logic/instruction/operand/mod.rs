@@ -40,6 +40,18 @@ pub enum Operand<N: Network> {
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the block round.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockRound,
+    /// The operand is the previous block hash.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockPreviousHash,
+    /// The operand is the iteration counter of the enclosing `repeat` block, as a constant `u32`.
+    /// Note: This variant is only accessible in the body of a `repeat` block.
+    RepeatIndex,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
@@ -26,6 +26,10 @@ impl<N: Network> Parser for Operand<N> {
             map(tag("self.signer"), |_| Self::Signer),
             map(tag("self.caller"), |_| Self::Caller),
             map(tag("block.height"), |_| Self::BlockHeight),
+            map(tag("block.timestamp"), |_| Self::BlockTimestamp),
+            map(tag("block.round"), |_| Self::BlockRound),
+            map(tag("block.previous_hash"), |_| Self::BlockPreviousHash),
+            map(tag("repeat.index"), |_| Self::RepeatIndex),
             map(Literal::parse, |literal| Self::Literal(literal)),
             map(Register::parse, |register| Self::Register(register)),
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
@@ -74,6 +78,14 @@ impl<N: Network> Display for Operand<N> {
             Self::Caller => write!(f, "self.caller"),
             // Prints the identifier for the block height, i.e. block.height
             Self::BlockHeight => write!(f, "block.height"),
+            // Prints the identifier for the block timestamp, i.e. block.timestamp
+            Self::BlockTimestamp => write!(f, "block.timestamp"),
+            // Prints the identifier for the block round, i.e. block.round
+            Self::BlockRound => write!(f, "block.round"),
+            // Prints the identifier for the previous block hash, i.e. block.previous_hash
+            Self::BlockPreviousHash => write!(f, "block.previous_hash"),
+            // Prints the identifier for the iteration counter of a `repeat` block, i.e. repeat.index
+            Self::RepeatIndex => write!(f, "repeat.index"),
         }
     }
 }
@@ -108,6 +120,18 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
         assert_eq!(Operand::BlockHeight, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(Operand::BlockTimestamp, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.round").unwrap().1;
+        assert_eq!(Operand::BlockRound, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(Operand::BlockPreviousHash, operand);
+
+        let operand = Operand::<CurrentNetwork>::parse("repeat.index").unwrap().1;
+        assert_eq!(Operand::RepeatIndex, operand);
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(Operand::Literal(Literal::Group(Group::generator())), operand);
 
@@ -139,6 +163,21 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
 
+        let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.height");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.timestamp").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.timestamp");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.round").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.round");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.previous_hash").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.previous_hash");
+
+        let operand = Operand::<CurrentNetwork>::parse("repeat.index").unwrap().1;
+        assert_eq!(format!("{operand}"), "repeat.index");
+
         let operand = Operand::<CurrentNetwork>::parse("group::GEN").unwrap().1;
         assert_eq!(
             format!("{operand}"),
@@ -14,6 +14,8 @@
 
 use crate::{
     traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Failure,
+    FailureKind,
     Opcode,
     Operand,
 };
@@ -96,12 +98,20 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         match VARIANT {
             0 => {
                 if input_a != input_b {
-                    bail!("'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)", Self::opcode())
+                    let message = format!(
+                        "'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)",
+                        Self::opcode()
+                    );
+                    return Err(Failure::new(FailureKind::AssertionFailed, message).into());
                 }
             }
             1 => {
                 if input_a == input_b {
-                    bail!("'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)", Self::opcode())
+                    let message = format!(
+                        "'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)",
+                        Self::opcode()
+                    );
+                    return Err(Failure::new(FailureKind::AssertionFailed, message).into());
                 }
             }
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
@@ -44,8 +44,9 @@ use console::{
         RegisterType,
         Value,
         ValueType,
+        VectorType,
     },
-    types::Field,
+    types::{Field, U32},
 };
 
 use indexmap::IndexMap;
@@ -249,6 +250,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                self.cast_to_vector(stack, registers, vector_type, inputs)
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -486,6 +490,46 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                 // Store the array.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                // Ensure the operands length is at least the minimum.
+                if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to a vector requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
+                }
+                // Ensure the number of elements does not exceed the capacity.
+                if inputs.len() > **vector_type.capacity() as usize {
+                    bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.capacity())
+                }
+
+                // Initialize the elements.
+                let mut elements = Vec::with_capacity(**vector_type.capacity() as usize);
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the plaintext matches the element type.
+                            stack.matches_plaintext(&plaintext.eject_value(), vector_type.element_type())?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into a vector element is illegal"),
+                        // Ensure the element is not a future.
+                        circuit::Value::Future(..) => bail!("Casting a future into a vector element is illegal"),
+                    };
+                    // Store the element.
+                    elements.push(plaintext);
+                }
+                // Pad the elements to the capacity of the vector.
+                let length = circuit::U32::constant(U32::new(elements.len() as u32));
+                let padding =
+                    circuit::Plaintext::constant(stack.program().default_plaintext(vector_type.element_type())?);
+                elements.resize(**vector_type.capacity() as usize, padding);
+
+                // Construct the vector.
+                let vector = circuit::Plaintext::new_vector(length, elements)?;
+                // Store the vector.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(vector))
+            }
             CastType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -625,6 +669,9 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
             CastType::Plaintext(PlaintextType::Array(array_type)) => {
                 self.cast_to_array(stack, registers, array_type, inputs)
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                self.cast_to_vector(stack, registers, vector_type, inputs)
+            }
             CastType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -758,6 +805,31 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
                     }
                 }
             }
+            CastType::Plaintext(PlaintextType::Vector(vector_type)) => {
+                // Ensure the input types length is at least the minimum.
+                if input_types.len() < N::MIN_ARRAY_ELEMENTS {
+                    bail!("Casting to a vector requires at least {} operand(s)", N::MIN_ARRAY_ELEMENTS)
+                }
+                // Ensure the number of elements does not exceed the capacity.
+                if input_types.len() > **vector_type.capacity() as usize {
+                    bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.capacity())
+                }
+
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    match input_type {
+                        RegisterType::Plaintext(plaintext_type) => ensure!(
+                            plaintext_type == vector_type.element_type(),
+                            "Vector element type mismatch: expected '{}', found '{plaintext_type}'",
+                            vector_type.element_type()
+                        ),
+                        _ => bail!(
+                            "Vector element type mismatch: expected '{}', found '{input_type}'",
+                            vector_type.element_type()
+                        ),
+                    }
+                }
+            }
             CastType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(record_name)?;
@@ -932,6 +1004,53 @@ impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
         // Store the array.
         registers.store(stack, &self.destination, Value::Plaintext(array))
     }
+
+    /// A helper method to handle casting to a vector.
+    fn cast_to_vector(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut impl RegistersStore<N>,
+        vector_type: &VectorType<N>,
+        inputs: Vec<Value<N>>,
+    ) -> Result<()> {
+        // Ensure that there is at least one operand.
+        if inputs.len() < N::MIN_ARRAY_ELEMENTS {
+            bail!("Casting to a vector requires at least {} operand", N::MIN_ARRAY_ELEMENTS)
+        }
+        // Ensure that the number of operands does not exceed the capacity of the vector.
+        let capacity = **vector_type.capacity() as usize;
+        if inputs.len() > capacity {
+            bail!("Casting to vector '{vector_type}' cannot exceed {} elements", vector_type.capacity())
+        }
+
+        // Initialize the elements.
+        let mut elements = Vec::with_capacity(capacity);
+        for element in inputs.iter() {
+            // Retrieve the plaintext value from the element.
+            let plaintext = match element {
+                Value::Plaintext(plaintext) => {
+                    // Ensure the plaintext matches the element type.
+                    stack.matches_plaintext(plaintext, vector_type.element_type())?;
+                    // Output the plaintext.
+                    plaintext.clone()
+                }
+                // Ensure the element is not a record.
+                Value::Record(..) => bail!("Casting a record into a vector element is illegal"),
+                // Ensure the element is not a future.
+                Value::Future(..) => bail!("Casting a future into a vector element is illegal"),
+            };
+            // Store the element.
+            elements.push(plaintext);
+        }
+        // Pad the elements to the capacity of the vector.
+        let length = U32::new(elements.len() as u32);
+        elements.resize(capacity, stack.program().default_plaintext(vector_type.element_type())?);
+
+        // Construct the vector.
+        let vector = Plaintext::new_vector(length, elements)?;
+        // Store the vector.
+        registers.store(stack, &self.destination, Value::Plaintext(vector))
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
@@ -973,6 +1092,7 @@ impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Vector(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         match !operands.is_empty() && (operands.len() <= max_operands) {
@@ -1021,6 +1141,7 @@ impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Vector(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
@@ -1066,6 +1187,7 @@ impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Vector(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if num_operands.is_zero() || num_operands > max_operands {
@@ -1087,6 +1209,7 @@ impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
             | CastType::Plaintext(PlaintextType::Literal(_)) => 1,
             CastType::Plaintext(PlaintextType::Struct(_)) => N::MAX_STRUCT_ENTRIES,
             CastType::Plaintext(PlaintextType::Array(_)) => N::MAX_ARRAY_ELEMENTS,
+            CastType::Plaintext(PlaintextType::Vector(_)) => N::MAX_ARRAY_ELEMENTS,
             CastType::Record(_) | CastType::ExternalRecord(_) => N::MAX_RECORD_ENTRIES,
         };
         if self.operands.is_empty() || self.operands.len() > max_operands {
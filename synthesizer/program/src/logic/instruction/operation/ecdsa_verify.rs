@@ -0,0 +1,346 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// The number of bytes in a secp256k1 signature, as `r || s`.
+const SIGNATURE_SIZE_IN_BYTES: u32 = 64;
+/// The number of bytes in an (uncompressed) secp256k1 public key, as `x || y`.
+const PUBLIC_KEY_SIZE_IN_BYTES: u32 = 64;
+
+/// Computes whether `signature` is a valid secp256k1 ECDSA signature for the given `public_key` and `message`.
+/// The message is hashed with Keccak-256 (as in Ethereum), and all operands are big-endian byte arrays.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EcdsaVerify<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> EcdsaVerify<N> {
+    /// Initializes a new `ecdsa.verify.secp256k1` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Ecdsa("ecdsa.verify.secp256k1")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> EcdsaVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = Self::load_bytes(registers.load(stack, &self.operands[0])?)?;
+        let public_key = Self::load_bytes(registers.load(stack, &self.operands[1])?)?;
+        let message = Self::load_bytes(registers.load(stack, &self.operands[2])?)?;
+
+        // Hash the message.
+        let digest = N::hash_keccak256(&message)?;
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(N::verify_ecdsa_secp256k1(&signature, &public_key, &digest)?));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = Self::load_bytes_circuit(registers.load_circuit(stack, &self.operands[0])?)?;
+        let public_key = Self::load_bytes_circuit(registers.load_circuit(stack, &self.operands[1])?)?;
+        let message = Self::load_bytes_circuit(registers.load_circuit(stack, &self.operands[2])?)?;
+
+        // Hash the message.
+        let digest = A::hash_keccak256(&message);
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(A::verify_ecdsa_secp256k1(&signature, &public_key, &digest));
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a signature.
+        if !Self::is_byte_array(&input_types[0], Some(SIGNATURE_SIZE_IN_BYTES)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a '[u8; {SIGNATURE_SIZE_IN_BYTES}u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a public key.
+        if !Self::is_byte_array(&input_types[1], Some(PUBLIC_KEY_SIZE_IN_BYTES)) {
+            bail!(
+                "Instruction '{}' expects the second input to be a '[u8; {PUBLIC_KEY_SIZE_IN_BYTES}u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a byte array.
+        if !Self::is_byte_array(&input_types[2], None) {
+            bail!(
+                "Instruction '{}' expects the third input to be a 'u8' array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> EcdsaVerify<N> {
+    /// Returns `true` if the given register type is a `u8` array, of the given length (if any).
+    fn is_byte_array(register_type: &RegisterType<N>, length: Option<u32>) -> bool {
+        match register_type {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+                    && length.map_or(true, |length| **array_type.length() == length)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the bits of the given `u8` array, as the little-endian bits of each byte in order.
+    fn load_bytes(value: Value<N>) -> Result<Vec<bool>> {
+        match value {
+            Value::Plaintext(Plaintext::Array(elements, _)) => {
+                let mut bits = Vec::with_capacity(elements.len() * 8);
+                for element in elements {
+                    match element {
+                        Plaintext::Literal(Literal::U8(byte), _) => bits.extend(byte.to_bits_le()),
+                        _ => bail!("Instruction '{}' expects a 'u8' array, found '{element}'", Self::opcode()),
+                    }
+                }
+                Ok(bits)
+            }
+            _ => bail!("Instruction '{}' expects a 'u8' array, found '{value}'", Self::opcode()),
+        }
+    }
+
+    /// Returns the bits of the given `u8` array, as the little-endian bits of each byte in order.
+    fn load_bytes_circuit<A: circuit::Aleo<Network = N>>(value: circuit::Value<A>) -> Result<Vec<circuit::Boolean<A>>> {
+        use circuit::traits::ToBits;
+
+        match value {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => {
+                let mut bits = Vec::with_capacity(elements.len() * 8);
+                for element in elements {
+                    match element {
+                        circuit::Plaintext::Literal(circuit::Literal::U8(byte), _) => bits.extend(byte.to_bits_le()),
+                        _ => bail!("Instruction '{}' expects a 'u8' array", Self::opcode()),
+                    }
+                }
+                Ok(bits)
+            }
+            _ => bail!("Instruction '{}' expects a 'u8' array", Self::opcode()),
+        }
+    }
+}
+
+impl<N: Network> Parser for EcdsaVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for EcdsaVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for EcdsaVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for EcdsaVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for EcdsaVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for EcdsaVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = EcdsaVerify::<CurrentNetwork>::parse("ecdsa.verify.secp256k1 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(is.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(is.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(is.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(is.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+}
@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Multiplies the group generator by the scalar `first`, storing the outcome in `destination`.
+pub type GroupGen<N> = GroupInstruction<N, { Variant::GroupGen as u8 }>;
+/// Computes the multi-scalar multiplication of the group array `first` and the scalar array `second`, storing the outcome in `destination`.
+pub type Msm<N> = GroupInstruction<N, { Variant::Msm as u8 }>;
+/// Hashes the field `first` to a group element using Poseidon with an input rate of 2, storing the outcome in `destination`.
+pub type HashToGroupPSD2<N> = GroupInstruction<N, { Variant::HashToGroupPSD2 as u8 }>;
+
+enum Variant {
+    GroupGen,
+    Msm,
+    HashToGroupPSD2,
+}
+
+/// Computes an operation on group elements, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct GroupInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> GroupInstruction<N, VARIANT> {
+    /// Initializes a new `group` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Group("group.gen"),
+            1 => Opcode::Group("msm"),
+            2 => Opcode::Group("hash_to_group.psd2"),
+            _ => panic!("Invalid 'group' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands for the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            1 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> GroupInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load(stack, &self.operands[0])? {
+                Value::Plaintext(Plaintext::Literal(Literal::Scalar(scalar), _)) => N::g_scalar_multiply(&scalar),
+                value => bail!("Instruction '{}' expects a scalar, found '{value}'", Self::opcode()),
+            },
+            1 => {
+                // Retrieve the bases and the scalars.
+                let bases = match registers.load(stack, &self.operands[0])? {
+                    Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                        .iter()
+                        .map(|element| match element {
+                            Plaintext::Literal(Literal::Group(base), _) => Ok(*base),
+                            _ => bail!("Instruction '{}' expects an array of groups", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    value => bail!("Instruction '{}' expects an array of groups, found '{value}'", Self::opcode()),
+                };
+                let scalars = match registers.load(stack, &self.operands[1])? {
+                    Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                        .iter()
+                        .map(|element| match element {
+                            Plaintext::Literal(Literal::Scalar(scalar), _) => Ok(*scalar),
+                            _ => bail!("Instruction '{}' expects an array of scalars", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    value => bail!("Instruction '{}' expects an array of scalars, found '{value}'", Self::opcode()),
+                };
+                // Ensure the number of bases and scalars match.
+                ensure!(
+                    bases.len() == scalars.len(),
+                    "Instruction '{}' expects the same number of groups and scalars",
+                    Self::opcode()
+                );
+                // Compute the sum of the products.
+                match bases.iter().zip_eq(&scalars).map(|(base, scalar)| *base * *scalar).reduce(|a, b| a + b) {
+                    Some(output) => output,
+                    None => bail!("Instruction '{}' expects at least one (group, scalar) pair", Self::opcode()),
+                }
+            }
+            2 => match registers.load(stack, &self.operands[0])? {
+                Value::Plaintext(Plaintext::Literal(Literal::Field(field), _)) => N::hash_to_group_psd2(&[field])?,
+                value => bail!("Instruction '{}' expects a field, found '{value}'", Self::opcode()),
+            },
+            _ => bail!("Invalid 'group' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Group(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => match registers.load_circuit(stack, &self.operands[0])? {
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(scalar), _)) => {
+                    A::g_scalar_multiply(&scalar)
+                }
+                _ => bail!("Instruction '{}' expects a scalar", Self::opcode()),
+            },
+            1 => {
+                // Retrieve the bases and the scalars.
+                let bases = match registers.load_circuit(stack, &self.operands[0])? {
+                    circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+                        .into_iter()
+                        .map(|element| match element {
+                            circuit::Plaintext::Literal(circuit::Literal::Group(base), _) => Ok(base),
+                            _ => bail!("Instruction '{}' expects an array of groups", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    _ => bail!("Instruction '{}' expects an array of groups", Self::opcode()),
+                };
+                let scalars = match registers.load_circuit(stack, &self.operands[1])? {
+                    circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+                        .into_iter()
+                        .map(|element| match element {
+                            circuit::Plaintext::Literal(circuit::Literal::Scalar(scalar), _) => Ok(scalar),
+                            _ => bail!("Instruction '{}' expects an array of scalars", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    _ => bail!("Instruction '{}' expects an array of scalars", Self::opcode()),
+                };
+                // Ensure the number of bases and scalars match.
+                ensure!(
+                    bases.len() == scalars.len(),
+                    "Instruction '{}' expects the same number of groups and scalars",
+                    Self::opcode()
+                );
+                // Compute the sum of the products.
+                match bases.iter().zip_eq(&scalars).map(|(base, scalar)| base * scalar).reduce(|a, b| a + b) {
+                    Some(output) => output,
+                    None => bail!("Instruction '{}' expects at least one (group, scalar) pair", Self::opcode()),
+                }
+            }
+            2 => match registers.load_circuit(stack, &self.operands[0])? {
+                circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(field), _)) => {
+                    A::hash_to_group_psd2(&[field])
+                }
+                _ => bail!("Instruction '{}' expects a field", Self::opcode()),
+            },
+            _ => bail!("Invalid 'group' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Group(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        match VARIANT {
+            0 => ensure!(
+                input_types[0] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)),
+                "Instruction '{}' expects a 'scalar', found '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+            1 => match (&input_types[0], &input_types[1]) {
+                (
+                    RegisterType::Plaintext(PlaintextType::Array(bases)),
+                    RegisterType::Plaintext(PlaintextType::Array(scalars)),
+                ) => {
+                    // Ensure the element types are correct.
+                    ensure!(
+                        Self::is_array_of(bases, LiteralType::Group),
+                        "Instruction '{}' expects an array of groups, found '{bases}'",
+                        Self::opcode()
+                    );
+                    ensure!(
+                        Self::is_array_of(scalars, LiteralType::Scalar),
+                        "Instruction '{}' expects an array of scalars, found '{scalars}'",
+                        Self::opcode()
+                    );
+                    // Ensure the number of bases and scalars match.
+                    ensure!(
+                        bases.length() == scalars.length(),
+                        "Instruction '{}' expects the same number of groups and scalars, found '{bases}' and '{scalars}'",
+                        Self::opcode()
+                    );
+                }
+                (first, second) => {
+                    bail!("Instruction '{}' expects two arrays, found '{first}' and '{second}'", Self::opcode())
+                }
+            },
+            2 => ensure!(
+                input_types[0] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field)),
+                "Instruction '{}' expects a 'field', found '{}'",
+                Self::opcode(),
+                input_types[0]
+            ),
+            _ => bail!("Invalid 'group' variant: {VARIANT}"),
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))])
+    }
+
+    /// Returns `true` if the given array type is a one-dimensional array of the given literal type.
+    fn is_array_of(array_type: &ArrayType<N>, literal_type: LiteralType) -> bool {
+        array_type.next_element_type() == &PlaintextType::Literal(literal_type)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for GroupInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for GroupInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for GroupInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for GroupInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for GroupInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for GroupInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, group) = GroupGen::<CurrentNetwork>::parse("group.gen r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(group.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(group.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(group.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, group) = Msm::<CurrentNetwork>::parse("msm r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(group.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(group.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(group.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(group.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, group) = HashToGroupPSD2::<CurrentNetwork>::parse("hash_to_group.psd2 1field into r0").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(group.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(group.operands[0], Operand::from_str("1field").unwrap(), "The first operand is incorrect");
+        assert_eq!(group.destination, Register::Locator(0), "The destination register is incorrect");
+
+        // Ensure the number of operands is enforced.
+        assert!(GroupGen::<CurrentNetwork>::parse("group.gen r0 r1 into r2").is_err());
+        assert!(Msm::<CurrentNetwork>::parse("msm r0 into r1").is_err());
+    }
+}
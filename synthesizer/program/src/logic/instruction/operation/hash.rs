@@ -50,6 +50,9 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::HashPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::HashPSD8 as u8 }>;
 
+/// SHA-256 is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashSha256<N> = HashInstruction<N, { Hasher::HashSha256 as u8 }>;
+
 /// SHA3-256 is a cryptographic hash function that outputs a 256-bit digest.
 pub type HashSha3_256<N> = HashInstruction<N, { Hasher::HashSha3_256 as u8 }>;
 /// SHA3-384 is a cryptographic hash function that outputs a 384-bit digest.
@@ -77,6 +80,7 @@ enum Hasher {
     HashPSD2,
     HashPSD4,
     HashPSD8,
+    HashSha256,
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
@@ -88,7 +92,7 @@ enum Hasher {
 /// Returns the expected number of operands given the variant.
 const fn expected_num_operands(variant: u8) -> usize {
     match variant {
-        15..=17 => 2,
+        16..=18 => 2,
         _ => 1,
     }
 }
@@ -156,13 +160,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             9 => Opcode::Hash("hash.psd2"),
             10 => Opcode::Hash("hash.psd4"),
             11 => Opcode::Hash("hash.psd8"),
-            12 => Opcode::Hash("hash.sha3_256"),
-            13 => Opcode::Hash("hash.sha3_384"),
-            14 => Opcode::Hash("hash.sha3_512"),
-            15 => Opcode::Hash("hash_many.psd2"),
-            16 => Opcode::Hash("hash_many.psd4"),
-            17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            12 => Opcode::Hash("hash.sha256"),
+            13 => Opcode::Hash("hash.sha3_256"),
+            14 => Opcode::Hash("hash.sha3_384"),
+            15 => Opcode::Hash("hash.sha3_512"),
+            16 => Opcode::Hash("hash_many.psd2"),
+            17 => Opcode::Hash("hash_many.psd4"),
+            18 => Opcode::Hash("hash_many.psd8"),
+            19.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -237,26 +242,31 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             }
             (11, PlaintextType::Literal(..)) => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
             (12, PlaintextType::Literal(..)) => {
-                Literal::Group(N::hash_to_group_bhp256(&N::hash_sha3_256(&input.to_bits_le())?)?)
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_sha256(&input.to_bits_le())?)?)
             }
             (13, PlaintextType::Literal(..)) => {
-                Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_384(&input.to_bits_le())?)?)
+                Literal::Group(N::hash_to_group_bhp256(&N::hash_sha3_256(&input.to_bits_le())?)?)
             }
             (14, PlaintextType::Literal(..)) => {
+                Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_384(&input.to_bits_le())?)?)
+            }
+            (15, PlaintextType::Literal(..)) => {
                 Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&input.to_bits_le())?)?)
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (16, _) => bail!("'hash_many.psd2' is not yet implemented"),
+            (17, _) => bail!("'hash_many.psd4' is not yet implemented"),
+            (18, _) => bail!("'hash_many.psd8' is not yet implemented"),
+            (19.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
@@ -308,26 +318,31 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             }
             (11, PlaintextType::Literal(..)) => circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
             (12, PlaintextType::Literal(..)) => {
-                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_sha3_256(&input.to_bits_le())))
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_sha256(&input.to_bits_le())))
             }
             (13, PlaintextType::Literal(..)) => {
-                circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_384(&input.to_bits_le())))
+                circuit::Literal::Group(A::hash_to_group_bhp256(&A::hash_sha3_256(&input.to_bits_le())))
             }
             (14, PlaintextType::Literal(..)) => {
+                circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_384(&input.to_bits_le())))
+            }
+            (15, PlaintextType::Literal(..)) => {
                 circuit::Literal::Group(A::hash_to_group_bhp512(&A::hash_sha3_512(&input.to_bits_le())))
             }
-            (15, _) => bail!("'hash_many.psd2' is not yet implemented"),
-            (16, _) => bail!("'hash_many.psd4' is not yet implemented"),
-            (17, _) => bail!("'hash_many.psd8' is not yet implemented"),
-            (18.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
+            (16, _) => bail!("'hash_many.psd2' is not yet implemented"),
+            (17, _) => bail!("'hash_many.psd4' is not yet implemented"),
+            (18, _) => bail!("'hash_many.psd8' is not yet implemented"),
+            (19.., _) => bail!("Invalid 'hash' variant: {VARIANT}"),
             (_, PlaintextType::Struct(..)) => bail!("Cannot hash into a struct"),
             (_, PlaintextType::Array(..)) => bail!("Cannot hash into an array (yet)"),
+            (_, PlaintextType::Vector(..)) => bail!("Cannot hash into a vector"),
         };
         // Cast the output to the destination type.
         let output = match self.destination_type {
             PlaintextType::Literal(literal_type) => output.cast_lossy(literal_type)?,
             PlaintextType::Struct(..) => bail!("Cannot hash into a struct"),
             PlaintextType::Array(..) => bail!("Cannot hash into an array (yet)"),
+            PlaintextType::Vector(..) => bail!("Cannot hash into a vector"),
         };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
@@ -362,9 +377,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
-            15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            0..=15 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
+            16..=18 => bail!("'hash_many' is not yet implemented"),
+            19.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -27,6 +27,12 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
+mod group;
+pub use group::*;
+
 mod hash;
 pub use hash::*;
 
@@ -38,9 +44,27 @@ pub use literals::*;
 
 mod macros;
 
+mod repeat;
+pub use repeat::*;
+
+mod sign_schnorr;
+pub use sign_schnorr::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
+mod string_to_bytes;
+pub use string_to_bytes::*;
+
+mod switch;
+pub use switch::*;
+
+mod vector;
+pub use vector::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -0,0 +1,416 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{InstructionTrait, RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Instruction,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Register, RegisterType},
+    types::U32,
+};
+
+/// Repeats the body instructions a fixed number of times, unrolling them into a static circuit.
+/// i.e. `repeat 4u32 carry r1: add r1 r0 into r2; end;`
+///
+/// On each iteration, the destinations of the body are shifted by the `stride`
+/// (the number of destinations assigned by the body). A value is only carried from one iteration
+/// into the next through a register declared with `carry`, which must be one of the `stride` registers
+/// preceding the block: on the first iteration it reads that register, and on every following iteration
+/// it reads the body destination `stride` registers after it, as assigned by the previous iteration.
+/// Every other register preceding the block is read as-is on every iteration.
+/// The counter of the current iteration is available to the body as the constant `u32` operand `repeat.index`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repeat<N: Network> {
+    /// The number of iterations.
+    count: U32<N>,
+    /// The registers carried from one iteration into the next.
+    carries: Vec<Register<N>>,
+    /// The body instructions, in order of execution.
+    instructions: Vec<Instruction<N>>,
+}
+
+impl<N: Network> Repeat<N> {
+    /// Initializes a new `repeat` instruction.
+    #[inline]
+    pub fn new(count: U32<N>, carries: Vec<Register<N>>, instructions: Vec<Instruction<N>>) -> Result<Self> {
+        // Ensure the number of iterations is within the bounds.
+        ensure!(*count > 0, "A 'repeat' block must have at least one iteration");
+        ensure!(
+            *count as usize <= N::MAX_REPEAT_ITERATIONS,
+            "A 'repeat' block cannot exceed {} iterations",
+            N::MAX_REPEAT_ITERATIONS
+        );
+        // Ensure the number of instructions is within the bounds.
+        ensure!(!instructions.is_empty(), "A 'repeat' block must contain at least one instruction");
+        ensure!(
+            instructions.len() <= N::MAX_INSTRUCTIONS,
+            "A 'repeat' block cannot contain more than {} instructions",
+            N::MAX_INSTRUCTIONS
+        );
+        // Ensure the number of unrolled instructions is within the bounds.
+        ensure!(
+            (*count as usize).saturating_mul(instructions.len()) <= N::MAX_INSTRUCTIONS,
+            "A 'repeat' block cannot unroll into more than {} instructions",
+            N::MAX_INSTRUCTIONS
+        );
+        // Ensure the body does not contain instructions that cannot be unrolled.
+        for instruction in instructions.iter() {
+            match instruction.opcode() {
+                Opcode::Async => bail!("An 'async' instruction is not allowed in a 'repeat' block"),
+                Opcode::Call => bail!("A 'call' instruction is not allowed in a 'repeat' block"),
+                Opcode::Repeat => bail!("A 'repeat' block cannot be nested in another 'repeat' block"),
+                _ => {}
+            }
+        }
+        // Initialize the instruction.
+        let repeat = Self { count, carries, instructions };
+        // Ensure the carried registers are distinct locators, in the window preceding the body destinations.
+        let (start, stride) = (repeat.start(), repeat.stride());
+        for (index, register) in repeat.carries.iter().enumerate() {
+            ensure!(matches!(register, Register::Locator(..)), "A carried register '{register}' must be a locator");
+            ensure!(
+                !repeat.carries[..index].contains(register),
+                "A 'repeat' block cannot carry '{register}' more than once"
+            );
+            ensure!(
+                (start.saturating_sub(stride)..start).contains(&register.locator()),
+                "A 'repeat' block can only carry the {stride} registers preceding it, found '{register}'"
+            );
+        }
+        // Return the instruction.
+        Ok(repeat)
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Repeat
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub const fn count(&self) -> &U32<N> {
+        &self.count
+    }
+
+    /// Returns the registers carried from one iteration into the next.
+    #[inline]
+    pub fn carries(&self) -> &[Register<N>] {
+        &self.carries
+    }
+
+    /// Returns the body instructions.
+    #[inline]
+    pub fn instructions(&self) -> &[Instruction<N>] {
+        &self.instructions
+    }
+
+    /// Returns the number of instructions, once the block is unrolled.
+    #[inline]
+    pub fn num_unrolled_instructions(&self) -> usize {
+        (*self.count as usize).saturating_mul(self.instructions.len())
+    }
+
+    /// Returns the operands in the operation.
+    /// Note: The operands of the body are only resolved once the block is unrolled.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &[]
+    }
+
+    /// Returns the destination registers, across all iterations.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        // Retrieve the destinations of a single iteration.
+        let destinations = self.body_destinations();
+        // Retrieve the stride.
+        let stride = destinations.len() as u64;
+        // Shift the destinations into each iteration.
+        (0..*self.count as u64)
+            .flat_map(|iteration| {
+                destinations.iter().map(move |register| Register::Locator(register.locator() + iteration * stride))
+            })
+            .collect()
+    }
+
+    /// Returns the number of destination registers assigned in a single iteration.
+    #[inline]
+    pub fn stride(&self) -> u64 {
+        self.body_destinations().len() as u64
+    }
+
+    /// Returns the locator of the first destination register in the body.
+    /// Every register at or above it is shifted on each iteration.
+    #[inline]
+    pub fn start(&self) -> u64 {
+        self.body_destinations().first().map(|register| register.locator()).unwrap_or_default()
+    }
+
+    /// Returns the destination registers of a single iteration.
+    fn body_destinations(&self) -> Vec<Register<N>> {
+        self.instructions.iter().flat_map(|instruction| instruction.destinations()).collect()
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'repeat'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        bail!("Forbidden operation: The output types of a 'repeat' are resolved in 'RegisterTypes'.")
+    }
+}
+
+impl<N: Network> Parser for Repeat<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses a carried register from the string.
+        fn parse_carry<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the carried register from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the number of iterations from the string.
+        let (string, count) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Optionally parse the "carry" from the string.
+        let (string, carries) = match opt(tag("carry"))(string)? {
+            // If the "carry" was not parsed, return the string and an empty vector of carried registers.
+            (string, None) => (string, vec![]),
+            // If the "carry" was parsed, parse the carried registers from the string.
+            (string, Some(_)) => {
+                let (string, carries) = many1(complete(parse_carry))(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                (string, carries)
+            }
+        };
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+        // Parse the body instructions from the string.
+        let (string, instructions) = many1(Instruction::parse)(string)?;
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'end' keyword from the string.
+        let (string, _) = tag("end")(string)?;
+
+        // Initialize the instruction, ensuring it is well-formed.
+        map_res(take(0usize), move |_| Self::new(count, carries.clone(), instructions.clone()))(string)
+    }
+}
+
+impl<N: Network> FromStr for Repeat<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Repeat<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Repeat<N> {
+    /// Prints the operation to a string.
+    /// Note: The body is indented relative to the `repeat`, and the enclosing scope indents every line of the block.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", Self::opcode(), self.count)?;
+        if !self.carries.is_empty() {
+            write!(f, " carry {}", self.carries.iter().map(|register| register.to_string()).join(" "))?;
+        }
+        write!(f, ":")?;
+        self.instructions.iter().try_for_each(|instruction| write!(f, "\n    {instruction}"))?;
+        write!(f, "\nend")
+    }
+}
+
+impl<N: Network> FromBytes for Repeat<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of iterations.
+        let count = U32::read_le(&mut reader)?;
+
+        // Read the number of carried registers.
+        let num_carries = u16::read_le(&mut reader)?;
+        // Read the carried registers.
+        let mut carries = Vec::with_capacity(num_carries as usize);
+        for _ in 0..num_carries {
+            carries.push(Register::read_le(&mut reader)?);
+        }
+
+        // Read the number of instructions.
+        let num_instructions = u32::read_le(&mut reader)?;
+        // Ensure the number of instructions is within the bounds.
+        if num_instructions as usize > N::MAX_INSTRUCTIONS {
+            return Err(error(format!("Failed to deserialize a 'repeat': too many instructions ({num_instructions})")));
+        }
+        // Read the instructions.
+        let mut instructions = Vec::with_capacity(num_instructions as usize);
+        for _ in 0..num_instructions {
+            instructions.push(Instruction::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Self::new(count, carries, instructions).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Repeat<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of instructions is within the bounds.
+        if self.instructions.len() > N::MAX_INSTRUCTIONS {
+            return Err(error(format!("The number of instructions must be <= {}", N::MAX_INSTRUCTIONS)));
+        }
+
+        // Write the number of iterations.
+        self.count.write_le(&mut writer)?;
+        // Write the number of carried registers.
+        u16::try_from(self.carries.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the carried registers.
+        self.carries.iter().try_for_each(|register| register.write_le(&mut writer))?;
+        // Write the number of instructions.
+        u32::try_from(self.instructions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the instructions.
+        self.instructions.iter().try_for_each(|instruction| instruction.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const TEST_CASES: &[&str] = &[
+        "repeat 1u32:\n    add r0 r1 into r2;\nend",
+        "repeat 4u32 carry r1:\n    add r1 r0 into r2;\nend",
+        "repeat 8u32 carry r1 r2:\n    mul r1 r0 into r3;\n    add r3 r2 into r4;\nend",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, repeat) =
+            Repeat::<CurrentNetwork>::parse("repeat 3u32 carry r2: mul r1 r0 into r3; add r3 r2 into r4; end").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.count(), U32::new(3));
+        assert_eq!(repeat.carries(), &[Register::Locator(2)]);
+        assert_eq!(repeat.instructions().len(), 2);
+        assert_eq!(repeat.stride(), 2);
+        assert_eq!(repeat.start(), 3);
+        assert_eq!(repeat.destinations(), (3..9).map(Register::Locator).collect::<Vec<_>>());
+        assert_eq!(repeat.num_unrolled_instructions(), 6);
+
+        // Ensure a block without carried registers is parsed.
+        let repeat = Repeat::<CurrentNetwork>::from_str("repeat 4u32: add r0 repeat.index into r1; end").unwrap();
+        assert!(repeat.carries().is_empty());
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Ensure the number of iterations is bounded.
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 0u32: add r0 r1 into r2; end").is_err());
+        let count = CurrentNetwork::MAX_REPEAT_ITERATIONS + 1;
+        assert!(Repeat::<CurrentNetwork>::from_str(&format!("repeat {count}u32: add r0 r1 into r2; end")).is_err());
+        // Ensure the body is not empty.
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32: end").is_err());
+        // Ensure the unrolled body is bounded.
+        let body = "add r0 r1 into r2; ".repeat(CurrentNetwork::MAX_INSTRUCTIONS / 2 + 1);
+        assert!(Repeat::<CurrentNetwork>::from_str(&format!("repeat 2u32: {body}end")).is_err());
+        // Ensure the body does not contain calls, async calls, or nested blocks.
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32: call foo r0 into r1; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32: async foo r0 into r1; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32: repeat 2u32: add r0 r1 into r2; end; end").is_err());
+        // Ensure the carried registers are distinct locators, in the window preceding the body destinations.
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32 carry: add r0 r1 into r2; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32 carry r1.foo: add r0 r1 into r2; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32 carry r1 r1: add r0 r1 into r2; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32 carry r0: add r0 r1 into r2; end").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 2u32 carry r2: add r0 r1 into r2; end").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(Repeat::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = Repeat::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Repeat::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
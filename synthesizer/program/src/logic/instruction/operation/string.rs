@@ -0,0 +1,480 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::StringType,
+};
+
+/// Concatenates the strings `first` and `second`, storing the outcome in `destination`.
+pub type StringConcat<N> = StringInstruction<N, { Variant::StringConcat as u8 }>;
+/// Converts the `u8` array `first` into a string, ignoring trailing zeros, storing the outcome in `destination`.
+pub type StringFromBytes<N> = StringInstruction<N, { Variant::StringFromBytes as u8 }>;
+/// Retrieves the byte of string `first` at index `second`, storing the outcome in `destination`.
+pub type StringGet<N> = StringInstruction<N, { Variant::StringGet as u8 }>;
+/// Computes the size of string `first` in bytes, storing the outcome in `destination`.
+pub type StringLen<N> = StringInstruction<N, { Variant::StringLen as u8 }>;
+
+enum Variant {
+    StringConcat,
+    StringFromBytes,
+    StringGet,
+    StringLen,
+}
+
+/// Computes an operation on strings, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Initializes a new `string` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::String("string.concat"),
+            1 => Opcode::String("string.from_bytes"),
+            2 => Opcode::String("string.get"),
+            3 => Opcode::String("string.len"),
+            _ => panic!("Invalid 'string' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands for the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            0 | 2 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> StringInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the inputs.
+        let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            1 => {
+                // Retrieve the bytes.
+                let bytes = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                        .iter()
+                        .map(|element| match element {
+                            Plaintext::Literal(Literal::U8(byte), _) => Ok(*byte),
+                            _ => bail!("Instruction '{}' expects a 'u8' array, found '{element}'", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    input => bail!("Instruction '{}' expects a 'u8' array, found '{input}'", Self::opcode()),
+                };
+                Literal::String(StringType::from_padded_bytes(&bytes)?)
+            }
+            _ => {
+                // Retrieve the literals.
+                let literals = inputs
+                    .iter()
+                    .map(|input| match input {
+                        Value::Plaintext(Plaintext::Literal(literal, _)) => Ok(literal),
+                        _ => bail!("Instruction '{}' expects literal operands, found '{input}'", Self::opcode()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                match (VARIANT, literals.as_slice()) {
+                    (0, [Literal::String(a), Literal::String(b)]) => Literal::String(a.concat(b)?),
+                    (2, [Literal::String(string), Literal::U32(index)]) => Literal::U8(string.byte_at(index)?),
+                    (3, [Literal::String(string)]) => Literal::U32(string.size_in_bytes()),
+                    (0 | 2 | 3, _) => bail!("Instruction '{}' received invalid operands", Self::opcode()),
+                    _ => bail!("Invalid 'string' variant: {VARIANT}"),
+                }
+            }
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::{Eject, Literal, Plaintext, Value};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the inputs.
+        let inputs: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            1 => {
+                // Retrieve the bytes.
+                let bytes = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Array(elements, _)) => elements
+                        .iter()
+                        .map(|element| match element {
+                            Plaintext::Literal(Literal::U8(byte), _) => Ok(byte.clone()),
+                            _ => bail!("Instruction '{}' expects a 'u8' array", Self::opcode()),
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    _ => bail!("Instruction '{}' expects a 'u8' array", Self::opcode()),
+                };
+                // Ensure the bytes are valid UTF-8, before enforcing it in the circuit.
+                StringType::from_padded_bytes(&bytes.eject_value())?;
+                Literal::String(circuit::StringType::from_padded_bytes(&bytes))
+            }
+            _ => {
+                // Retrieve the literals.
+                let literals = inputs
+                    .iter()
+                    .map(|input| match input {
+                        Value::Plaintext(Plaintext::Literal(literal, _)) => Ok(literal),
+                        _ => bail!("Instruction '{}' expects literal operands", Self::opcode()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                match (VARIANT, literals.as_slice()) {
+                    (0, [Literal::String(a), Literal::String(b)]) => {
+                        // Ensure the concatenated string is within the allowed capacity.
+                        a.eject_value().concat(&b.eject_value())?;
+                        Literal::String(a.concat(b))
+                    }
+                    (2, [Literal::String(string), Literal::U32(index)]) => {
+                        // Ensure the string is not empty.
+                        ensure!(
+                            !string.eject_value().is_empty(),
+                            "Instruction '{}' cannot index into an empty string",
+                            Self::opcode()
+                        );
+                        Literal::U8(string.byte_at(index))
+                    }
+                    (3, [Literal::String(string)]) => Literal::U32(string.size_in_bytes()),
+                    (0 | 2 | 3, _) => bail!("Instruction '{}' received invalid operands", Self::opcode()),
+                    _ => bail!("Invalid 'string' variant: {VARIANT}"),
+                }
+            }
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        let string_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String));
+        let output_type = match VARIANT {
+            0 => {
+                // Ensure the operands are strings.
+                for input_type in input_types {
+                    ensure!(
+                        input_type == &string_type,
+                        "Instruction '{}' expects a string, found '{input_type}'",
+                        Self::opcode()
+                    );
+                }
+                LiteralType::String
+            }
+            1 => {
+                // Ensure the operand is a `u8` array.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Array(array_type))
+                        if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8) =>
+                    {
+                        // Ensure the array fits within the maximum string size.
+                        ensure!(
+                            **array_type.length() <= N::MAX_STRING_BYTES,
+                            "Instruction '{}' expects at most {} bytes, found '{array_type}'",
+                            Self::opcode(),
+                            N::MAX_STRING_BYTES
+                        );
+                    }
+                    input_type => bail!("Instruction '{}' expects a 'u8' array, found '{input_type}'", Self::opcode()),
+                }
+                LiteralType::String
+            }
+            2 => {
+                // Ensure the operands are a string and a `u32` index.
+                ensure!(
+                    input_types[0] == string_type,
+                    "Instruction '{}' expects a string, found '{}'",
+                    Self::opcode(),
+                    input_types[0]
+                );
+                ensure!(
+                    input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+                    "Instruction '{}' expects a 'u32' index, found '{}'",
+                    Self::opcode(),
+                    input_types[1]
+                );
+                LiteralType::U8
+            }
+            3 => {
+                // Ensure the operand is a string.
+                ensure!(
+                    input_types[0] == string_type,
+                    "Instruction '{}' expects a string, found '{}'",
+                    Self::opcode(),
+                    input_types[0]
+                );
+                LiteralType::U32
+            }
+            _ => bail!("Invalid 'string' variant: {VARIANT}"),
+        };
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(output_type))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for StringInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for StringInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for StringInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for StringInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for StringInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for StringInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) = StringConcat::<CurrentNetwork>::parse("string.concat r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(instruction.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, instruction) = StringFromBytes::<CurrentNetwork>::parse("string.from_bytes r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, instruction) = StringGet::<CurrentNetwork>::parse("string.get r0 2u32 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[1], Operand::from_str("2u32").unwrap(), "The second operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, instruction) = StringLen::<CurrentNetwork>::parse("string.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the number of operands is enforced.
+        assert!(StringLen::<CurrentNetwork>::parse("string.len r0 r1 into r2").is_err());
+        assert!(StringConcat::<CurrentNetwork>::parse("string.concat r0 into r1").is_err());
+    }
+}
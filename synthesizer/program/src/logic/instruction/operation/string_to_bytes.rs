@@ -0,0 +1,316 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Converts the string `first` into a `u8` array, padded with trailing zeros, storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringToBytes<N: Network> {
+    /// The operand.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination array type.
+    destination_type: ArrayType<N>,
+}
+
+impl<N: Network> StringToBytes<N> {
+    /// Initializes a new `string.to_bytes` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, destination_type: ArrayType<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Ensure the destination type is a `u8` array.
+        ensure!(Self::is_valid_destination_type(&destination_type), "Invalid destination type '{destination_type}'");
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::String("string.to_bytes")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there is exactly one operand.
+        debug_assert!(self.operands.len() == 1, "Instruction '{}' must have one operand", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination array type.
+    #[inline]
+    pub const fn destination_type(&self) -> &ArrayType<N> {
+        &self.destination_type
+    }
+
+    /// Returns `true` if the given type is a one-dimensional `u8` array.
+    fn is_valid_destination_type(destination_type: &ArrayType<N>) -> bool {
+        destination_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+    }
+}
+
+impl<N: Network> StringToBytes<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the string.
+        let string = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Literal(Literal::String(string), _)) => string,
+            value => bail!("Instruction '{}' expects a string, found '{value}'", Self::opcode()),
+        };
+        // Convert the string into padded bytes.
+        let bytes = string.to_padded_bytes(**self.destination_type.length())?;
+        // Construct the array.
+        let array = Plaintext::Array(
+            bytes.into_iter().map(|byte| Plaintext::from(Literal::U8(byte))).collect(),
+            Default::default(),
+        );
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(array))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Eject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 1 {
+            bail!("Instruction '{}' expects 1 operand, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the string.
+        let string = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::String(string), _)) => string,
+            _ => bail!("Instruction '{}' expects a string", Self::opcode()),
+        };
+        // Ensure the string fits within the destination type.
+        let length = **self.destination_type.length();
+        if string.eject_value().len() > length as usize {
+            bail!("Instruction '{}' cannot fit the string into '{}'", Self::opcode(), self.destination_type)
+        }
+        // Convert the string into padded bytes.
+        let bytes = string.to_padded_bytes(length);
+        // Construct the array.
+        let array = circuit::Plaintext::Array(
+            bytes.into_iter().map(|byte| circuit::Plaintext::from(circuit::Literal::U8(byte))).collect(),
+            Default::default(),
+        );
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 1 {
+            bail!("Instruction '{}' expects 1 input, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the operand is a string.
+        ensure!(
+            input_types[0] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String)),
+            "Instruction '{}' expects a string, found '{}'",
+            Self::opcode(),
+            input_types[0]
+        );
+        // Ensure the destination type is a `u8` array.
+        ensure!(
+            Self::is_valid_destination_type(&self.destination_type),
+            "Instruction '{}' expects a 'u8' array destination, found '{}'",
+            Self::opcode(),
+            self.destination_type
+        );
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Array(self.destination_type.clone()))])
+    }
+}
+
+impl<N: Network> Parser for StringToBytes<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the operand from the string.
+        let (string, operand) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination type from the string.
+        let (string, destination_type) = ArrayType::parse(string)?;
+        // Ensure the destination type is allowed.
+        match Self::is_valid_destination_type(&destination_type) {
+            true => Ok((string, Self { operands: vec![operand], destination, destination_type })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse '{}': the destination must be a 'u8' array", Self::opcode())))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for StringToBytes<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for StringToBytes<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for StringToBytes<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} into {} as {}", Self::opcode(), self.operands[0], self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network> FromBytes for StringToBytes<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operand.
+        let operand = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination type.
+        let destination_type = ArrayType::read_le(&mut reader)?;
+        // Ensure the destination type is allowed.
+        if !Self::is_valid_destination_type(&destination_type) {
+            return Err(error(format!("Invalid destination type '{destination_type}'")));
+        }
+        // Return the operation.
+        Ok(Self { operands: vec![operand], destination, destination_type })
+    }
+}
+
+impl<N: Network> ToBytes for StringToBytes<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 1.
+        if self.operands.len() != 1 {
+            return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
+        }
+        // Write the operand.
+        self.operands[0].write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, instruction) =
+            StringToBytes::<CurrentNetwork>::parse("string.to_bytes r0 into r1 as [u8; 16u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(instruction.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(instruction.operands[0], Operand::Register(Register::Locator(0)), "The operand is incorrect");
+        assert_eq!(instruction.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            instruction.destination_type,
+            ArrayType::from_str("[u8; 16u32]").unwrap(),
+            "The destination type is incorrect"
+        );
+
+        // Ensure the destination type must be a `u8` array.
+        assert!(StringToBytes::<CurrentNetwork>::parse("string.to_bytes r0 into r1 as [u16; 16u32]").is_err());
+        assert!(StringToBytes::<CurrentNetwork>::parse("string.to_bytes r0 into r1 as [[u8; 2u32]; 2u32]").is_err());
+    }
+}
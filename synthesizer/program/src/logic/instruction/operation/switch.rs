@@ -0,0 +1,397 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::{
+    traits::{Equal, FromBoolean, One, Ternary, Zero},
+    Eject,
+    Inject,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{U16, U32, U8},
+};
+
+/// Selects the case operand at the index given by the selector `first`, storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Switch<N: Network> {
+    /// The operands, consisting of the selector followed by the cases.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Switch<N> {
+    /// The minimum number of cases in a `switch` instruction.
+    pub const MIN_CASES: usize = 2;
+
+    /// Initializes a new `switch` instruction.
+    #[inline]
+    pub fn new(selector: Operand<N>, cases: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Ensure the number of cases is within the bounds.
+        ensure!(Self::is_valid_num_cases(cases.len()), "Instruction 'switch' has an invalid number of cases");
+        // Prepare the operands.
+        let mut operands = Vec::with_capacity(1 + cases.len());
+        operands.push(selector);
+        operands.extend(cases);
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Switch
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the selector operand.
+    #[inline]
+    pub fn selector(&self) -> &Operand<N> {
+        &self.operands[0]
+    }
+
+    /// Returns the case operands.
+    #[inline]
+    pub fn cases(&self) -> &[Operand<N>] {
+        &self.operands[1..]
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns `true` if the given number of cases is within the bounds.
+    /// Note: The selector counts towards the maximum number of operands.
+    #[inline]
+    fn is_valid_num_cases(num_cases: usize) -> bool {
+        (Self::MIN_CASES..N::MAX_OPERANDS).contains(&num_cases)
+    }
+}
+
+impl<N: Network> Switch<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of cases is correct.
+        if !Self::is_valid_num_cases(self.cases().len()) {
+            bail!("Instruction '{}' has an invalid number of cases ({})", Self::opcode(), self.cases().len())
+        }
+
+        // Retrieve the selector.
+        let index = match registers.load(stack, self.selector())? {
+            Value::Plaintext(Plaintext::Literal(Literal::U8(selector), _)) => *selector as usize,
+            Value::Plaintext(Plaintext::Literal(Literal::U16(selector), _)) => *selector as usize,
+            Value::Plaintext(Plaintext::Literal(Literal::U32(selector), _)) => *selector as usize,
+            value => {
+                bail!("Instruction '{}' expects a 'u8', 'u16', or 'u32' selector, found '{value}'", Self::opcode())
+            }
+        };
+        // Retrieve the selected case, ensuring the selector is in bounds.
+        let case = match self.cases().get(index) {
+            Some(case) => case,
+            None => bail!("Selector '{index}' is out of bounds for {} cases", self.cases().len()),
+        };
+        // Load the selected case.
+        let output = registers.load(stack, case)?;
+        // Store the output.
+        registers.store(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of cases is correct.
+        if !Self::is_valid_num_cases(self.cases().len()) {
+            bail!("Instruction '{}' has an invalid number of cases ({})", Self::opcode(), self.cases().len())
+        }
+        let num_cases = self.cases().len();
+
+        // Compute the one-hot encoding of the selector, where the i-th bit is set iff `selector == i`.
+        let bits: Vec<circuit::Boolean<A>> = match registers.load_circuit(stack, self.selector())? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U8(selector), _)) => {
+                (0..num_cases).map(|i| selector.is_equal(&circuit::U8::constant(U8::new(i as u8)))).collect()
+            }
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U16(selector), _)) => {
+                (0..num_cases).map(|i| selector.is_equal(&circuit::U16::constant(U16::new(i as u16)))).collect()
+            }
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U32(selector), _)) => {
+                (0..num_cases).map(|i| selector.is_equal(&circuit::U32::constant(U32::new(i as u32)))).collect()
+            }
+            _ => bail!("Instruction '{}' expects a 'u8', 'u16', or 'u32' selector", Self::opcode()),
+        };
+        // Ensure a constant selector is in bounds, as a constraint on constants cannot be left unsatisfied.
+        if bits.iter().all(|bit| bit.is_constant()) && bits.iter().filter(|bit| bit.eject_value()).count() != 1 {
+            bail!("Selector is out of bounds for {num_cases} cases")
+        }
+        // Ensure exactly one bit is set, which enforces that the selector is in bounds.
+        // Note: As the bits are mutually exclusive, this is a single linear constraint over their sum.
+        let sum = bits.iter().fold(circuit::Field::<A>::zero(), |sum, bit| sum + circuit::Field::from_boolean(bit));
+        A::assert_eq(&sum, &circuit::Field::<A>::one());
+
+        // Load the cases.
+        let cases = self
+            .cases()
+            .iter()
+            .map(|case| match registers.load_circuit(stack, case)? {
+                circuit::Value::Plaintext(plaintext) => Ok(plaintext),
+                _ => bail!("Instruction '{}' expects plaintext cases", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Select the case at the index.
+        // Note: The last case is selected by default, as its bit is implied by the one-hot constraint.
+        let (last, rest) = match cases.split_last() {
+            Some((last, rest)) => (last, rest),
+            None => bail!("Instruction '{}' has no cases", Self::opcode()),
+        };
+        let output = rest
+            .iter()
+            .zip_eq(&bits[..rest.len()])
+            .rev()
+            .fold(last.clone(), |selected, (case, bit)| circuit::Plaintext::ternary(bit, case, &selected));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != self.operands.len() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                self.operands.len(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of cases is correct.
+        if !Self::is_valid_num_cases(self.cases().len()) {
+            bail!("Instruction '{}' has an invalid number of cases ({})", Self::opcode(), self.cases().len())
+        }
+
+        // Ensure the selector is an unsigned integer.
+        match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8 | LiteralType::U16 | LiteralType::U32)) => {}
+            input_type => {
+                bail!("Instruction '{}' expects a 'u8', 'u16', or 'u32' selector, found '{input_type}'", Self::opcode())
+            }
+        }
+
+        // Ensure the cases are plaintexts that can be selected in a circuit.
+        let case_type = &input_types[1];
+        match case_type {
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String)) => {
+                bail!("Instruction '{}' cannot select between strings", Self::opcode())
+            }
+            RegisterType::Plaintext(PlaintextType::Vector(..)) => {
+                bail!("Instruction '{}' cannot select between vectors", Self::opcode())
+            }
+            RegisterType::Plaintext(..) => {}
+            _ => bail!("Instruction '{}' expects plaintext cases, found '{case_type}'", Self::opcode()),
+        }
+        // Ensure all of the cases are of the same type.
+        for input_type in &input_types[2..] {
+            ensure!(
+                input_type == case_type,
+                "Instruction '{}' expects all cases to be of type '{case_type}', found '{input_type}'",
+                Self::opcode()
+            );
+        }
+
+        Ok(vec![case_type.clone()])
+    }
+}
+
+impl<N: Network> Parser for Switch<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the selector and the cases from the string.
+        let (string, operands) = map_res(many1(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of cases is within the bounds.
+            match Self::is_valid_num_cases(operands.len().saturating_sub(1)) {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'switch' opcode: invalid number of cases")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Switch<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Switch<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Switch<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of cases is within the bounds.
+        if !Self::is_valid_num_cases(self.cases().len()) {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Switch<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of cases.
+        let num_cases = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of cases is within the bounds.
+        if !Self::is_valid_num_cases(num_cases) {
+            return Err(error(format!("Invalid number of cases in 'switch' ({num_cases})")));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(1 + num_cases);
+        // Read the selector and the cases.
+        for _ in 0..1 + num_cases {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Switch<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of cases is within the bounds.
+        if !Self::is_valid_num_cases(self.cases().len()) {
+            return Err(error(format!("Invalid number of cases in 'switch' ({})", self.cases().len())));
+        }
+        // Write the number of cases.
+        u8::try_from(self.cases().len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the selector and the cases.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, switch) = Switch::<CurrentNetwork>::parse("switch r0 r1 r2 5field into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(switch.selector(), &Operand::Register(Register::Locator(0)), "The selector is incorrect");
+        assert_eq!(switch.cases().len(), 3, "The number of cases is incorrect");
+        assert_eq!(switch.cases()[0], Operand::Register(Register::Locator(1)), "The first case is incorrect");
+        assert_eq!(switch.cases()[1], Operand::Register(Register::Locator(2)), "The second case is incorrect");
+        assert_eq!(switch.cases()[2], Operand::from_str("5field").unwrap(), "The third case is incorrect");
+        assert_eq!(switch.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the number of cases is enforced.
+        assert!(Switch::<CurrentNetwork>::parse("switch r0 into r1").is_err());
+        assert!(Switch::<CurrentNetwork>::parse("switch r0 r1 into r2").is_err());
+        let too_many = (0..=CurrentNetwork::MAX_OPERANDS).map(|i| format!("r{i} ")).collect::<String>();
+        assert!(Switch::<CurrentNetwork>::parse(&format!("switch {too_many}into r99")).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = Switch::<CurrentNetwork>::from_str("switch r0 1u8 2u8 r1 into r2").unwrap();
+        let candidate = Switch::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
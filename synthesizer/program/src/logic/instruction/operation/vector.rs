@@ -0,0 +1,449 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::{
+    traits::{Compare, Equal, Ternary},
+    Inject,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U32,
+};
+
+/// Retrieves the element of vector `first` at index `second`, storing the outcome in `destination`.
+pub type VecGet<N> = VectorInstruction<N, { Variant::VecGet as u8 }>;
+/// Computes the length of vector `first` as a `u32`, storing the outcome in `destination`.
+pub type VecLen<N> = VectorInstruction<N, { Variant::VecLen as u8 }>;
+/// Appends the element `second` to vector `first`, storing the outcome in `destination`.
+pub type VecPush<N> = VectorInstruction<N, { Variant::VecPush as u8 }>;
+
+enum Variant {
+    VecGet,
+    VecLen,
+    VecPush,
+}
+
+/// Computes an operation on a bounded vector, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct VectorInstruction<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Initializes a new `vec` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(
+            operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Vector("vec.get"),
+            1 => Opcode::Vector("vec.len"),
+            2 => Opcode::Vector("vec.push"),
+            _ => panic!("Invalid 'vec' instruction opcode"),
+        }
+    }
+
+    /// Returns the number of operands for the instruction.
+    #[inline]
+    const fn num_operands() -> usize {
+        match VARIANT {
+            1 => 1,
+            _ => 2,
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check the number of operands.
+        debug_assert!(
+            self.operands.len() == Self::num_operands(),
+            "Instruction '{}' must have {} operand(s)",
+            Self::opcode(),
+            Self::num_operands()
+        );
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> VectorInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the vector.
+        let vector = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            value => bail!("Instruction '{}' expects a vector, found '{value}'", Self::opcode()),
+        };
+        let (length, elements) = vector.to_vector()?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => {
+                // Retrieve the index.
+                let index = match registers.load(stack, &self.operands[1])? {
+                    Value::Plaintext(Plaintext::Literal(Literal::U32(index), _)) => index,
+                    value => bail!("Instruction '{}' expects a 'u32' index, found '{value}'", Self::opcode()),
+                };
+                // Ensure the index is in bounds.
+                ensure!(index < length, "Index '{index}' is out of bounds for a vector of length {length}");
+                // Retrieve the element.
+                elements[*index as usize].clone()
+            }
+            1 => Plaintext::from(Literal::U32(length)),
+            2 => {
+                // Retrieve the element.
+                let element = match registers.load(stack, &self.operands[1])? {
+                    Value::Plaintext(plaintext) => plaintext,
+                    value => bail!("Instruction '{}' expects a plaintext element, found '{value}'", Self::opcode()),
+                };
+                // Ensure the vector is not full.
+                ensure!(
+                    (*length as usize) < elements.len(),
+                    "Cannot push onto a full vector with a capacity of {}",
+                    elements.len()
+                );
+                // Write the element into the first unused slot.
+                let mut elements = elements.to_vec();
+                elements[*length as usize] = element;
+                Plaintext::new_vector(U32::new(*length + 1), elements)?
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Retrieve the vector.
+        // Note: The capacity of the vector is part of the circuit shape, while its length is a circuit variable.
+        let vector = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Instruction '{}' expects a vector", Self::opcode()),
+        };
+        let (length, elements) = vector.to_vector()?;
+
+        // Compute the output.
+        let output = match VARIANT {
+            0 => {
+                // Retrieve the index.
+                let index = match registers.load_circuit(stack, &self.operands[1])? {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U32(index), _)) => index,
+                    _ => bail!("Instruction '{}' expects a 'u32' index", Self::opcode()),
+                };
+                // Ensure the vector has a slot.
+                let (last, rest) = match elements.split_last() {
+                    Some((last, rest)) => (last, rest),
+                    None => bail!("Instruction '{}' cannot index into a vector without capacity", Self::opcode()),
+                };
+                // Ensure the index is in bounds.
+                A::assert(index.is_less_than(length));
+                // Select the element at the index.
+                rest.iter().enumerate().rev().fold(last.clone(), |selected, (i, element)| {
+                    let is_index = index.is_equal(&circuit::U32::constant(U32::new(i as u32)));
+                    circuit::Plaintext::ternary(&is_index, element, &selected)
+                })
+            }
+            1 => circuit::Plaintext::from(circuit::Literal::U32(length.clone())),
+            2 => {
+                // Retrieve the element.
+                let element = match registers.load_circuit(stack, &self.operands[1])? {
+                    circuit::Value::Plaintext(plaintext) => plaintext,
+                    _ => bail!("Instruction '{}' expects a plaintext element", Self::opcode()),
+                };
+                // Ensure the vector is not full.
+                let capacity = circuit::U32::constant(U32::new(elements.len() as u32));
+                A::assert(length.is_less_than(&capacity));
+                // Write the element into the first unused slot.
+                let elements = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, slot)| {
+                        let is_length = length.is_equal(&circuit::U32::constant(U32::new(i as u32)));
+                        circuit::Plaintext::ternary(&is_length, &element, slot)
+                    })
+                    .collect();
+                circuit::Plaintext::new_vector(length + &circuit::U32::constant(U32::new(1)), elements)?
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        };
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} inputs, found {} inputs",
+                Self::opcode(),
+                Self::num_operands(),
+                input_types.len()
+            )
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            bail!(
+                "Instruction '{}' expects {} operands, found {} operands",
+                Self::opcode(),
+                Self::num_operands(),
+                self.operands.len()
+            )
+        }
+
+        // Ensure the first operand is a vector.
+        let vector_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Vector(vector_type)) => vector_type,
+            input_type => bail!("Instruction '{}' expects a vector, found '{input_type}'", Self::opcode()),
+        };
+
+        match VARIANT {
+            0 => {
+                // Ensure the index is a `u32`.
+                ensure!(
+                    input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+                    "Instruction '{}' expects a 'u32' index, found '{}'",
+                    Self::opcode(),
+                    input_types[1]
+                );
+                Ok(vec![RegisterType::Plaintext(vector_type.element_type().clone())])
+            }
+            1 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32))]),
+            2 => {
+                // Ensure the element type matches the vector.
+                ensure!(
+                    input_types[1] == RegisterType::Plaintext(vector_type.element_type().clone()),
+                    "Instruction '{}' expects an element of type '{}', found '{}'",
+                    Self::opcode(),
+                    vector_type.element_type(),
+                    input_types[1]
+                );
+                Ok(vec![input_types[0].clone()])
+            }
+            _ => bail!("Invalid 'vec' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for VectorInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(parse_operand, Self::num_operands())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for VectorInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for VectorInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for VectorInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for VectorInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(Self::num_operands());
+        // Read the operands.
+        for _ in 0..Self::num_operands() {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for VectorInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != Self::num_operands() {
+            return Err(error(format!(
+                "The number of operands must be {}, found {}",
+                Self::num_operands(),
+                self.operands.len()
+            )));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, vec) = VecGet::<CurrentNetwork>::parse("vec.get r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(vec.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vec.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, vec) = VecLen::<CurrentNetwork>::parse("vec.len r0 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(vec.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(1), "The destination register is incorrect");
+
+        let (string, vec) = VecPush::<CurrentNetwork>::parse("vec.push r0 5u8 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(vec.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(vec.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(vec.operands[1], Operand::from_str("5u8").unwrap(), "The second operand is incorrect");
+        assert_eq!(vec.destination, Register::Locator(1), "The destination register is incorrect");
+
+        // Ensure the number of operands is enforced.
+        assert!(VecLen::<CurrentNetwork>::parse("vec.len r0 r1 into r2").is_err());
+        assert!(VecPush::<CurrentNetwork>::parse("vec.push r0 into r1").is_err());
+    }
+}
@@ -15,6 +15,9 @@
 pub mod command;
 pub use command::*;
 
+mod failure;
+pub use failure::*;
+
 mod finalize_global_state;
 pub use finalize_global_state::*;
 
@@ -21,6 +21,8 @@ use console::{
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the instruction.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns the number of instructions this instruction unrolls into.
+    fn num_unrolled_instructions(&self) -> usize;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
 }
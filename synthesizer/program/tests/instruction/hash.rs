@@ -36,6 +36,7 @@ use snarkvm_synthesizer_program::{
     HashPSD2,
     HashPSD4,
     HashPSD8,
+    HashSha256,
     HashSha3_256,
     HashSha3_384,
     HashSha3_512,
@@ -241,6 +242,8 @@ test_hash!(hash_psd2, HashPSD2, ITERATIONS);
 test_hash!(hash_psd4, HashPSD4, ITERATIONS);
 test_hash!(hash_psd8, HashPSD8, ITERATIONS);
 
+test_hash!(hash_sha256, HashSha256, 5);
+
 test_hash!(hash_sha3_256, HashSha3_256, 5);
 test_hash!(hash_sha3_384, HashSha3_384, 5);
 test_hash!(hash_sha3_512, HashSha3_512, 5);
@@ -41,7 +41,7 @@ mod universal_srs;
 pub use universal_srs::UniversalSRS;
 
 mod verifying_key;
-pub use verifying_key::VerifyingKey;
+pub use verifying_key::{VerifierInputs, VerifyingKey};
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
@@ -52,6 +52,11 @@ mod tests {
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Proof::read_le(&expected_bytes[..])?);
+        // Check the expected size.
+        assert_eq!(
+            Proof::<console::network::Testnet3>::expected_size_in_bytes(expected.batch_sizes()),
+            expected_bytes.len()
+        );
 
         Ok(())
     }
@@ -29,6 +29,12 @@ impl<N: Network> Proof<N> {
     pub(super) const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns the size in bytes of a proof over circuits with the given batch sizes.
+    pub fn expected_size_in_bytes(batch_sizes: &[usize]) -> usize {
+        // Note: The proof is prefixed with its version byte.
+        1 + varuna::Proof::<N::PairingCurve>::expected_size_in_bytes(batch_sizes)
+    }
 }
 
 impl<N: Network> Deref for Proof<N> {
@@ -20,6 +20,9 @@ mod serialize;
 
 use std::collections::BTreeMap;
 
+/// The verifying keys and public inputs for a batch proof.
+pub type VerifierInputs<N> = Vec<(VerifyingKey<N>, Vec<Vec<<N as Environment>::Field>>)>;
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct VerifyingKey<N: Network> {
     /// The verifying key for the function.
@@ -87,6 +90,45 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if all of the given batch proofs are valid for their public inputs.
+    /// The proofs are checked together, with a single product of pairings.
+    pub fn verify_aggregate<R: Rng + CryptoRng>(
+        locator: &str,
+        instances: Vec<(VerifierInputs<N>, &Proof<N>)>,
+        rng: &mut R,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let instances: Vec<_> = instances
+            .iter()
+            .map(|(inputs, proof)| {
+                let keys_to_inputs: BTreeMap<_, _> =
+                    inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+                (keys_to_inputs, (*proof).deref())
+            })
+            .collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_aggregate(universal_verifier, fiat_shamir, &instances, rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
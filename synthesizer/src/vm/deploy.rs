@@ -69,14 +69,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a deployment for the given program.
+    /// If the program is already deployed, this returns an upgrade to the next edition of the program.
     #[inline]
     pub(super) fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+        // Determine the next edition of the program, if it is already deployed.
+        let next_edition = match self.transaction_store().deployment_store().get_edition(program.id())? {
+            Some(edition) => match edition.checked_add(1) {
+                Some(next_edition) => Some(next_edition),
+                None => bail!("Program '{}' has reached the maximum edition", program.id()),
+            },
+            None => None,
+        };
+
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the program.
                 let program = cast_ref!(&program as Program<$network>);
                 // Compute the deployment.
-                let deployment = $process.deploy::<$aleo, _>(program, rng)?;
+                let deployment = match next_edition {
+                    Some(edition) => $process.upgrade::<$aleo, _>(program, edition, rng)?,
+                    None => $process.deploy::<$aleo, _>(program, rng)?,
+                };
                 // Prepare the deployment.
                 Ok(cast_ref!(deployment as Deployment<N>).clone())
             }};
@@ -104,6 +104,57 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         debug_assert!(authorization.is_fee_private() || authorization.is_fee_public(), "Expected a fee authorization");
         self.execute_fee_authorization_raw(authorization, query, rng)
     }
+
+    /// Returns an estimate of the cost to execute the given program function with the given inputs,
+    /// without synthesizing a proof, and without the private key of the caller.
+    ///
+    /// The cost of an execution does not depend on who signs it, so the requests are signed by an
+    /// ephemeral account, and any record inputs are reassigned to it, as a record input must belong to the signer.
+    /// Note: The transitions are computed with `authorize` (under the ephemeral account) rather than `Process::evaluate`,
+    /// as evaluating a function requires the signed requests of every nested call, which are only known once it is authorized.
+    /// The estimate includes the expected size of the execution proof, but excludes the fee transition.
+    pub fn estimate_execution_cost<R: Rng + CryptoRng>(
+        &self,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<ExecutionCostEstimate<N>> {
+        let timer = timer!("VM::estimate_execution_cost");
+
+        // Sample an ephemeral account to sign the requests.
+        let private_key = PrivateKey::new(rng)?;
+        let signer = Address::try_from(&private_key)?;
+        // Prepare the inputs, reassigning any record inputs to the ephemeral account.
+        let inputs = inputs
+            .map(|input| match input.try_into().map_err(|_| anyhow!("Failed to parse input"))? {
+                Value::Record(record) => {
+                    // Preserve the visibility of the record owner.
+                    let owner = match record.owner() {
+                        Owner::Public(..) => Owner::Public(signer),
+                        Owner::Private(..) => Owner::Private(Plaintext::from(Literal::Address(signer))),
+                    };
+                    Ok(Value::Record(Record::<N, Plaintext<N>>::from_plaintext(
+                        owner,
+                        record.data().clone(),
+                        *record.nonce(),
+                    )?))
+                }
+                input => Ok(input),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Prepare the inputs");
+
+        // Compute the authorization.
+        let authorization = self.authorize(&private_key, program_id, function_name, inputs.into_iter(), rng)?;
+        lap!(timer, "Compute the authorization");
+
+        // Construct the execution, without a proof.
+        let execution = Execution::from(authorization.transitions().into_values(), N::StateRoot::default(), None)?;
+        // Compute the cost estimate.
+        let estimate = ExecutionCostEstimate::new(&self.process().read(), &execution);
+        finish!(timer, "Compute the cost estimate");
+        estimate
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -315,6 +366,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_estimate_execution_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+
+        // Estimate the execution cost.
+        // Note: The estimate does not require the private key of the caller.
+        let estimate =
+            vm.estimate_execution_cost(("credits.aleo", "transfer_public"), inputs.clone().into_iter(), rng).unwrap();
+        assert_eq!(estimate.transitions().len(), 1);
+        assert!(!estimate.transitions()[0].finalize().unwrap().commands().is_empty());
+
+        // Execute.
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let execution = transaction.execution().unwrap();
+
+        // Ensure the estimate matches the execution cost, including the proof.
+        let (total_cost, (storage_cost, finalize_cost)) = execution_cost(&vm, execution).unwrap();
+        assert_eq!(estimate.total_cost().unwrap(), (total_cost, (storage_cost, finalize_cost)));
+        assert_eq!(estimate.storage_in_bytes(), execution.size_in_bytes().unwrap());
+        assert!(estimate.transitions()[0].size_in_bytes() < estimate.storage_in_bytes());
+
+        // Fetch the unspent records.
+        let mut records = records.values();
+        let record_1 = records.next().unwrap().decrypt(&caller_view_key).unwrap();
+        let record_2 = records.next().unwrap().decrypt(&caller_view_key).unwrap();
+        let inputs = [Value::<CurrentNetwork>::Record(record_1), Value::<CurrentNetwork>::Record(record_2)];
+
+        // Ensure the estimate accounts for the inclusion proof of the record inputs, without the private key of their owner.
+        let estimate = vm.estimate_execution_cost(("credits.aleo", "join"), inputs.clone().into_iter(), rng).unwrap();
+        assert!(estimate.transitions()[0].finalize().is_none());
+        let transaction =
+            vm.execute(&caller_private_key, ("credits.aleo", "join"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        let execution = transaction.execution().unwrap();
+        assert_eq!(estimate.storage_in_bytes(), execution.size_in_bytes().unwrap());
+    }
+
     #[test]
     fn test_join_transaction_size() {
         let rng = &mut TestRng::default();
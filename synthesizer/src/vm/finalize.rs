@@ -116,7 +116,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<Vec<FinalizeOperation<N>>, ExecutionError<N>> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
@@ -214,6 +214,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut aborted = Vec::new();
             // Initialize a counter for the confirmed transaction index.
             let mut counter = 0u32;
+            // Initialize a set of the program IDs deployed or upgraded in this block.
+            let mut deployed_program_ids = IndexSet::new();
 
             // Finalize the transactions.
             'outer: for transaction in transactions {
@@ -226,6 +228,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     continue 'outer;
                 }
 
+                // Ensure each program is deployed or upgraded at most once per block.
+                // Note: Each edition is checked against the latest stored edition, so a second deployment
+                // of the same program in this block would be speculated against a stale edition.
+                if let Transaction::Deploy(_, _, deployment, _) = transaction {
+                    if !deployed_program_ids.insert(*deployment.program_id()) {
+                        // Store the aborted transaction.
+                        aborted.push((
+                            transaction.clone(),
+                            format!("Program '{}' is already deployed in this block", deployment.program_id()),
+                        ));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+                }
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -405,8 +422,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::atomic_finalize");
 
+        // Initialize a slot for the error of an accepted execute transaction that failed to finalize.
+        let mut execution_error = None;
+
         // Perform the finalize operation on the preset finalize mode.
-        atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
+        let result = atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => true,
@@ -505,7 +525,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
-                                return Err(format!("Failed to finalize an accepted execute transaction - {error}"));
+                                let message = format!("Failed to finalize an accepted execute transaction - {error}");
+                                // Store the error, to return it with its classification.
+                                execution_error = Some(ExecutionError::<N>::from(error));
+                                return Err(message);
                             }
                         }
                         Ok(())
@@ -624,7 +647,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
             Ok(ratified_finalize_operations)
-        })
+        });
+
+        // If an accepted execute transaction failed to finalize, return its error (with its classification).
+        match (result, execution_error) {
+            (Err(error), Some(execution_error)) => Err(execution_error.context(error.to_string()).into()),
+            (result, _) => result,
+        }
     }
 
     /// Performs the pre-ratifications before finalizing transactions.
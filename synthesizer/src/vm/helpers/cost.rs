@@ -15,12 +15,15 @@
 use crate::VM;
 use console::{
     prelude::*,
-    program::{LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType, ProgramID},
 };
-use ledger_block::{Deployment, Execution};
+use ledger_block::{Deployment, Execution, Input, Transition};
 use ledger_store::ConsensusStorage;
-use synthesizer_program::{Command, Finalize, Instruction};
+use synthesizer_process::{Process, Stack, StackProgramTypes};
+use synthesizer_program::{Command, Finalize, Instruction, MappingLocator, StackProgram};
+use synthesizer_snark::Proof;
 
+use indexmap::IndexMap;
 use std::collections::HashMap;
 
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
@@ -56,15 +59,21 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     vm: &VM<N, C>,
     execution: &Execution<N>,
 ) -> Result<(u64, (u64, u64))> {
+    process_execution_cost(&vm.process().read(), execution)
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)),
+/// using the programs in the given process.
+pub fn process_execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>) -> Result<(u64, (u64, u64))> {
     // Compute the storage cost in microcredits.
     let storage_cost = execution.size_in_bytes()?;
 
-    // Prepare the program lookup.
+    // Prepare the stack lookup.
     let lookup = execution
         .transitions()
         .map(|transition| {
             let program_id = transition.program_id();
-            Ok((*program_id, vm.process().read().get_program(program_id)?.clone()))
+            Ok((*program_id, process.get_stack(program_id)?.clone()))
         })
         .collect::<Result<HashMap<_, _>>>()?;
 
@@ -76,11 +85,11 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
         let program_id = transition.program_id();
         // Retrieve the function name.
         let function_name = transition.function_name();
-        // Retrieve the program.
-        let program = lookup.get(program_id).ok_or(anyhow!("Program '{program_id}' is missing"))?;
+        // Retrieve the stack.
+        let stack = lookup.get(program_id).ok_or(anyhow!("Program '{program_id}' is missing"))?;
         // Retrieve the finalize cost.
-        let cost = match program.get_function(function_name)?.finalize_logic() {
-            Some(finalize) => cost_in_microcredits(finalize)?,
+        let cost = match stack.program().get_function(function_name)?.finalize_logic() {
+            Some(finalize) => cost_in_microcredits(stack, finalize)?,
             None => continue,
         };
         // Accumulate the finalize cost.
@@ -97,10 +106,273 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
+/// Returns the expected number of bytes in the proof of an execution with the given transitions.
+pub fn expected_proof_size_in_bytes<'a, N: Network>(transitions: impl Iterator<Item = &'a Transition<N>>) -> usize {
+    // Count the number of calls to each function, and the number of record inputs.
+    let mut batch_sizes = IndexMap::<(ProgramID<N>, Identifier<N>), usize>::new();
+    let mut num_record_inputs = 0;
+    for transition in transitions {
+        *batch_sizes.entry((*transition.program_id(), *transition.function_name())).or_default() += 1;
+        num_record_inputs += transition.inputs().iter().filter(|input| matches!(input, Input::Record(..))).count();
+    }
+    // Construct the batch sizes, with one circuit per function, and one inclusion circuit for the record inputs.
+    let mut batch_sizes = batch_sizes.into_values().collect::<Vec<_>>();
+    if num_record_inputs > 0 {
+        batch_sizes.push(num_record_inputs);
+    }
+    // Add one byte for the proof variant.
+    1 + Proof::<N>::expected_size_in_bytes(&batch_sizes)
+}
+
+/// An estimate of the cost of an execution, computed without synthesizing a proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionCostEstimate<N: Network> {
+    /// The number of bytes in the execution, including the expected size of the proof.
+    storage_in_bytes: u64,
+    /// The cost breakdown of each transition, in execution order.
+    transitions: Vec<TransitionCostEstimate<N>>,
+}
+
+impl<N: Network> ExecutionCostEstimate<N> {
+    /// Returns the cost estimate for the given execution, using the programs in the given process.
+    /// If the execution does not contain a proof, the expected size of its proof is used instead.
+    pub fn new(process: &Process<N>, execution: &Execution<N>) -> Result<Self> {
+        // Determine the number of bytes in the execution.
+        let mut storage_in_bytes = execution.size_in_bytes()?;
+        // If the execution does not contain a proof, replace the proof variant with the expected proof.
+        if execution.proof().is_none() {
+            let proof_size_in_bytes = u64::try_from(expected_proof_size_in_bytes(execution.transitions()))?;
+            storage_in_bytes = storage_in_bytes
+                .saturating_sub(1)
+                .checked_add(proof_size_in_bytes)
+                .ok_or(anyhow!("The storage size computation overflowed for an execution"))?;
+        }
+        // Compute the cost breakdown of each transition.
+        let transitions = execution
+            .transitions()
+            .map(|transition| TransitionCostEstimate::new(process, transition))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { storage_in_bytes, transitions })
+    }
+
+    /// Returns the number of bytes in the execution, including the expected size of the proof.
+    pub const fn storage_in_bytes(&self) -> u64 {
+        self.storage_in_bytes
+    }
+
+    /// Returns the cost breakdown of each transition, in execution order.
+    pub fn transitions(&self) -> &[TransitionCostEstimate<N>] {
+        &self.transitions
+    }
+
+    /// Returns the storage cost in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_in_bytes
+    }
+
+    /// Returns the finalize cost in microcredits.
+    pub fn finalize_cost(&self) -> Result<u64> {
+        self.transitions.iter().try_fold(0u64, |acc, transition| {
+            acc.checked_add(transition.finalize_cost()?)
+                .ok_or(anyhow!("The finalize cost computation overflowed for an execution"))
+        })
+    }
+
+    /// Returns the estimated total cost in microcredits (total cost, (storage cost, finalize cost)).
+    pub fn total_cost(&self) -> Result<(u64, (u64, u64))> {
+        // Compute the storage cost.
+        let storage_cost = self.storage_cost();
+        // Compute the finalize cost.
+        let finalize_cost = self.finalize_cost()?;
+        // Compute the total cost.
+        let total_cost = storage_cost
+            .checked_add(finalize_cost)
+            .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
+        Ok((total_cost, (storage_cost, finalize_cost)))
+    }
+}
+
+/// The cost breakdown of a single transition in an execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionCostEstimate<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of bytes in the transition.
+    size_in_bytes: u64,
+    /// The cost breakdown of the finalize block, if any.
+    finalize: Option<FinalizeCostReport<N>>,
+}
+
+impl<N: Network> TransitionCostEstimate<N> {
+    /// Returns the cost breakdown for the given transition, using the programs in the given process.
+    pub fn new(process: &Process<N>, transition: &Transition<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = *transition.program_id();
+        // Retrieve the function name.
+        let function_name = *transition.function_name();
+        // Determine the number of bytes in the transition.
+        let size_in_bytes = u64::try_from(transition.to_bytes_le()?.len())?;
+        // Retrieve the stack.
+        let stack = process.get_stack(program_id)?;
+        // Compute the cost breakdown of the finalize block.
+        let finalize = match stack.program().get_function_ref(&function_name)?.finalize_logic() {
+            Some(finalize) => Some(FinalizeCostReport::new(stack, finalize)?),
+            None => None,
+        };
+        Ok(Self { program_id, function_name, size_in_bytes, finalize })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of bytes in the transition.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the cost breakdown of the finalize block, if any.
+    pub const fn finalize(&self) -> Option<&FinalizeCostReport<N>> {
+        self.finalize.as_ref()
+    }
+
+    /// Returns the finalize cost in microcredits of the transition.
+    pub fn finalize_cost(&self) -> Result<u64> {
+        match &self.finalize {
+            Some(finalize) => finalize.total_cost(),
+            None => Ok(0),
+        }
+    }
+}
+
+/// A per-command breakdown of the cost of a finalize block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeCostReport<N: Network> {
+    /// The finalize name.
+    name: Identifier<N>,
+    /// The cost of each finalize command, in program order.
+    commands: Vec<FinalizeCommandCost<N>>,
+}
+
+/// The cost of a single command in a finalize block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeCommandCost<N: Network> {
+    /// The command.
+    command: Command<N>,
+    /// The cost of the command in microcredits.
+    cost: u64,
+    /// The mapping accessed by the command, if any.
+    mapping: Option<MappingLocator<N>>,
+}
+
+impl<N: Network> FinalizeCostReport<N> {
+    /// Returns the cost report for the given finalize block, using the register types in the given stack.
+    pub fn new(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<Self> {
+        let commands = finalize
+            .commands()
+            .iter()
+            .map(|command| {
+                // Determine the mapping accessed by the command.
+                let mapping = match command {
+                    Command::Contains(contains) => Some(contains.mapping().clone()),
+                    Command::Get(get) => Some(get.mapping().clone()),
+                    Command::GetOrUse(get_or_use) => Some(get_or_use.mapping().clone()),
+                    Command::MappingIterate(iterate) => Some(iterate.mapping().clone()),
+                    Command::MappingLength(length) => Some(length.mapping().clone()),
+                    Command::Remove(remove) => Some(MappingLocator::Resource(*remove.mapping_name())),
+                    Command::Set(set) => Some(MappingLocator::Resource(*set.mapping_name())),
+                    _ => None,
+                };
+                Ok(FinalizeCommandCost {
+                    command: command.clone(),
+                    cost: command_cost_in_microcredits(stack, finalize, command)?,
+                    mapping,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { name: *finalize.name(), commands })
+    }
+
+    /// Returns the finalize name.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the cost of each finalize command, in program order.
+    pub fn commands(&self) -> &[FinalizeCommandCost<N>] {
+        &self.commands
+    }
+
+    /// Returns the total cost of the finalize block in microcredits.
+    pub fn total_cost(&self) -> Result<u64> {
+        self.commands
+            .iter()
+            .try_fold(0u64, |acc, command| acc.checked_add(command.cost).ok_or(anyhow!("Finalize cost overflowed")))
+    }
+}
+
+impl<N: Network> FinalizeCommandCost<N> {
+    /// Returns the command.
+    pub const fn command(&self) -> &Command<N> {
+        &self.command
+    }
+
+    /// Returns the cost of the command in microcredits.
+    pub const fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    /// Returns the mapping accessed by the command, if any.
+    pub const fn mapping(&self) -> Option<&MappingLocator<N>> {
+        self.mapping.as_ref()
+    }
+}
+
+impl<N: Network> Display for FinalizeCostReport<N> {
+    /// Prints the cost report as a table, one command per line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Determine the width of the command column.
+        let width = self.commands.iter().map(|entry| entry.command.to_string().len()).max().unwrap_or_default();
+        // Print each command.
+        for entry in &self.commands {
+            write!(f, "{:<width$}  {:>10}", entry.command.to_string(), entry.cost)?;
+            if let Some(mapping) = &entry.mapping {
+                write!(f, "  ({mapping})")?;
+            }
+            writeln!(f)?;
+        }
+        // Print the total cost.
+        match self.total_cost() {
+            Ok(total_cost) => write!(f, "{:<width$}  {total_cost:>10}", "total"),
+            Err(_) => write!(f, "{:<width$}  {:>10}", "total", "overflow"),
+        }
+    }
+}
+
 /// Returns the minimum number of microcredits required to run the finalize.
-pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
-    // Defines the cost of each command.
-    let cost = |command: &Command<N>| match command {
+pub fn cost_in_microcredits<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<u64> {
+    finalize
+        .commands()
+        .iter()
+        .map(|command| command_cost_in_microcredits(stack, finalize, command))
+        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+}
+
+/// Returns the number of microcredits required to run the given finalize command.
+pub fn command_cost_in_microcredits<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    command: &Command<N>,
+) -> Result<u64> {
+    match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
         Command::Instruction(Instruction::AbsWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Add(_)) => Ok(2_000),
@@ -121,8 +393,10 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::EcdsaVerify(_)) => Ok(500_000),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
+        Command::Instruction(Instruction::GroupGen(_)) => Ok(150_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashBHP512(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashBHP768(_)) => Ok(100_000),
@@ -147,6 +421,7 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
             PlaintextType::Literal(..) => Ok(200_000),
             plaintext_type => bail!("`hash.psd8` is not supported for plaintext type '{plaintext_type}'"),
         },
+        Command::Instruction(Instruction::HashSha256(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashSha3_256(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashSha3_384(_)) => Ok(100_000),
         Command::Instruction(Instruction::HashSha3_512(_)) => Ok(100_000),
@@ -159,12 +434,22 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::HashManyPSD8(_)) => {
             bail!("`hash_many.psd8` is not supported in finalize.")
         }
+        Command::Instruction(Instruction::HashToGroupPSD2(_)) => Ok(600_000),
         Command::Instruction(Instruction::Inv(_)) => Ok(10_000),
         Command::Instruction(Instruction::IsEq(_)) => Ok(2_000),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
         Command::Instruction(Instruction::LessThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::Modulo(_)) => Ok(2_000),
+        // Note: `msm` is priced per (group, scalar) pair.
+        Command::Instruction(Instruction::Msm(msm)) => {
+            // Retrieve the type of the group array.
+            let finalize_types = stack.get_finalize_types(finalize.name())?;
+            match finalize_types.get_type_from_operand(stack, &msm.operands()[0])? {
+                FinalizeType::Plaintext(PlaintextType::Array(array_type)) => Ok(150_000 * **array_type.length() as u64),
+                finalize_type => bail!("Instruction 'msm' expects an array of groups, found '{finalize_type}'"),
+            }
+        }
         Command::Instruction(Instruction::Mul(_)) => Ok(150_000),
         Command::Instruction(Instruction::MulWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Nand(_)) => Ok(2_000),
@@ -176,6 +461,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Repeat(_)) => bail!("`repeat` is not supported in finalize."),
+        Command::Instruction(Instruction::SignSchnorr(_)) => bail!("`sign.schnorr` is not supported in finalize."),
         Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
         Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
@@ -183,9 +470,19 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::ShrWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Square(_)) => Ok(2_000),
         Command::Instruction(Instruction::SquareRoot(_)) => Ok(120_000),
+        Command::Instruction(Instruction::StringConcat(_)) => Ok(10_000),
+        Command::Instruction(Instruction::StringFromBytes(_)) => Ok(10_000),
+        Command::Instruction(Instruction::StringGet(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StringLen(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StringToBytes(_)) => Ok(10_000),
         Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
+        // Note: `switch` is priced per case, as with a chain of `ternary` instructions.
+        Command::Instruction(Instruction::Switch(switch)) => Ok(2_000 * switch.cases().len() as u64),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecGet(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecLen(_)) => Ok(2_000),
+        Command::Instruction(Instruction::VecPush(_)) => Ok(2_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
         //  Expect these numbers to change as their usage is stabilized.
@@ -193,15 +490,82 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Contains(_) => Ok(12_500),
         Command::Get(_) => Ok(25_000),
         Command::GetOrUse(_) => Ok(25_000),
+        // Note: `mapping.iterate` reads one indexed entry per unit of its limit, and `mapping.length` reads a stored counter.
+        Command::MappingIterate(iterate) => Ok(25_000 * (1 + *iterate.limit() as u64)),
+        Command::MappingLength(_) => Ok(25_000),
         Command::RandChaCha(_) => Ok(25_000),
         Command::Remove(_) => Ok(10_000),
         Command::Set(_) => Ok(100_000),
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
         Command::Position(_) => Ok(1_000),
-    };
-    finalize
-        .commands()
-        .iter()
-        .map(cost)
-        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_finalize_cost_report() {
+        // Retrieve the finalize block of `credits.aleo/transfer_public`.
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+        let function = program.get_function_ref(&Identifier::from_str("transfer_public").unwrap()).unwrap();
+        let finalize = function.finalize_logic().unwrap();
+
+        // Retrieve the stack of `credits.aleo`.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the cost report.
+        let report = FinalizeCostReport::new(stack, finalize).unwrap();
+        assert_eq!(report.name(), finalize.name());
+        assert_eq!(report.commands().len(), finalize.commands().len());
+        assert_eq!(report.total_cost().unwrap(), cost_in_microcredits(stack, finalize).unwrap());
+
+        // Ensure the mapping accessed by each command is reported.
+        let account = MappingLocator::Resource(Identifier::from_str("account").unwrap());
+        for entry in report.commands() {
+            match entry.command() {
+                Command::Get(_) | Command::GetOrUse(_) | Command::Set(_) => {
+                    assert_eq!(entry.mapping(), Some(&account))
+                }
+                _ => assert_eq!(entry.mapping(), None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_msm_cost() {
+        // Initialize a program that calls `msm` on three (group, scalar) pairs in finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program msm_cost.aleo;
+
+function commit:
+    input r0 as [group; 3u32].public;
+    input r1 as [scalar; 3u32].public;
+    async commit r0 r1 into r2;
+    output r2 as msm_cost.aleo/commit.future;
+
+finalize commit:
+    input r0 as [group; 3u32].public;
+    input r1 as [scalar; 3u32].public;
+    msm r0 r1 into r2;",
+        )
+        .unwrap();
+        let function = program.get_function_ref(&Identifier::from_str("commit").unwrap()).unwrap();
+        let finalize = function.finalize_logic().unwrap();
+
+        // Add the program to a process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Ensure `msm` is priced by the number of pairs.
+        assert_eq!(cost_in_microcredits(stack, finalize).unwrap(), 3 * 150_000);
+    }
 }
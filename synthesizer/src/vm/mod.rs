@@ -25,7 +25,7 @@ use crate::{cast_mut_ref, cast_ref, process};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
+    program::{Identifier, Literal, Locator, Owner, Plaintext, ProgramID, ProgramOwner, Record, Value},
     types::{Field, U64},
 };
 use ledger_block::{
@@ -55,11 +55,11 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
-use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_process::{Authorization, ExecutionError, Process, Trace};
+use synthesizer_program::{Failure, FailureKind, FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -109,6 +109,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 return Ok(());
             }
 
+            // If the program was upgraded, load the latest edition of the program instead.
+            if let Some(latest_transaction_id) =
+                transaction_store.deployment_store().find_transaction_id_from_program_id(program_id)?
+            {
+                if latest_transaction_id != transaction_id {
+                    return load_deployment_and_imports(process, transaction_store, latest_transaction_id);
+                }
+            }
+
             // Iterate through the program imports.
             for import_program_id in program.imports().keys() {
                 // Add the imports to the process if does not exist yet.
@@ -242,7 +251,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let ratifications = vec![Ratify::Genesis(committee, public_balances)];
         // Prepare the solutions.
         let solutions = None; // The genesis block does not require solutions.
-        // Prepare the transactions.
+                              // Prepare the transactions.
         let transactions = (0..Block::<N>::NUM_GENESIS_TRANSACTIONS)
             .map(|_| self.execute(private_key, locator, inputs.iter(), None, 0, None, rng))
             .collect::<Result<Vec<_>, _>>()?;
@@ -287,6 +296,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -304,7 +314,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Rollback the block.
                 self.block_store().remove_last_n(1)?;
                 // Return the error.
-                Err(error)
+                Err(error.into())
             }
         }
     }
@@ -331,7 +341,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, 0, [0u8; 32], [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -1032,6 +1042,98 @@ function check:
         assert!(vm.contains_program(&ProgramID::from_str("parent_program.aleo").unwrap()));
     }
 
+    #[test]
+    fn test_upgrade_deployment() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a private key.
+        let private_key = sample_genesis_private_key(rng);
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the first edition of the program.
+        let program_id = ProgramID::from_str("upgradable_program.aleo").unwrap();
+        let program = Program::from_str(
+            r"
+program upgradable_program.aleo;
+
+function check:
+    input r0 as field.private;
+    assert.eq r0 1field;
+        ",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+        vm.check_transaction(&deployment, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+        assert_eq!(
+            Some(Testnet3::EDITION),
+            vm.transaction_store().deployment_store().get_edition(&program_id).unwrap()
+        );
+
+        // Prepare the second edition of the program.
+        let upgrade = Program::from_str(
+            r"
+program upgradable_program.aleo;
+
+function check:
+    input r0 as field.private;
+    assert.eq r0 2field;
+
+function double:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+        ",
+        )
+        .unwrap();
+
+        // Ensure an upgrade by another account is rejected.
+        let other_private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let other_upgrade = vm.deploy(&other_private_key, &upgrade, None, 0, None, rng).unwrap();
+        let error = vm.check_transaction(&other_upgrade, None, rng).unwrap_err();
+        assert!(error.to_string().contains("only the owner may upgrade"), "{error}");
+
+        // Ensure an upgrade by the owner is accepted.
+        let first_upgrade = vm.deploy(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        vm.check_transaction(&first_upgrade, None, rng).unwrap();
+        let second_upgrade = vm.deploy(&private_key, &upgrade, None, 0, None, rng).unwrap();
+        vm.check_transaction(&second_upgrade, None, rng).unwrap();
+        let second_upgrade_id = second_upgrade.id();
+
+        // Ensure only the first upgrade of the program in a block is speculated on.
+        let (_, transactions, aborted_transaction_ids, _) = vm
+            .speculate(sample_finalize_state(1), None, vec![], None, [first_upgrade.clone(), second_upgrade].iter())
+            .unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(aborted_transaction_ids, vec![second_upgrade_id]);
+
+        // Add the first upgrade, and ensure the program is at the second edition.
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[first_upgrade], rng).unwrap()).unwrap();
+        assert_eq!(
+            Some(Testnet3::EDITION + 1),
+            vm.transaction_store().deployment_store().get_edition(&program_id).unwrap()
+        );
+        let process = vm.process();
+        let program = process.read().get_program(program_id).unwrap().clone();
+        let double = Identifier::from_str("double").unwrap();
+        assert!(program.contains_function(&double));
+
+        // Ensure each edition of the program can still be fetched, including through a query.
+        let query = Query::<Testnet3, _>::VM(vm.block_store().clone());
+        let first_edition = query.get_program_for_edition(&program_id, Testnet3::EDITION).unwrap();
+        assert!(!first_edition.contains_function(&double));
+        let second_edition = query.get_program_for_edition(&program_id, Testnet3::EDITION + 1).unwrap();
+        assert!(second_edition.contains_function(&double));
+        assert!(query.get_program_for_edition(&program_id, Testnet3::EDITION + 2).is_err());
+    }
+
     #[test]
     #[ignore]
     fn test_deployment_memory_overload() {
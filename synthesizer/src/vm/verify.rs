@@ -14,6 +14,12 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+use synthesizer_snark::VerifierInputs;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -39,7 +45,57 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
+    ) -> Result<(), ExecutionError<N>> {
+        self.check_transaction_internal(transaction, rejected_id, false, rng).map(|_| ()).map_err(ExecutionError::from)
+    }
+
+    /// Verifies the given transactions in the VM, where each transaction is paired with its rejected ID (if any).
+    /// The execution and fee proofs of all transactions are checked in aggregate. On failure, returns an error.
+    #[inline]
+    pub fn check_transactions<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
     ) -> Result<()> {
+        // Verify the transactions, except for their execution and fee proofs.
+        let verifier_inputs = self.check_transactions_deferred(transactions, rng)?;
+        // Verify the execution and fee proofs.
+        let instances: Vec<_> = transactions.iter().map(|(transaction, _)| *transaction).zip(verifier_inputs).collect();
+        Transaction::verify_proofs(&instances, rng)
+    }
+
+    /// Verifies the given transactions in the VM, where each transaction is paired with its rejected ID (if any),
+    /// *except* for their execution and fee proofs. On failure, returns an error.
+    ///
+    /// On success, returns the verifier inputs for the proofs of each transaction, in the order of
+    /// `Transaction::proofs`. The proofs must then be checked with `Transaction::verify_proofs`.
+    #[inline]
+    pub fn check_transactions_deferred<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<Vec<Vec<VerifierInputs<N>>>> {
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        cfg_iter!(transactions)
+            .zip(rngs)
+            .map(|((transaction, rejected_id), mut rng)| {
+                self.check_transaction_internal(transaction, *rejected_id, true, &mut rng)
+                    .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+            })
+            .collect()
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If `defer` is `true`, the execution and fee proofs are not checked, and on success,
+    /// the verifier inputs for the proofs are returned, in the order of `Transaction::proofs`.
+    #[inline]
+    fn check_transaction_internal<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        defer: bool,
+        rng: &mut R,
+    ) -> Result<Vec<VerifierInputs<N>>> {
         let timer = timer!("VM::check_transaction");
 
         /* Transaction */
@@ -93,10 +149,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Check for duplicate elements");
 
         // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        let fee_verifier_inputs = self.check_fee_with(transaction, rejected_id, defer)?;
 
         // Next, verify the deployment or execution.
-        match transaction {
+        let execution_verifier_inputs = match transaction {
             Transaction::Deploy(id, owner, deployment, _) => {
                 // Compute the deployment ID.
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
@@ -104,16 +160,45 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist..
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
+                // Retrieve the latest edition of the program, if it exists.
+                match self.transaction_store().deployment_store().get_edition(program_id)? {
+                    // If the program exists, ensure the deployment is an upgrade by the original program owner.
+                    Some(edition) => {
+                        // Ensure the edition is the next edition.
+                        match edition.checked_add(1) {
+                            Some(next_edition) if next_edition == deployment.edition() => (),
+                            Some(next_edition) => {
+                                bail!("Invalid deployment transaction '{id}' - expected edition {next_edition}")
+                            }
+                            None => bail!("Invalid deployment transaction '{id}' - '{program_id}' cannot be upgraded"),
+                        }
+                        // Retrieve the original program owner.
+                        let Some(original_owner) =
+                            self.transaction_store().deployment_store().get_owner(program_id, N::EDITION)?
+                        else {
+                            bail!("Failed to find the owner of program '{program_id}'")
+                        };
+                        // Ensure the upgrade is authorized by the original program owner.
+                        if owner.address() != original_owner.address() {
+                            bail!("Invalid deployment transaction '{id}' - only the owner may upgrade '{program_id}'")
+                        }
+                    }
+                    // Otherwise, ensure the edition is correct.
+                    None => {
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program ID does not already exist.
+                        if self.contains_program(program_id) {
+                            bail!("Program ID '{program_id}' is already deployed")
+                        }
+                    }
                 }
                 // Verify the deployment.
                 self.check_deployment_internal(deployment, rng)?;
+                None
             }
             Transaction::Execute(id, execution, _) => {
                 // Compute the execution ID.
@@ -125,18 +210,31 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
                 // Verify the execution.
-                self.check_execution_internal(execution)?;
+                self.check_execution_internal(execution, defer)?
             }
-            Transaction::Fee(..) => { /* no-op */ }
-        }
+            Transaction::Fee(..) => None,
+        };
 
         finish!(timer, "Verify the transaction");
-        Ok(())
+        // Return the deferred verifier inputs, with the execution before the fee.
+        Ok(execution_verifier_inputs.into_iter().chain(fee_verifier_inputs).collect())
     }
 
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_fee_with(transaction, rejected_id, false).map(|_| ())
+    }
+
+    /// Verifies the `fee` in the given transaction. On failure, returns an error.
+    /// If `defer` is `true`, the fee proof is not checked, and on success, the verifier inputs for the proof are returned.
+    #[inline]
+    fn check_fee_with(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        defer: bool,
+    ) -> Result<Option<VerifierInputs<N>>> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -152,7 +250,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, defer)
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -180,10 +278,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    self.check_fee_internal(fee, execution_id, defer)
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
+                    Ok(None)
                 }
             }
             // Note: This transaction type does not need to check the fee amount, because:
@@ -192,12 +291,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, defer),
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
         }
-        Ok(())
     }
 }
 
@@ -225,37 +323,50 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Verifies the given execution. On failure, returns an error.
+    /// If `defer` is `true`, the execution proof is not checked, and on success, the verifier inputs for the proof are returned.
     ///
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_execution_internal(&self, execution: &Execution<N>) -> Result<()> {
+    fn check_execution_internal(&self, execution: &Execution<N>, defer: bool) -> Result<Option<VerifierInputs<N>>> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution.
-        let verification = self.process.read().verify_execution(execution);
+        let verification = match defer {
+            true => self.process.read().verify_execution_deferred(execution).map(Some),
+            false => self.process.read().verify_execution(execution).map(|()| None),
+        };
         lap!(timer, "Verify the execution");
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => Ok(()),
+            Ok(verifier_inputs) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+                Ok(true) => Ok(verifier_inputs),
                 Ok(false) => bail!("Execution verification failed: global state root not found"),
                 Err(error) => bail!("Execution verification failed: {error}"),
             },
-            Err(error) => bail!("Execution verification failed: {error}"),
+            Err(error) => {
+                let message = format!("Execution verification failed: {error}");
+                Err(error.context(message).into())
+            }
         };
         finish!(timer, "Check the global state root");
         result
     }
 
     /// Verifies the given fee. On failure, returns an error.
+    /// If `defer` is `true`, the fee proof is not checked, and on success, the verifier inputs for the proof are returned.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        defer: bool,
+    ) -> Result<Option<VerifierInputs<N>>> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -263,7 +374,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        let verification = match defer {
+            true => self.process.read().verify_fee_deferred(fee, deployment_or_execution_id).map(Some),
+            false => self.process.read().verify_fee(fee, deployment_or_execution_id).map(|()| None),
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -285,13 +399,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 bail!("Fee verification failed: fee is public, but the payer account balance is missing");
             };
             // Ensure the balance is sufficient.
-            ensure!(balance >= fee_amount, "Fee verification failed: insufficient balance");
+            if balance < fee_amount {
+                let message = "Fee verification failed: insufficient balance";
+                return Err(Failure::new(FailureKind::InsufficientBalance, message).into());
+            }
         }
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => Ok(()),
+            Ok(verifier_inputs) => match self.block_store().contains_state_root(&fee.global_state_root()) {
+                Ok(true) => Ok(verifier_inputs),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
             },
@@ -336,6 +453,46 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch execution transactions.
+        let transaction_a = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let transaction_b = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transaction_c = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+
+        // Ensure the transactions verify in aggregate.
+        let transactions = [(&transaction_a, None), (&transaction_b, None), (&transaction_c, None)];
+        vm.check_transactions(&transactions, rng).unwrap();
+
+        // Ensure the deferred verifier inputs align with the proofs of each transaction.
+        let verifier_inputs = vm.check_transactions_deferred(&transactions, rng).unwrap();
+        assert_eq!(verifier_inputs.len(), 3);
+        for ((transaction, _), verifier_inputs) in transactions.iter().zip_eq(&verifier_inputs) {
+            assert_eq!(transaction.proofs().len(), verifier_inputs.len());
+        }
+
+        // Construct a transaction with the execution proof of another transaction.
+        let execution_a = transaction_a.execution().unwrap();
+        let execution_b = transaction_b.execution().unwrap();
+        let execution = Execution::from(
+            execution_a.transitions().cloned(),
+            execution_a.global_state_root(),
+            execution_b.proof().cloned(),
+        )
+        .unwrap();
+        let tampered_transaction = Transaction::from_execution(execution, transaction_a.fee_transition()).unwrap();
+
+        // Ensure the invalid proof is identified.
+        let transactions = [(&transaction_b, None), (&tampered_transaction, None)];
+        let error = vm.check_transactions(&transactions, rng).unwrap_err();
+        assert!(error.to_string().contains(&tampered_transaction.id().to_string()));
+        // Ensure the tampered transaction is otherwise well-formed.
+        assert!(vm.check_transactions_deferred(&transactions, rng).is_ok());
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
@@ -373,13 +530,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    vm.check_execution_internal(&execution).unwrap();
+                    vm.check_execution_internal(&execution, false).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    vm.check_execution_internal(&recovered_execution).unwrap();
+                    vm.check_execution_internal(&recovered_execution, false).unwrap();
                 }
                 _ => panic!("Expected an execution transaction"),
             }
@@ -405,12 +562,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee, execution_id, false).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee, execution_id, false).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -45,6 +45,20 @@ impl<'a, T> ExecutionPool<'a, T> {
             self.jobs.into_iter().map(|f| f()).collect()
         }
     }
+
+    /// Executes the jobs in chunks of at most `chunk_size` jobs, so that fewer jobs are in flight at once.
+    pub fn execute_in_chunks(self, chunk_size: usize) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        let mut results = Vec::with_capacity(self.jobs.len());
+        let mut jobs = self.jobs.into_iter().peekable();
+        while jobs.peek().is_some() {
+            let chunk = Self { jobs: jobs.by_ref().take(chunk_size.max(1)).collect() };
+            results.extend(chunk.execute_all());
+        }
+        results
+    }
 }
 
 impl<'a, T> Default for ExecutionPool<'a, T> {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Build, Clean, Debugger, Devnet, Execute, New, Run, Test, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -42,12 +42,18 @@ pub enum Command {
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "debug")]
+    Debug(Debugger),
+    #[clap(name = "devnet")]
+    Devnet(Devnet),
     #[clap(name = "execute")]
     Execute(Execute),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
     Run(Run),
+    #[clap(name = "test")]
+    Test(Test),
     #[clap(name = "update")]
     Update(Update),
 }
@@ -58,9 +64,12 @@ impl Command {
         match self {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            Self::Debug(command) => command.parse(),
+            Self::Devnet(command) => command.parse(),
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
+            Self::Test(command) => command.parse(),
             Self::Update(command) => command.parse(),
         }
     }
@@ -13,6 +13,10 @@
 // limitations under the License.
 
 use super::*;
+use crate::{ledger::query::Query, package::Registry};
+
+use clap::Args;
+use std::path::PathBuf;
 
 /// Compiles an Aleo program.
 #[derive(Debug, Parser)]
@@ -20,9 +24,9 @@ pub struct Build {
     /// Uses the specified endpoint.
     #[clap(long)]
     endpoint: Option<String>,
-    /// Toggles offline mode.
-    #[clap(long)]
-    offline: bool,
+    /// The options to resolve the dependencies.
+    #[clap(flatten)]
+    dependencies: Dependencies,
 }
 
 impl Build {
@@ -36,6 +40,9 @@ impl Build {
 
         println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
 
+        // Resolve the dependencies, if any are declared.
+        self.dependencies.resolve(&package)?;
+
         // Build the package, if the package requires building.
         package.build::<Aleo>(self.endpoint)?;
 
@@ -51,3 +58,54 @@ impl Build {
         Ok(format!("✅ Built '{}' {}", package.program_id().to_string().bold(), path_string.dimmed()))
     }
 }
+
+/// The options to resolve the dependencies declared in the manifest.
+#[derive(Debug, Args)]
+pub struct Dependencies {
+    /// Toggles offline mode, where dependencies are only read from the imports directory.
+    #[clap(long)]
+    offline: bool,
+    /// Resolves dependencies from the specified local registry directory.
+    #[clap(long)]
+    registry: Option<PathBuf>,
+    /// Resolves dependencies from the specified query endpoint.
+    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
+    query: String,
+}
+
+impl Dependencies {
+    /// Resolves the dependencies of the given package into its imports directory, if any are declared.
+    /// A local registry takes precedence, and the query endpoint is never used in offline mode.
+    pub fn resolve(&self, package: &Package<CurrentNetwork>) -> Result<()> {
+        if package.manifest_file().dependencies().is_empty() {
+            return Ok(());
+        }
+        let registry = match (&self.registry, self.offline) {
+            (Some(directory), _) => Registry::Directory(directory.clone()),
+            (None, true) => Registry::Offline,
+            (None, false) => Registry::Query(Query::from(self.query.as_str())),
+        };
+        let program_ids = package.resolve(&registry)?;
+        println!("📦 Resolved {} imports into '{}'\n", program_ids.len(), package.imports_directory().display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_build_offline() {
+        let arg_vec = vec!["snarkvm", "build", "--offline"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Build(build) = cli.command {
+            assert!(build.dependencies.offline);
+            assert!(build.dependencies.registry.is_none());
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::synthesizer::{process, program::Instruction};
+
+use indexmap::IndexMap;
+use std::{
+    io::{BufRead, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Steps through an Aleo program function, pausing at breakpoints
+#[derive(Debug, Parser)]
+pub struct Debugger {
+    /// The function name.
+    function: Identifier<CurrentNetwork>,
+    /// The function inputs.
+    inputs: Vec<Value<CurrentNetwork>>,
+    /// The instruction indices to pause at (e.g. `--breakpoint 0,3`).
+    #[clap(short, long, value_delimiter = ',')]
+    breakpoint: Vec<usize>,
+    /// Pauses at every instruction.
+    #[clap(long)]
+    step: bool,
+}
+
+impl Debugger {
+    /// Steps through an Aleo program function with the specified name.
+    #[allow(clippy::format_in_format_args)]
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+        // Load the private key.
+        let private_key = crate::cli::helpers::dotenv_private_key()?;
+
+        // Prepare the locator.
+        let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Load the process, and set the debugger.
+        let process = package.get_process()?;
+        process.set_debugger(Some(Arc::new(Stepper::new(locator, self.breakpoint, self.step))));
+
+        // Authorize the function call.
+        let authorization =
+            process.authorize::<Aleo, _>(&private_key, package.program_id(), self.function, self.inputs.iter(), rng)?;
+        // Evaluate the function call.
+        let response = process.evaluate::<Aleo>(authorization)?;
+
+        // Log the outputs.
+        match response.outputs().len() {
+            0 => (),
+            1 => println!("\n➡️  Output\n"),
+            _ => println!("\n➡️  Outputs\n"),
+        };
+        for output in response.outputs() {
+            println!("{}", format!(" • {output}"));
+        }
+        println!();
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        Ok(format!("✅ Finished debugging '{}' {}", locator.to_string().bold(), path_string.dimmed()))
+    }
+}
+
+/// A debugger that pauses before the breakpoints of a function, and prompts the user to step or continue.
+struct Stepper {
+    /// The locator of the function being debugged.
+    locator: Locator<CurrentNetwork>,
+    /// The instruction indices to pause at.
+    breakpoints: HashSet<usize>,
+    /// Whether to pause at the next instruction.
+    stepping: AtomicBool,
+}
+
+impl Stepper {
+    /// Initializes a new stepper for the given function and breakpoints.
+    fn new(locator: Locator<CurrentNetwork>, breakpoints: Vec<usize>, step: bool) -> Self {
+        Self { locator, breakpoints: breakpoints.into_iter().collect(), stepping: AtomicBool::new(step) }
+    }
+
+    /// Logs the given instruction and registers, and prompts the user to step or continue.
+    fn pause(
+        &self,
+        position: String,
+        instruction: &Instruction<CurrentNetwork>,
+        registers: &IndexMap<u64, Value<CurrentNetwork>>,
+    ) {
+        // Log the instruction.
+        println!(
+            "\n⏸  Paused at '{}' {position}: {}\n",
+            self.locator.to_string().bold(),
+            instruction.to_string().bold()
+        );
+        // Log the registers.
+        for (register, value) in registers {
+            println!(" • r{register} = {value}");
+        }
+
+        // Prompt the user to step or continue.
+        print!("\n{}", "[s]tep, [c]ontinue > ".dimmed());
+        let _ = std::io::stdout().flush();
+        let mut line = String::new();
+        let _ = std::io::stdin().lock().read_line(&mut line);
+        self.stepping.store(line.trim() == "s", Ordering::SeqCst);
+    }
+}
+
+impl process::Debugger<CurrentNetwork> for Stepper {
+    fn before_instruction(
+        &self,
+        locator: &Locator<CurrentNetwork>,
+        index: usize,
+        instruction: &Instruction<CurrentNetwork>,
+        registers: &IndexMap<u64, Value<CurrentNetwork>>,
+    ) {
+        // Only pause in the function being debugged, at a breakpoint or when stepping.
+        if locator == &self.locator && (self.breakpoints.contains(&index) || self.stepping.load(Ordering::SeqCst)) {
+            self.pause(format!("#{index}"), instruction, registers);
+        }
+    }
+
+    fn before_repeat_instruction(
+        &self,
+        locator: &Locator<CurrentNetwork>,
+        index: usize,
+        iteration: u32,
+        body_index: usize,
+        instruction: &Instruction<CurrentNetwork>,
+        registers: &IndexMap<u64, Value<CurrentNetwork>>,
+    ) {
+        // Only pause in the function being debugged, when stepping.
+        // Note: A breakpoint on a `repeat` block pauses before the block, from where the user can step into it.
+        if locator == &self.locator && self.stepping.load(Ordering::SeqCst) {
+            self.pause(format!("#{index} (iteration {iteration}, #{body_index})"), instruction, registers);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cli::{Command, CLI},
+        prelude::{Identifier, Value},
+    };
+
+    #[test]
+    fn clap_snarkvm_debug() {
+        let arg_vec = vec!["snarkvm", "debug", "hello", "1u32", "2u32", "--breakpoint", "0,2"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Debug(debug) = cli.command {
+            assert_eq!(debug.function, Identifier::try_from(arg_vec[2]).unwrap());
+            assert_eq!(debug.inputs, vec![Value::try_from(arg_vec[3]).unwrap(), Value::try_from(arg_vec[4]).unwrap()]);
+            assert_eq!(debug.breakpoint, vec![0, 2]);
+            assert!(!debug.step);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
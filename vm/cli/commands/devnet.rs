@@ -0,0 +1,341 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::{account::PrivateKey, types::Field},
+    ledger::{
+        block::Block,
+        store::{helpers::memory::ConsensusMemory, ConsensusStorage, ConsensusStore},
+        Ledger,
+    },
+    synthesizer::VM,
+};
+
+use anyhow::{anyhow, ensure};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+};
+
+/// The maximum number of bytes in a request body.
+const MAX_REQUEST_BODY_SIZE: usize = 10 * 1024 * 1024; // 10 MiB
+
+/// Starts a local development network, with a REST API for the ledger
+#[derive(Debug, Parser)]
+pub struct Devnet {
+    /// The address to serve the REST API on.
+    #[clap(default_value = "127.0.0.1:3030", long)]
+    rest: SocketAddr,
+    /// Persists the ledger in RocksDB storage, with the given development ID.
+    #[cfg(feature = "rocks")]
+    #[clap(long)]
+    dev: Option<u16>,
+    /// Mints a new block for every broadcast transaction.
+    #[clap(long)]
+    auto_mint: bool,
+}
+
+impl Devnet {
+    /// Starts a local development network, and serves the REST API until the process is stopped.
+    pub fn parse(self) -> Result<String> {
+        // Load the private key, which is used to construct the genesis block and to sign new blocks.
+        let private_key = crate::cli::helpers::dotenv_private_key()?;
+
+        #[cfg(feature = "rocks")]
+        if let Some(dev) = self.dev {
+            use crate::ledger::store::helpers::rocksdb::ConsensusDB;
+            let devnet = DevnetLedger::<ConsensusDB<CurrentNetwork>>::load(private_key, Some(dev))?;
+            return self.serve(devnet);
+        }
+
+        let devnet = DevnetLedger::<ConsensusMemory<CurrentNetwork>>::load(private_key, None)?;
+        self.serve(devnet)
+    }
+
+    /// Serves the REST API for the given devnet ledger.
+    fn serve<C: ConsensusStorage<CurrentNetwork>>(&self, mut devnet: DevnetLedger<C>) -> Result<String> {
+        // Bind the REST API listener.
+        let listener = TcpListener::bind(self.rest)?;
+
+        println!("🌐 Serving the devnet REST API at 'http://{}/testnet3'\n", self.rest);
+        println!(" • Latest height: {}", devnet.ledger.latest_height());
+        println!(" • Latest state root: {}\n", devnet.ledger.latest_state_root());
+
+        for stream in listener.incoming() {
+            if let Err(error) = self.handle(&mut devnet, stream?) {
+                eprintln!("⚠️  Failed to handle a request - {error}");
+            }
+        }
+
+        Ok(format!("✅ Stopped the devnet at '{}'", self.rest))
+    }
+
+    /// Handles a single HTTP request.
+    fn handle<C: ConsensusStorage<CurrentNetwork>>(
+        &self,
+        devnet: &mut DevnetLedger<C>,
+        mut stream: TcpStream,
+    ) -> Result<()> {
+        // Read the request, rejecting it if it is malformed or too large.
+        let (method, path, body) = match read_request(BufReader::new(stream.try_clone()?)) {
+            Ok(request) => request,
+            Err(error) => {
+                return write_response(&mut stream, "400 Bad Request", &serde_json::to_string(&error.to_string())?)
+            }
+        };
+
+        // Route the request.
+        let (status, body) = match devnet.route(&method, &path, &String::from_utf8_lossy(&body)) {
+            Ok(Some(json)) => ("200 OK", json),
+            Ok(None) => ("404 Not Found", serde_json::to_string(&format!("Unknown route '{method} {path}'"))?),
+            Err(error) => ("500 Internal Server Error", serde_json::to_string(&error.to_string())?),
+        };
+        println!(" • {method} {path} - {}", status.dimmed());
+
+        // Auto-mint a block for a broadcast transaction.
+        if self.auto_mint && method == "POST" && path == "/testnet3/transaction/broadcast" && status == "200 OK" {
+            match devnet.mint() {
+                Ok(block) => {
+                    println!("⛏️  Minted block {} ({} transactions)", block.height(), block.transactions().len())
+                }
+                Err(error) => eprintln!("⚠️  Failed to mint a block - {error}"),
+            }
+        }
+
+        // Write the response.
+        write_response(&mut stream, status, &body)
+    }
+}
+
+/// Reads an HTTP request, returning its method, path, and body.
+/// The request is rejected if its body exceeds `MAX_REQUEST_BODY_SIZE` bytes.
+fn read_request(mut reader: impl BufRead) -> Result<(String, String, Vec<u8>)> {
+    // Read the request line.
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut request_line = request_line.split_whitespace();
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+
+    // Read the headers, to determine the length of the body.
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse()?;
+            }
+        }
+    }
+    // Ensure the body is not too large, before allocating it.
+    ensure!(
+        content_length <= MAX_REQUEST_BODY_SIZE,
+        "Request body of {content_length} bytes exceeds the maximum of {MAX_REQUEST_BODY_SIZE} bytes"
+    );
+    // Read the body.
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok((method, path, body))
+}
+
+/// Writes an HTTP response with the given status and JSON body.
+fn write_response(stream: &mut TcpStream, status: &str, body: &str) -> Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// A local development ledger, which holds a mempool of transactions and mints blocks on demand.
+pub struct DevnetLedger<C: ConsensusStorage<CurrentNetwork>> {
+    /// The ledger.
+    ledger: Ledger<CurrentNetwork, C>,
+    /// The private key used to sign new blocks.
+    private_key: PrivateKey<CurrentNetwork>,
+    /// The transactions waiting to be included in the next block.
+    mempool: Vec<Transaction<CurrentNetwork>>,
+}
+
+impl<C: ConsensusStorage<CurrentNetwork>> DevnetLedger<C> {
+    /// Loads the devnet ledger from storage, or initializes it from a new genesis block.
+    pub fn load(private_key: PrivateKey<CurrentNetwork>, dev: Option<u16>) -> Result<Self> {
+        // Retrieve the genesis block from storage, if it exists.
+        let store = ConsensusStore::<CurrentNetwork, C>::open(dev)?;
+        let genesis = match store.block_store().get_block_hash(0)? {
+            Some(hash) => store.block_store().get_block(&hash)?,
+            None => None,
+        };
+        drop(store);
+
+        // Otherwise, construct a new genesis block, with the given private key in the committee.
+        let genesis = match genesis {
+            Some(genesis) => genesis,
+            None => {
+                let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None)?)?;
+                vm.genesis_beacon(&private_key, &mut rand::thread_rng())?
+            }
+        };
+
+        // Load the ledger.
+        let ledger = Ledger::load(genesis, dev)?;
+        Ok(Self { ledger, private_key, mempool: vec![] })
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<CurrentNetwork, C> {
+        &self.ledger
+    }
+
+    /// Adds the given transaction to the mempool, returning its transaction ID.
+    pub fn broadcast(
+        &mut self,
+        transaction: Transaction<CurrentNetwork>,
+    ) -> Result<<CurrentNetwork as crate::prelude::Network>::TransactionID> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the mempool.
+        ensure!(
+            !self.mempool.iter().any(|candidate| candidate.id() == transaction_id),
+            "Transaction '{transaction_id}' is already in the mempool"
+        );
+        // Ensure the transaction is well-formed.
+        self.ledger.check_transaction_basic(&transaction, None, &mut rand::thread_rng())?;
+        // Add the transaction to the mempool.
+        self.mempool.push(transaction);
+        Ok(transaction_id)
+    }
+
+    /// Mints a new block with the transactions in the mempool, and advances the ledger to it.
+    /// The mempool is drained even if minting fails, so that a failing transaction is not retried in every block.
+    pub fn mint(&mut self) -> Result<Block<CurrentNetwork>> {
+        let rng = &mut rand::thread_rng();
+
+        // Drain the mempool.
+        let transactions = std::mem::take(&mut self.mempool);
+        let num_transactions = transactions.len();
+
+        // Prepare the next block.
+        let block = self
+            .ledger
+            .prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], transactions, rng)
+            .map_err(|e| anyhow!("Dropped {num_transactions} transactions from the mempool - {e}"))?;
+        // Ensure the next block is valid.
+        self.ledger.check_next_block(&block, rng)?;
+        // Advance to the next block.
+        self.ledger.advance_to_next_block(&block)?;
+        Ok(block)
+    }
+
+    /// Routes the given request, returning the JSON response, or `None` if the route does not exist.
+    /// The paths match the ones used by `Query`, so that a `Query` may target the devnet.
+    pub fn route(&mut self, method: &str, path: &str, body: &str) -> Result<Option<String>> {
+        // Retrieve the path segments.
+        let segments = match path.strip_prefix("/testnet3/") {
+            Some(path) => path.split('/').collect::<Vec<_>>(),
+            None => return Ok(None),
+        };
+
+        let json = match (method, segments.as_slice()) {
+            ("GET", ["latest", "height"]) => serde_json::to_string(&self.ledger.latest_height())?,
+            ("GET", ["latest", "block"]) => serde_json::to_string(&self.ledger.latest_block())?,
+            ("GET", ["latest", "stateRoot"]) => serde_json::to_string(&self.ledger.latest_state_root())?,
+            ("GET", ["block", height]) => serde_json::to_string(&self.ledger.get_block(u32::from_str(height)?)?)?,
+            ("GET", ["statePath", commitment]) => {
+                let commitment = Field::<CurrentNetwork>::from_str(commitment)?;
+                serde_json::to_string(&self.ledger.get_state_path_for_commitment(&commitment)?)?
+            }
+            ("GET", ["program", program_id]) => {
+                serde_json::to_string(&self.ledger.get_program(ProgramID::from_str(program_id)?)?)?
+            }
+            ("GET", ["mempool"]) => {
+                let transaction_ids = self.mempool.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+                serde_json::to_string(&transaction_ids)?
+            }
+            ("POST", ["transaction", "broadcast"]) => {
+                let transaction = serde_json::from_str(body).map_err(|e| anyhow!("Invalid transaction - {e}"))?;
+                serde_json::to_string(&self.broadcast(transaction)?)?
+            }
+            ("POST", ["block", "mint"]) => serde_json::to_string(&self.mint()?)?,
+            _ => return Ok(None),
+        };
+        Ok(Some(json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_devnet() {
+        let arg_vec = vec!["snarkvm", "devnet", "--rest", "127.0.0.1:4040", "--auto-mint"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Devnet(devnet) = cli.command {
+            assert_eq!(devnet.rest, SocketAddr::from_str(arg_vec[3]).unwrap());
+            assert!(devnet.auto_mint);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_devnet_route() {
+        // Initialize the devnet ledger.
+        let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+        let mut devnet = DevnetLedger::<ConsensusMemory<CurrentNetwork>>::load(private_key, None).unwrap();
+
+        // Check the latest height and state root.
+        assert_eq!(devnet.route("GET", "/testnet3/latest/height", "").unwrap(), Some("0".to_string()));
+        let state_root = devnet.route("GET", "/testnet3/latest/stateRoot", "").unwrap().unwrap();
+        assert_eq!(state_root, serde_json::to_string(&devnet.ledger().latest_state_root()).unwrap());
+
+        // Mint an empty block.
+        let block = devnet.route("POST", "/testnet3/block/mint", "").unwrap().unwrap();
+        assert_eq!(block, serde_json::to_string(&devnet.ledger().latest_block()).unwrap());
+        assert_eq!(devnet.route("GET", "/testnet3/latest/height", "").unwrap(), Some("1".to_string()));
+
+        // Check that unknown routes and invalid transactions are rejected.
+        assert_eq!(devnet.route("GET", "/testnet3/unknown", "").unwrap(), None);
+        assert_eq!(devnet.route("GET", "/latest/height", "").unwrap(), None);
+        assert!(devnet.route("GET", "/testnet3/program/missing.aleo", "").is_err());
+        assert!(devnet.route("POST", "/testnet3/transaction/broadcast", "{}").is_err());
+    }
+
+    #[test]
+    fn test_devnet_read_request() {
+        // Read a well-formed request.
+        let request = "POST /testnet3/block/mint HTTP/1.1\r\nContent-Length: 2\r\n\r\n{}";
+        let (method, path, body) = read_request(request.as_bytes()).unwrap();
+        assert_eq!((method.as_str(), path.as_str(), body.as_slice()), ("POST", "/testnet3/block/mint", &b"{}"[..]));
+
+        // Ensure a request with an oversized body is rejected, without reading the body.
+        let request = format!(
+            "POST /testnet3/transaction/broadcast HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_REQUEST_BODY_SIZE + 1
+        );
+        let error = read_request(request.as_bytes()).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum"));
+    }
+}
@@ -18,6 +18,12 @@ pub use build::*;
 pub mod clean;
 pub use clean::*;
 
+pub mod debug;
+pub use debug::*;
+
+pub mod devnet;
+pub use devnet::*;
+
 pub mod execute;
 pub use execute::*;
 
@@ -27,6 +33,9 @@ pub use new::*;
 pub mod run;
 pub use run::*;
 
+pub mod test;
+pub use test::*;
+
 pub mod update;
 pub use update::*;
 
@@ -34,13 +43,14 @@ use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
     ledger::block::Transaction,
     package::Package,
+    synthesizer::FinalizeCostReport,
 };
 
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
 
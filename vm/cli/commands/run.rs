@@ -21,6 +21,12 @@ pub struct Run {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
+    /// Prints the cost of each finalize command.
+    #[clap(long)]
+    finalize_cost: bool,
+    /// The options to resolve the dependencies.
+    #[clap(flatten)]
+    dependencies: Dependencies,
 }
 
 impl Run {
@@ -35,6 +41,9 @@ impl Run {
         // Load the private key.
         let private_key = crate::cli::helpers::dotenv_private_key()?;
 
+        // Resolve the dependencies, if any are declared.
+        self.dependencies.resolve(&package)?;
+
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
 
@@ -87,6 +96,33 @@ impl Run {
         }
         println!();
 
+        // Log the finalize costs.
+        if self.finalize_cost {
+            // Load the process.
+            let process = package.get_process()?;
+
+            println!("⛽ Finalize costs (in microcredits)\n");
+            let mut logged = HashSet::new();
+            for metric in metrics.iter() {
+                // Skip functions that have already been logged.
+                if !logged.insert((metric.program_id, metric.function_name)) {
+                    continue;
+                }
+                // Retrieve the stack and function.
+                let stack = process.get_stack(metric.program_id)?;
+                let function = process.get_program(metric.program_id)?.get_function_ref(&metric.function_name)?;
+                // Log the cost report, if the function has a finalize block.
+                if let Some(finalize) = function.finalize_logic() {
+                    let report = FinalizeCostReport::new(stack, finalize)?;
+                    println!("{}", format!("'{}/{}'", metric.program_id, report.name()).bold());
+                    for line in report.to_string().lines() {
+                        println!(" • {line}");
+                    }
+                    println!();
+                }
+            }
+        }
+
         // Prepare the locator.
         let locator = Locator::<CurrentNetwork>::from_str(&format!("{}/{}", package.program_id(), self.function))?;
         // Prepare the path string.
@@ -109,6 +145,34 @@ mod tests {
         let arg_vec = vec!["snarkvm", "run", "hello", "1u32", "2u32"];
         let cli = CLI::parse_from(&arg_vec);
 
+        if let Command::Run(run) = cli.command {
+            assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
+            assert_eq!(run.inputs, vec![Value::try_from(arg_vec[3]).unwrap(), Value::try_from(arg_vec[4]).unwrap()]);
+            assert!(!run.finalize_cost);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkvm_run_finalize_cost() {
+        let arg_vec = vec!["snarkvm", "run", "hello", "1u32", "2u32", "--finalize-cost"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Run(run) = cli.command {
+            assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
+            assert_eq!(run.inputs, vec![Value::try_from(arg_vec[3]).unwrap(), Value::try_from(arg_vec[4]).unwrap()]);
+            assert!(run.finalize_cost);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkvm_run_offline() {
+        let arg_vec = vec!["snarkvm", "run", "hello", "1u32", "2u32", "--offline"];
+        let cli = CLI::parse_from(&arg_vec);
+
         if let Command::Run(run) = cli.command {
             assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
             assert_eq!(run.inputs, vec![Value::try_from(arg_vec[3]).unwrap(), Value::try_from(arg_vec[4]).unwrap()]);
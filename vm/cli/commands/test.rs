@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Runs the test files of an Aleo package
+#[derive(Debug, Parser)]
+pub struct Test;
+
+impl Test {
+    /// Runs the test files in the Aleo package tests directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+        // Load the private key.
+        let private_key = crate::cli::helpers::dotenv_private_key()?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Run the tests.
+        let outcomes = package.test::<Aleo, _>(&private_key, rng)?;
+
+        // Log the outcomes.
+        use num_format::ToFormattedString;
+
+        println!("🧪 Tests\n");
+        for outcome in &outcomes {
+            // Prepare the test name string.
+            let test_string = format!(
+                "{} #{} '{}/{}'",
+                outcome.file_name,
+                outcome.index,
+                package.program_id(),
+                outcome.function_name
+            );
+            // Prepare the constraints string.
+            let num_constraints = outcome.metrics.iter().map(|metric| metric.num_function_constraints).sum::<u64>();
+            let constraints_string = format!("({} constraints)", num_constraints.to_formatted_string(LOCALE));
+
+            match &outcome.failure {
+                None => println!(" ✅ {} {}", test_string.bold(), constraints_string.dimmed()),
+                Some(failure) => println!(" ❌ {} - {failure}", test_string.bold()),
+            }
+        }
+        println!();
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        // Count the number of failed tests.
+        let num_failed = outcomes.iter().filter(|outcome| !outcome.is_success()).count();
+        match num_failed {
+            0 => Ok(format!("✅ Passed {} tests {}", outcomes.len(), path_string.dimmed())),
+            _ => anyhow::bail!("Failed {num_failed} of {} tests {}", outcomes.len(), path_string.dimmed()),
+        }
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     synthesizer::Program,
 };
 
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use core::str::FromStr;
 use std::{
     fs::{self, File},
@@ -27,11 +27,66 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// A program dependency, declared in the manifest as either a program ID,
+/// or an object with a program ID and an edition:
+/// ```json
+/// "dependencies": ["token.aleo", { "program": "wallet.aleo", "edition": 1 }]
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dependency<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The edition, if it is pinned.
+    edition: Option<u16>,
+}
+
+impl<N: Network> Dependency<N> {
+    /// Initializes a new dependency.
+    pub const fn new(program_id: ProgramID<N>, edition: Option<u16>) -> Self {
+        Self { program_id, edition }
+    }
+
+    /// Parses a dependency from the given JSON value.
+    fn from_json(json: &serde_json::Value) -> Result<Self> {
+        match json {
+            serde_json::Value::String(program_id) => Ok(Self::new(ProgramID::from_str(program_id)?, None)),
+            serde_json::Value::Object(dependency) => {
+                // Retrieve the program ID.
+                let id_string = dependency
+                    .get("program")
+                    .and_then(|id| id.as_str())
+                    .ok_or_else(|| anyhow!("Dependency program ID not found."))?;
+                // Retrieve the edition, if it is pinned.
+                let edition = match dependency.get("edition") {
+                    Some(edition) => Some(u16::try_from(
+                        edition.as_u64().ok_or_else(|| anyhow!("Invalid dependency edition: {edition}"))?,
+                    )?),
+                    None => None,
+                };
+                Ok(Self::new(ProgramID::from_str(id_string)?, edition))
+            }
+            _ => bail!("Invalid dependency: {json}"),
+        }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the edition, if it is pinned.
+    pub const fn edition(&self) -> Option<u16> {
+        self.edition
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
     /// The program ID.
     program_id: ProgramID<N>,
+    /// The program dependencies.
+    dependencies: Vec<Dependency<N>>,
 }
 
 impl<N: Network> Manifest<N> {
@@ -62,7 +117,7 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id })
+        Ok(Self { path, program_id: *id, dependencies: vec![] })
     }
 
     /// Opens the manifest file for reading.
@@ -85,8 +140,27 @@ impl<N: Network> Manifest<N> {
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(id.name()), "Program name is invalid (reserved): {id}");
 
+        // Retrieve the dependencies, if any.
+        let dependencies = match json.get("dependencies") {
+            Some(dependencies) => dependencies
+                .as_array()
+                .ok_or_else(|| anyhow!("Dependencies must be an array."))?
+                .iter()
+                .map(Dependency::from_json)
+                .collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        // Ensure the dependencies are unique.
+        for (index, dependency) in dependencies.iter().enumerate() {
+            ensure!(
+                !dependencies[..index].iter().any(|d| d.program_id() == dependency.program_id()),
+                "Duplicate dependency: {}",
+                dependency.program_id()
+            );
+        }
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id })
+        Ok(Self { path, program_id: id, dependencies })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -111,4 +185,62 @@ impl<N: Network> Manifest<N> {
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
+
+    /// Returns the program dependencies.
+    pub fn dependencies(&self) -> &[Dependency<N>] {
+        &self.dependencies
+    }
+
+    /// Returns the dependency for the given program ID, if it is declared.
+    pub fn get_dependency(&self, program_id: &ProgramID<N>) -> Option<&Dependency<N>> {
+        self.dependencies.iter().find(|dependency| dependency.program_id() == program_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    fn temp_dir() -> PathBuf {
+        tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+    }
+
+    #[test]
+    fn test_dependencies() {
+        // Initialize a temporary directory.
+        let directory = temp_dir();
+
+        // Create a manifest without dependencies.
+        let id = ProgramID::<CurrentNetwork>::from_str("wallet.aleo").unwrap();
+        let manifest = Manifest::create(&directory, &id).unwrap();
+        assert!(manifest.dependencies().is_empty());
+
+        // Write a manifest with dependencies.
+        fs::write(
+            manifest.path(),
+            r#"{
+    "program": "wallet.aleo",
+    "dependencies": ["token.aleo", { "program": "bank.aleo", "edition": 1 }]
+}"#,
+        )
+        .unwrap();
+
+        // Read the dependencies.
+        let manifest = Manifest::<CurrentNetwork>::open(&directory).unwrap();
+        let token = ProgramID::from_str("token.aleo").unwrap();
+        let bank = ProgramID::from_str("bank.aleo").unwrap();
+        assert_eq!(manifest.dependencies(), &[Dependency::new(token, None), Dependency::new(bank, Some(1))]);
+        assert_eq!(manifest.get_dependency(&bank).and_then(|dependency| dependency.edition()), Some(1));
+        assert!(manifest.get_dependency(&id).is_none());
+
+        // Ensure duplicate dependencies are rejected.
+        fs::write(manifest.path(), r#"{ "program": "wallet.aleo", "dependencies": ["token.aleo", "token.aleo"] }"#)
+            .unwrap();
+        assert!(Manifest::<CurrentNetwork>::open(&directory).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        fs::remove_dir_all(directory).unwrap();
+    }
 }
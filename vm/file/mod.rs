@@ -19,7 +19,7 @@ mod avm;
 pub use avm::AVMFile;
 
 mod manifest;
-pub use manifest::Manifest;
+pub use manifest::{Dependency, Manifest};
 
 mod prover;
 pub use prover::ProverFile;
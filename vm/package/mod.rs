@@ -17,10 +17,14 @@ mod clean;
 mod deploy;
 mod execute;
 mod is_build_required;
+mod resolve;
 mod run;
+mod test;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
+pub use resolve::Registry;
+pub use test::{TestCase, TestOutcome};
 
 use crate::{
     console::{
@@ -32,7 +36,7 @@ use crate::{
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
-        process::{Assignments, CallMetrics, CallStack, Process, StackExecute},
+        process::{Assignments, Authorization, CallMetrics, CallStack, Process, StackExecute},
         program::{CallOperator, Instruction, Program},
         snark::{ProvingKey, VerifyingKey},
     },
@@ -155,20 +159,16 @@ impl<N: Network> Package<N> {
         // Prepare the imports directory.
         let imports_directory = self.imports_directory();
 
-        // Initialize the 'credits.aleo' program ID.
-        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Retrieve the (transitive) import programs from the imports directory, ensuring the import graph is acyclic.
+        let imports = self.resolve_imports(|program_id| {
+            // Open the Aleo program file.
+            Ok(AleoFile::open(&imports_directory, program_id, false)?.program().clone())
+        })?;
 
         // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
-            // Don't add `credits.aleo` as the process is already loaded with it.
-            if program_id != &credits_program_id {
-                // Open the Aleo program file.
-                let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-                // Add the import program.
-                process.add_program(import_program_file.program())?;
-            }
-            Ok::<_, Error>(())
-        })?;
+        for import in &imports {
+            process.add_program(import)?;
+        }
 
         // Add the program to the process.
         process.add_program(self.program())?;
@@ -299,6 +299,35 @@ function main:
         sample_package_with_program_and_imports(&main_program, &[imported_program])
     }
 
+    /// Samples a (temporary) package containing a `counter.aleo` program with a mapping.
+    pub(crate) fn sample_counter_package() -> (PathBuf, Package<CurrentNetwork>) {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program counter.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    async increment self.caller r0 into r1;
+    output r1 as counter.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];",
+        )
+        .unwrap();
+
+        // Sample the package using the program.
+        sample_package_with_program_and_imports(&program, &[])
+    }
+
     /// Samples a (temporary) package using a main program and imported programs.
     pub(crate) fn sample_package_with_program_and_imports(
         main_program: &Program<CurrentNetwork>,
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// A source of program dependencies.
+pub enum Registry<N: Network> {
+    /// Fetches programs with the given query, at their pinned edition if any.
+    Query(Query<N, BlockMemory<N>>),
+    /// Reads programs from a local directory, as `{directory}/{program_id}`,
+    /// or as `{directory}/{edition}/{program_id}` if the edition is pinned.
+    Directory(PathBuf),
+    /// Fetches nothing, so that only the programs already in the imports directory are used.
+    Offline,
+}
+
+impl<N: Network> Registry<N> {
+    /// Returns the program for the given program ID and (optional) edition.
+    pub fn get_program(&self, program_id: &ProgramID<N>, edition: Option<u16>) -> Result<Program<N>> {
+        let program = match self {
+            Self::Query(query) => match edition {
+                // Fetch the pinned edition, as the latest program may have been upgraded since.
+                Some(edition) => query.get_program_for_edition(program_id, edition)?,
+                None => query.get_program(program_id)?,
+            },
+            Self::Directory(directory) => {
+                let directory = match edition {
+                    Some(edition) => directory.join(edition.to_string()),
+                    None => directory.clone(),
+                };
+                // Open (and parse) the Aleo program file.
+                AleoFile::open(&directory, program_id, false)?.program().clone()
+            }
+            Self::Offline => {
+                bail!("Program '{program_id}' is not in the imports directory, and cannot be fetched offline")
+            }
+        };
+        // Ensure the program ID matches.
+        ensure!(program.id() == program_id, "Expected program '{program_id}', found '{}'", program.id());
+        Ok(program)
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Resolves the imports of the main program, and writes them to the imports directory.
+    /// Dependencies declared in the manifest are fetched from the given registry, at their pinned edition.
+    /// Undeclared imports are read from the imports directory, if present, and fetched from the registry otherwise.
+    /// With an offline registry, every import must already be in the imports directory.
+    /// Returns the program IDs of the resolved imports, in the order they must be added to a process.
+    pub fn resolve(&self, registry: &Registry<N>) -> Result<Vec<ProgramID<N>>> {
+        // Prepare the imports directory.
+        let imports_directory = self.imports_directory();
+
+        // Resolve the imports.
+        let imports = self.resolve_imports(|program_id| {
+            let dependency = self.manifest_file.get_dependency(program_id);
+            // Read the import from the imports directory, unless it is a declared dependency that can be fetched.
+            if dependency.is_none() || matches!(registry, Registry::Offline) {
+                if let Ok(file) = AleoFile::<N>::open(&imports_directory, program_id, false) {
+                    return Ok(file.program().clone());
+                }
+            }
+            registry.get_program(program_id, dependency.and_then(|dependency| dependency.edition()))
+        })?;
+
+        // Ensure each declared dependency is imported.
+        for dependency in self.manifest_file.dependencies() {
+            ensure!(
+                imports.iter().any(|import| import.id() == dependency.program_id()),
+                "Dependency '{}' is not imported by '{}'",
+                dependency.program_id(),
+                self.program_id
+            );
+        }
+
+        // Write the imports to the imports directory.
+        if !imports.is_empty() {
+            std::fs::create_dir_all(&imports_directory)?;
+        }
+        for import in &imports {
+            std::fs::write(imports_directory.join(import.id().to_string()), import.to_string())?;
+        }
+
+        Ok(imports.iter().map(|import| *import.id()).collect())
+    }
+
+    /// Returns the (transitive) imports of the main program, excluding `credits.aleo`,
+    /// in the order they must be added to a process. The imports are retrieved with the given function.
+    /// This method ensures the import graph is acyclic.
+    pub(crate) fn resolve_imports(
+        &self,
+        mut get_program: impl FnMut(&ProgramID<N>) -> Result<Program<N>>,
+    ) -> Result<Vec<Program<N>>> {
+        let mut resolved = IndexMap::new();
+        Self::resolve_imports_of(self.program(), &mut get_program, &mut vec![], &mut resolved)?;
+        Ok(resolved.into_values().collect())
+    }
+
+    /// Resolves the imports of the given program, in depth-first order.
+    /// The `path` is the chain of programs currently being resolved, which is used to detect cycles.
+    fn resolve_imports_of(
+        program: &Program<N>,
+        get_program: &mut impl FnMut(&ProgramID<N>) -> Result<Program<N>>,
+        path: &mut Vec<ProgramID<N>>,
+        resolved: &mut IndexMap<ProgramID<N>, Program<N>>,
+    ) -> Result<()> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+
+        path.push(*program.id());
+        for import_id in program.imports().keys() {
+            // Skip `credits.aleo` (as a process is already loaded with it) and resolved imports.
+            if import_id == &credits_program_id || resolved.contains_key(import_id) {
+                continue;
+            }
+            // Ensure the import does not form a cycle.
+            if path.contains(import_id) {
+                let path = path.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(" -> ");
+                bail!("Found a cycle in the import graph: {path} -> {import_id}")
+            }
+            // Retrieve the import.
+            let import = get_program(import_id)?;
+            ensure!(import.id() == import_id, "Expected program '{import_id}', found '{}'", import.id());
+            // Resolve the imports of the import, before the import itself.
+            Self::resolve_imports_of(&import, get_program, path, resolved)?;
+            resolved.insert(*import_id, import);
+        }
+        path.pop();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    /// Writes the given programs to a registry directory, returning the registry.
+    fn sample_registry(programs: &[&str]) -> Registry<CurrentNetwork> {
+        let directory = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        for program in programs {
+            let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+            std::fs::write(directory.join(program.id().to_string()), program.to_string()).unwrap();
+        }
+        Registry::Directory(directory)
+    }
+
+    #[test]
+    fn test_resolve() {
+        // Samples a new package at a temporary directory, which imports `token.aleo`.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        // Declare the dependency, and remove the imports directory.
+        std::fs::write(
+            package.manifest_file().path(),
+            r#"{ "program": "wallet.aleo", "dependencies": ["token.aleo"] }"#,
+        )
+        .unwrap();
+        let token = std::fs::read_to_string(package.imports_directory().join("token.aleo")).unwrap();
+        std::fs::remove_dir_all(package.imports_directory()).unwrap();
+
+        // Ensure the package cannot load its process.
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+        assert!(package.get_process().is_err());
+
+        // Resolve the imports from the registry.
+        let registry = sample_registry(&[&token]);
+        let program_ids = package.resolve(&registry).unwrap();
+        assert_eq!(program_ids, vec![ProgramID::from_str("token.aleo").unwrap()]);
+        assert!(package.imports_directory().join("token.aleo").exists());
+
+        // Ensure the package loads its process.
+        assert!(package.get_process().is_ok());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_offline() {
+        // Samples a new package at a temporary directory, which imports `token.aleo`.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        // Declare the dependency.
+        std::fs::write(
+            package.manifest_file().path(),
+            r#"{ "program": "wallet.aleo", "dependencies": ["token.aleo"] }"#,
+        )
+        .unwrap();
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+
+        // Ensure the cached import is used offline.
+        let program_ids = package.resolve(&Registry::Offline).unwrap();
+        assert_eq!(program_ids, vec![ProgramID::from_str("token.aleo").unwrap()]);
+
+        // Ensure resolution fails offline once the import is no longer cached.
+        std::fs::remove_dir_all(package.imports_directory()).unwrap();
+        let error = package.resolve(&Registry::Offline).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Program 'token.aleo' is not in the imports directory, and cannot be fetched offline"
+        );
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_pinned_edition_from_rest_query() {
+        // Samples a new package at a temporary directory, which imports `token.aleo`.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        // Pin the dependency to the network edition.
+        std::fs::write(
+            package.manifest_file().path(),
+            r#"{ "program": "wallet.aleo", "dependencies": [{ "program": "token.aleo", "edition": 0 }] }"#,
+        )
+        .unwrap();
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+
+        // Ensure a REST query, which only serves the latest edition, does not resolve the pinned edition.
+        let registry = Registry::Query(Query::from("http://localhost:3030"));
+        let error = package.resolve(&registry).unwrap_err();
+        assert_eq!(error.to_string(), "Cannot fetch edition 0 of program token.aleo from a REST query");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_cycle() {
+        // Samples a new package at a temporary directory, which imports `token.aleo`.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        std::fs::remove_dir_all(package.imports_directory()).unwrap();
+
+        // Initialize a registry where `token.aleo` imports `wallet.aleo`.
+        let registry = sample_registry(&[
+            "program wallet.aleo;\n\nfunction foo:\n    input r0 as u8.private;\n    output r0 as u8.private;",
+            "import wallet.aleo;\n\nprogram token.aleo;\n\nfunction bar:\n    input r0 as u8.private;\n    output r0 as u8.private;",
+        ]);
+
+        // Ensure the cycle is detected.
+        let error = package.resolve(&registry).unwrap_err();
+        assert_eq!(error.to_string(), "Found a cycle in the import graph: wallet.aleo -> token.aleo -> wallet.aleo");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
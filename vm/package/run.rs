@@ -42,12 +42,22 @@ impl<N: Network> Package<N> {
         let process = self.get_process()?;
         // Authorize the function call.
         let authorization = process.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
+        // Run the function call.
+        Self::run_authorization::<A, R>(&process, private_key, authorization, rng)
+    }
 
+    /// Runs the given authorization, returning the response and call metrics.
+    pub(crate) fn run_authorization<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        process: &Process<N>,
+        private_key: &PrivateKey<N>,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Vec<CallMetrics<N>>)> {
         // TODO (howardwu): Retrieve the value directly from the authorize call.
         // Pop the first request.
         let request = authorization.next()?;
         // Retrieve the stack.
-        let stack = process.get_stack(program_id)?;
+        let stack = process.get_stack(request.program_id())?;
         // Initialize the assignments.
         let assignments = Assignments::<N>::default();
         // Initialize the call stack.
@@ -0,0 +1,442 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::program::Plaintext,
+    ledger::store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    synthesizer::program::FinalizeGlobalState,
+};
+
+use anyhow::anyhow;
+use indexmap::{IndexMap, IndexSet};
+
+/// The extension of a test file.
+const TEST_FILE_EXTENSION: &str = "json";
+
+/// A test case, which calls a function of the main program and checks the outcome.
+///
+/// A test file is a JSON array of test cases, which are run in order against the same finalize state:
+/// ```json
+/// [
+///     {
+///         "function": "increment",
+///         "inputs": ["1u64"],
+///         "outputs": [],
+///         "mappings": { "counts": { "aleo1...": "1u64" } }
+///     },
+///     {
+///         "function": "decrement",
+///         "inputs": ["2u64"],
+///         "fails": true,
+///         "error": "Failed to evaluate command"
+///     }
+/// ]
+/// ```
+/// The `outputs`, `mappings`, `fails`, and `error` fields are optional.
+/// A test case with `"fails": true` passes only if the call fails, with an error containing `error` (if given).
+pub struct TestCase<N: Network> {
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The expected function outputs, if any.
+    outputs: Option<Vec<Value<N>>>,
+    /// The expected mapping entries after finalize, as `(mapping name, (key, value))`.
+    mappings: IndexMap<Identifier<N>, IndexMap<Plaintext<N>, Value<N>>>,
+    /// Whether the call is expected to fail.
+    fails: bool,
+    /// The expected error message of a failing call, if any.
+    error: Option<String>,
+}
+
+impl<N: Network> TestCase<N> {
+    /// Opens the test cases in the given test file.
+    pub fn open(path: &Path) -> Result<Vec<Self>> {
+        // Read the file to a string.
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        // Parse the test cases.
+        match json.as_array() {
+            Some(cases) => cases.iter().map(Self::from_json).collect(),
+            None => bail!("Test file '{}' must contain an array of test cases", path.display()),
+        }
+    }
+
+    /// Parses a test case from the given JSON object.
+    fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Parses a list of values from the given JSON array.
+        let parse_values = |json: &serde_json::Value| {
+            json.as_array()
+                .ok_or_else(|| anyhow!("Expected an array of values, found '{json}'"))?
+                .iter()
+                .map(|value| {
+                    Value::from_str(value.as_str().ok_or_else(|| anyhow!("Expected a value, found '{value}'"))?)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        // Retrieve the function name.
+        let function_name = json["function"].as_str().ok_or_else(|| anyhow!("Test case is missing a 'function'"))?;
+        let function_name = Identifier::from_str(function_name)?;
+        // Retrieve the function inputs.
+        let inputs = match json.get("inputs") {
+            Some(inputs) => parse_values(inputs)?,
+            None => vec![],
+        };
+        // Retrieve the expected function outputs.
+        let outputs = json.get("outputs").map(parse_values).transpose()?;
+        // Retrieve the expected mapping entries.
+        let mut mappings = IndexMap::new();
+        if let Some(json) = json.get("mappings") {
+            let json = json.as_object().ok_or_else(|| anyhow!("Expected an object of mappings, found '{json}'"))?;
+            for (mapping_name, entries) in json {
+                let entries = entries
+                    .as_object()
+                    .ok_or_else(|| anyhow!("Expected an object of entries for mapping '{mapping_name}'"))?
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = value.as_str().ok_or_else(|| anyhow!("Expected a value, found '{value}'"))?;
+                        Ok((Plaintext::from_str(key)?, Value::from_str(value)?))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                mappings.insert(Identifier::from_str(mapping_name)?, entries);
+            }
+        }
+
+        // Retrieve the expected failure.
+        let fails = match json.get("fails") {
+            Some(fails) => {
+                fails.as_bool().ok_or_else(|| anyhow!("Expected 'fails' to be a boolean, found '{fails}'"))?
+            }
+            None => false,
+        };
+        let error = match json.get("error") {
+            Some(error) => Some(error.as_str().ok_or_else(|| anyhow!("Expected an error message, found '{error}'"))?),
+            None => None,
+        };
+        ensure!(fails || error.is_none(), "Test case has an 'error' but does not set 'fails'");
+
+        Ok(Self { function_name, inputs, outputs, mappings, fails, error: error.map(|error| error.to_string()) })
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+}
+
+/// The outcome of a test case.
+pub struct TestOutcome<N: Network> {
+    /// The name of the test file.
+    pub file_name: String,
+    /// The index of the test case in the test file.
+    pub index: usize,
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The call metrics, if the function ran to completion.
+    pub metrics: Vec<CallMetrics<N>>,
+    /// The reason the test case failed, if it failed.
+    pub failure: Option<String>,
+}
+
+impl<N: Network> TestOutcome<N> {
+    /// Returns `true` if the test case passed.
+    pub const fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the tests directory.
+    pub fn tests_directory(&self) -> PathBuf {
+        self.directory.join("tests")
+    }
+
+    /// Runs the test files in the tests directory, in lexicographic order.
+    /// Each test file is run against a new in-memory finalize state.
+    pub fn test<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Vec<TestOutcome<N>>> {
+        // Retrieve the test files.
+        let tests_directory = self.tests_directory();
+        let mut test_files = match tests_directory.exists() {
+            true => std::fs::read_dir(&tests_directory)?
+                .map(|entry| Ok::<_, Error>(entry?.path()))
+                .filter(|path| match path {
+                    Ok(path) => path.is_file() && path.extension().map_or(false, |e| e == TEST_FILE_EXTENSION),
+                    Err(_) => true,
+                })
+                .collect::<Result<Vec<_>>>()?,
+            false => vec![],
+        };
+        test_files.sort();
+
+        // Construct the process.
+        let process = self.get_process()?;
+
+        let mut outcomes = Vec::new();
+        for path in test_files {
+            // Retrieve the file name.
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            // Open the test cases.
+            let cases = TestCase::<N>::open(&path)?;
+
+            // Initialize a new in-memory store, with the mappings of the main program and its transitive imports.
+            let store = ConsensusStore::<N, ConsensusMemory<N>>::open(None)?;
+            let mut program_ids = IndexSet::from([*self.program_id()]);
+            let mut next = 0;
+            while let Some(program_id) = program_ids.get_index(next).copied() {
+                let program = process.get_program(program_id)?;
+                for mapping_name in program.mappings().keys() {
+                    store.finalize_store().initialize_mapping(program_id, *mapping_name)?;
+                }
+                program_ids.extend(program.imports().keys().copied());
+                next += 1;
+            }
+
+            // Run the test cases.
+            for (index, case) in cases.iter().enumerate() {
+                let result = self.run_test_case::<A, R>(&process, &store, private_key, case, rng);
+                let (metrics, failure) = match (result, case.fails) {
+                    (Ok((metrics, failure)), false) => (metrics, failure),
+                    (Err(error), false) => (vec![], Some(error.to_string())),
+                    (Ok((metrics, _)), true) => {
+                        (metrics, Some("Expected the call to fail, but it succeeded".to_string()))
+                    }
+                    (Err(error), true) => match &case.error {
+                        Some(expected) if !error.to_string().contains(expected.as_str()) => {
+                            (vec![], Some(format!("Expected the call to fail with '{expected}', found '{error}'")))
+                        }
+                        _ => (vec![], None),
+                    },
+                };
+                outcomes.push(TestOutcome {
+                    file_name: file_name.clone(),
+                    index,
+                    function_name: *case.function_name(),
+                    metrics,
+                    failure,
+                });
+            }
+        }
+        Ok(outcomes)
+    }
+
+    /// Runs the given test case, returning the call metrics and the reason the test case failed (if any).
+    fn run_test_case<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        process: &Process<N>,
+        store: &ConsensusStore<N, ConsensusMemory<N>>,
+        private_key: &PrivateKey<N>,
+        case: &TestCase<N>,
+        rng: &mut R,
+    ) -> Result<(Vec<CallMetrics<N>>, Option<String>)> {
+        // Authorize the function call.
+        let authorization =
+            process.authorize::<A, R>(private_key, self.program_id(), case.function_name, case.inputs.iter(), rng)?;
+        // Run the function call.
+        let (response, metrics) =
+            Self::run_authorization::<A, R>(process, private_key, authorization.replicate(), rng)?;
+
+        // Ensure the outputs match.
+        if let Some(outputs) = &case.outputs {
+            if response.outputs() != outputs.as_slice() {
+                let found = response.outputs().iter().map(|output| output.to_string()).collect::<Vec<_>>();
+                let expected = outputs.iter().map(|output| output.to_string()).collect::<Vec<_>>();
+                return Ok((metrics, Some(format!("Expected outputs {expected:?}, found {found:?}"))));
+            }
+        }
+
+        // Finalize the function call.
+        let execution = Execution::from(authorization.transitions().into_values(), N::StateRoot::default(), None)?;
+        let state = FinalizeGlobalState::new_genesis::<N>()?;
+        process.finalize_execution(state, store.finalize_store(), &execution, None)?;
+
+        // Ensure the mapping entries match.
+        for (mapping_name, entries) in &case.mappings {
+            for (key, expected) in entries {
+                let value = store.finalize_store().get_value_speculative(*self.program_id(), *mapping_name, key)?;
+                if value.as_ref() != Some(expected) {
+                    let found = value.map_or_else(|| "nothing".to_string(), |value| value.to_string());
+                    return Ok((
+                        metrics,
+                        Some(format!("Expected '{mapping_name}[{key}]' to be {expected}, found {found}")),
+                    ));
+                }
+            }
+        }
+
+        Ok((metrics, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::{account::Address, network::Testnet3};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_test() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_counter_package();
+
+        // Initialize the caller.
+        let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+        let caller = Address::try_from(&private_key).unwrap();
+
+        // Write a test file, with a passing and a failing test case.
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        std::fs::write(
+            package.tests_directory().join("increment.json"),
+            format!(
+                r#"[
+    {{ "function": "increment", "inputs": ["2u64"], "mappings": {{ "counts": {{ "{caller}": "2u64" }} }} }},
+    {{ "function": "increment", "inputs": ["3u64"], "mappings": {{ "counts": {{ "{caller}": "4u64" }} }} }}
+]"#
+            ),
+        )
+        .unwrap();
+
+        // Run the tests.
+        let rng = &mut TestRng::default();
+        let outcomes = package.test::<CurrentAleo, _>(&private_key, rng).unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes[0].is_success());
+        assert!(!outcomes[0].metrics.is_empty());
+        assert!(!outcomes[1].is_success());
+        assert_eq!(
+            outcomes[1].failure.as_deref(),
+            Some(format!("Expected 'counts[{caller}]' to be 4u64, found 5u64").as_str())
+        );
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_test_expected_failure() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_counter_package();
+
+        // Initialize the caller.
+        let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+
+        // Write a test file, where the second and third calls overflow the counter.
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        std::fs::write(
+            package.tests_directory().join("overflow.json"),
+            r#"[
+    { "function": "increment", "inputs": ["2u64"] },
+    { "function": "increment", "inputs": ["18446744073709551615u64"], "fails": true },
+    { "function": "increment", "inputs": ["18446744073709551615u64"], "fails": true, "error": "not the error" },
+    { "function": "increment", "inputs": ["1u64"], "fails": true }
+]"#,
+        )
+        .unwrap();
+
+        // Run the tests.
+        let rng = &mut TestRng::default();
+        let outcomes = package.test::<CurrentAleo, _>(&private_key, rng).unwrap();
+        assert_eq!(outcomes.len(), 4);
+        assert!(outcomes[0].is_success());
+        assert!(outcomes[1].is_success());
+        assert!(outcomes[2].failure.as_deref().unwrap().starts_with("Expected the call to fail with 'not the error'"));
+        assert_eq!(outcomes[3].failure.as_deref(), Some("Expected the call to fail, but it succeeded"));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_test_transitive_imports() {
+        // Initialize a program with a mapping, which is imported transitively.
+        let base = Program::<CurrentNetwork>::from_str(
+            "
+program base.aleo;
+
+mapping counts:
+    key as u8.public;
+    value as u64.public;
+
+function bump:
+    async bump into r0;
+    output r0 as base.aleo/bump.future;
+
+finalize bump:
+    get.or_use counts[0u8] 0u64 into r0;
+    add r0 1u64 into r1;
+    set r1 into counts[0u8];",
+        )
+        .unwrap();
+        // Initialize the intermediate and main programs.
+        let mid = Program::<CurrentNetwork>::from_str(
+            "
+import base.aleo;
+
+program mid.aleo;
+
+function bump:
+    call base.aleo/bump into r0;
+    async bump r0 into r1;
+    output r1 as mid.aleo/bump.future;
+
+finalize bump:
+    input r0 as base.aleo/bump.future;
+    await r0;",
+        )
+        .unwrap();
+        let main = Program::<CurrentNetwork>::from_str(
+            "
+import mid.aleo;
+
+program main_bump.aleo;
+
+function bump:
+    call mid.aleo/bump into r0;
+    async bump r0 into r1;
+    output r1 as main_bump.aleo/bump.future;
+
+finalize bump:
+    input r0 as mid.aleo/bump.future;
+    await r0;",
+        )
+        .unwrap();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) =
+            crate::package::test_helpers::sample_package_with_program_and_imports(&main, &[base, mid]);
+
+        // Initialize the caller.
+        let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+
+        // Write a test file, which finalizes into the mapping of the transitive import.
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        std::fs::write(package.tests_directory().join("bump.json"), r#"[{ "function": "bump" }]"#).unwrap();
+
+        // Run the tests.
+        let rng = &mut TestRng::default();
+        let outcomes = package.test::<CurrentAleo, _>(&private_key, rng).unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].is_success(), "{:?}", outcomes[0].failure);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
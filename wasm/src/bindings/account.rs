@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::CurrentNetwork;
+use snarkvm_console::{account, prelude::*};
+
+use core::ops::Deref;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// An account private key.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct PrivateKey {
+    private_key: account::PrivateKey<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, String> {
+        Ok(Self { private_key: account::PrivateKey::new(&mut OsRng).map_err(|error| error.to_string())? })
+    }
+
+    /// Imports a private key from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
+        Ok(Self { private_key: account::PrivateKey::from_str(private_key).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the private key.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.private_key.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, String> {
+        Ok(ViewKey { view_key: account::ViewKey::try_from(&self.private_key).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Ok(Address { address: account::Address::try_from(&self.private_key).map_err(|error| error.to_string())? })
+    }
+}
+
+impl Deref for PrivateKey {
+    type Target = account::PrivateKey<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.private_key
+    }
+}
+
+/// An account view key.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ViewKey {
+    view_key: account::ViewKey<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Imports a view key from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, String> {
+        Ok(Self { view_key: account::ViewKey::from_str(view_key).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the view key.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.view_key.to_string()
+    }
+
+    /// Returns the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address { address: self.view_key.to_address() }
+    }
+}
+
+impl Deref for ViewKey {
+    type Target = account::ViewKey<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.view_key
+    }
+}
+
+/// An account address.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Address {
+    address: account::Address<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Address {
+    /// Imports an address from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, String> {
+        Ok(Self { address: account::Address::from_str(address).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the address.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.address.to_string()
+    }
+}
+
+impl Deref for Address {
+    type Target = account::Address<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.address
+    }
+}
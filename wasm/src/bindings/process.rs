@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::{CurrentAleo, CurrentNetwork, JsQuery, PrivateKey, Program, QueryAdapter};
+use snarkvm_console::{
+    prelude::*,
+    program::{Locator, ProgramID, Value},
+};
+use snarkvm_ledger_block::Execution as ExecutionCore;
+use snarkvm_synthesizer::{process, process_execution_cost};
+
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// An authorization of a function call, which can be executed to produce an execution.
+#[wasm_bindgen]
+pub struct Authorization {
+    authorization: process::Authorization<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Imports an authorization from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(authorization: &str) -> Result<Authorization, String> {
+        Ok(Self { authorization: process::Authorization::from_str(authorization).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the authorization.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.authorization.to_string()
+    }
+}
+
+/// An execution of a function call, including its proof.
+#[wasm_bindgen]
+pub struct Execution {
+    execution: ExecutionCore<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Execution {
+    /// Imports an execution from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(execution: &str) -> Result<Execution, String> {
+        Ok(Self { execution: ExecutionCore::from_str(execution).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the execution.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.execution.to_string()
+    }
+}
+
+/// A process, which holds the programs that may be authorized and executed.
+#[wasm_bindgen]
+pub struct Process {
+    process: process::Process<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Process {
+    /// Initializes a new process, containing the 'credits.aleo' program.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<Process, String> {
+        Ok(Self { process: process::Process::load_web().map_err(|error| error.to_string())? })
+    }
+
+    /// Adds the given program to the process.
+    /// Note: The imports of the program must be added first.
+    #[wasm_bindgen(js_name = addProgram)]
+    pub fn add_program(&mut self, program: &Program) -> Result<(), String> {
+        self.process.add_program(program).map_err(|error| error.to_string())
+    }
+
+    /// Returns `true` if the process contains the program with the given ID.
+    #[wasm_bindgen(js_name = containsProgram)]
+    pub fn contains_program(&self, program_id: &str) -> Result<bool, String> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).map_err(|error| error.to_string())?;
+        Ok(self.process.contains_program(&program_id))
+    }
+
+    /// Authorizes a call to the given function, with the given inputs.
+    pub fn authorize(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        function_name: &str,
+        inputs: Vec<JsValue>,
+    ) -> Result<Authorization, String> {
+        // Parse the inputs.
+        let inputs = inputs
+            .iter()
+            .map(|input| match input.as_string() {
+                Some(input) => Value::<CurrentNetwork>::from_str(&input),
+                None => bail!("The inputs must be strings"),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|error| error.to_string())?;
+        // Authorize the call.
+        let authorization = self
+            .process
+            .authorize::<CurrentAleo, _>(private_key, program_id, function_name, inputs.iter(), &mut OsRng)
+            .map_err(|error| error.to_string())?;
+        Ok(Authorization { authorization })
+    }
+
+    /// Executes the given authorization, and proves it against the ledger state served by the given query.
+    pub fn execute(&self, authorization: &Authorization, query: &JsQuery) -> Result<Execution, String> {
+        self.execute_internal(authorization, query).map_err(|error| error.to_string())
+    }
+
+    /// Returns the *minimum* fee in microcredits to publish the given execution.
+    #[wasm_bindgen(js_name = estimateExecutionFee)]
+    pub fn estimate_execution_fee(&self, execution: &Execution) -> Result<u64, String> {
+        let (cost, _) = process_execution_cost(&self.process, &execution.execution).map_err(|error| error.to_string())?;
+        Ok(cost)
+    }
+}
+
+impl Process {
+    /// Executes the given authorization, and proves it against the ledger state served by the given query.
+    fn execute_internal(&self, authorization: &Authorization, query: &JsQuery) -> Result<Execution> {
+        // Retrieve the main request.
+        let request = authorization.authorization.peek_next()?;
+        // Construct the locator.
+        let locator = Locator::new(*request.program_id(), *request.function_name());
+        // Execute the authorization.
+        let (_, mut trace) =
+            self.process.execute::<CurrentAleo, _>(authorization.authorization.replicate(), &mut OsRng)?;
+        // Prepare the inclusion assignments.
+        trace.prepare(QueryAdapter(query))?;
+        // Prove the execution.
+        let execution = trace.prove_execution::<CurrentAleo, _>(&locator.to_string(), &mut OsRng)?;
+        Ok(Execution { execution })
+    }
+}
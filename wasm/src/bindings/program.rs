@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::CurrentNetwork;
+use snarkvm_console::prelude::*;
+use snarkvm_synthesizer::program;
+
+use core::ops::Deref;
+use wasm_bindgen::prelude::*;
+
+/// An Aleo program.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Program {
+    program: program::Program<CurrentNetwork>,
+}
+
+#[wasm_bindgen]
+impl Program {
+    /// Parses a program from its source code.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(program: &str) -> Result<Program, String> {
+        Ok(Self { program: program::Program::from_str(program).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the source code of the program.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.program.to_string()
+    }
+
+    /// Returns the program ID.
+    pub fn id(&self) -> String {
+        self.program.id().to_string()
+    }
+
+    /// Returns the program IDs of the imports.
+    pub fn imports(&self) -> Vec<JsValue> {
+        self.program.imports().keys().map(|id| JsValue::from_str(&id.to_string())).collect()
+    }
+
+    /// Returns the function names.
+    pub fn functions(&self) -> Vec<JsValue> {
+        self.program.functions().keys().map(|name| JsValue::from_str(&name.to_string())).collect()
+    }
+
+    /// Returns the mapping names.
+    pub fn mappings(&self) -> Vec<JsValue> {
+        self.program.mappings().keys().map(|name| JsValue::from_str(&name.to_string())).collect()
+    }
+}
+
+impl Deref for Program {
+    type Target = program::Program<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.program
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::CurrentNetwork;
+use snarkvm_console::{prelude::*, program::StatePath, types::Field};
+use snarkvm_ledger_query::QueryTrait;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(typescript_custom_section)]
+const QUERY_INTERFACE: &str = r#"
+export interface Query {
+    currentStateRoot(): string;
+    getStatePathForCommitment(commitment: string): string;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A caller-supplied JavaScript object that serves the ledger state used to prove an execution.
+    #[wasm_bindgen(typescript_type = "Query")]
+    pub type JsQuery;
+
+    /// Returns the current state root, as a string.
+    #[wasm_bindgen(method, catch, js_name = currentStateRoot)]
+    fn current_state_root(this: &JsQuery) -> Result<String, JsValue>;
+
+    /// Returns the state path for the given commitment, as a string.
+    #[wasm_bindgen(method, catch, js_name = getStatePathForCommitment)]
+    fn get_state_path_for_commitment(this: &JsQuery, commitment: String) -> Result<String, JsValue>;
+}
+
+/// An adapter that implements `QueryTrait` for a caller-supplied JavaScript query.
+pub(crate) struct QueryAdapter<'a>(pub(crate) &'a JsQuery);
+
+impl QueryTrait<CurrentNetwork> for QueryAdapter<'_> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+        // Fetch the current state root.
+        let state_root =
+            self.0.current_state_root().map_err(|error| anyhow!("Failed to fetch the current state root - {error:?}"))?;
+        // Parse the current state root.
+        <CurrentNetwork as Network>::StateRoot::from_str(&state_root)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<CurrentNetwork>) -> Result<StatePath<CurrentNetwork>> {
+        // Fetch the state path.
+        let state_path = self
+            .0
+            .get_state_path_for_commitment(commitment.to_string())
+            .map_err(|error| anyhow!("Failed to fetch the state path for '{commitment}' - {error:?}"))?;
+        // Parse the state path.
+        StatePath::from_str(&state_path)
+    }
+}
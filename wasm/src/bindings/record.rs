@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::{CurrentNetwork, ViewKey};
+use snarkvm_console::{
+    prelude::*,
+    program::{Ciphertext, Plaintext, Record},
+};
+
+use wasm_bindgen::prelude::*;
+
+/// An encrypted record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordCiphertext {
+    record: Record<CurrentNetwork, Ciphertext<CurrentNetwork>>,
+}
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Imports a record ciphertext from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, String> {
+        Ok(Self { record: Record::from_str(record).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the record ciphertext.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.record.to_string()
+    }
+
+    /// Returns `true` if the given view key belongs to the owner of the record.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.record.is_owner(view_key)
+    }
+
+    /// Decrypts the record with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, String> {
+        Ok(RecordPlaintext { record: self.record.decrypt(view_key).map_err(|error| error.to_string())? })
+    }
+}
+
+/// A decrypted record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordPlaintext {
+    record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+}
+
+#[wasm_bindgen]
+impl RecordPlaintext {
+    /// Imports a record plaintext from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
+        Ok(Self { record: Record::from_str(record).map_err(|error| error.to_string())? })
+    }
+
+    /// Returns the string representation of the record plaintext.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.record.to_string()
+    }
+}
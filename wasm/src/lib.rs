@@ -12,16 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "circuit")]
+pub use snarkvm_circuit as circuit;
 #[cfg(feature = "console")]
 pub use snarkvm_console as console;
 #[cfg(feature = "curves")]
 pub use snarkvm_curves as curves;
 #[cfg(feature = "fields")]
 pub use snarkvm_fields as fields;
+#[cfg(feature = "ledger")]
+pub use snarkvm_ledger_block as ledger_block;
+#[cfg(feature = "ledger")]
+pub use snarkvm_ledger_query as ledger_query;
 #[cfg(feature = "synthesizer")]
 pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 
+#[cfg(all(feature = "circuit", feature = "console", feature = "ledger", feature = "synthesizer"))]
+mod bindings;
+#[cfg(all(feature = "circuit", feature = "console", feature = "ledger", feature = "synthesizer"))]
+pub use bindings::*;
+
 #[cfg(test)]
 mod tests;
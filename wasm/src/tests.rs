@@ -14,11 +14,14 @@
 
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    program::{Plaintext, Record},
+    types::{Field, Scalar},
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
+use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
 
 const ITERATIONS: usize = 1000;
@@ -58,3 +61,118 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+/// A program with a single function, used to test the bindings.
+const PROGRAM: &str = r"
+program bindings.aleo;
+
+function double:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;
+";
+
+#[wasm_bindgen(inline_js = "export function sample_query(state_root) {
+    return {
+        currentStateRoot: () => state_root,
+        getStatePathForCommitment: (commitment) => { throw new Error(`Unknown commitment ${commitment}`); },
+    };
+}")]
+extern "C" {
+    fn sample_query(state_root: &str) -> crate::JsQuery;
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_account() {
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    // Import the account.
+    let private_key = crate::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+    assert_eq!(ALEO_PRIVATE_KEY, private_key.to_js_string());
+    assert_eq!(ALEO_VIEW_KEY, private_key.to_view_key().unwrap().to_js_string());
+    assert_eq!(ALEO_ADDRESS, private_key.to_address().unwrap().to_js_string());
+    assert_eq!(ALEO_ADDRESS, crate::ViewKey::from_string(ALEO_VIEW_KEY).unwrap().to_address().to_js_string());
+    assert!(crate::PrivateKey::from_string(ALEO_VIEW_KEY).is_err());
+
+    // Generate a new account.
+    let private_key = crate::PrivateKey::new().unwrap();
+    let expected = Address::try_from(&*private_key).unwrap();
+    assert_eq!(expected.to_string(), private_key.to_address().unwrap().to_js_string());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_record() {
+    let mut rng = TestRng::default();
+
+    // Sample an account.
+    let private_key = crate::PrivateKey::new().unwrap();
+    let view_key = private_key.to_view_key().unwrap();
+    let address = private_key.to_address().unwrap();
+
+    // Construct and encrypt a record that belongs to the account.
+    let randomizer = Scalar::<Testnet3>::rand(&mut rng);
+    let nonce = Testnet3::g_scalar_multiply(&randomizer);
+    let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+        "{{ owner: {}.private, amount: 100u64.private, _nonce: {nonce}.public }}",
+        address.to_js_string()
+    ))
+    .unwrap();
+    let ciphertext = crate::RecordCiphertext::from_string(&record.encrypt(randomizer).unwrap().to_string()).unwrap();
+
+    // Ensure the record is owned by the account, and decrypts correctly.
+    assert!(ciphertext.is_owner(&view_key));
+    assert_eq!(record.to_string(), ciphertext.decrypt(&view_key).unwrap().to_js_string());
+
+    // Ensure the record is not owned by another account.
+    let other_view_key = crate::PrivateKey::new().unwrap().to_view_key().unwrap();
+    assert!(!ciphertext.is_owner(&other_view_key));
+    assert!(ciphertext.decrypt(&other_view_key).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_program() {
+    // Parse the program.
+    let program = crate::Program::from_string(PROGRAM).unwrap();
+    assert_eq!("bindings.aleo", program.id());
+    assert_eq!(vec!["double".to_string()], program.functions().iter().map(|f| f.as_string().unwrap()).collect::<Vec<_>>());
+    assert!(program.mappings().is_empty());
+    assert!(program.imports().is_empty());
+
+    // Ensure an invalid program fails to parse.
+    assert!(crate::Program::from_string("program bindings.aleo;\n\nfunction:").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_authorize_execute_and_estimate_fee() {
+    // Initialize the process.
+    let mut process = crate::Process::new().unwrap();
+    assert!(process.contains_program("credits.aleo").unwrap());
+
+    // Add the program.
+    let program = crate::Program::from_string(PROGRAM).unwrap();
+    process.add_program(&program).unwrap();
+    assert!(process.contains_program("bindings.aleo").unwrap());
+
+    // Authorize the function call.
+    let private_key = crate::PrivateKey::new().unwrap();
+    let inputs = vec![JsValue::from_str("3u64")];
+    let authorization = process.authorize(&private_key, "bindings.aleo", "double", inputs).unwrap();
+    // Ensure the authorization round-trips through its string representation.
+    let authorization = crate::Authorization::from_string(&authorization.to_js_string()).unwrap();
+
+    // Ensure non-string inputs are rejected.
+    assert!(process.authorize(&private_key, "bindings.aleo", "double", vec![JsValue::from_f64(3.0)]).is_err());
+
+    // Execute the authorization.
+    let state_root = <Testnet3 as Network>::StateRoot::from(Field::from_u64(0));
+    let query = sample_query(&state_root.to_string());
+    let execution = process.execute(&authorization, &query).unwrap();
+    // Ensure the execution round-trips through its string representation.
+    let execution = crate::Execution::from_string(&execution.to_js_string()).unwrap();
+
+    // Estimate the fee.
+    let fee = process.estimate_execution_fee(&execution).unwrap();
+    assert!(fee > 0);
+}